@@ -1,14 +1,15 @@
-use std::collections::HashSet;
-use std::path::Path;
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, OnceLock};
-use std::time::Duration;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
 
 use nix::unistd::Pid;
+use tokio_util::sync::CancellationToken;
 
-use crate::config::CliTool;
+use crate::config::{AgentConfig, CliTool, CustomAgentConfig};
 use crate::types::PhaseResult;
-use crate::{log_debug, log_warn};
+use crate::{log_debug, log_error, log_info, log_warn};
 
 /// Maximum time to wait for graceful shutdown after SIGTERM before sending SIGKILL.
 const SIGTERM_GRACE_PERIOD_SECONDS: u64 = 5;
@@ -16,6 +17,12 @@ const SIGTERM_GRACE_PERIOD_SECONDS: u64 = 5;
 /// Polling interval when waiting for a process group to exit after SIGTERM.
 const KILL_POLL_INTERVAL_MS: u64 = 100;
 
+/// A second SIGINT within this many seconds of the first force-quits.
+const FORCE_QUIT_WINDOW_SECONDS: u64 = 5;
+
+/// Exit code after a force quit (128 + SIGINT), distinct from the usual error exit of 1.
+pub const FORCE_QUIT_EXIT_CODE: i32 = 130;
+
 /// Global shutdown flag shared with signal handlers.
 fn shutdown_flag() -> &'static Arc<AtomicBool> {
     static FLAG: OnceLock<Arc<AtomicBool>> = OnceLock::new();
@@ -27,16 +34,124 @@ pub fn is_shutdown_requested() -> bool {
     shutdown_flag().load(Ordering::Relaxed)
 }
 
-/// Install signal handlers for SIGTERM and SIGINT that set the shutdown flag.
+/// Set by `run --summary-only`, where stdout carries only the JSON summary.
+static STDOUT_RESERVED: AtomicBool = AtomicBool::new(false);
+
+/// Keep agent subprocesses off stdout for the rest of the process: output that
+/// isn't captured to an agent log is discarded instead of inherited.
+pub fn reserve_stdout() {
+    STDOUT_RESERVED.store(true, Ordering::Relaxed);
+}
+
+/// Runtime directory whose stale result files a force quit removes.
+fn force_quit_cleanup_dir() -> &'static Mutex<Option<PathBuf>> {
+    static DIR: OnceLock<Mutex<Option<PathBuf>>> = OnceLock::new();
+    DIR.get_or_init(|| Mutex::new(None))
+}
+
+/// Install signal handlers for SIGTERM and SIGINT.
+///
+/// SIGTERM and a first SIGINT set the shutdown flag (graceful: in-flight
+/// phases finish). A second SIGINT within `FORCE_QUIT_WINDOW_SECONDS` kills
+/// all agent processes, removes stale result files from `runtime_dir`, and
+/// exits with `FORCE_QUIT_EXIT_CODE`.
 ///
-/// Call once at program startup. Subsequent calls are safe (re-registers handlers).
-pub fn install_signal_handlers() -> Result<(), String> {
-    let flag = Arc::clone(shutdown_flag());
-    signal_hook::flag::register(signal_hook::consts::SIGTERM, Arc::clone(&flag))
-        .map_err(|e| format!("Failed to register SIGTERM handler: {}", e))?;
-    signal_hook::flag::register(signal_hook::consts::SIGINT, flag)
-        .map_err(|e| format!("Failed to register SIGINT handler: {}", e))?;
-    Ok(())
+/// Call once at program startup. Subsequent calls are safe: the handler
+/// thread is only started once, and `runtime_dir` is updated.
+pub fn install_signal_handlers(runtime_dir: &Path) -> Result<(), String> {
+    static INSTALLED: OnceLock<Result<(), String>> = OnceLock::new();
+
+    if let Ok(mut dir) = force_quit_cleanup_dir().lock() {
+        *dir = Some(runtime_dir.to_path_buf());
+    }
+
+    INSTALLED
+        .get_or_init(|| {
+            use signal_hook::consts::{SIGINT, SIGTERM};
+
+            let mut signals = signal_hook::iterator::Signals::new([SIGINT, SIGTERM])
+                .map_err(|e| format!("Failed to register signal handlers: {}", e))?;
+            std::thread::Builder::new()
+                .name("signal-handler".to_string())
+                .spawn(move || {
+                    let mut last_interrupt: Option<Instant> = None;
+                    for signal in signals.forever() {
+                        let now = Instant::now();
+                        if signal == SIGINT && is_force_quit(last_interrupt, now) {
+                            force_quit();
+                        }
+                        if signal == SIGINT {
+                            last_interrupt = Some(now);
+                        }
+                        if !shutdown_flag().swap(true, Ordering::Relaxed) {
+                            log_warn!(
+                                "Shutdown requested — finishing in-flight phases \
+                                 (press Ctrl-C again within {}s to force quit)",
+                                FORCE_QUIT_WINDOW_SECONDS
+                            );
+                        } else {
+                            log_warn!(
+                                "Shutdown already in progress (press Ctrl-C twice within {}s to force quit)",
+                                FORCE_QUIT_WINDOW_SECONDS
+                            );
+                        }
+                    }
+                })
+                .map_err(|e| format!("Failed to start signal handler thread: {}", e))?;
+            Ok(())
+        })
+        .clone()
+}
+
+/// Whether a SIGINT at `now` is the second within the force-quit window.
+fn is_force_quit(last_interrupt: Option<Instant>, now: Instant) -> bool {
+    last_interrupt.is_some_and(|at| {
+        now.saturating_duration_since(at) <= Duration::from_secs(FORCE_QUIT_WINDOW_SECONDS)
+    })
+}
+
+/// Kill every agent, clean up result files, and exit without waiting for
+/// in-flight phases.
+fn force_quit() -> ! {
+    log_error!("Force quit requested — killing agent processes and exiting");
+    kill_all_children();
+
+    let dir = force_quit_cleanup_dir()
+        .lock()
+        .ok()
+        .and_then(|dir| dir.clone());
+    if let Some(dir) = dir {
+        remove_result_files(&dir);
+    }
+
+    crate::log::flush_compact_logs();
+    std::process::exit(FORCE_QUIT_EXIT_CODE);
+}
+
+/// Best-effort removal of `phase_result_*.json` files in `dir`.
+fn remove_result_files(dir: &Path) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    let mut removed = 0u32;
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        // NOTE: must match executor::result_file_path() naming convention
+        if name.starts_with("phase_result_")
+            && name.ends_with(".json")
+            && std::fs::remove_file(entry.path()).is_ok()
+        {
+            removed += 1;
+        }
+    }
+    if removed > 0 {
+        log_info!(
+            "[force-quit] Cleaned up {} stale result file(s) from {}",
+            removed,
+            dir.display()
+        );
+    }
 }
 
 // --- Process Registry ---
@@ -120,21 +235,70 @@ pub trait AgentRunner: Send + Sync {
         result_path: &Path,
         timeout: Duration,
     ) -> impl std::future::Future<Output = Result<PhaseResult, String>> + Send;
+
+    /// Like `run_agent`, with the agent started in `workdir` instead of the
+    /// current directory (used by `execution.isolation = "clone"`), and with
+    /// `model` (a phase's `model` override) in place of the configured model
+    /// when set.
+    ///
+    /// Runners that don't spawn a process can keep the default, which ignores
+    /// `workdir` and `model`.
+    fn run_agent_in(
+        &self,
+        prompt: &str,
+        result_path: &Path,
+        timeout: Duration,
+        workdir: &Path,
+        model: Option<&str>,
+    ) -> impl std::future::Future<Output = Result<PhaseResult, String>> + Send {
+        let _ = (workdir, model);
+        self.run_agent(prompt, result_path, timeout)
+    }
 }
 
 /// Real implementation that spawns a CLI agent as a subprocess.
 pub struct CliAgentRunner {
     pub tool: CliTool,
     pub model: Option<String>,
+    /// Command template for `CliTool::Custom`.
+    pub custom: Option<CustomAgentConfig>,
+    /// Extra environment variables set on the spawned agent.
+    pub env: BTreeMap<String, String>,
 }
 
 impl CliAgentRunner {
     pub fn new(tool: CliTool, model: Option<String>) -> Self {
-        Self { tool, model }
+        Self {
+            tool,
+            model,
+            custom: None,
+            env: BTreeMap::new(),
+        }
+    }
+
+    pub fn from_config(agent: &AgentConfig) -> Self {
+        Self {
+            tool: agent.cli.clone(),
+            model: agent.model.clone(),
+            custom: agent.custom.clone(),
+            env: agent.env.clone(),
+        }
     }
 
     /// Verify that the configured CLI tool is available on PATH.
     pub fn verify_cli_available(&self) -> Result<(), String> {
+        if self.tool == CliTool::Custom {
+            let custom = self.custom_config()?;
+            if !is_program_available(&custom.command) {
+                return Err(format!(
+                    "Custom agent command '{}' not found. {}",
+                    custom.command,
+                    self.tool.install_hint()
+                ));
+            }
+            return Ok(());
+        }
+
         let output = std::process::Command::new(self.tool.binary_name())
             .args(self.tool.version_args())
             .output()
@@ -158,6 +322,71 @@ impl CliAgentRunner {
 
         Ok(())
     }
+
+    /// Build the subprocess command for the configured tool, prompt, and model.
+    pub fn build_command(&self, prompt: &str) -> tokio::process::Command {
+        self.build_command_with_model(prompt, None)
+    }
+
+    /// Like `build_command`, with `model` in place of the configured model when set.
+    pub fn build_command_with_model(
+        &self,
+        prompt: &str,
+        model: Option<&str>,
+    ) -> tokio::process::Command {
+        let mut cmd = tokio::process::Command::new(self.tool.binary_name());
+        cmd.args(
+            self.tool
+                .build_args(prompt, model.or(self.model.as_deref())),
+        );
+        cmd.envs(&self.env);
+        cmd
+    }
+
+    /// Build the subprocess command for `CliTool::Custom` from its argument template.
+    pub fn build_custom_command(
+        &self,
+        prompt_file: &Path,
+        result_path: &Path,
+    ) -> Result<tokio::process::Command, String> {
+        self.build_custom_command_with_model(prompt_file, result_path, None)
+    }
+
+    /// Like `build_custom_command`, with `model` in place of the configured model when set.
+    pub fn build_custom_command_with_model(
+        &self,
+        prompt_file: &Path,
+        result_path: &Path,
+        model: Option<&str>,
+    ) -> Result<tokio::process::Command, String> {
+        let custom = self.custom_config()?;
+        let mut cmd = tokio::process::Command::new(&custom.command);
+        cmd.args(custom.build_args(prompt_file, result_path, model.or(self.model.as_deref())));
+        cmd.envs(&self.env);
+        Ok(cmd)
+    }
+
+    fn custom_config(&self) -> Result<&CustomAgentConfig, String> {
+        self.custom
+            .as_ref()
+            .ok_or_else(|| "agent.cli = \"custom\" requires an [agent.custom] table".to_string())
+    }
+}
+
+/// Path of the prompt file handed to custom agents, next to the result file.
+pub fn prompt_file_path(result_path: &Path) -> PathBuf {
+    result_path.with_extension("prompt.md")
+}
+
+/// Whether `program` resolves to a file, either directly (if it contains a '/')
+/// or via a `PATH` lookup.
+fn is_program_available(program: &str) -> bool {
+    if program.contains('/') {
+        return Path::new(program).is_file();
+    }
+    std::env::var_os("PATH")
+        .map(|paths| std::env::split_paths(&paths).any(|dir| dir.join(program).is_file()))
+        .unwrap_or(false)
 }
 
 impl AgentRunner for CliAgentRunner {
@@ -167,9 +396,67 @@ impl AgentRunner for CliAgentRunner {
         result_path: &Path,
         timeout: Duration,
     ) -> Result<PhaseResult, String> {
-        let mut cmd = tokio::process::Command::new(self.tool.binary_name());
-        cmd.args(self.tool.build_args(prompt, self.model.as_deref()));
-        run_subprocess_agent(cmd, result_path, timeout).await
+        self.run_in(prompt, result_path, timeout, None, None).await
+    }
+
+    async fn run_agent_in(
+        &self,
+        prompt: &str,
+        result_path: &Path,
+        timeout: Duration,
+        workdir: &Path,
+        model: Option<&str>,
+    ) -> Result<PhaseResult, String> {
+        self.run_in(prompt, result_path, timeout, Some(workdir), model)
+            .await
+    }
+}
+
+impl CliAgentRunner {
+    async fn run_in(
+        &self,
+        prompt: &str,
+        result_path: &Path,
+        timeout: Duration,
+        workdir: Option<&Path>,
+        model: Option<&str>,
+    ) -> Result<PhaseResult, String> {
+        if self.tool != CliTool::Custom {
+            let mut cmd = self.build_command_with_model(prompt, model);
+            if let Some(dir) = workdir {
+                cmd.current_dir(dir);
+            }
+            let log_path = agent_log_path(result_path);
+            return run_subprocess_agent_with_log(cmd, result_path, timeout, Some(&log_path)).await;
+        }
+
+        // Custom commands read the prompt from a file rather than argv
+        let prompt_file = prompt_file_path(result_path);
+        tokio::fs::write(&prompt_file, prompt).await.map_err(|e| {
+            format!(
+                "Failed to write prompt file {}: {}",
+                prompt_file.display(),
+                e
+            )
+        })?;
+        let log_path = agent_log_path(result_path);
+        let result = match self.build_custom_command_with_model(&prompt_file, result_path, model) {
+            Ok(mut cmd) => {
+                if let Some(dir) = workdir {
+                    cmd.current_dir(dir);
+                }
+                run_subprocess_agent_with_log(cmd, result_path, timeout, Some(&log_path)).await
+            }
+            Err(e) => Err(e),
+        };
+        if let Err(e) = tokio::fs::remove_file(&prompt_file).await {
+            log_warn!(
+                "Warning: Failed to remove prompt file {}: {}",
+                prompt_file.display(),
+                e
+            );
+        }
+        result
     }
 }
 
@@ -181,9 +468,23 @@ impl AgentRunner for CliAgentRunner {
 ///
 /// Note: checks the global `shutdown_flag()` after subprocess completion.
 pub async fn run_subprocess_agent(
+    cmd: tokio::process::Command,
+    result_path: &Path,
+    timeout: Duration,
+) -> Result<PhaseResult, String> {
+    run_subprocess_agent_with_log(cmd, result_path, timeout, None).await
+}
+
+/// Like `run_subprocess_agent`, but when `log_path` is set the child's stdout
+/// and stderr are written to that file (truncated per attempt). At `debug` log
+/// level each line is also logged as it arrives, prefixed with
+/// `agent_output_label`; other levels keep the console quiet. On failure the
+/// last `AGENT_LOG_TAIL_LINES` lines of output are appended to the error.
+pub async fn run_subprocess_agent_with_log(
     mut cmd: tokio::process::Command,
     result_path: &Path,
     timeout: Duration,
+    log_path: Option<&Path>,
 ) -> Result<PhaseResult, String> {
     // Delete stale result file if it exists (unconditional to avoid TOCTOU)
     match tokio::fs::remove_file(result_path).await {
@@ -201,12 +502,35 @@ pub async fn run_subprocess_agent(
         }
     }
 
+    let log_file = match log_path {
+        Some(path) => match open_agent_log(path) {
+            Ok(files) => Some(files),
+            Err(e) => {
+                log_warn!(
+                    "Warning: Could not open agent log {}: {}",
+                    path.display(),
+                    e
+                );
+                None
+            }
+        },
+        None => None,
+    };
+
     // Configure stdio and process group
     // stdin MUST be null — with setpgid the child is in a background process group,
     // and any attempt to read from the terminal would cause SIGTTIN (silent stop).
     cmd.stdin(std::process::Stdio::null());
-    cmd.stdout(std::process::Stdio::inherit());
-    cmd.stderr(std::process::Stdio::inherit());
+    if log_file.is_some() {
+        cmd.stdout(std::process::Stdio::piped());
+        cmd.stderr(std::process::Stdio::piped());
+    } else if STDOUT_RESERVED.load(Ordering::Relaxed) {
+        cmd.stdout(std::process::Stdio::null());
+        cmd.stderr(std::process::Stdio::inherit());
+    } else {
+        cmd.stdout(std::process::Stdio::inherit());
+        cmd.stderr(std::process::Stdio::inherit());
+    }
     cmd.kill_on_drop(true);
 
     // SAFETY: pre_exec runs between fork() and exec() where only async-signal-safe
@@ -233,6 +557,38 @@ pub async fn run_subprocess_agent(
     // Register in process registry
     register_child(pgid);
 
+    let label = agent_output_label(result_path);
+    let capture = log_file
+        .map(|(out_file, err_file)| OutputCapture::start(&mut child, out_file, err_file, &label));
+
+    let outcome = wait_for_agent(&mut child, child_pid, pgid, result_path, timeout).await;
+
+    let Some(capture) = capture else {
+        return outcome;
+    };
+    let tail = capture.finish().await;
+    match outcome {
+        Err(e) if e != "Shutdown requested" && !tail.is_empty() => Err(format!(
+            "{}\n--- last {} lines of agent output ({}) ---\n{}",
+            e,
+            tail.len(),
+            log_path
+                .map(|p| p.display().to_string())
+                .unwrap_or_default(),
+            tail.join("\n")
+        )),
+        other => other,
+    }
+}
+
+/// Wait for the agent with a timeout, then read its result file.
+async fn wait_for_agent(
+    child: &mut tokio::process::Child,
+    child_pid: i32,
+    pgid: Pid,
+    result_path: &Path,
+    timeout: Duration,
+) -> Result<PhaseResult, String> {
     // Wait with timeout
     log_debug!("[agent] Waiting (timeout={}s)...", timeout.as_secs());
     let wait_result = tokio::time::timeout(timeout, child.wait()).await;
@@ -297,6 +653,194 @@ pub async fn run_subprocess_agent(
     }
 }
 
+// --- Agent output capture ---
+
+/// Number of trailing output lines attached to a failed agent's error.
+pub const AGENT_LOG_TAIL_LINES: usize = 20;
+
+/// How long to keep draining output after the agent exits. Bounds the wait when
+/// a stray grandchild still holds the pipes open.
+const OUTPUT_DRAIN_TIMEOUT_MS: u64 = 2000;
+
+/// Per-phase agent log path: `{runtime_dir}/logs/{item_id}_{phase}.log`, derived
+/// from the result path `{runtime_dir}/phase_result_{item_id}_{phase}.json`.
+pub fn agent_log_path(result_path: &Path) -> PathBuf {
+    let stem = result_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let name = stem.strip_prefix("phase_result_").unwrap_or(&stem);
+    let dir = result_path.parent().unwrap_or_else(|| Path::new("."));
+    dir.join("logs").join(format!("{}.log", name))
+}
+
+/// Console prefix for a phase's live agent output: `[{item_id}][{phase}]`,
+/// derived from the result path like `agent_log_path`. Splits at the last `_`:
+/// project prefixes may contain underscores, phase names can't (`config::validate`).
+pub fn agent_output_label(result_path: &Path) -> String {
+    let stem = result_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let name = stem.strip_prefix("phase_result_").unwrap_or(&stem);
+    match name.rsplit_once('_') {
+        Some((item_id, phase)) => format!("[{}][{}]", item_id, phase),
+        None => format!("[{}]", name),
+    }
+}
+
+/// Create the log directory and open the log, truncating output from earlier attempts.
+///
+/// Returns one handle per captured stream (stdout, stderr).
+fn open_agent_log(path: &Path) -> std::io::Result<(std::fs::File, std::fs::File)> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let file = std::fs::File::create(path)?;
+    let clone = file.try_clone()?;
+    Ok((file, clone))
+}
+
+/// Background tasks copying the child's stdout/stderr to the log file (and, at
+/// `debug` level, the logger).
+struct OutputCapture {
+    tasks: Vec<tokio::task::JoinHandle<()>>,
+    tail: Arc<Mutex<VecDeque<String>>>,
+}
+
+impl OutputCapture {
+    fn start(
+        child: &mut tokio::process::Child,
+        out_file: std::fs::File,
+        err_file: std::fs::File,
+        label: &str,
+    ) -> Self {
+        let tail = Arc::new(Mutex::new(VecDeque::new()));
+        let mut tasks = Vec::new();
+        if let Some(stdout) = child.stdout.take() {
+            tasks.push(tokio::spawn(tee_lines(
+                stdout,
+                label.to_string(),
+                tokio::fs::File::from_std(out_file),
+                Arc::clone(&tail),
+            )));
+        }
+        if let Some(stderr) = child.stderr.take() {
+            tasks.push(tokio::spawn(tee_lines(
+                stderr,
+                label.to_string(),
+                tokio::fs::File::from_std(err_file),
+                Arc::clone(&tail),
+            )));
+        }
+        Self { tasks, tail }
+    }
+
+    /// Wait (bounded) for the streams to drain and return the captured tail.
+    async fn finish(self) -> Vec<String> {
+        for mut task in self.tasks {
+            let drain = Duration::from_millis(OUTPUT_DRAIN_TIMEOUT_MS);
+            if tokio::time::timeout(drain, &mut task).await.is_err() {
+                task.abort();
+            }
+        }
+        let tail = self.tail.lock().unwrap_or_else(|e| e.into_inner());
+        tail.iter().cloned().collect()
+    }
+}
+
+/// Copy `reader` line by line to `file`, keeping the last lines in `tail`.
+/// At `debug` level each line is also logged, prefixed with `label`.
+async fn tee_lines<R>(
+    reader: R,
+    label: String,
+    mut file: tokio::fs::File,
+    tail: Arc<Mutex<VecDeque<String>>>,
+) where
+    R: tokio::io::AsyncRead + Unpin,
+{
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
+
+    let mut reader = tokio::io::BufReader::new(reader);
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        match reader.read_until(b'\n', &mut line).await {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {}
+        }
+        let _ = file.write_all(&line).await;
+
+        let text = String::from_utf8_lossy(&line).trim_end().to_string();
+        log_debug!("{} {}", label, text);
+        let mut lines = tail.lock().unwrap_or_else(|e| e.into_inner());
+        if lines.len() == AGENT_LOG_TAIL_LINES {
+            lines.pop_front();
+        }
+        lines.push_back(text);
+    }
+    let _ = file.flush().await;
+}
+
+/// Run a hook command via `sh -c` in `cwd`, in its own process group.
+///
+/// On a nonzero exit, returns the hook's trimmed stderr (or its exit status
+/// when stderr is empty). On cancellation the process group is killed and
+/// `Err("Cancelled")` is returned.
+pub async fn run_hook_command(
+    command: &str,
+    cwd: &Path,
+    cancel: &CancellationToken,
+) -> Result<(), String> {
+    let mut cmd = tokio::process::Command::new("sh");
+    cmd.arg("-c")
+        .arg(command)
+        .current_dir(cwd)
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .kill_on_drop(true);
+
+    // SAFETY: setpgid is async-signal-safe per POSIX (see run_subprocess_agent_with_log).
+    unsafe {
+        cmd.pre_exec(|| {
+            nix::unistd::setpgid(nix::unistd::Pid::from_raw(0), nix::unistd::Pid::from_raw(0))
+                .map_err(std::io::Error::other)?;
+            Ok(())
+        });
+    }
+
+    let child = cmd
+        .spawn()
+        .map_err(|e| format!("Failed to spawn hook: {}", e))?;
+    let child_pid = child
+        .id()
+        .ok_or_else(|| "Failed to get hook PID".to_string())? as i32;
+    let pgid = Pid::from_raw(child_pid);
+    register_child(pgid);
+
+    let output = tokio::select! {
+        output = child.wait_with_output() => output,
+        _ = cancel.cancelled() => {
+            kill_process_group(child_pid).await;
+            unregister_child(pgid);
+            return Err("Cancelled".to_string());
+        }
+    };
+    unregister_child(pgid);
+
+    let output = output.map_err(|e| format!("Error waiting for hook: {}", e))?;
+    if output.status.success() {
+        return Ok(());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+    if stderr.is_empty() {
+        Err(format!("hook exited with {}", output.status))
+    } else {
+        Err(stderr)
+    }
+}
+
 /// Kill a process group by PID. Sends SIGTERM, polls for exit, then SIGKILL if needed.
 ///
 /// The blocking poll-and-sleep loop runs on the tokio blocking thread pool
@@ -432,4 +976,30 @@ mod tests {
 
         set_shutdown_flag_for_testing(false);
     }
+
+    #[test]
+    fn force_quit_only_on_second_interrupt_within_window() {
+        let now = Instant::now();
+        let window = Duration::from_secs(FORCE_QUIT_WINDOW_SECONDS);
+
+        assert!(!is_force_quit(None, now));
+        assert!(is_force_quit(Some(now), now + Duration::from_secs(1)));
+        assert!(is_force_quit(Some(now), now + window));
+        assert!(!is_force_quit(
+            Some(now),
+            now + window + Duration::from_millis(1)
+        ));
+    }
+
+    #[test]
+    fn remove_result_files_keeps_other_files() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("phase_result_WRK-001_build.json"), "{}").unwrap();
+        std::fs::write(dir.path().join("phase-golem.lock"), "").unwrap();
+
+        remove_result_files(dir.path());
+
+        assert!(!dir.path().join("phase_result_WRK-001_build.json").exists());
+        assert!(dir.path().join("phase-golem.lock").exists());
+    }
 }
@@ -1,5 +1,5 @@
-use std::collections::{HashMap, HashSet};
-use std::path::Path;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
 use serde::Deserialize;
 
@@ -12,13 +12,69 @@ pub struct PhaseGolemConfig {
     pub guardrails: GuardrailsConfig,
     pub execution: ExecutionConfig,
     pub agent: AgentConfig,
+    pub git: GitConfig,
+    pub security: SecurityConfig,
     pub pipelines: HashMap<String, PipelineConfig>,
+    /// `[routing]`: item tag → pipeline type. Triage of an item with a mapped
+    /// tag uses that pipeline whatever the agent suggests.
+    pub routing: BTreeMap<String, String>,
 }
 
 #[derive(Deserialize, Clone, Debug, PartialEq)]
 #[serde(default)]
 pub struct ProjectConfig {
     pub prefix: String,
+    /// Other prefixes items in the store use (e.g. `DOC` next to `WRK`).
+    /// Follow-ups of an item with one of these prefixes keep its prefix.
+    pub prefixes: Vec<String>,
+    /// How worklog entries under `_worklog/` are split into files.
+    pub worklog_naming: WorklogNaming,
+}
+
+impl ProjectConfig {
+    /// Prefix for IDs minted on behalf of `item_id` (e.g. its follow-ups):
+    /// the item's own prefix if configured, else `prefix`.
+    pub fn prefix_for(&self, item_id: &str) -> &str {
+        match item_id_prefix(item_id) {
+            Some(own) if self.prefixes.iter().any(|p| p == own) => own,
+            _ => &self.prefix,
+        }
+    }
+}
+
+/// The part of an item ID before its last `-` (`WRK` for `WRK-001`, `tg`
+/// for `tg-a1b2c`). `None` for IDs without one.
+pub fn item_id_prefix(item_id: &str) -> Option<&str> {
+    item_id
+        .rsplit_once('-')
+        .map(|(prefix, _)| prefix)
+        .filter(|prefix| !prefix.is_empty())
+}
+
+/// Worklog file layout under `_worklog/`.
+#[derive(Default, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WorklogNaming {
+    /// One file per calendar month: `YYYY-MM.md`.
+    #[default]
+    PerMonth,
+    /// One file per day: `YYYY-MM-DD.md`.
+    PerDay,
+    /// One file per item: `{item_id}.md`.
+    PerItem,
+    /// Everything in `worklog.md`.
+    Single,
+}
+
+/// Format of phase entries written under `_worklog/`.
+#[derive(Default, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WorklogFormat {
+    /// Readable `## {datetime} — {id} ({title})` sections in `.md` files.
+    #[default]
+    Markdown,
+    /// One JSON object per line in `.jsonl` files.
+    Jsonl,
 }
 
 #[derive(Deserialize, Clone, Debug, PartialEq)]
@@ -27,6 +83,27 @@ pub struct GuardrailsConfig {
     pub max_size: SizeLevel,
     pub max_complexity: DimensionLevel,
     pub max_risk: DimensionLevel,
+    /// Phase outputs reporting a lower `confidence` must go through a review
+    /// phase next, or block for a human check. `None` disables the gate.
+    pub min_confidence: Option<f32>,
+    /// What happens to an item that exceeds the thresholds above when it
+    /// would otherwise become Ready.
+    pub on_violation: GuardrailViolationPolicy,
+}
+
+/// Handling of items exceeding the guardrails (`guardrails.on_violation`).
+#[derive(Default, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum GuardrailViolationPolicy {
+    /// Block the item until a human unblocks it.
+    #[default]
+    Block,
+    /// Send an item triage routed straight to Ready through its pipeline's
+    /// pre-phases instead. Items that already finished scoping, or whose
+    /// pipeline has no pre-phases, are blocked.
+    Scope,
+    /// Log a warning and let the item become Ready.
+    Warn,
 }
 
 #[derive(Deserialize, Clone, Debug, PartialEq)]
@@ -34,9 +111,87 @@ pub struct GuardrailsConfig {
 pub struct ExecutionConfig {
     pub phase_timeout_minutes: u32,
     pub max_retries: u32,
+    /// Delay before the first retry of a failed phase; doubles per retry. 0 disables.
+    pub retry_backoff_seconds: u64,
+    /// Upper bound for the doubling retry delay.
+    pub retry_backoff_max_seconds: u64,
     pub default_phase_cap: u32,
     pub max_wip: u32,
     pub max_concurrent: u32,
+    /// Also append each serialized `PhaseResult` to `_worklog/YYYY-MM.jsonl`.
+    pub worklog_include_full_result: bool,
+    /// Markdown (default) or JSONL worklog entries.
+    pub worklog_format: WorklogFormat,
+    /// Cap on actions processed per scheduler loop pass. `None` = unlimited.
+    pub max_actions_per_iteration: Option<u32>,
+    /// Upper bound on the scheduler's idle wait when nothing is running.
+    pub idle_poll_ms: u64,
+    /// Skip follow-ups whose title matches a New item or an active item
+    /// already spawned from the same origin item. Also read as `follow_up_dedup`.
+    #[serde(alias = "follow_up_dedup")]
+    pub dedup_follow_ups: bool,
+    /// Word-overlap similarity (0.0–1.0] at which a follow-up title counts as a
+    /// duplicate. Titles equal up to case and whitespace always do.
+    pub follow_up_similarity: f64,
+    /// Where destructive phases run: in the main checkout, or in a disposable clone.
+    pub isolation: Isolation,
+    /// `triage` stops after this many agent failures in a row.
+    pub triage_failure_threshold: u32,
+    /// Commit each item's destructive phase outputs on its own
+    /// `phase-golem/{item_id}` branch instead of the current branch.
+    pub branch_per_item: bool,
+    /// What happens to an item whose pre-phase reports `BLOCKED`.
+    pub on_prephase_block: PrePhaseBlockPolicy,
+    /// Block an item instead of running its destructive phase when files
+    /// outside phase-golem's own paths are dirty.
+    pub guard_external_dirty: bool,
+    /// Consecutive retry exhaustions before `run` halts; 0 disables the
+    /// circuit breaker.
+    ///
+    /// In targeted runs only exhaustions on the targets themselves count, so
+    /// churn on other items (e.g. dependencies pulled in by `--wait-deps`)
+    /// can't halt the run.
+    pub circuit_breaker_threshold: u32,
+    /// Write each `run`'s scheduler events to `.phase-golem/runs/{timestamp}.jsonl`.
+    pub event_log: bool,
+    /// Items whose deadline is at most this many days away are scheduled
+    /// ahead of impact and priority (overdue items first of all).
+    pub deadline_window_days: u32,
+    /// Write `.phase-golem/metrics.prom` (Prometheus text format) after each
+    /// `run`, for the node exporter textfile collector.
+    pub metrics_file: bool,
+    /// Move items to the archive when they reach Done. When false they stay
+    /// in the active store as Done (and get no archive worklog entry).
+    pub archive_on_done: bool,
+    /// Shell command run (via `sh -c`, in the phase's working tree) after an
+    /// agent reports `PHASE_COMPLETE` and before the phase is staged or
+    /// committed. `{item_id}` and `{phase}` are substituted. A nonzero exit
+    /// fails the attempt with the hook's stderr as the reason.
+    pub post_phase_hook: Option<String>,
+}
+
+/// Workspace for destructive phases (`execution.isolation`).
+#[derive(Default, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Isolation {
+    /// Run in the main checkout.
+    #[default]
+    None,
+    /// Run in a shallow temporary clone; on success the clone's changes are
+    /// applied back to the main checkout and the clone is removed.
+    Clone,
+}
+
+/// Handling of items blocked during a pre-phase (`execution.on_prephase_block`).
+#[derive(Default, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PrePhaseBlockPolicy {
+    /// Block the item until a human unblocks it.
+    #[default]
+    Block,
+    /// Send the item back to `New` so triage assesses it again. An item that
+    /// blocks a second time in the same run is blocked instead.
+    ReturnToNew,
 }
 
 #[derive(Default, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -45,6 +200,9 @@ pub enum CliTool {
     #[default]
     Claude,
     OpenCode,
+    Gemini,
+    /// User-defined command from the `[agent.custom]` table.
+    Custom,
 }
 
 impl CliTool {
@@ -52,6 +210,9 @@ impl CliTool {
         match self {
             CliTool::Claude => "claude",
             CliTool::OpenCode => "opencode",
+            CliTool::Gemini => "gemini",
+            // Placeholder: the real program comes from `AgentConfig::binary_name`.
+            CliTool::Custom => "custom",
         }
     }
 
@@ -59,6 +220,8 @@ impl CliTool {
         match self {
             CliTool::Claude => "Claude CLI",
             CliTool::OpenCode => "OpenCode CLI",
+            CliTool::Gemini => "Gemini CLI",
+            CliTool::Custom => "Custom agent command",
         }
     }
 
@@ -84,6 +247,18 @@ impl CliTool {
                 args.push(prompt.to_string());
                 args
             }
+            CliTool::Gemini => {
+                let mut args = vec!["--yolo".to_string()];
+                if let Some(m) = model {
+                    args.push("--model".to_string());
+                    args.push(m.to_string());
+                }
+                args.push("--prompt".to_string());
+                args.push(prompt.to_string());
+                args
+            }
+            // Custom commands are built from `CustomAgentConfig::build_args`.
+            CliTool::Custom => Vec::new(),
         }
     }
 
@@ -91,6 +266,8 @@ impl CliTool {
         match self {
             CliTool::Claude => vec!["--version"],
             CliTool::OpenCode => vec!["--version"],
+            CliTool::Gemini => vec!["--version"],
+            CliTool::Custom => Vec::new(),
         }
     }
 
@@ -98,6 +275,8 @@ impl CliTool {
         match self {
             CliTool::Claude => "Install: https://docs.anthropic.com/en/docs/claude-code",
             CliTool::OpenCode => "Install: https://github.com/opencode-ai/opencode",
+            CliTool::Gemini => "Install: https://github.com/google-gemini/gemini-cli",
+            CliTool::Custom => "Check agent.custom.command in phase-golem.toml",
         }
     }
 }
@@ -107,6 +286,79 @@ impl CliTool {
 pub struct AgentConfig {
     pub cli: CliTool,
     pub model: Option<String>,
+    /// Command template used when `cli = "custom"`.
+    pub custom: Option<CustomAgentConfig>,
+    /// Extra environment variables set on every spawned agent (`[agent.env]`).
+    pub env: BTreeMap<String, String>,
+}
+
+impl AgentConfig {
+    /// Program to spawn: the custom command for `cli = "custom"`, else the tool's binary.
+    pub fn binary_name(&self) -> &str {
+        match (&self.cli, &self.custom) {
+            (CliTool::Custom, Some(custom)) => &custom.command,
+            _ => self.cli.binary_name(),
+        }
+    }
+}
+
+/// Placeholder substituted with the path of the file holding the prompt.
+pub const PROMPT_FILE_PLACEHOLDER: &str = "{prompt_file}";
+/// Placeholder substituted with the path the agent must write its result JSON to.
+pub const RESULT_FILE_PLACEHOLDER: &str = "{result_file}";
+/// Placeholder substituted with `agent.model`, or an empty string when unset.
+pub const MODEL_PLACEHOLDER: &str = "{model}";
+
+#[derive(Default, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct CustomAgentConfig {
+    /// Program to run (resolved on PATH unless it contains a '/').
+    pub command: String,
+    /// Argument template; `{prompt_file}`, `{result_file}`, and `{model}` are substituted.
+    pub args: Vec<String>,
+}
+
+impl CustomAgentConfig {
+    pub fn build_args(
+        &self,
+        prompt_file: &Path,
+        result_file: &Path,
+        model: Option<&str>,
+    ) -> Vec<String> {
+        let prompt_file = prompt_file.to_string_lossy();
+        let result_file = result_file.to_string_lossy();
+        self.args
+            .iter()
+            .map(|arg| {
+                arg.replace(PROMPT_FILE_PLACEHOLDER, &prompt_file)
+                    .replace(RESULT_FILE_PLACEHOLDER, &result_file)
+                    .replace(MODEL_PLACEHOLDER, model.unwrap_or(""))
+            })
+            .collect()
+    }
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct GitConfig {
+    /// When true, `run` and `triage` fail fast if `remote` is unreachable.
+    pub verify_remote: bool,
+    /// Remote name or URL used by `verify_remote` and `push_on_complete`.
+    pub remote: String,
+    /// When true, `run` pushes to `remote` after a successful run.
+    pub push_on_complete: bool,
+    /// Branch to push. `None` pushes the current branch (`HEAD`).
+    pub branch: Option<String>,
+    /// When true, a failed push fails the run instead of only logging a warning.
+    pub require_push: bool,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct SecurityConfig {
+    /// Keys whose values are masked (case-insensitive, at any depth) in
+    /// anything serialized for output, such as the `--summary-only` JSON.
+    pub redact_keys: Vec<String>,
 }
 
 #[derive(Default, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -129,12 +381,42 @@ pub struct PhaseConfig {
     pub is_destructive: bool,
     #[serde(default)]
     pub staleness: StalenessAction,
+    /// Overrides `execution.phase_timeout_minutes` for this phase.
+    #[serde(default)]
+    pub timeout_minutes: Option<u32>,
+    /// Overrides `guardrails.min_confidence` for this phase's output.
+    #[serde(default)]
+    pub min_confidence: Option<f32>,
+    /// Treat follow-ups reported by this phase as blocking: the item is blocked
+    /// instead of advancing. The follow-ups are still ingested.
+    #[serde(default)]
+    pub followups_block: bool,
+    /// Commit the item store once this phase completes, so the repo has a
+    /// restore point that includes the item's updated state.
+    #[serde(default)]
+    pub checkpoint: bool,
+    /// Skip this phase for items matching a filter expression (same grammar
+    /// as `run --only`, e.g. `"size=small"`). The item advances as if the
+    /// phase had completed.
+    #[serde(default)]
+    pub skip_if: Option<String>,
+    /// Overrides `agent.model` for this phase's agent runs.
+    #[serde(default)]
+    pub model: Option<String>,
+    /// A `Failed` result is a verdict on the item rather than a transient
+    /// failure: it is not retried, and the item is blocked and tagged
+    /// `validation-failed`.
+    #[serde(default)]
+    pub is_validation: bool,
 }
 
 impl PhaseConfig {
     /// Construct a PhaseConfig with sensible defaults for workflows and staleness.
     ///
-    /// Defaults: `workflows` = `vec![]`, `staleness` = `StalenessAction::Ignore`.
+    /// Defaults: `workflows` = `vec![]`, `staleness` = `StalenessAction::Ignore`,
+    /// `timeout_minutes` = `None` (use the global timeout), `min_confidence` = `None`
+    /// (use the guardrail), `followups_block` = `false`, `checkpoint` = `false`,
+    /// `skip_if` = `None`, `model` = `None` (use `agent.model`), `is_validation` = `false`.
     /// These match the `#[serde(default)]` field attributes on the struct
     /// to keep programmatic and deserialized configs consistent.
     pub fn new(name: &str, is_destructive: bool) -> Self {
@@ -143,8 +425,28 @@ impl PhaseConfig {
             workflows: vec![],
             is_destructive,
             staleness: StalenessAction::Ignore,
+            timeout_minutes: None,
+            min_confidence: None,
+            followups_block: false,
+            checkpoint: false,
+            skip_if: None,
+            model: None,
+            is_validation: false,
         }
     }
+
+    /// Timeout for this phase in minutes: the per-phase override if set,
+    /// otherwise `execution.phase_timeout_minutes`.
+    pub fn effective_timeout_minutes(&self, execution: &ExecutionConfig) -> u32 {
+        self.timeout_minutes
+            .unwrap_or(execution.phase_timeout_minutes)
+    }
+
+    /// Model for this phase's agent: the per-phase override if set, otherwise
+    /// `agent.model` (`None` lets the CLI pick its default).
+    pub fn effective_model<'a>(&'a self, agent: &'a AgentConfig) -> Option<&'a str> {
+        self.model.as_deref().or(agent.model.as_deref())
+    }
 }
 
 #[derive(Default, Deserialize, Clone, Debug, PartialEq)]
@@ -152,12 +454,19 @@ impl PhaseConfig {
 pub struct PipelineConfig {
     pub pre_phases: Vec<PhaseConfig>,
     pub phases: Vec<PhaseConfig>,
+    /// Limit on InProgress items of this pipeline. Capped at `execution.max_wip`.
+    pub max_wip: Option<u32>,
+    /// Limit on concurrently running phases of this pipeline. Capped at
+    /// `execution.max_concurrent`.
+    pub max_concurrent: Option<u32>,
 }
 
 impl Default for ProjectConfig {
     fn default() -> Self {
         Self {
             prefix: "WRK".to_string(),
+            prefixes: Vec::new(),
+            worklog_naming: WorklogNaming::default(),
         }
     }
 }
@@ -168,6 +477,31 @@ impl Default for GuardrailsConfig {
             max_size: SizeLevel::Medium,
             max_complexity: DimensionLevel::Medium,
             max_risk: DimensionLevel::Low,
+            min_confidence: None,
+            on_violation: GuardrailViolationPolicy::Block,
+        }
+    }
+}
+
+impl Default for GitConfig {
+    fn default() -> Self {
+        Self {
+            verify_remote: false,
+            remote: "origin".to_string(),
+            push_on_complete: false,
+            branch: None,
+            require_push: false,
+        }
+    }
+}
+
+impl Default for SecurityConfig {
+    fn default() -> Self {
+        Self {
+            redact_keys: ["token", "webhook_url", "api_key", "password", "secret"]
+                .iter()
+                .map(|k| k.to_string())
+                .collect(),
         }
     }
 }
@@ -177,9 +511,28 @@ impl Default for ExecutionConfig {
         Self {
             phase_timeout_minutes: 30,
             max_retries: 2,
+            retry_backoff_seconds: 0,
+            retry_backoff_max_seconds: 300,
             default_phase_cap: 100,
             max_wip: 1,
             max_concurrent: 1,
+            worklog_include_full_result: false,
+            worklog_format: WorklogFormat::Markdown,
+            max_actions_per_iteration: None,
+            idle_poll_ms: 10,
+            dedup_follow_ups: true,
+            follow_up_similarity: 1.0,
+            isolation: Isolation::None,
+            triage_failure_threshold: 3,
+            branch_per_item: false,
+            on_prephase_block: PrePhaseBlockPolicy::Block,
+            guard_external_dirty: false,
+            circuit_breaker_threshold: 2,
+            event_log: false,
+            deadline_window_days: 3,
+            metrics_file: false,
+            archive_on_done: true,
+            post_phase_hook: None,
         }
     }
 }
@@ -227,6 +580,8 @@ pub fn default_feature_pipeline() -> PipelineConfig {
                 ..PhaseConfig::new("review", false)
             },
         ],
+        max_wip: None,
+        max_concurrent: None,
     }
 }
 
@@ -241,9 +596,127 @@ pub fn normalize_agent_config(config: &mut PhaseGolemConfig) {
     }
 }
 
+/// Parse a `run --agent` value. Only the built-in tools are selectable;
+/// `custom` needs its `[agent.custom]` table and stays config-only.
+pub fn parse_cli_tool(s: &str) -> Result<CliTool, String> {
+    match s.to_lowercase().as_str() {
+        "claude" => Ok(CliTool::Claude),
+        "opencode" => Ok(CliTool::OpenCode),
+        "gemini" => Ok(CliTool::Gemini),
+        _ => Err(format!(
+            "Invalid agent '{}': expected claude, opencode, or gemini",
+            s
+        )),
+    }
+}
+
+/// Apply `run --agent`/`--model` on top of the loaded config.
+///
+/// Switching to a different CLI without `--model` drops the configured model,
+/// since model names are specific to each tool.
+pub fn apply_agent_overrides(
+    config: &mut PhaseGolemConfig,
+    cli: Option<CliTool>,
+    model: Option<String>,
+) -> Result<(), String> {
+    if let Some(cli) = cli {
+        if cli != config.agent.cli && model.is_none() {
+            config.agent.model = None;
+        }
+        config.agent.cli = cli;
+    }
+    if model.is_some() {
+        config.agent.model = model;
+    }
+    normalize_agent_config(config);
+
+    validate(config).map_err(|errors| {
+        format!(
+            "Invalid agent override:\n{}",
+            errors
+                .iter()
+                .map(|e| format!("  - {}", e))
+                .collect::<Vec<_>>()
+                .join("\n")
+        )
+    })
+}
+
+/// Whether `key` is a portable environment variable name: a letter or
+/// underscore followed by letters, digits, or underscores.
+pub fn is_valid_env_key(key: &str) -> bool {
+    let mut chars = key.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Parse repeated `--agent-env KEY=VALUE` flags. The value may be empty or
+/// contain `=`; later flags for the same key win. Every malformed entry is
+/// reported at once.
+pub fn parse_agent_env(entries: &[String]) -> Result<BTreeMap<String, String>, String> {
+    let mut env = BTreeMap::new();
+    let mut errors = Vec::new();
+    for entry in entries {
+        match entry.split_once('=') {
+            Some((key, value)) if is_valid_env_key(key) => {
+                env.insert(key.to_string(), value.to_string());
+            }
+            _ => errors.push(format!(
+                "Invalid --agent-env '{}': expected KEY=VALUE with KEY made of letters, digits, and '_'",
+                entry
+            )),
+        }
+    }
+    if errors.is_empty() {
+        Ok(env)
+    } else {
+        Err(errors.join("\n"))
+    }
+}
+
+/// Whether `prefix` is safe in item IDs, result-file names, and change dirs:
+/// non-empty, ASCII alphanumeric, hyphen, or underscore only.
+pub fn is_valid_prefix(prefix: &str) -> bool {
+    !prefix.is_empty()
+        && prefix
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Why `model` can't be passed to an agent CLI as a `--model` value, if it can't.
+fn invalid_model_reason(model: &str) -> Option<&'static str> {
+    let is_valid = !model.is_empty()
+        && model
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '/' | '-'));
+    if !is_valid {
+        Some("contains invalid characters (allowed: alphanumeric, '.', '_', '/', '-')")
+    } else if model.starts_with('-') {
+        Some("must not start with '-' (flag-like values are rejected)")
+    } else {
+        None
+    }
+}
+
 pub fn validate(config: &PhaseGolemConfig) -> Result<(), Vec<String>> {
     let mut errors = Vec::new();
 
+    if !is_valid_prefix(&config.project.prefix) {
+        errors.push(format!(
+            "project.prefix '{}' must contain only alphanumeric characters, hyphens, and underscores",
+            config.project.prefix
+        ));
+    }
+
+    for prefix in &config.project.prefixes {
+        if !is_valid_prefix(prefix) {
+            errors.push(format!(
+                "project.prefixes entry '{}' must contain only alphanumeric characters, hyphens, and underscores",
+                prefix
+            ));
+        }
+    }
+
     if config.execution.max_wip < 1 {
         errors.push("execution.max_wip must be >= 1".to_string());
     }
@@ -252,19 +725,90 @@ pub fn validate(config: &PhaseGolemConfig) -> Result<(), Vec<String>> {
         errors.push("execution.max_concurrent must be >= 1".to_string());
     }
 
-    if let Some(ref model) = config.agent.model {
-        let is_valid = !model.is_empty()
-            && model
-                .chars()
-                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '/' | '-'));
-        if !is_valid {
+    if let Some(threshold) = config.guardrails.min_confidence {
+        if !(0.0..=1.0).contains(&threshold) {
+            errors.push("guardrails.min_confidence must be between 0.0 and 1.0".to_string());
+        }
+    }
+
+    let similarity = config.execution.follow_up_similarity;
+    if !(similarity > 0.0 && similarity <= 1.0) {
+        errors.push("execution.follow_up_similarity must be > 0.0 and <= 1.0".to_string());
+    }
+
+    if config.execution.triage_failure_threshold < 1 {
+        errors.push("execution.triage_failure_threshold must be >= 1".to_string());
+    }
+
+    if config
+        .execution
+        .post_phase_hook
+        .as_deref()
+        .is_some_and(|hook| hook.trim().is_empty())
+    {
+        errors.push("execution.post_phase_hook must not be empty when set".to_string());
+    }
+
+    if config.execution.max_actions_per_iteration == Some(0) {
+        errors.push("execution.max_actions_per_iteration must be >= 1 when set".to_string());
+    }
+
+    if let Some(reason) = config.agent.model.as_deref().and_then(invalid_model_reason) {
+        errors.push(format!("agent.model {}", reason));
+    }
+
+    for key in config.agent.env.keys() {
+        if !is_valid_env_key(key) {
+            errors.push(format!(
+                "agent.env key '{}' is not a valid environment variable name",
+                key
+            ));
+        }
+    }
+
+    if config.agent.cli == CliTool::Custom {
+        match config.agent.custom {
+            None => errors.push(
+                "agent.cli = \"custom\" requires an [agent.custom] table with command and args"
+                    .to_string(),
+            ),
+            Some(ref custom) => {
+                if custom.command.trim().is_empty() {
+                    errors.push("agent.custom.command must not be empty".to_string());
+                }
+                if !custom
+                    .args
+                    .iter()
+                    .any(|arg| arg.contains(RESULT_FILE_PLACEHOLDER))
+                {
+                    errors.push(format!(
+                        "agent.custom.args must reference {} (phase results are read from that file)",
+                        RESULT_FILE_PLACEHOLDER
+                    ));
+                }
+            }
+        }
+    }
+
+    if config.git.verify_remote || config.git.push_on_complete {
+        if config.git.remote.trim().is_empty() {
             errors.push(
-                "agent.model contains invalid characters (allowed: alphanumeric, '.', '_', '/', '-')"
+                "git.remote must not be empty when git.verify_remote or git.push_on_complete is true"
                     .to_string(),
             );
-        } else if model.starts_with('-') {
+        } else if config.git.remote.starts_with('-') {
             errors.push(
-                "agent.model must not start with '-' (flag-like values are rejected)".to_string(),
+                "git.remote must not start with '-' (flag-like values are rejected)".to_string(),
+            );
+        }
+    }
+
+    if let Some(ref branch) = config.git.branch {
+        if branch.trim().is_empty() {
+            errors.push("git.branch must not be empty when set".to_string());
+        } else if branch.starts_with('-') {
+            errors.push(
+                "git.branch must not start with '-' (flag-like values are rejected)".to_string(),
             );
         }
     }
@@ -288,6 +832,61 @@ pub fn validate(config: &PhaseGolemConfig) -> Result<(), Vec<String>> {
             }
         }
 
+        for phase in pipeline.pre_phases.iter().chain(pipeline.phases.iter()) {
+            // Result and log file names join the item ID and phase with '_',
+            // and the agent output label splits them at the last one
+            if phase.name.contains('_') {
+                errors.push(format!(
+                    "pipelines.{}: phase name '{}' must not contain '_' (use '-' instead)",
+                    pipeline_name, phase.name
+                ));
+            }
+            if phase.timeout_minutes == Some(0) {
+                errors.push(format!(
+                    "pipelines.{}: phase '{}' timeout_minutes must be >= 1 when set",
+                    pipeline_name, phase.name
+                ));
+            }
+            if let Some(threshold) = phase.min_confidence {
+                if !(0.0..=1.0).contains(&threshold) {
+                    errors.push(format!(
+                        "pipelines.{}: phase '{}' min_confidence must be between 0.0 and 1.0",
+                        pipeline_name, phase.name
+                    ));
+                }
+            }
+            if let Some(ref expr) = phase.skip_if {
+                if let Err(e) = crate::filter::parse_filter(expr) {
+                    errors.push(format!(
+                        "pipelines.{}: phase '{}' skip_if is invalid: {}",
+                        pipeline_name, phase.name, e
+                    ));
+                }
+            }
+            if phase.is_validation && phase.is_destructive {
+                errors.push(format!(
+                    "pipelines.{}: validation phase '{}' cannot be destructive",
+                    pipeline_name, phase.name
+                ));
+            }
+            if let Some(reason) = phase.model.as_deref().and_then(invalid_model_reason) {
+                errors.push(format!(
+                    "pipelines.{}: phase '{}' model {}",
+                    pipeline_name, phase.name, reason
+                ));
+            }
+        }
+
+        if pipeline.max_wip == Some(0) {
+            errors.push(format!("pipelines.{}: max_wip must be >= 1", pipeline_name));
+        }
+        if pipeline.max_concurrent == Some(0) {
+            errors.push(format!(
+                "pipelines.{}: max_concurrent must be >= 1",
+                pipeline_name
+            ));
+        }
+
         // destructive rejected on pre_phases
         for phase in &pipeline.pre_phases {
             if phase.is_destructive {
@@ -311,6 +910,15 @@ pub fn validate(config: &PhaseGolemConfig) -> Result<(), Vec<String>> {
         }
     }
 
+    for (tag, pipeline_type) in &config.routing {
+        if !config.pipelines.contains_key(pipeline_type) {
+            errors.push(format!(
+                "routing.{}: pipeline '{}' is not defined",
+                tag, pipeline_type
+            ));
+        }
+    }
+
     if errors.is_empty() {
         Ok(())
     } else {
@@ -318,7 +926,34 @@ pub fn validate(config: &PhaseGolemConfig) -> Result<(), Vec<String>> {
     }
 }
 
-/// Load config from an explicit path (if provided) or fall back to `{project_root}/phase-golem.toml`.
+/// Config file names looked for in a directory, in order of preference.
+pub const CONFIG_FILE_NAMES: [&str; 3] =
+    ["phase-golem.toml", "phase-golem.yaml", "phase-golem.yml"];
+
+/// The first of `CONFIG_FILE_NAMES` that exists in `dir`.
+fn config_file_in(dir: &Path) -> Option<PathBuf> {
+    CONFIG_FILE_NAMES
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.is_file())
+}
+
+/// Parse config file contents: YAML for `.yaml`/`.yml` paths, TOML otherwise.
+fn parse_config(path: &Path, contents: &str) -> Result<PhaseGolemConfig, String> {
+    let is_yaml = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml"));
+    if is_yaml {
+        serde_yaml_ng::from_str(contents)
+            .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))
+    } else {
+        toml::from_str(contents).map_err(|e| format!("Failed to parse {}: {}", path.display(), e))
+    }
+}
+
+/// Load config from an explicit path (if provided) or fall back to `{project_root}/phase-golem.toml`
+/// (or `phase-golem.yaml`/`.yml`).
 ///
 /// When `config_path` is `Some`, the file MUST exist — returns an error if missing.
 /// When `config_path` is `None`, delegates to `load_config` (returns defaults if missing).
@@ -332,6 +967,46 @@ pub fn load_config_from(
     }
 }
 
+/// Resolve the config file and the base directory for config-relative paths
+/// (workflow files).
+///
+/// An explicit `--config` path is used as-is. Otherwise, if `project_root` has no config
+/// file (see `CONFIG_FILE_NAMES`), walks up toward the enclosing git repository root looking for one, so
+/// commands work from a project subdirectory. Returns `(None, project_root)` when
+/// nothing is found, which falls back to `load_config` defaults.
+pub fn resolve_config_location(
+    explicit: Option<&Path>,
+    project_root: &Path,
+) -> (Option<PathBuf>, PathBuf) {
+    if let Some(path) = explicit {
+        let base = path.parent().unwrap_or(Path::new(".")).to_path_buf();
+        return (Some(path.to_path_buf()), base);
+    }
+    match discover_config(project_root) {
+        Some(path) => {
+            let base = path.parent().unwrap_or(project_root).to_path_buf();
+            (Some(path), base)
+        }
+        None => (None, project_root.to_path_buf()),
+    }
+}
+
+/// Search `project_root`'s ancestors, up to and including the git repository root,
+/// for a config file. Returns `None` when one is at `project_root` itself
+/// (the default location) or when `project_root` is not inside a git repository.
+fn discover_config(project_root: &Path) -> Option<PathBuf> {
+    if config_file_in(project_root).is_some() {
+        return None;
+    }
+    let start = project_root.canonicalize().ok()?;
+    let git_root = start.ancestors().find(|dir| dir.join(".git").exists())?;
+    start
+        .ancestors()
+        .skip(1)
+        .take_while(|dir| dir.starts_with(git_root))
+        .find_map(config_file_in)
+}
+
 /// Load config from a specific file path. Errors if the file does not exist.
 fn load_config_at(path: &Path) -> Result<PhaseGolemConfig, String> {
     if !path.exists() {
@@ -341,8 +1016,7 @@ fn load_config_at(path: &Path) -> Result<PhaseGolemConfig, String> {
     let contents = std::fs::read_to_string(path)
         .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
 
-    let mut config: PhaseGolemConfig = toml::from_str(&contents)
-        .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+    let mut config = parse_config(path, &contents)?;
 
     normalize_agent_config(&mut config);
     populate_default_pipelines(&mut config);
@@ -361,36 +1035,17 @@ fn load_config_at(path: &Path) -> Result<PhaseGolemConfig, String> {
     Ok(config)
 }
 
+/// Load the config file in `project_root` (TOML preferred over YAML, see
+/// `CONFIG_FILE_NAMES`), or defaults when there is none.
 pub fn load_config(project_root: &Path) -> Result<PhaseGolemConfig, String> {
-    let config_path = project_root.join("phase-golem.toml");
-
-    if !config_path.exists() {
-        let mut config = PhaseGolemConfig::default();
-        populate_default_pipelines(&mut config);
-        return Ok(config);
+    match config_file_in(project_root) {
+        Some(config_path) => load_config_at(&config_path),
+        None => {
+            let mut config = PhaseGolemConfig::default();
+            populate_default_pipelines(&mut config);
+            Ok(config)
+        }
     }
-
-    let contents = std::fs::read_to_string(&config_path)
-        .map_err(|e| format!("Failed to read {}: {}", config_path.display(), e))?;
-
-    let mut config: PhaseGolemConfig = toml::from_str(&contents)
-        .map_err(|e| format!("Failed to parse {}: {}", config_path.display(), e))?;
-
-    normalize_agent_config(&mut config);
-    populate_default_pipelines(&mut config);
-
-    validate(&config).map_err(|errors| {
-        format!(
-            "Config validation failed:\n{}",
-            errors
-                .iter()
-                .map(|e| format!("  - {}", e))
-                .collect::<Vec<_>>()
-                .join("\n")
-        )
-    })?;
-
-    Ok(config)
 }
 
 fn populate_default_pipelines(config: &mut PhaseGolemConfig) {
@@ -1,14 +1,18 @@
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Duration;
 
 use task_golem::model::item::Item;
 use task_golem::store::Store;
-use tokio::sync::{mpsc, oneshot};
+use tokio::sync::{mpsc, oneshot, Notify};
 
+use crate::config::{WorklogFormat, WorklogNaming};
 use crate::git::StatusEntry;
 use crate::pg_error::PgError;
 use crate::pg_item::{self, PgItem};
-use crate::types::{FollowUp, ItemStatus, ItemUpdate, PhaseResult, StructuredDescription};
+use crate::types::{
+    FollowUp, ItemStatus, ItemUpdate, PhaseHistoryEntry, PhaseResult, StructuredDescription,
+};
 use crate::{log_error, log_warn};
 
 // --- Aliases for task-golem git module (distinguished from phase-golem's own git) ---
@@ -35,9 +39,28 @@ pub enum CoordinatorCommand {
     BatchCommit {
         reply: oneshot::Sender<Result<(), PgError>>,
     },
+    CommitCheckpoint {
+        item_id: String,
+        phase: String,
+        reply: oneshot::Sender<Result<(), PgError>>,
+    },
+    ApplyPatch {
+        patch: String,
+        reply: oneshot::Sender<Result<(), PgError>>,
+    },
+    EnterItemBranch {
+        item_id: String,
+        reply: oneshot::Sender<Result<String, PgError>>,
+    },
+    LeaveItemBranch {
+        reply: oneshot::Sender<Result<(), PgError>>,
+    },
     GetHeadSha {
         reply: oneshot::Sender<Result<String, PgError>>,
     },
+    UnexpectedDirtyPaths {
+        reply: oneshot::Sender<Result<Vec<String>, PgError>>,
+    },
     IsAncestor {
         sha: String,
         reply: oneshot::Sender<Result<bool, PgError>>,
@@ -53,6 +76,7 @@ pub enum CoordinatorCommand {
         phase: String,
         outcome: String,
         summary: String,
+        full_result: Option<Box<PhaseResult>>,
         reply: oneshot::Sender<Result<(), PgError>>,
     },
     ArchiveItem {
@@ -62,6 +86,7 @@ pub enum CoordinatorCommand {
     IngestFollowUps {
         follow_ups: Vec<FollowUp>,
         origin: String,
+        prefix: Option<String>,
         reply: oneshot::Sender<Result<Vec<String>, PgError>>,
     },
     UnblockItem {
@@ -74,6 +99,28 @@ pub enum CoordinatorCommand {
         target_id: String,
         reply: oneshot::Sender<Result<(), PgError>>,
     },
+    ReassignPipeline {
+        item_id: String,
+        pipeline_type: String,
+        reply: oneshot::Sender<Result<(), PgError>>,
+    },
+}
+
+impl CoordinatorCommand {
+    /// Whether a successful run of this command changes the item store.
+    fn mutates_store(&self) -> bool {
+        matches!(
+            self,
+            CoordinatorCommand::UpdateItem { .. }
+                | CoordinatorCommand::CompletePhase { .. }
+                | CoordinatorCommand::RecordPhaseStart { .. }
+                | CoordinatorCommand::ArchiveItem { .. }
+                | CoordinatorCommand::IngestFollowUps { .. }
+                | CoordinatorCommand::UnblockItem { .. }
+                | CoordinatorCommand::MergeItem { .. }
+                | CoordinatorCommand::ReassignPipeline { .. }
+        )
+    }
 }
 
 // --- CoordinatorHandle ---
@@ -81,9 +128,18 @@ pub enum CoordinatorCommand {
 #[derive(Clone)]
 pub struct CoordinatorHandle {
     sender: mpsc::Sender<CoordinatorCommand>,
+    state_changed: Arc<Notify>,
 }
 
 impl CoordinatorHandle {
+    /// Resolve on the next successful store mutation by any handle.
+    ///
+    /// Only changes made while waiting are observed; earlier ones are not
+    /// buffered, so callers should pair this with a timeout.
+    pub async fn wait_for_state_change(&self) {
+        self.state_changed.notified().await;
+    }
+
     async fn send_command<T>(
         &self,
         command: CoordinatorCommand,
@@ -141,12 +197,67 @@ impl CoordinatorHandle {
             .await?
     }
 
+    /// Commit the item store together with any pending batch phase outputs,
+    /// leaving a clean restore point after a checkpoint phase.
+    pub async fn commit_checkpoint(&self, item_id: &str, phase: &str) -> Result<(), PgError> {
+        let (reply, rx) = oneshot::channel();
+        self.send_command(
+            CoordinatorCommand::CommitCheckpoint {
+                item_id: item_id.to_string(),
+                phase: phase.to_string(),
+                reply,
+            },
+            rx,
+        )
+        .await?
+    }
+
+    /// Apply a patch produced in an isolated clone to the main working tree.
+    /// Changes are left unstaged so the phase's normal completion commits them.
+    pub async fn apply_isolated_patch(&self, patch: String) -> Result<(), PgError> {
+        let (reply, rx) = oneshot::channel();
+        self.send_command(CoordinatorCommand::ApplyPatch { patch, reply }, rx)
+            .await?
+    }
+
+    /// Commit pending work on the current branch, then check out the item's
+    /// own branch for a destructive phase (`execution.branch_per_item`).
+    /// Returns the branch name.
+    ///
+    /// The next destructive `complete_phase` for the item commits there and
+    /// switches back; `leave_item_branch` switches back without committing.
+    pub async fn enter_item_branch(&self, item_id: &str) -> Result<String, PgError> {
+        let (reply, rx) = oneshot::channel();
+        self.send_command(
+            CoordinatorCommand::EnterItemBranch {
+                item_id: item_id.to_string(),
+                reply,
+            },
+            rx,
+        )
+        .await?
+    }
+
+    /// Return to the base branch if an item branch is checked out.
+    pub async fn leave_item_branch(&self) -> Result<(), PgError> {
+        let (reply, rx) = oneshot::channel();
+        self.send_command(CoordinatorCommand::LeaveItemBranch { reply }, rx)
+            .await?
+    }
+
     pub async fn get_head_sha(&self) -> Result<String, PgError> {
         let (reply, rx) = oneshot::channel();
         self.send_command(CoordinatorCommand::GetHeadSha { reply }, rx)
             .await?
     }
 
+    /// Dirty paths in the working tree that phase-golem did not produce.
+    pub async fn unexpected_dirty_paths(&self) -> Result<Vec<String>, PgError> {
+        let (reply, rx) = oneshot::channel();
+        self.send_command(CoordinatorCommand::UnexpectedDirtyPaths { reply }, rx)
+            .await?
+    }
+
     pub async fn is_ancestor(&self, sha: &str) -> Result<bool, PgError> {
         let (reply, rx) = oneshot::channel();
         self.send_command(
@@ -188,6 +299,32 @@ impl CoordinatorHandle {
                 phase: phase.to_string(),
                 outcome: outcome.to_string(),
                 summary: summary.to_string(),
+                full_result: None,
+                reply,
+            },
+            rx,
+        )
+        .await?
+    }
+
+    /// Like `write_worklog`, but also appends the serialized `PhaseResult`
+    /// to the month's JSONL worklog for auditing.
+    pub async fn write_worklog_with_result(
+        &self,
+        id: &str,
+        title: &str,
+        outcome: &str,
+        result: &PhaseResult,
+    ) -> Result<(), PgError> {
+        let (reply, rx) = oneshot::channel();
+        self.send_command(
+            CoordinatorCommand::WriteWorklog {
+                id: id.to_string(),
+                title: title.to_string(),
+                phase: result.phase.clone(),
+                outcome: outcome.to_string(),
+                summary: result.summary.clone(),
+                full_result: Some(Box::new(result.clone())),
                 reply,
             },
             rx,
@@ -207,16 +344,20 @@ impl CoordinatorHandle {
         .await?
     }
 
+    /// Create `follow_ups` as New items. IDs use `prefix`, or the project
+    /// prefix when `None`.
     pub async fn ingest_follow_ups(
         &self,
         follow_ups: Vec<FollowUp>,
         origin: &str,
+        prefix: Option<&str>,
     ) -> Result<Vec<String>, PgError> {
         let (reply, rx) = oneshot::channel();
         self.send_command(
             CoordinatorCommand::IngestFollowUps {
                 follow_ups,
                 origin: origin.to_string(),
+                prefix: prefix.map(str::to_string),
                 reply,
             },
             rx,
@@ -253,10 +394,104 @@ impl CoordinatorHandle {
         )
         .await?
     }
+
+    /// Move an item to another pipeline in a single store write.
+    ///
+    /// The caller is responsible for checking that `pipeline_type` exists in
+    /// config; the coordinator has no view of pipelines.
+    pub async fn reassign_pipeline(
+        &self,
+        item_id: &str,
+        pipeline_type: &str,
+    ) -> Result<(), PgError> {
+        let (reply, rx) = oneshot::channel();
+        self.send_command(
+            CoordinatorCommand::ReassignPipeline {
+                item_id: item_id.to_string(),
+                pipeline_type: pipeline_type.to_string(),
+                reply,
+            },
+            rx,
+        )
+        .await?
+    }
+}
+
+// --- Change artifact checks ---
+
+/// Locate an item's change artifacts under `changes/`.
+///
+/// Agents normally write to a `changes/<item_id>_<slug>/` directory, but some
+/// write a loose file instead (`changes/<item_id>.md` or `changes/<item_id>_<slug>.md`).
+/// Both forms are returned, sorted by path.
+fn find_change_artifacts(changes_dir: &Path, item_id: &str) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(changes_dir) else {
+        return Vec::new();
+    };
+    let dir_prefix = format!("{}_", item_id);
+    let file_prefixes = [format!("{}_", item_id), format!("{}.", item_id)];
+
+    let mut artifacts: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            match entry.file_type() {
+                Ok(t) if t.is_dir() => name.starts_with(&dir_prefix),
+                Ok(t) if t.is_file() => file_prefixes.iter().any(|p| name.starts_with(p)),
+                _ => false,
+            }
+        })
+        .map(|entry| entry.path())
+        .collect();
+    artifacts.sort();
+    artifacts
+}
+
+/// Warn when a destructive phase left neither a change directory nor a loose
+/// change file for the item.
+///
+/// Either form is already staged with the rest of the dirty paths; this only
+/// flags the phase that produced nothing under `changes/`.
+fn warn_if_no_change_artifacts(project_root: &Path, item_id: &str) {
+    if find_change_artifacts(&project_root.join("changes"), item_id).is_empty() {
+        log_warn!(
+            "[{}] No change directory or loose change file found under changes/",
+            item_id
+        );
+    }
 }
 
 // --- Pure helpers ---
 
+/// Paths phase-golem itself writes between commits: the item store, runtime
+/// files, change artifacts, and the worklog.
+const EXPECTED_DIRTY_PREFIXES: &[&str] =
+    &[".task-golem/", ".phase-golem/", "changes/", "_worklog/"];
+
+/// Dirty paths not accounted for by phase-golem.
+///
+/// Fully staged entries are expected only when phase-golem staged them itself:
+/// non-destructive phase output waits in the index until the next batch commit.
+/// `batch_staged` holds those paths; directory entries cover everything under them.
+fn unexpected_dirty_paths(status: &[StatusEntry], batch_staged: &[String]) -> Vec<String> {
+    status
+        .iter()
+        .filter(|entry| {
+            !(entry.status_code.ends_with(' ')
+                && batch_staged.iter().any(|staged| {
+                    entry.path == *staged
+                        || (staged.ends_with('/') && entry.path.starts_with(staged.as_str()))
+                }))
+        })
+        .filter(|entry| {
+            !EXPECTED_DIRTY_PREFIXES
+                .iter()
+                .any(|prefix| entry.path.starts_with(prefix))
+        })
+        .map(|entry| entry.path.clone())
+        .collect()
+}
+
 fn has_staged_changes(status: &[StatusEntry]) -> bool {
     status.iter().any(|entry| {
         entry
@@ -280,6 +515,19 @@ fn build_phase_commit_message(item_id: &str, phase: &str, commit_summary: Option
     }
 }
 
+fn build_checkpoint_commit_message(
+    item_id: &str,
+    phase: &str,
+    pending: &[(String, String, Option<String>)],
+) -> String {
+    // Pending batch outputs ride along in the checkpoint commit
+    if pending.is_empty() {
+        format!("[{}][{}] Checkpoint", item_id, phase)
+    } else {
+        build_batch_commit_message(pending)
+    }
+}
+
 fn build_batch_commit_message(phases: &[(String, String, Option<String>)]) -> String {
     // Single-phase batch: use same format as a direct phase commit
     if phases.len() == 1 {
@@ -404,9 +652,24 @@ struct CoordinatorState {
     store: Store,
     project_root: PathBuf,
     prefix: String,
+    worklog_naming: WorklogNaming,
+    worklog_format: WorklogFormat,
     /// Tracks non-destructive phase completions pending batch commit.
     /// Each entry: (item_id, phase, commit_summary).
     pending_batch_phases: Vec<(String, String, Option<String>)>,
+    /// Paths staged for those pending phases, as `git status` reported them
+    /// (directories end with `/`).
+    batch_staged_paths: Vec<String>,
+    /// Item branch currently checked out for a destructive phase.
+    item_branch: Option<ItemBranch>,
+}
+
+/// An item branch checked out by `EnterItemBranch`, and the branch to return to.
+#[derive(Clone)]
+struct ItemBranch {
+    item_id: String,
+    branch: String,
+    base: String,
 }
 
 impl CoordinatorState {
@@ -417,6 +680,64 @@ impl CoordinatorState {
 
 // --- Handler implementations ---
 
+/// Commit pending work on the base branch, then check out `branch`.
+async fn enter_item_branch(
+    state: &mut CoordinatorState,
+    item_id: String,
+    branch: String,
+) -> Result<String, PgError> {
+    // Another item's branch is still out: go back to its base first
+    if let Some(previous) = state.item_branch.clone() {
+        checkout_base_branch(&state.project_root, &previous).await?;
+        state.item_branch = None;
+    }
+
+    let project_root = state.project_root.clone();
+    let message = if state.pending_batch_phases.is_empty() {
+        format!("[phase-golem] Save task state before {}", branch)
+    } else {
+        build_batch_commit_message(&state.pending_batch_phases)
+    };
+    let branch_for_checkout = branch.clone();
+
+    let base = tokio::task::spawn_blocking(move || -> Result<String, PgError> {
+        tg_git::stage_self(&project_root)
+            .map_err(|e| PgError::Git(format!("stage_self failed: {}", e)))?;
+        let status = crate::git::get_status(Some(&project_root)).map_err(PgError::Git)?;
+        if has_staged_changes(&status) {
+            tg_git::commit(&message, &project_root)
+                .map_err(|e| PgError::Git(format!("commit failed: {}", e)))?;
+        }
+
+        let base = crate::git::current_branch(&project_root).map_err(PgError::Git)?;
+        crate::git::checkout_item_branch(&branch_for_checkout, &base, &project_root)
+            .map_err(PgError::Git)?;
+        Ok(base)
+    })
+    .await
+    .map_err(|e| PgError::InternalPanic(format!("{e:?}")))??;
+
+    state.pending_batch_phases.clear();
+    state.batch_staged_paths.clear();
+    state.item_branch = Some(ItemBranch {
+        item_id,
+        branch: branch.clone(),
+        base,
+    });
+    Ok(branch)
+}
+
+/// Check out the base branch an item branch was entered from.
+async fn checkout_base_branch(project_root: &Path, branch: &ItemBranch) -> Result<(), PgError> {
+    let project_root = project_root.to_path_buf();
+    let base = branch.base.clone();
+    tokio::task::spawn_blocking(move || {
+        crate::git::checkout_branch(&base, &project_root).map_err(PgError::Git)
+    })
+    .await
+    .map_err(|e| PgError::InternalPanic(format!("{e:?}")))?
+}
+
 async fn handle_get_snapshot(state: &CoordinatorState) -> Result<Vec<PgItem>, PgError> {
     let store = state.store.clone();
     let items = tokio::task::spawn_blocking(move || store.load_active())
@@ -476,13 +797,28 @@ fn handle_write_worklog(
     phase: &str,
     outcome: &str,
     summary: &str,
+    full_result: Option<&PhaseResult>,
 ) -> Result<(), PgError> {
-    crate::worklog::write_entry(&state.worklog_dir(), id, title, phase, outcome, summary)
-        .map_err(PgError::Git)
+    // Entries are written one command at a time by the actor, each in a
+    // single append, so they land in the order the commands were received
+    crate::worklog::write_formatted_entry(
+        &state.worklog_dir(),
+        state.worklog_naming,
+        state.worklog_format,
+        id,
+        title,
+        phase,
+        outcome,
+        summary,
+        full_result,
+    )
+    .map_err(PgError::Git)
 }
 
 async fn handle_archive_item(state: &CoordinatorState, item_id: String) -> Result<(), PgError> {
     let worklog_dir = state.worklog_dir();
+    let worklog_naming = state.worklog_naming;
+    let worklog_format = state.worklog_format;
 
     // Store operation: find item, archive it, remove from active, save
     let archived_item = with_store_retry(&state.store, move |store| {
@@ -504,32 +840,40 @@ async fn handle_archive_item(state: &CoordinatorState, item_id: String) -> Resul
     .await?;
 
     // Write worklog entry outside the lock
-    let worklog_month = chrono::Utc::now().format("%Y-%m").to_string();
-    let worklog_path = worklog_dir.join(format!("{}.md", worklog_month));
-
-    write_archive_worklog_entry(&worklog_path, &archived_item)
-        .map_err(|e| PgError::Git(format!("Worklog write failed: {}", e)))?;
+    match worklog_format {
+        WorklogFormat::Markdown => {
+            let worklog_path = crate::worklog::worklog_path(
+                &worklog_dir,
+                worklog_naming,
+                &archived_item.id,
+                chrono::Utc::now(),
+                "md",
+            );
+            write_archive_worklog_entry(&worklog_path, &archived_item)
+        }
+        WorklogFormat::Jsonl => {
+            let phase = PgItem(archived_item.clone())
+                .phase()
+                .unwrap_or_else(|| "unknown".to_string());
+            crate::worklog::write_jsonl_entry(
+                &worklog_dir,
+                worklog_naming,
+                &archived_item.id,
+                &archived_item.title,
+                &phase,
+                "Archived",
+                "Item archived",
+                None,
+            )
+        }
+    }
+    .map_err(|e| PgError::Git(format!("Worklog write failed: {}", e)))?;
 
     Ok(())
 }
 
 /// Write an archive worklog entry for a completed/archived item.
 fn write_archive_worklog_entry(worklog_path: &Path, item: &Item) -> Result<(), String> {
-    use std::fs::{self, OpenOptions};
-    use std::io::Write;
-
-    let worklog_dir = worklog_path
-        .parent()
-        .ok_or_else(|| "Cannot determine worklog directory".to_string())?;
-
-    fs::create_dir_all(worklog_dir).map_err(|e| {
-        format!(
-            "Failed to create worklog directory {}: {}",
-            worklog_dir.display(),
-            e
-        )
-    })?;
-
     let pg = PgItem(item.clone());
     let datetime = chrono::Utc::now().to_rfc3339();
     let phase = pg.phase().unwrap_or_else(|| "unknown".to_string());
@@ -539,27 +883,7 @@ fn write_archive_worklog_entry(worklog_path: &Path, item: &Item) -> Result<(), S
         datetime, item.id, item.title, phase,
     );
 
-    let mut file = OpenOptions::new()
-        .append(true)
-        .create(true)
-        .open(worklog_path)
-        .map_err(|e| {
-            format!(
-                "Failed to open worklog at {}: {}",
-                worklog_path.display(),
-                e
-            )
-        })?;
-
-    file.write_all(entry.as_bytes()).map_err(|e| {
-        format!(
-            "Failed to write worklog at {}: {}",
-            worklog_path.display(),
-            e
-        )
-    })?;
-
-    Ok(())
+    crate::worklog::append_entry(worklog_path, &entry)
 }
 
 async fn handle_ingest_follow_ups(
@@ -772,6 +1096,73 @@ async fn handle_merge_item(
     .await
 }
 
+/// Set a new pipeline type and reset all per-pipeline progress.
+///
+/// Clears phase, phase pool, `last_phase_commit`, and completed phases. Items
+/// that were mid-pipeline (Scoping or InProgress, including Blocked items that
+/// will be restored to one of those) go back to Ready so the scheduler promotes
+/// them into the new pipeline's first phase. Done items are rejected.
+async fn handle_reassign_pipeline(
+    state: &CoordinatorState,
+    item_id: String,
+    pipeline_type: String,
+) -> Result<(), PgError> {
+    with_store_retry(&state.store, move |store| {
+        store
+            .with_lock(|s| {
+                if pipeline_type.trim().is_empty() {
+                    return Err(task_golem::errors::TgError::InvalidInput(
+                        "Pipeline type must not be empty".to_string(),
+                    ));
+                }
+
+                let mut items = s.load_active()?;
+                let idx = items
+                    .iter()
+                    .position(|i| i.id == item_id)
+                    .ok_or_else(|| task_golem::errors::TgError::ItemNotFound(item_id.clone()))?;
+
+                let pg = PgItem(items[idx].clone());
+                let resets_to_ready = |status: &ItemStatus| {
+                    matches!(status, ItemStatus::Scoping | ItemStatus::InProgress)
+                };
+                match pg.pg_status() {
+                    ItemStatus::Done => {
+                        return Err(task_golem::errors::TgError::InvalidInput(format!(
+                            "Cannot reassign pipeline of {}: item is already Done",
+                            item_id
+                        )));
+                    }
+                    ItemStatus::Blocked => {
+                        if pg
+                            .pg_blocked_from_status()
+                            .is_some_and(|from| resets_to_ready(&from))
+                        {
+                            pg_item::set_blocked_from_status(
+                                &mut items[idx],
+                                Some(&ItemStatus::Ready),
+                            );
+                        }
+                    }
+                    status if resets_to_ready(&status) => {
+                        pg_item::set_pg_status(&mut items[idx], ItemStatus::Ready);
+                    }
+                    _ => {}
+                }
+
+                pg_item::set_pipeline_type(&mut items[idx], Some(&pipeline_type));
+                pg_item::set_phase(&mut items[idx], None);
+                pg_item::set_phase_pool(&mut items[idx], None);
+                pg_item::set_last_phase_commit(&mut items[idx], None);
+                pg_item::set_completed_phases(&mut items[idx], &[]);
+
+                s.save_active(&items)
+            })
+            .map_err(PgError::from)
+    })
+    .await
+}
+
 // --- Actor loop ---
 
 async fn run_coordinator(
@@ -779,6 +1170,9 @@ async fn run_coordinator(
     store: Store,
     project_root: PathBuf,
     prefix: String,
+    worklog_naming: WorklogNaming,
+    worklog_format: WorklogFormat,
+    state_changed: Arc<Notify>,
 ) {
     // Startup probe: verify the store is accessible
     match store.load_active() {
@@ -821,11 +1215,16 @@ async fn run_coordinator(
         store,
         project_root,
         prefix,
+        worklog_naming,
+        worklog_format,
         pending_batch_phases: Vec::new(),
+        batch_staged_paths: Vec::new(),
+        item_branch: None,
     };
 
     while let Some(cmd) = rx.recv().await {
         let is_fatal_result: Option<bool>;
+        let mutates_store = cmd.mutates_store();
 
         match cmd {
             CoordinatorCommand::GetSnapshot { reply } => {
@@ -849,10 +1248,14 @@ async fn run_coordinator(
                 let item_id_for_push = item_id.clone();
                 let phase_for_push = phase_result.phase.clone();
                 let commit_summary_for_push = phase_result.commit_summary.clone();
+                let history_item_id = item_id.clone();
+                let history_phase = phase_result.phase.clone();
+                let history_result = phase_result.result.clone();
 
                 // Step 1: Stage artifact files via phase-golem's git module
-                let staging_result: Result<(), PgError> = {
+                let staging_result: Result<Vec<String>, PgError> = {
                     let project_root_clone = project_root.clone();
+                    let item_id_for_artifacts = item_id.clone();
                     match tokio::task::spawn_blocking(move || {
                         let status = crate::git::get_status(Some(&project_root_clone))
                             .map_err(PgError::Git)?;
@@ -868,7 +1271,14 @@ async fn run_coordinator(
                                 .map_err(PgError::Git)?;
                         }
 
-                        Ok(())
+                        if is_destructive {
+                            warn_if_no_change_artifacts(
+                                &project_root_clone,
+                                &item_id_for_artifacts,
+                            );
+                        }
+
+                        Ok(status.into_iter().map(|entry| entry.path).collect())
                     })
                     .await
                     {
@@ -877,26 +1287,49 @@ async fn run_coordinator(
                     }
                 };
 
-                if let Err(e) = staging_result {
-                    // Staging failed — abort without JSONL update
-                    is_fatal_result = Some(e.is_fatal());
-                    let _ = reply.send(Err(e));
-                    // Check fatal below
-                    if is_fatal_result == Some(true) {
-                        break;
+                let staged_paths = match staging_result {
+                    Ok(paths) => paths,
+                    Err(e) => {
+                        // Staging failed — abort without JSONL update
+                        is_fatal_result = Some(e.is_fatal());
+                        let _ = reply.send(Err(e));
+                        // Check fatal below
+                        if is_fatal_result == Some(true) {
+                            break;
+                        }
+                        continue;
                     }
-                    continue;
-                }
+                };
 
                 // Step 2: Update item state in store via with_lock
+                // Status/phase updates happen via separate UpdateItem calls in the
+                // executor; here only the phase history entry is appended, so it
+                // lands in the same commit as the phase output
+                let history_commit = {
+                    let project_root_clone = project_root.clone();
+                    tokio::task::spawn_blocking(move || {
+                        crate::git::get_head_sha(&project_root_clone).ok()
+                    })
+                    .await
+                    .ok()
+                    .flatten()
+                };
+                let history_entry = PhaseHistoryEntry {
+                    phase: history_phase,
+                    result: history_result,
+                    completed_at: chrono::Utc::now(),
+                    commit: history_commit,
+                };
                 let store_result = {
                     with_store_retry(&state.store, move |store| {
                         store
                             .with_lock(|s| {
-                                let items = s.load_active()?;
-                                // Item update is handled by the caller after CompletePhase
-                                // CompletePhase itself just stages + commits; item state updates
-                                // happen via separate UpdateItem calls in the executor
+                                let mut items = s.load_active()?;
+                                if let Some(item) =
+                                    items.iter_mut().find(|i| i.id == history_item_id)
+                                {
+                                    pg_item::append_phase_history(item, &history_entry);
+                                }
                                 s.save_active(&items)
                             })
                             .map_err(PgError::from)
@@ -916,10 +1349,20 @@ async fn run_coordinator(
                 // Step 3: stage task-golem files + commit (for destructive) or accumulate batch
                 if is_destructive {
                     let project_root_clone = project_root.clone();
+                    let item_branch = state.item_branch.clone().filter(|b| b.item_id == item_id);
+                    let on_item_branch = item_branch.is_some();
                     let commit_result: Result<(), PgError> =
                         match tokio::task::spawn_blocking(move || {
-                            tg_git::stage_self(&project_root_clone)
-                                .map_err(|e| PgError::Git(format!("stage_self failed: {}", e)))?;
+                            if on_item_branch {
+                                // The item store stays on the base branch; it rides
+                                // back there uncommitted.
+                                crate::git::unstage_path(".task-golem", &project_root_clone)
+                                    .map_err(PgError::Git)?;
+                            } else {
+                                tg_git::stage_self(&project_root_clone).map_err(|e| {
+                                    PgError::Git(format!("stage_self failed: {}", e))
+                                })?;
+                            }
 
                             let message = build_phase_commit_message(
                                 &item_id,
@@ -948,6 +1391,18 @@ async fn run_coordinator(
                         log_warn!("CompletePhase commit failed (JSONL state preserved): {}", e);
                     }
 
+                    if let Some(branch) = item_branch {
+                        match checkout_base_branch(&project_root, &branch).await {
+                            Ok(()) => state.item_branch = None,
+                            Err(e) => log_warn!(
+                                "Failed to return to {} from {}: {}",
+                                branch.base,
+                                branch.branch,
+                                e
+                            ),
+                        }
+                    }
+
                     is_fatal_result = None;
                     // Return success even if commit failed — JSONL is authoritative
                     let _ = reply.send(Ok(()));
@@ -975,6 +1430,7 @@ async fn run_coordinator(
                         phase_for_push,
                         commit_summary_for_push,
                     ));
+                    state.batch_staged_paths.extend(staged_paths);
 
                     is_fatal_result = None;
                     let _ = reply.send(Ok(()));
@@ -1011,12 +1467,111 @@ async fn run_coordinator(
 
                     if result.is_ok() {
                         state.pending_batch_phases.clear();
+                        state.batch_staged_paths.clear();
                     }
 
                     is_fatal_result = result.as_ref().err().map(|e| e.is_fatal());
                     let _ = reply.send(result);
                 }
             }
+            CoordinatorCommand::CommitCheckpoint {
+                item_id,
+                phase,
+                reply,
+            } => {
+                let project_root = state.project_root.clone();
+                let message =
+                    build_checkpoint_commit_message(&item_id, &phase, &state.pending_batch_phases);
+
+                let result: Result<(), PgError> = match tokio::task::spawn_blocking(move || {
+                    tg_git::stage_self(&project_root)
+                        .map_err(|e| PgError::Git(format!("stage_self failed: {}", e)))?;
+
+                    let status =
+                        crate::git::get_status(Some(&project_root)).map_err(PgError::Git)?;
+
+                    if has_staged_changes(&status) {
+                        tg_git::commit(&message, &project_root)
+                            .map_err(|e| PgError::Git(format!("commit failed: {}", e)))?;
+                    }
+
+                    Ok(())
+                })
+                .await
+                {
+                    Ok(r) => r,
+                    Err(e) => Err(PgError::InternalPanic(format!("{e:?}"))),
+                };
+
+                if result.is_ok() {
+                    state.pending_batch_phases.clear();
+                    state.batch_staged_paths.clear();
+                }
+
+                is_fatal_result = result.as_ref().err().map(|e| e.is_fatal());
+                let _ = reply.send(result);
+            }
+            CoordinatorCommand::ApplyPatch { patch, reply } => {
+                let project_root = state.project_root.clone();
+                let result: Result<(), PgError> = match tokio::task::spawn_blocking(move || {
+                    crate::git::apply_patch(&patch, &project_root).map_err(PgError::Git)
+                })
+                .await
+                {
+                    Ok(r) => r,
+                    Err(e) => Err(PgError::InternalPanic(format!("{e:?}"))),
+                };
+
+                is_fatal_result = result.as_ref().err().map(|e| e.is_fatal());
+                let _ = reply.send(result);
+            }
+            CoordinatorCommand::EnterItemBranch { item_id, reply } => {
+                let branch = crate::git::item_branch_name(&item_id);
+                let result = if state
+                    .item_branch
+                    .as_ref()
+                    .is_some_and(|b| b.branch == branch)
+                {
+                    Ok(branch)
+                } else {
+                    enter_item_branch(&mut state, item_id, branch).await
+                };
+
+                is_fatal_result = result.as_ref().err().map(|e| e.is_fatal());
+                let _ = reply.send(result);
+            }
+            CoordinatorCommand::LeaveItemBranch { reply } => {
+                let result = match state.item_branch.clone() {
+                    Some(branch) => {
+                        let result = checkout_base_branch(&state.project_root, &branch).await;
+                        if result.is_ok() {
+                            state.item_branch = None;
+                        }
+                        result
+                    }
+                    None => Ok(()),
+                };
+
+                is_fatal_result = result.as_ref().err().map(|e| e.is_fatal());
+                let _ = reply.send(result);
+            }
+            CoordinatorCommand::UnexpectedDirtyPaths { reply } => {
+                let project_root = state.project_root.clone();
+                let batch_staged_paths = state.batch_staged_paths.clone();
+                let result: Result<Vec<String>, PgError> =
+                    match tokio::task::spawn_blocking(move || {
+                        crate::git::get_status(Some(&project_root))
+                            .map(|status| unexpected_dirty_paths(&status, &batch_staged_paths))
+                            .map_err(PgError::Git)
+                    })
+                    .await
+                    {
+                        Ok(r) => r,
+                        Err(e) => Err(PgError::InternalPanic(format!("{e:?}"))),
+                    };
+                is_fatal_result = result.as_ref().err().map(|e| e.is_fatal());
+                let _ = reply.send(result);
+            }
             CoordinatorCommand::GetHeadSha { reply } => {
                 let project_root = state.project_root.clone();
                 let result: Result<String, PgError> = match tokio::task::spawn_blocking(move || {
@@ -1058,9 +1613,18 @@ async fn run_coordinator(
                 phase,
                 outcome,
                 summary,
+                full_result,
                 reply,
             } => {
-                let result = handle_write_worklog(&state, &id, &title, &phase, &outcome, &summary);
+                let result = handle_write_worklog(
+                    &state,
+                    &id,
+                    &title,
+                    &phase,
+                    &outcome,
+                    &summary,
+                    full_result.as_deref(),
+                );
                 is_fatal_result = result.as_ref().err().map(|e| e.is_fatal());
                 let _ = reply.send(result);
             }
@@ -1072,11 +1636,11 @@ async fn run_coordinator(
             CoordinatorCommand::IngestFollowUps {
                 follow_ups,
                 origin,
+                prefix,
                 reply,
             } => {
-                let result =
-                    handle_ingest_follow_ups(&state, follow_ups, origin, state.prefix.clone())
-                        .await;
+                let prefix = prefix.unwrap_or_else(|| state.prefix.clone());
+                let result = handle_ingest_follow_ups(&state, follow_ups, origin, prefix).await;
                 is_fatal_result = result.as_ref().err().map(|e| e.is_fatal());
                 let _ = reply.send(result);
             }
@@ -1098,6 +1662,19 @@ async fn run_coordinator(
                 is_fatal_result = result.as_ref().err().map(|e| e.is_fatal());
                 let _ = reply.send(result);
             }
+            CoordinatorCommand::ReassignPipeline {
+                item_id,
+                pipeline_type,
+                reply,
+            } => {
+                let result = handle_reassign_pipeline(&state, item_id, pipeline_type).await;
+                is_fatal_result = result.as_ref().err().map(|e| e.is_fatal());
+                let _ = reply.send(result);
+            }
+        }
+
+        if mutates_store && is_fatal_result.is_none() {
+            state_changed.notify_waiters();
         }
 
         // Fatal error propagation: break out of the handler loop
@@ -1117,17 +1694,100 @@ pub fn spawn_coordinator(
     project_root: PathBuf,
     prefix: String,
 ) -> (CoordinatorHandle, tokio::task::JoinHandle<()>) {
-    let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+    spawn_coordinator_with_worklog_naming(store, project_root, prefix, WorklogNaming::default())
+}
+
+/// Like `spawn_coordinator`, routing worklog entries per `project.worklog_naming`.
+pub fn spawn_coordinator_with_worklog_naming(
+    store: Store,
+    project_root: PathBuf,
+    prefix: String,
+    worklog_naming: WorklogNaming,
+) -> (CoordinatorHandle, tokio::task::JoinHandle<()>) {
+    spawn_coordinator_with_worklog(
+        store,
+        project_root,
+        prefix,
+        worklog_naming,
+        WorklogFormat::default(),
+    )
+}
 
-    let task_handle = tokio::spawn(run_coordinator(rx, store, project_root, prefix));
+/// Like `spawn_coordinator_with_worklog_naming`, also writing worklog entries
+/// in `execution.worklog_format`.
+pub fn spawn_coordinator_with_worklog(
+    store: Store,
+    project_root: PathBuf,
+    prefix: String,
+    worklog_naming: WorklogNaming,
+    worklog_format: WorklogFormat,
+) -> (CoordinatorHandle, tokio::task::JoinHandle<()>) {
+    let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+    let state_changed = Arc::new(Notify::new());
 
-    (CoordinatorHandle { sender: tx }, task_handle)
+    let task_handle = tokio::spawn(run_coordinator(
+        rx,
+        store,
+        project_root,
+        prefix,
+        worklog_naming,
+        worklog_format,
+        Arc::clone(&state_changed),
+    ));
+
+    (
+        CoordinatorHandle {
+            sender: tx,
+            state_changed,
+        },
+        task_handle,
+    )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // =========================================================================
+    // unexpected_dirty_paths tests
+    // =========================================================================
+
+    fn status(code: &str, path: &str) -> StatusEntry {
+        StatusEntry {
+            status_code: code.to_string(),
+            path: path.to_string(),
+        }
+    }
+
+    #[test]
+    fn unexpected_dirty_paths_skips_own_paths_and_staged_entries() {
+        let entries = vec![
+            status(" M", ".task-golem/tasks.jsonl"),
+            status("??", "changes/WRK-001_feature/"),
+            status("??", "_worklog/2026-10.md"),
+            status("M ", "src/staged_by_batch.rs"),
+            status("A ", "docs/design/overview.md"),
+            status(" M", "src/lib.rs"),
+            status("??", "notes.txt"),
+        ];
+        let batch_staged = vec!["src/staged_by_batch.rs".to_string(), "docs/".to_string()];
+
+        assert_eq!(
+            unexpected_dirty_paths(&entries, &batch_staged),
+            vec!["src/lib.rs".to_string(), "notes.txt".to_string()]
+        );
+    }
+
+    #[test]
+    fn unexpected_dirty_paths_reports_staged_entries_phase_golem_did_not_stage() {
+        let entries = vec![status("M ", "src/lib.rs"), status("A ", "docs/notes.md")];
+
+        assert_eq!(
+            unexpected_dirty_paths(&entries, &["docs/design/".to_string()]),
+            vec!["src/lib.rs".to_string(), "docs/notes.md".to_string()]
+        );
+    }
+
     // =========================================================================
     // build_phase_commit_message tests
     // =========================================================================
@@ -1214,6 +1874,35 @@ mod tests {
         assert_eq!(msg, "[WRK-001][build] Phase output");
     }
 
+    // =========================================================================
+    // find_change_artifacts tests
+    // =========================================================================
+
+    #[test]
+    fn change_artifacts_include_directory_and_loose_files() {
+        let dir = tempfile::tempdir().expect("create tempdir");
+        std::fs::create_dir(dir.path().join("WRK-001_add-thing")).unwrap();
+        std::fs::write(dir.path().join("WRK-001.md"), "loose").unwrap();
+        std::fs::write(dir.path().join("WRK-0010.md"), "other item").unwrap();
+        std::fs::create_dir(dir.path().join("WRK-002_other")).unwrap();
+
+        let artifacts = find_change_artifacts(dir.path(), "WRK-001");
+
+        assert_eq!(
+            artifacts,
+            vec![
+                dir.path().join("WRK-001.md"),
+                dir.path().join("WRK-001_add-thing"),
+            ]
+        );
+    }
+
+    #[test]
+    fn change_artifacts_missing_changes_dir_is_empty() {
+        let dir = tempfile::tempdir().expect("create tempdir");
+        assert!(find_change_artifacts(&dir.path().join("changes"), "WRK-001").is_empty());
+    }
+
     // =========================================================================
     // spawn_coordinator tests
     // =========================================================================
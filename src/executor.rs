@@ -3,9 +3,10 @@ use std::time::Duration;
 
 use tokio_util::sync::CancellationToken;
 
-use crate::agent::AgentRunner;
+use crate::agent::{run_hook_command, AgentRunner};
 use crate::config::{
-    GuardrailsConfig, PhaseConfig, PhaseGolemConfig, PipelineConfig, StalenessAction,
+    ExecutionConfig, GuardrailViolationPolicy, GuardrailsConfig, Isolation, PhaseConfig,
+    PhaseGolemConfig, PipelineConfig, StalenessAction,
 };
 use crate::coordinator::CoordinatorHandle;
 use crate::pg_item::PgItem;
@@ -122,10 +123,12 @@ pub async fn check_staleness(
 /// to the item via the coordinator.
 ///
 /// Cases:
+/// - Confidence below threshold and next phase is not a review → SetBlocked
 /// - Last pre_phase completed → check guardrails → ClearPhase + Ready, or SetBlocked
 /// - Last main phase completed → TransitionStatus(Done)
 /// - Mid-pipeline → SetPhase(next) + SetLastPhaseCommit
 /// - Phase failed (result code) → SetBlocked with reason
+/// - Validation phase failed → SetBlocked + AddTag(`validation-failed`)
 /// - Retry exhaustion → SetBlocked with reason
 pub fn resolve_transition(
     item: &PgItem,
@@ -135,6 +138,13 @@ pub fn resolve_transition(
 ) -> Vec<ItemUpdate> {
     match result.result {
         ResultCode::PhaseComplete => resolve_phase_complete(item, result, pipeline, guardrails),
+        ResultCode::Failed if is_validation_phase(pipeline, &result.phase) => vec![
+            ItemUpdate::SetBlocked(format!(
+                "Validation failed in phase {}: {}",
+                result.phase, result.summary
+            )),
+            ItemUpdate::AddTag(VALIDATION_FAILED_TAG.to_string()),
+        ],
         ResultCode::Failed => {
             // Failed result: the caller handles retry counting.
             // If we get here, retries are exhausted.
@@ -159,12 +169,30 @@ pub fn resolve_transition(
     }
 }
 
+/// Tag added to items whose validation phase returned `Failed`.
+pub const VALIDATION_FAILED_TAG: &str = "validation-failed";
+
+fn is_validation_phase(pipeline: &PipelineConfig, phase: &str) -> bool {
+    pipeline
+        .pre_phases
+        .iter()
+        .chain(pipeline.phases.iter())
+        .any(|p| p.name == phase && p.is_validation)
+}
+
 fn resolve_phase_complete(
     item: &PgItem,
     result: &PhaseResult,
     pipeline: &PipelineConfig,
     guardrails: &GuardrailsConfig,
 ) -> Vec<ItemUpdate> {
+    if let Some(reason) = low_confidence_block_reason(item, result, pipeline, guardrails) {
+        return vec![ItemUpdate::SetBlocked(reason)];
+    }
+    if let Some(reason) = follow_up_block_reason(item, result, pipeline) {
+        return vec![ItemUpdate::SetBlocked(reason)];
+    }
+
     let phase_pool = item.phase_pool();
     let current_phase = result.phase.as_str();
 
@@ -185,10 +213,12 @@ fn resolve_phase_complete(
                     )];
                 }
 
-                if !passes_guardrails(item, guardrails) {
-                    return vec![ItemUpdate::SetBlocked(
-                        "Exceeds autonomous guardrail thresholds".to_string(),
-                    )];
+                // Already scoped, so `scope` has nowhere left to send it
+                let violations = guardrail_violations(item, guardrails);
+                if !violations.is_empty()
+                    && guardrails.on_violation != GuardrailViolationPolicy::Warn
+                {
+                    return vec![ItemUpdate::SetBlocked(guardrail_block_reason(&violations))];
                 }
 
                 vec![
@@ -243,6 +273,84 @@ fn resolve_phase_complete(
     }
 }
 
+/// Confidence gate for a completed phase.
+///
+/// When the agent reports a `confidence` below the phase's `min_confidence`
+/// (falling back to `guardrails.min_confidence`), the output may only advance
+/// into a review phase. Otherwise — including when the phase is the last one —
+/// returns the reason to block for a human check. Results without a
+/// confidence, or with no threshold configured, always pass.
+fn low_confidence_block_reason(
+    item: &PgItem,
+    result: &PhaseResult,
+    pipeline: &PipelineConfig,
+    guardrails: &GuardrailsConfig,
+) -> Option<String> {
+    let confidence = result.confidence?;
+    let phases = pool_phases(item, pipeline);
+    let threshold = phases
+        .iter()
+        .find(|p| p.name == result.phase)
+        .and_then(|p| p.min_confidence)
+        .or(guardrails.min_confidence)?;
+
+    if confidence >= threshold {
+        return None;
+    }
+
+    let next = next_phase_in_list(phases, &result.phase);
+    if next.as_deref().is_some_and(is_review_phase) {
+        return None;
+    }
+
+    Some(format!(
+        "Low confidence in {} output ({:.2} < {:.2}) and no review phase follows; needs a human check",
+        result.phase, confidence, threshold
+    ))
+}
+
+/// Follow-up gate for a completed phase.
+///
+/// When the phase sets `followups_block` and the result carries follow-ups,
+/// returns a block reason listing their titles. Ingestion is unaffected.
+fn follow_up_block_reason(
+    item: &PgItem,
+    result: &PhaseResult,
+    pipeline: &PipelineConfig,
+) -> Option<String> {
+    if result.follow_ups.is_empty() {
+        return None;
+    }
+    let blocks = pool_phases(item, pipeline)
+        .iter()
+        .find(|p| p.name == result.phase)
+        .is_some_and(|p| p.followups_block);
+    if !blocks {
+        return None;
+    }
+
+    let titles: Vec<&str> = result.follow_ups.iter().map(|f| f.title.as_str()).collect();
+    Some(format!(
+        "Phase {} reported {} blocking follow-up(s): {}",
+        result.phase,
+        titles.len(),
+        titles.join("; ")
+    ))
+}
+
+/// The phase list for the item's current pool (main when unset).
+fn pool_phases<'a>(item: &PgItem, pipeline: &'a PipelineConfig) -> &'a [PhaseConfig] {
+    match item.phase_pool() {
+        Some(PhasePool::Pre) => &pipeline.pre_phases,
+        Some(PhasePool::Main) | None => &pipeline.phases,
+    }
+}
+
+/// Whether a phase name denotes a review phase (e.g. `review`, `change-review`).
+pub fn is_review_phase(name: &str) -> bool {
+    name.to_lowercase().contains("review")
+}
+
 /// Find the next phase name after `current` in the given phase list.
 fn next_phase_in_list(phases: &[PhaseConfig], current: &str) -> Option<String> {
     let idx = phases.iter().position(|p| p.name == current)?;
@@ -251,34 +359,98 @@ fn next_phase_in_list(phases: &[PhaseConfig], current: &str) -> Option<String> {
 
 // --- Guardrails ---
 
-/// Check if an item passes all guardrail thresholds.
-///
-/// An item passes if all of its dimensions are within the configured maximums.
-/// Missing dimensions are treated as passing (no data = no concern).
-pub fn passes_guardrails(item: &PgItem, guardrails: &GuardrailsConfig) -> bool {
-    let size_ok = match item.size() {
-        Some(ref size) => size_level_value(size) <= size_level_value(&guardrails.max_size),
-        None => true,
-    };
+/// One dimension of an item that exceeds its guardrail maximum.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuardrailViolation {
+    /// `"size"`, `"complexity"`, or `"risk"`.
+    pub dimension: &'static str,
+    /// The item's level, lowercased (e.g. `"large"`).
+    pub actual: String,
+    /// The configured maximum, lowercased.
+    pub max: String,
+}
 
-    let complexity_ok = match item.complexity() {
-        Some(ref complexity) => {
-            dimension_level_value(complexity) <= dimension_level_value(&guardrails.max_complexity)
+/// Every dimension of `item` that exceeds `guardrails`, in size, complexity,
+/// risk order. Missing dimensions never violate (no data = no concern).
+pub fn guardrail_violations(
+    item: &PgItem,
+    guardrails: &GuardrailsConfig,
+) -> Vec<GuardrailViolation> {
+    let lowercase = |v: &dyn std::fmt::Debug| format!("{:?}", v).to_lowercase();
+    let mut violations = Vec::new();
+
+    if let Some(ref size) = item.size() {
+        if size_level_value(size) > size_level_value(&guardrails.max_size) {
+            violations.push(GuardrailViolation {
+                dimension: "size",
+                actual: lowercase(size),
+                max: lowercase(&guardrails.max_size),
+            });
         }
-        None => true,
-    };
+    }
 
-    let risk_ok = match item.risk() {
-        Some(ref risk) => {
-            dimension_level_value(risk) <= dimension_level_value(&guardrails.max_risk)
+    for (dimension, level, max) in [
+        ("complexity", item.complexity(), &guardrails.max_complexity),
+        ("risk", item.risk(), &guardrails.max_risk),
+    ] {
+        if let Some(ref level) = level {
+            if dimension_level_value(level) > dimension_level_value(max) {
+                violations.push(GuardrailViolation {
+                    dimension,
+                    actual: lowercase(level),
+                    max: lowercase(max),
+                });
+            }
         }
-        None => true,
-    };
+    }
+
+    violations
+}
+
+/// Block reason for an item exceeding the guardrails, naming each violation.
+pub fn guardrail_block_reason(violations: &[GuardrailViolation]) -> String {
+    let details: Vec<String> = violations
+        .iter()
+        .map(|v| format!("{} {} > {}", v.dimension, v.actual, v.max))
+        .collect();
+    format!(
+        "Exceeds autonomous guardrail thresholds ({})",
+        details.join(", ")
+    )
+}
+
+/// Warning to log when `guardrails.on_violation = "warn"` lets `item` become
+/// Ready via `updates` despite exceeding the guardrails. `None` otherwise.
+pub fn guardrail_warning(
+    item: &PgItem,
+    updates: &[ItemUpdate],
+    guardrails: &GuardrailsConfig,
+) -> Option<String> {
+    if guardrails.on_violation != GuardrailViolationPolicy::Warn
+        || !updates.contains(&ItemUpdate::TransitionStatus(ItemStatus::Ready))
+    {
+        return None;
+    }
+    let violations = guardrail_violations(item, guardrails);
+    if violations.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "[{}] {}; proceeding (guardrails.on_violation = \"warn\")",
+        item.id(),
+        guardrail_block_reason(&violations)
+    ))
+}
 
-    size_ok && complexity_ok && risk_ok
+/// Check if an item passes all guardrail thresholds.
+///
+/// An item passes if all of its dimensions are within the configured maximums.
+/// Missing dimensions are treated as passing (no data = no concern).
+pub fn passes_guardrails(item: &PgItem, guardrails: &GuardrailsConfig) -> bool {
+    guardrail_violations(item, guardrails).is_empty()
 }
 
-fn size_level_value(level: &SizeLevel) -> u8 {
+pub(crate) fn size_level_value(level: &SizeLevel) -> u8 {
     match level {
         SizeLevel::Small => 1,
         SizeLevel::Medium => 2,
@@ -286,7 +458,7 @@ fn size_level_value(level: &SizeLevel) -> u8 {
     }
 }
 
-fn dimension_level_value(level: &DimensionLevel) -> u8 {
+pub(crate) fn dimension_level_value(level: &DimensionLevel) -> u8 {
     match level {
         DimensionLevel::Low => 1,
         DimensionLevel::Medium => 2,
@@ -294,6 +466,21 @@ fn dimension_level_value(level: &DimensionLevel) -> u8 {
     }
 }
 
+// --- Retry backoff ---
+
+/// Delay before retrying after the `failed_attempt`-th attempt (1-based).
+///
+/// Starts at `retry_backoff_seconds` and doubles per failure, capped at
+/// `retry_backoff_max_seconds`. Zero when backoff is disabled.
+pub fn retry_backoff_delay(execution: &ExecutionConfig, failed_attempt: u32) -> Duration {
+    let doublings = failed_attempt.saturating_sub(1).min(63);
+    let secs = execution
+        .retry_backoff_seconds
+        .saturating_mul(1u64 << doublings)
+        .min(execution.retry_backoff_max_seconds);
+    Duration::from_secs(secs)
+}
+
 // --- Phase execution ---
 
 /// Execute a single phase for a backlog item.
@@ -307,6 +494,10 @@ fn dimension_level_value(level: &DimensionLevel) -> u8 {
 ///
 /// The executor does NOT apply transitions itself — it returns a
 /// `PhaseExecutionResult` that the scheduler uses to drive coordinator updates.
+///
+/// With `execution.branch_per_item`, a destructive phase runs on the item's
+/// branch. A successful result stays there until the scheduler's
+/// `complete_phase` commits it; any other outcome switches back right away.
 #[allow(clippy::too_many_arguments)]
 pub async fn execute_phase(
     item: &PgItem,
@@ -318,6 +509,84 @@ pub async fn execute_phase(
     root: &Path,
     previous_summary: Option<&str>,
     config_base: &Path,
+) -> PhaseExecutionResult {
+    if config.execution.guard_external_dirty && phase_config.is_destructive {
+        match coordinator.unexpected_dirty_paths().await {
+            Ok(paths) if !paths.is_empty() => {
+                return PhaseExecutionResult::Blocked(format!(
+                    "Working tree has changes phase-golem did not make: {} \
+                     (commit, stash, or revert them, then unblock)",
+                    paths.join(", ")
+                ))
+            }
+            Ok(_) => {}
+            Err(e) => {
+                return PhaseExecutionResult::Failed(format!("Failed to check working tree: {}", e))
+            }
+        }
+    }
+
+    let on_item_branch = config.execution.branch_per_item && phase_config.is_destructive;
+    if on_item_branch {
+        match coordinator.enter_item_branch(item.id()).await {
+            Ok(branch) => log_info!(
+                "[{}][{}] On branch {}",
+                item.id(),
+                phase_config.name.to_uppercase(),
+                branch
+            ),
+            Err(e) => {
+                return PhaseExecutionResult::Failed(format!(
+                    "Failed to check out item branch: {}",
+                    e
+                ))
+            }
+        }
+    }
+
+    let result = run_phase(
+        item,
+        phase_config,
+        config,
+        coordinator,
+        runner,
+        cancel,
+        root,
+        previous_summary,
+        config_base,
+    )
+    .await;
+
+    if on_item_branch
+        && !matches!(
+            result,
+            PhaseExecutionResult::Success(_) | PhaseExecutionResult::SubphaseComplete(_)
+        )
+    {
+        if let Err(e) = coordinator.leave_item_branch().await {
+            log_warn!(
+                "[{}][{}] Failed to leave item branch: {}",
+                item.id(),
+                phase_config.name.to_uppercase(),
+                e
+            );
+        }
+    }
+
+    result
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn run_phase(
+    item: &PgItem,
+    phase_config: &PhaseConfig,
+    config: &PhaseGolemConfig,
+    coordinator: &CoordinatorHandle,
+    runner: &impl AgentRunner,
+    cancel: &CancellationToken,
+    root: &Path,
+    previous_summary: Option<&str>,
+    config_base: &Path,
 ) -> PhaseExecutionResult {
     // 1. Staleness check (destructive phases only)
     if phase_config.is_destructive {
@@ -348,24 +617,137 @@ pub async fn execute_phase(
 
     // 3. Build prompt and paths
     let result_path = result_file_path(root, item.id(), &phase_config.name);
-    let change_folder = match resolve_or_find_change_folder(root, item.id(), item.title()).await {
-        Ok(path) => path,
-        Err(e) => return PhaseExecutionResult::Failed(e),
+
+    // Destructive phases under clone isolation run in a throwaway clone; only
+    // a successful result's changes are applied back to the main tree.
+    let clone = if config.execution.isolation == Isolation::Clone && phase_config.is_destructive {
+        // The clone only sees committed state: commit pending batch phase
+        // output first so the agent works on top of it.
+        if let Err(e) = coordinator.batch_commit().await {
+            return PhaseExecutionResult::Failed(format!(
+                "Failed to commit pending phase output before cloning: {}",
+                e
+            ));
+        }
+        let dir = isolated_clone_dir(item.id(), &phase_config.name);
+        let base_sha = match prepare_isolated_clone(root, &dir) {
+            Ok(sha) => sha,
+            Err(e) => return PhaseExecutionResult::Failed(e),
+        };
+        log_info!(
+            "[{}][{}] Running in isolated clone {}",
+            item.id(),
+            phase_config.name.to_uppercase(),
+            dir.display()
+        );
+        Some((dir, base_sha))
+    } else {
+        None
     };
+    let clone_dir = clone.as_ref().map(|(dir, _)| dir.as_path());
+    let work_root = clone_dir.unwrap_or(root);
 
-    let timeout = Duration::from_secs(config.execution.phase_timeout_minutes as u64 * 60);
-    let max_attempts = config.execution.max_retries + 1;
+    let change_folder =
+        match resolve_or_find_change_folder(work_root, item.id(), item.title()).await {
+            Ok(path) => path,
+            Err(e) => {
+                remove_isolated_clone(clone_dir);
+                return PhaseExecutionResult::Failed(e);
+            }
+        };
+
+    let timeout_minutes = phase_config.effective_timeout_minutes(&config.execution);
+    let timeout = Duration::from_secs(timeout_minutes as u64 * 60);
 
-    // 4. Log CLI tool and model for this phase
+    // 4. Log CLI tool, model, and timeout for this phase
     log_info!(
-        "[{}][{}] Using {} (model: {})",
+        "[{}][{}] Using {} (model: {}{})",
         item.id(),
         phase_config.name.to_uppercase(),
         config.agent.cli.display_name(),
-        config.agent.model.as_deref().unwrap_or("default")
+        phase_config
+            .effective_model(&config.agent)
+            .unwrap_or("default"),
+        if phase_config.model.is_some() {
+            ", phase override"
+        } else {
+            ""
+        }
+    );
+    log_info!(
+        "[{}][{}] Timeout: {}m ({})",
+        item.id(),
+        phase_config.name.to_uppercase(),
+        timeout_minutes,
+        if phase_config.timeout_minutes.is_some() {
+            "phase override"
+        } else {
+            "execution.phase_timeout_minutes"
+        }
     );
 
-    // 5. Retry loop
+    // 5. Run the agent, retrying per execution.max_retries
+    let result = run_phase_attempts(
+        item,
+        phase_config,
+        config,
+        runner,
+        cancel,
+        work_root,
+        &result_path,
+        &change_folder,
+        previous_summary,
+        config_base,
+        timeout,
+    )
+    .await;
+
+    // 6. Bring isolated changes back on success; always discard the clone
+    let Some((dir, base_sha)) = clone else {
+        return result;
+    };
+    let result = match result {
+        PhaseExecutionResult::Success(_) | PhaseExecutionResult::SubphaseComplete(_) => {
+            let applied = match crate::git::isolated_clone_patch(&dir, &base_sha) {
+                Ok(patch) => coordinator
+                    .apply_isolated_patch(patch)
+                    .await
+                    .map_err(|e| e.to_string()),
+                Err(e) => Err(e),
+            };
+            match applied {
+                Ok(()) => result,
+                Err(e) => PhaseExecutionResult::Failed(format!(
+                    "Failed to apply isolated phase output: {}",
+                    e
+                )),
+            }
+        }
+        other => other,
+    };
+    remove_isolated_clone(Some(&dir));
+    result
+}
+
+/// Run the agent for one phase, retrying failed attempts with backoff.
+///
+/// `work_root` is the directory the agent runs in: the project root, or an
+/// isolated clone of it.
+#[allow(clippy::too_many_arguments)]
+async fn run_phase_attempts(
+    item: &PgItem,
+    phase_config: &PhaseConfig,
+    config: &PhaseGolemConfig,
+    runner: &impl AgentRunner,
+    cancel: &CancellationToken,
+    work_root: &Path,
+    result_path: &Path,
+    change_folder: &Path,
+    previous_summary: Option<&str>,
+    config_base: &Path,
+    timeout: Duration,
+) -> PhaseExecutionResult {
+    let max_attempts = config.execution.max_retries + 1;
     let mut failure_context: Option<String> = None;
 
     for attempt in 1..=max_attempts {
@@ -385,8 +767,8 @@ pub async fn execute_phase(
             &phase_config.name,
             phase_config,
             item,
-            &result_path,
-            &change_folder,
+            result_path,
+            change_folder,
             previous_summary,
             item.unblock_context().as_deref(),
             failure_context.as_deref(),
@@ -397,7 +779,13 @@ pub async fn execute_phase(
         // executes them all. Multi-workflow phases run as a single agent invocation
         // (the prompt lists all workflow files).
         let workflow_result = tokio::select! {
-            result = runner.run_agent(&prompt, &result_path, timeout) => result,
+            result = runner.run_agent_in(
+                &prompt,
+                result_path,
+                timeout,
+                work_root,
+                phase_config.effective_model(&config.agent),
+            ) => result,
             _ = cancel.cancelled() => return PhaseExecutionResult::Cancelled,
         };
 
@@ -415,7 +803,34 @@ pub async fn execute_phase(
                         return PhaseExecutionResult::SubphaseComplete(phase_result);
                     }
                     ResultCode::PhaseComplete => {
-                        return PhaseExecutionResult::Success(phase_result);
+                        let Some(hook) = config.execution.post_phase_hook.as_deref() else {
+                            return PhaseExecutionResult::Success(phase_result);
+                        };
+                        let hook = hook
+                            .replace("{item_id}", item.id())
+                            .replace("{phase}", &phase_config.name);
+                        let reason = match run_hook_command(&hook, work_root, cancel).await {
+                            Ok(()) => return PhaseExecutionResult::Success(phase_result),
+                            Err(_) if cancel.is_cancelled() => {
+                                return PhaseExecutionResult::Cancelled
+                            }
+                            Err(e) => format!("Post-phase hook failed: {}", e),
+                        };
+                        if attempt >= max_attempts {
+                            return PhaseExecutionResult::Failed(format!(
+                                "Phase {} failed after {} attempts. Last failure: {}",
+                                phase_config.name, attempt, reason
+                            ));
+                        }
+                        log_info!(
+                            "[{}][{}] Failed (attempt {}/{}): {}",
+                            item.id(),
+                            phase_config.name.to_uppercase(),
+                            attempt,
+                            max_attempts,
+                            reason
+                        );
+                        failure_context = Some(reason);
                     }
                     ResultCode::Blocked => {
                         let reason = phase_result
@@ -425,6 +840,11 @@ pub async fn execute_phase(
                             .to_string();
                         return PhaseExecutionResult::Blocked(reason);
                     }
+                    ResultCode::Failed if phase_config.is_validation => {
+                        // A verdict, not a flaky run: hand it to the scheduler
+                        // to fail the item instead of retrying
+                        return PhaseExecutionResult::Success(phase_result);
+                    }
                     ResultCode::Failed => {
                         if attempt >= max_attempts {
                             return PhaseExecutionResult::Failed(format!(
@@ -462,6 +882,21 @@ pub async fn execute_phase(
                 failure_context = Some(e);
             }
         }
+
+        // Back off before the next attempt; cancellation cuts the wait short
+        let backoff = retry_backoff_delay(&config.execution, attempt);
+        if !backoff.is_zero() {
+            log_info!(
+                "[{}][{}] Retrying in {}s",
+                item.id(),
+                phase_config.name.to_uppercase(),
+                backoff.as_secs()
+            );
+            tokio::select! {
+                _ = tokio::time::sleep(backoff) => {}
+                _ = cancel.cancelled() => return PhaseExecutionResult::Cancelled,
+            }
+        }
     }
 
     // Should not be reached due to loop logic, but safety fallback
@@ -475,9 +910,10 @@ pub async fn execute_phase(
 
 /// Build the prompt for executor-driven phase execution.
 ///
-/// Uses the existing prompt infrastructure with the context preamble.
+/// Uses the existing prompt infrastructure with the context preamble. Also used by
+/// `dump-prompt`, so what it prints matches what the agent would receive.
 #[allow(clippy::too_many_arguments)]
-fn build_executor_prompt(
+pub fn build_executor_prompt(
     phase: &str,
     phase_config: &PhaseConfig,
     item: &PgItem,
@@ -510,51 +946,99 @@ pub fn result_file_path(root: &Path, item_id: &str, phase: &str) -> PathBuf {
         .join(format!("phase_result_{}_{}.json", item_id, phase))
 }
 
+/// Directory for the isolated clone of a phase run, under the system temp dir.
+pub fn isolated_clone_dir(item_id: &str, phase: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "phase-golem-{}-{}-{}",
+        item_id,
+        phase,
+        std::process::id()
+    ))
+}
+
+/// Create a fresh isolated clone at `dir`, replacing any stale one. Returns
+/// the cloned HEAD SHA.
+fn prepare_isolated_clone(root: &Path, dir: &Path) -> Result<String, String> {
+    remove_isolated_clone(Some(dir));
+    crate::git::create_isolated_clone(root, dir).map_err(|e| {
+        remove_isolated_clone(Some(dir));
+        format!("Failed to create isolated clone: {}", e)
+    })
+}
+
+fn remove_isolated_clone(dir: Option<&Path>) {
+    let Some(dir) = dir else {
+        return;
+    };
+    if let Err(e) = std::fs::remove_dir_all(dir) {
+        if e.kind() != std::io::ErrorKind::NotFound {
+            log_warn!(
+                "Warning: failed to remove isolated clone {}: {}",
+                dir.display(),
+                e
+            );
+        }
+    }
+}
+
 /// Resolve an existing change folder or create one if not found.
 ///
-/// Searches the `changes/` directory for a folder prefixed with `{item_id}_`.
-/// Falls back to creating `{item_id}_{slugified_title}` if none exists.
+/// Uses `find_change_folder`, falling back to creating
+/// `{item_id}_{slugified_title}` if none exists.
 async fn resolve_or_find_change_folder(
     root: &Path,
     item_id: &str,
     title: &str,
 ) -> Result<PathBuf, String> {
-    let changes_dir = root.join("changes");
-    let prefix = format!("{}_", item_id);
-
-    match tokio::fs::read_dir(&changes_dir).await {
-        Ok(mut entries) => {
-            while let Some(entry) = entries
-                .next_entry()
-                .await
-                .map_err(|e| format!("Failed to read directory entry: {}", e))?
-            {
-                let name = entry.file_name().to_string_lossy().to_string();
-                if name.starts_with(&prefix)
-                    && entry.file_type().await.map(|t| t.is_dir()).unwrap_or(false)
-                {
-                    return Ok(entry.path());
-                }
-            }
-        }
-        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
-            // Directory doesn't exist yet — fall through to creation
-        }
-        Err(e) => {
-            return Err(format!("Failed to read {}: {}", changes_dir.display(), e));
-        }
+    if let Some(existing) = find_change_folder(root, item_id)? {
+        return Ok(existing);
     }
 
-    // Create the directory if it doesn't exist
-    let slug = slugify(title);
-    let folder_name = format!("{}_{}", item_id, slug);
-    let folder_path = changes_dir.join(folder_name);
+    let folder_path = new_change_folder_path(root, item_id, title);
     tokio::fs::create_dir_all(&folder_path)
         .await
         .map_err(|e| format!("Failed to create {}: {}", folder_path.display(), e))?;
     Ok(folder_path)
 }
 
+/// Find the item's existing change folder: a directory under `changes/`
+/// prefixed with `{item_id}_`. A missing `changes/` directory means none.
+pub fn find_change_folder(root: &Path, item_id: &str) -> Result<Option<PathBuf>, String> {
+    let changes_dir = root.join("changes");
+    let prefix = format!("{}_", item_id);
+
+    let entries = match std::fs::read_dir(&changes_dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("Failed to read {}: {}", changes_dir.display(), e)),
+    };
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+        if entry.file_name().to_string_lossy().starts_with(&prefix)
+            && entry.file_type().map(|t| t.is_dir()).unwrap_or(false)
+        {
+            return Ok(Some(entry.path()));
+        }
+    }
+    Ok(None)
+}
+
+/// Change folder for an item without creating it: the existing `changes/{id}_*`
+/// directory if there is one, else the path `resolve_or_find_change_folder`
+/// would create.
+pub fn change_folder_path(root: &Path, item_id: &str, title: &str) -> PathBuf {
+    find_change_folder(root, item_id)
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| new_change_folder_path(root, item_id, title))
+}
+
+/// Path of a fresh change folder: `changes/{item_id}_{slugified_title}`.
+fn new_change_folder_path(root: &Path, item_id: &str, title: &str) -> PathBuf {
+    root.join("changes")
+        .join(format!("{}_{}", item_id, slugify(title)))
+}
+
 /// Convert a title to a URL-friendly slug.
 pub fn slugify(title: &str) -> String {
     title
@@ -1,5 +1,8 @@
 use std::collections::HashSet;
 
+use chrono::{DateTime, Datelike, Duration, Utc};
+
+use crate::executor::{dimension_level_value, size_level_value};
 use crate::pg_item::PgItem;
 use crate::types::{
     parse_dimension_level, parse_item_status, parse_size_level, DimensionLevel, ItemStatus,
@@ -15,6 +18,27 @@ pub enum FilterField {
     Complexity,
     Tag,
     PipelineType,
+    Phase,
+    Age,
+}
+
+/// Unit for an `older-than:<N><unit>` age window.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AgeUnit {
+    Hours,
+    Days,
+    Weeks,
+}
+
+/// Named created-date window used by `--only age=...`.
+///
+/// Calendar windows (`today`, `this-week`) are evaluated in UTC, matching the
+/// timestamps stored on items. Weeks start on Monday.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AgeWindow {
+    Today,
+    ThisWeek,
+    OlderThan { amount: u32, unit: AgeUnit },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -24,14 +48,50 @@ pub enum FilterValue {
     Size(SizeLevel),
     Tag(String),
     PipelineType(String),
+    Phase(String),
+    Age(AgeWindow),
+    /// Elapsed time since creation, for `age>7d`-style comparisons.
+    AgeDuration(std::time::Duration),
+}
+
+/// Comparison operator between a field and its value(s).
+///
+/// Only `Eq` and `Ne` accept comma-separated values; `Ne` excludes items
+/// matching any of them. The ordering operators apply to the ordered
+/// dimensions (`impact`, `size`, `complexity`, `risk`) and take a single value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FilterOp {
+    Eq,
+    Ne,
+    Gte,
+    Gt,
+    Lte,
+    Lt,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct FilterCriterion {
     pub field: FilterField,
+    pub op: FilterOp,
     pub values: Vec<FilterValue>,
 }
 
+impl FilterField {
+    /// Whether the field's values have an ordinal ranking usable with `>=`, `<` etc.
+    pub fn is_ordered(&self) -> bool {
+        matches!(
+            self,
+            FilterField::Impact | FilterField::Size | FilterField::Complexity | FilterField::Risk
+        )
+    }
+
+    /// Whether `>=`, `<` etc. are accepted: the ordered dimensions, plus `age`
+    /// compared as a duration since creation.
+    pub fn supports_comparison(&self) -> bool {
+        self.is_ordered() || *self == FilterField::Age
+    }
+}
+
 impl std::fmt::Display for FilterField {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let name = match self {
@@ -42,6 +102,8 @@ impl std::fmt::Display for FilterField {
             FilterField::Complexity => "complexity",
             FilterField::Tag => "tag",
             FilterField::PipelineType => "pipeline_type",
+            FilterField::Phase => "phase",
+            FilterField::Age => "age",
         };
         write!(f, "{}", name)
     }
@@ -62,23 +124,114 @@ impl std::fmt::Display for FilterValue {
             FilterValue::Size(s) => write!(f, "{}", s),
             FilterValue::Tag(t) => write!(f, "{}", t),
             FilterValue::PipelineType(p) => write!(f, "{}", p),
+            FilterValue::Phase(p) => write!(f, "{}", p),
+            FilterValue::Age(a) => write!(f, "{}", a),
+            FilterValue::AgeDuration(d) => write!(f, "{}", humantime::format_duration(*d)),
         }
     }
 }
 
+impl std::fmt::Display for AgeWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AgeWindow::Today => write!(f, "today"),
+            AgeWindow::ThisWeek => write!(f, "this-week"),
+            AgeWindow::OlderThan { amount, unit } => {
+                let suffix = match unit {
+                    AgeUnit::Hours => "h",
+                    AgeUnit::Days => "d",
+                    AgeUnit::Weeks => "w",
+                };
+                write!(f, "older-than:{}{}", amount, suffix)
+            }
+        }
+    }
+}
+
+impl AgeWindow {
+    /// Returns true if `created_at` falls inside this window relative to `now`.
+    pub fn contains(&self, created_at: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        match self {
+            AgeWindow::Today => created_at.date_naive() == now.date_naive(),
+            AgeWindow::ThisWeek => {
+                let days_since_monday = i64::from(now.weekday().num_days_from_monday());
+                let week_start = now.date_naive() - Duration::days(days_since_monday);
+                created_at.date_naive() >= week_start && created_at <= now
+            }
+            AgeWindow::OlderThan { amount, unit } => {
+                let amount = i64::from(*amount);
+                let threshold = match unit {
+                    AgeUnit::Hours => Duration::hours(amount),
+                    AgeUnit::Days => Duration::days(amount),
+                    AgeUnit::Weeks => Duration::weeks(amount),
+                };
+                now - created_at > threshold
+            }
+        }
+    }
+}
+
+fn parse_age_window(token: &str) -> Result<AgeWindow, String> {
+    let invalid = || {
+        format!(
+            "Invalid value '{}' for field 'age'. Valid values: today, this-week, older-than:<N>h|d|w (e.g. older-than:7d)",
+            token
+        )
+    };
+
+    match token.to_lowercase().as_str() {
+        "today" => Ok(AgeWindow::Today),
+        "this-week" => Ok(AgeWindow::ThisWeek),
+        other => {
+            let duration = other.strip_prefix("older-than:").ok_or_else(invalid)?;
+            if duration.len() < 2 {
+                return Err(invalid());
+            }
+            let (amount_str, unit_str) = duration.split_at(duration.len() - 1);
+            let unit = match unit_str {
+                "h" => AgeUnit::Hours,
+                "d" => AgeUnit::Days,
+                "w" => AgeUnit::Weeks,
+                _ => return Err(invalid()),
+            };
+            let amount: u32 = amount_str.parse().map_err(|_| invalid())?;
+            Ok(AgeWindow::OlderThan { amount, unit })
+        }
+    }
+}
+
+impl std::fmt::Display for FilterOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let symbol = match self {
+            FilterOp::Eq => "=",
+            FilterOp::Ne => "!=",
+            FilterOp::Gte => ">=",
+            FilterOp::Gt => ">",
+            FilterOp::Lte => "<=",
+            FilterOp::Lt => "<",
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
 impl std::fmt::Display for FilterCriterion {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let values_str: Vec<String> = self.values.iter().map(|v| v.to_string()).collect();
-        write!(f, "{}={}", self.field, values_str.join(","))
+        write!(f, "{}{}{}", self.field, self.op, values_str.join(","))
     }
 }
 
-fn parse_single_value(field: &FilterField, token: &str) -> Result<FilterValue, String> {
+fn parse_single_value(
+    field: &FilterField,
+    op: FilterOp,
+    token: &str,
+) -> Result<FilterValue, String> {
     match field {
         FilterField::Status => {
-            let status = parse_item_status(token).map_err(|_| {
+            // Accept the hyphenated `in-progress` spelling alongside `in_progress`
+            let status = parse_item_status(&token.replace('-', "_")).map_err(|_| {
                 format!(
-                    "Invalid value '{}' for field 'status'. Valid values: new, scoping, ready, in_progress, done, blocked",
+                    "Invalid value '{}' for field 'status'. Valid values: new, scoping, ready, in_progress (or in-progress), done, blocked",
                     token
                 )
             })?;
@@ -122,11 +275,55 @@ fn parse_single_value(field: &FilterField, token: &str) -> Result<FilterValue, S
         }
         FilterField::Tag => Ok(FilterValue::Tag(token.to_string())),
         FilterField::PipelineType => Ok(FilterValue::PipelineType(token.to_string())),
+        FilterField::Phase => Ok(FilterValue::Phase(token.to_string())),
+        FilterField::Age if matches!(op, FilterOp::Eq | FilterOp::Ne) => {
+            if humantime::parse_duration(token).is_ok() {
+                return Err(format!(
+                    "Field 'age' compares durations with >, >=, <, <= (e.g. age>{}), not '{}'",
+                    token, op
+                ));
+            }
+            Ok(FilterValue::Age(parse_age_window(token)?))
+        }
+        FilterField::Age => {
+            let duration = humantime::parse_duration(token).map_err(|_| {
+                format!(
+                    "Invalid duration '{}' for field 'age'. Use e.g. 24h, 7d, 2w",
+                    token
+                )
+            })?;
+            Ok(FilterValue::AgeDuration(duration))
+        }
     }
 }
 
+/// Split `raw` at the first operator (`!=`, `>=`, `<=`, `>`, `<`, `=`).
+///
+/// Only the first operator counts, so values may themselves contain `=`, `<`
+/// or `>` (e.g. `tag=a=b`). A `!` not followed by `=` is not an operator.
+fn split_operator(raw: &str) -> Option<(&str, FilterOp, &str)> {
+    let index = raw.find(['!', '=', '<', '>'])?;
+    let rest = &raw[index..];
+    let (op, op_len) = if rest.starts_with("!=") {
+        (FilterOp::Ne, 2)
+    } else if rest.starts_with('!') {
+        return None;
+    } else if rest.starts_with(">=") {
+        (FilterOp::Gte, 2)
+    } else if rest.starts_with("<=") {
+        (FilterOp::Lte, 2)
+    } else if rest.starts_with('>') {
+        (FilterOp::Gt, 1)
+    } else if rest.starts_with('<') {
+        (FilterOp::Lt, 1)
+    } else {
+        (FilterOp::Eq, 1)
+    };
+    Some((&raw[..index], op, &rest[op_len..]))
+}
+
 pub fn parse_filter(raw: &str) -> Result<FilterCriterion, String> {
-    let Some((field_str, value_str)) = raw.split_once('=') else {
+    let Some((field_str, op, value_str)) = split_operator(raw) else {
         return Err(format!("Filter must be in format KEY=VALUE, got: {}", raw));
     };
 
@@ -145,15 +342,31 @@ pub fn parse_filter(raw: &str) -> Result<FilterCriterion, String> {
         "complexity" => FilterField::Complexity,
         "tag" => FilterField::Tag,
         "pipeline_type" => FilterField::PipelineType,
+        "phase" => FilterField::Phase,
+        "age" => FilterField::Age,
         _ => {
             return Err(format!(
-                "Unknown filter field: {}. Supported: status, impact, size, risk, complexity, tag, pipeline_type",
+                "Unknown filter field: {}. Supported: status, impact, size, risk, complexity, tag, pipeline_type, phase, age",
                 field_str
             ));
         }
     };
 
+    // `tag=!spike` or `tag!==spike` could mean either operator
+    if value_str.starts_with(['=', '!']) {
+        return Err(format!(
+            "Ambiguous operator in '{}': use either {}=VALUE or {}!=VALUE",
+            raw, field_str, field_str
+        ));
+    }
+
     let tokens: Vec<&str> = value_str.split(',').collect();
+    if !matches!(op, FilterOp::Eq | FilterOp::Ne) && tokens.len() > 1 {
+        return Err(format!(
+            "Comparison '{}' takes a single value, got: {}",
+            op, value_str
+        ));
+    }
     let mut parsed: Vec<(String, FilterValue)> = Vec::with_capacity(tokens.len());
 
     for token in &tokens {
@@ -164,7 +377,7 @@ pub fn parse_filter(raw: &str) -> Result<FilterCriterion, String> {
                 field
             ));
         }
-        let value = parse_single_value(&field, trimmed)?;
+        let value = parse_single_value(&field, op, trimmed)?;
         parsed.push((trimmed.to_string(), value));
     }
 
@@ -180,10 +393,15 @@ pub fn parse_filter(raw: &str) -> Result<FilterCriterion, String> {
 
     let values: Vec<FilterValue> = parsed.into_iter().map(|(_, v)| v).collect();
 
-    Ok(FilterCriterion { field, values })
+    Ok(FilterCriterion { field, op, values })
 }
 
-fn matches_single_value(field: &FilterField, value: &FilterValue, item: &PgItem) -> bool {
+fn matches_single_value(
+    field: &FilterField,
+    value: &FilterValue,
+    item: &PgItem,
+    now: DateTime<Utc>,
+) -> bool {
     match (field, value) {
         (FilterField::Status, FilterValue::Status(target)) => item.pg_status() == *target,
         (FilterField::Impact, FilterValue::Dimension(target)) => {
@@ -198,26 +416,134 @@ fn matches_single_value(field: &FilterField, value: &FilterValue, item: &PgItem)
         (FilterField::PipelineType, FilterValue::PipelineType(target)) => {
             item.pipeline_type().as_deref() == Some(target.as_str())
         }
+        (FilterField::Phase, FilterValue::Phase(target)) => {
+            item.phase().as_deref() == Some(target.as_str())
+        }
+        (FilterField::Age, FilterValue::Age(window)) => window.contains(item.created_at(), now),
         // Mismatched field/value combinations should never occur with parse_filter,
         // but return false for safety.
         _ => false,
     }
 }
 
-/// OR logic: item matches if ANY value in the criterion matches.
+/// Ordinal rank of an item's value for an ordered field, or of a parsed value.
+fn item_rank(field: &FilterField, item: &PgItem) -> Option<u8> {
+    match field {
+        FilterField::Impact => item.impact().as_ref().map(dimension_level_value),
+        FilterField::Size => item.size().as_ref().map(size_level_value),
+        FilterField::Risk => item.risk().as_ref().map(dimension_level_value),
+        FilterField::Complexity => item.complexity().as_ref().map(dimension_level_value),
+        _ => None,
+    }
+}
+
+fn value_rank(value: &FilterValue) -> Option<u8> {
+    match value {
+        FilterValue::Dimension(level) => Some(dimension_level_value(level)),
+        FilterValue::Size(level) => Some(size_level_value(level)),
+        _ => None,
+    }
+}
+
+/// Items with no value for the field never match a comparison.
+fn matches_comparison(
+    field: &FilterField,
+    op: FilterOp,
+    value: &FilterValue,
+    item: &PgItem,
+    now: DateTime<Utc>,
+) -> bool {
+    if let FilterValue::AgeDuration(target) = value {
+        // Items stamped in the future count as zero age
+        let age = (now - item.created_at()).to_std().unwrap_or_default();
+        return compare(op, age, *target);
+    }
+    let (Some(actual), Some(target)) = (item_rank(field, item), value_rank(value)) else {
+        return false;
+    };
+    compare(op, actual, target)
+}
+
+fn compare<T: PartialOrd>(op: FilterOp, actual: T, target: T) -> bool {
+    match op {
+        FilterOp::Eq => actual == target,
+        FilterOp::Ne => actual != target,
+        FilterOp::Gte => actual >= target,
+        FilterOp::Gt => actual > target,
+        FilterOp::Lte => actual <= target,
+        FilterOp::Lt => actual < target,
+    }
+}
+
+/// OR logic: item matches if ANY value in the criterion matches. For `!=`
+/// the item matches only if NONE of the values match (items with no value
+/// for the field are not excluded).
 pub fn matches_item(criterion: &FilterCriterion, item: &PgItem) -> bool {
-    criterion
-        .values
-        .iter()
-        .any(|v| matches_single_value(&criterion.field, v, item))
+    matches_item_at(criterion, item, Utc::now())
+}
+
+/// Like `matches_item`, but evaluates age windows against an explicit `now`.
+pub fn matches_item_at(criterion: &FilterCriterion, item: &PgItem, now: DateTime<Utc>) -> bool {
+    if criterion.op == FilterOp::Ne {
+        return !criterion
+            .values
+            .iter()
+            .any(|v| matches_single_value(&criterion.field, v, item, now));
+    }
+    criterion.values.iter().any(|v| match criterion.op {
+        FilterOp::Eq => matches_single_value(&criterion.field, v, item, now),
+        op => matches_comparison(&criterion.field, op, v, item, now),
+    })
+}
+
+/// Parse filter criteria from the contents of a `--filter-file`.
+///
+/// Each non-blank line is one criterion, equivalent to one `--only` flag:
+/// comma-separated values are OR'd within the line and lines are AND'd together.
+/// Lines starting with `#` are comments. Cross-line validation is left to
+/// `validate_filter_criteria` so file and inline criteria can be merged first.
+pub fn parse_filter_file(contents: &str) -> Result<Vec<FilterCriterion>, String> {
+    contents
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(line_number, line)| {
+            parse_filter(line).map_err(|e| format!("line {}: {}", line_number, e))
+        })
+        .collect()
 }
 
 pub fn validate_filter_criteria(criteria: &[FilterCriterion]) -> Result<(), String> {
     let mut seen_scalar_fields = HashSet::new();
     let mut seen_tag_criteria = HashSet::new();
+    let mut seen_comparison_criteria = HashSet::new();
+    let mut seen_negated_criteria = HashSet::new();
 
     for criterion in criteria {
-        if criterion.field == FilterField::Tag {
+        if criterion.op == FilterOp::Ne {
+            // Negations may repeat a field; each excludes more items (AND)
+            if !seen_negated_criteria.insert(criterion) {
+                return Err(format!(
+                    "Duplicate filter: {} specified multiple times",
+                    criterion
+                ));
+            }
+        } else if criterion.op != FilterOp::Eq {
+            if !criterion.field.supports_comparison() {
+                return Err(format!(
+                    "Comparison '{}' is not supported for field '{}'. Supported for: impact, size, complexity, risk, age",
+                    criterion.op, criterion.field
+                ));
+            }
+            // Comparisons may repeat a field to express a range (impact>=low, impact<high)
+            if !seen_comparison_criteria.insert(criterion) {
+                return Err(format!(
+                    "Duplicate filter: {} specified multiple times",
+                    criterion
+                ));
+            }
+        } else if criterion.field == FilterField::Tag {
             if !seen_tag_criteria.insert(criterion) {
                 return Err(format!(
                     "Duplicate filter: {} specified multiple times",
@@ -236,9 +562,10 @@ pub fn validate_filter_criteria(criteria: &[FilterCriterion]) -> Result<(), Stri
 }
 
 pub fn apply_filters(criteria: &[FilterCriterion], items: &[PgItem]) -> Vec<PgItem> {
+    let now = Utc::now();
     items
         .iter()
-        .filter(|item| criteria.iter().all(|c| matches_item(c, item)))
+        .filter(|item| criteria.iter().all(|c| matches_item_at(c, item, now)))
         .cloned()
         .collect()
 }
@@ -1,5 +1,6 @@
+use std::io::Write;
 use std::path::Path;
-use std::process::Command;
+use std::process::{Command, Stdio};
 
 /// A single entry from `git status --porcelain` output.
 ///
@@ -30,7 +31,8 @@ pub fn is_git_repo(repo_dir: Option<&Path>) -> Result<(), String> {
 /// - Git repo exists (`git rev-parse --git-dir`)
 /// - Working tree is clean (`git status --porcelain` is empty)
 /// - Not in detached HEAD or rebase/merge state
-pub fn check_preconditions(repo_dir: Option<&Path>) -> Result<(), String> {
+/// - When `remote` is `Some`, the remote is reachable (`git ls-remote`)
+pub fn check_preconditions(repo_dir: Option<&Path>, remote: Option<&str>) -> Result<(), String> {
     // Verify git repo exists and capture git dir path for later checks
     let git_dir_output = run_git_command(&["rev-parse", "--git-dir"], repo_dir)
         .map_err(|_| "Not a git repository (or git is not installed)".to_string())?;
@@ -74,9 +76,82 @@ pub fn check_preconditions(repo_dir: Option<&Path>) -> Result<(), String> {
         );
     }
 
+    if let Some(remote) = remote {
+        verify_remote_reachable(remote, repo_dir)?;
+    }
+
     Ok(())
 }
 
+/// Build the argument list used to probe a remote for reachability.
+///
+/// Only heads are listed to keep the response small on large remotes.
+pub fn ls_remote_args(remote: &str) -> Vec<&str> {
+    vec!["ls-remote", "--heads", remote]
+}
+
+/// Verify that `remote` (a remote name or URL) responds to `git ls-remote`.
+///
+/// Credential prompts are disabled so an unauthenticated remote fails
+/// instead of blocking the run on stdin.
+pub fn verify_remote_reachable(remote: &str, repo_dir: Option<&Path>) -> Result<(), String> {
+    let mut cmd = Command::new("git");
+    cmd.args(ls_remote_args(remote));
+    cmd.env("GIT_TERMINAL_PROMPT", "0");
+
+    if let Some(dir) = repo_dir {
+        cmd.current_dir(dir);
+    }
+
+    let output = cmd
+        .output()
+        .map_err(|e| format!("Failed to run git ls-remote: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!(
+            "Git remote '{}' is unreachable: {}. Fix connectivity or set git.verify_remote = false.",
+            remote,
+            stderr.trim()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Build the argument list for pushing to `remote`.
+///
+/// Without an explicit `branch`, `HEAD` is pushed to the same-named branch
+/// on the remote.
+pub fn push_args<'a>(remote: &'a str, branch: Option<&'a str>) -> Vec<&'a str> {
+    vec!["push", remote, branch.unwrap_or("HEAD")]
+}
+
+/// Push to `remote` (optionally a specific `branch`) and return git's output.
+///
+/// Credential prompts are disabled, as in `verify_remote_reachable`.
+pub fn push(remote: &str, branch: Option<&str>, repo_dir: Option<&Path>) -> Result<String, String> {
+    let mut cmd = Command::new("git");
+    cmd.args(push_args(remote, branch));
+    cmd.env("GIT_TERMINAL_PROMPT", "0");
+
+    if let Some(dir) = repo_dir {
+        cmd.current_dir(dir);
+    }
+
+    let output = cmd
+        .output()
+        .map_err(|e| format!("Failed to run git push: {}", e))?;
+
+    // git push reports progress and ref updates on stderr
+    let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+    if !output.status.success() {
+        return Err(format!("git push to '{}' failed: {}", remote, stderr));
+    }
+
+    Ok(stderr)
+}
+
 /// Stage specific file paths for commit in a specific repo directory.
 ///
 /// Uses `git add` with explicit paths only (never `-A` or `.`).
@@ -109,6 +184,19 @@ pub fn commit(message: &str, repo_dir: Option<&Path>) -> Result<String, String>
     Ok(hash.trim().to_string())
 }
 
+/// Stash `paths` (tracked changes and untracked files) under `message`,
+/// leaving the rest of the working tree alone. Restore with `git stash pop`.
+pub fn stash_paths(paths: &[&str], message: &str, repo_dir: Option<&Path>) -> Result<(), String> {
+    if paths.is_empty() {
+        return Ok(());
+    }
+
+    let mut args = vec!["stash", "push", "--include-untracked", "-m", message, "--"];
+    args.extend_from_slice(paths);
+    run_git_command(&args, repo_dir)?;
+    Ok(())
+}
+
 /// Parse `git status --porcelain` output into structured entries.
 pub fn get_status(repo_dir: Option<&Path>) -> Result<Vec<StatusEntry>, String> {
     let output = run_git_command(&["status", "--porcelain"], repo_dir)?;
@@ -175,6 +263,199 @@ pub fn is_ancestor(sha: &str, project_root: &Path) -> Result<bool, String> {
     }
 }
 
+/// Branch that holds an item's destructive phase commits under
+/// `execution.branch_per_item`.
+pub fn item_branch_name(item_id: &str) -> String {
+    format!("phase-golem/{}", item_id)
+}
+
+/// Name of the checked-out branch. Errors on a detached HEAD.
+pub fn current_branch(project_root: &Path) -> Result<String, String> {
+    let output = run_git_command(
+        &["symbolic-ref", "--quiet", "--short", "HEAD"],
+        Some(project_root),
+    )
+    .map_err(|_| "HEAD is detached".to_string())?;
+    Ok(output.trim().to_string())
+}
+
+/// Whether a local branch named `branch` exists.
+pub fn branch_exists(branch: &str, project_root: &Path) -> bool {
+    let reference = format!("refs/heads/{}", branch);
+    run_git_command(
+        &["show-ref", "--verify", "--quiet", &reference],
+        Some(project_root),
+    )
+    .is_ok()
+}
+
+/// Check out `branch`, carrying uncommitted changes along.
+pub fn checkout_branch(branch: &str, project_root: &Path) -> Result<(), String> {
+    run_git_command(&["checkout", "--quiet", branch], Some(project_root))?;
+    Ok(())
+}
+
+/// Check out an item branch, creating it from `base` on first use.
+///
+/// An existing branch has `base` merged in so it starts from the current
+/// item store and picks up work committed on `base` since it was last used.
+/// On a merge conflict the merge is aborted and `base` checked out again.
+pub fn checkout_item_branch(branch: &str, base: &str, project_root: &Path) -> Result<(), String> {
+    if !branch_exists(branch, project_root) {
+        run_git_command(&["checkout", "--quiet", "-b", branch], Some(project_root))?;
+        return Ok(());
+    }
+
+    checkout_branch(branch, project_root)?;
+    if let Err(e) = run_git_command(&["merge", "--quiet", "--no-edit", base], Some(project_root)) {
+        let _ = run_git_command(&["merge", "--abort"], Some(project_root));
+        let _ = checkout_branch(base, project_root);
+        return Err(format!("Failed to merge {} into {}: {}", base, branch, e));
+    }
+    Ok(())
+}
+
+/// Remove `path` from the index, leaving the working tree untouched.
+pub fn unstage_path(path: &str, project_root: &Path) -> Result<(), String> {
+    run_git_command(&["reset", "--quiet", "--", path], Some(project_root))?;
+    Ok(())
+}
+
+/// Changed-line counts for one file in a diff (`git diff --numstat`).
+///
+/// `added` and `removed` are `None` for binary files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileDiffStat {
+    pub path: String,
+    pub added: Option<u64>,
+    pub removed: Option<u64>,
+}
+
+/// Per-file changed-line counts between `from` and the current HEAD.
+pub fn diff_stat_since(from: &str, project_root: &Path) -> Result<Vec<FileDiffStat>, String> {
+    let range = format!("{}..HEAD", from);
+    let output = run_git_command(&["diff", "--numstat", &range], Some(project_root))?;
+    Ok(parse_numstat(&output))
+}
+
+/// Parse `git diff --numstat` output (`added<TAB>removed<TAB>path`).
+pub fn parse_numstat(output: &str) -> Vec<FileDiffStat> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, '\t');
+            let added = parts.next()?;
+            let removed = parts.next()?;
+            let path = parts.next()?;
+            Some(FileDiffStat {
+                path: path.to_string(),
+                added: added.parse().ok(),
+                removed: removed.parse().ok(),
+            })
+        })
+        .collect()
+}
+
+/// Commits on HEAD since `from`, oldest first, as `(sha, subject)` pairs.
+pub fn commits_since(from: &str, project_root: &Path) -> Result<Vec<(String, String)>, String> {
+    let range = format!("{}..HEAD", from);
+    let output = run_git_command(
+        &["log", "--reverse", "--format=%H%x09%s", &range],
+        Some(project_root),
+    )?;
+    Ok(output
+        .lines()
+        .filter_map(|line| {
+            let (sha, subject) = line.split_once('\t')?;
+            Some((sha.to_string(), subject.to_string()))
+        })
+        .collect())
+}
+
+/// Make a shallow clone of `source`'s HEAD at `dest` for an isolated phase.
+///
+/// A `file://` URL is used so `--depth 1` is honored for a local repository.
+/// Only committed state is cloned. Returns the cloned HEAD SHA, the base
+/// `isolated_clone_patch` diffs against.
+pub fn create_isolated_clone(source: &Path, dest: &Path) -> Result<String, String> {
+    let source = source
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve {}: {}", source.display(), e))?;
+    let url = format!("file://{}", source.display());
+    let dest_str = dest
+        .to_str()
+        .ok_or_else(|| format!("Path contains invalid UTF-8: {:?}", dest))?;
+    run_git_command(
+        &[
+            "clone",
+            "--quiet",
+            "--depth",
+            "1",
+            "--no-tags",
+            &url,
+            dest_str,
+        ],
+        None,
+    )?;
+    get_head_sha(dest)
+}
+
+/// Collect everything changed in an isolated clone since `base_sha` (from
+/// `create_isolated_clone`) as a binary patch: commits the agent made in the
+/// clone as well as its uncommitted changes. The task-golem store is
+/// excluded; item state only changes through the coordinator.
+///
+/// Stages with `git add -A`, which is safe only because the clone is disposable.
+pub fn isolated_clone_patch(clone_dir: &Path, base_sha: &str) -> Result<String, String> {
+    run_git_command(&["add", "-A"], Some(clone_dir))?;
+    run_git_command(
+        &[
+            "diff",
+            "--cached",
+            "--binary",
+            base_sha,
+            "--",
+            ".",
+            ":(exclude).task-golem",
+        ],
+        Some(clone_dir),
+    )
+}
+
+/// Apply a patch from `isolated_clone_patch` to the working tree of `repo_dir`.
+///
+/// Changes are left unstaged so the normal phase-completion staging picks them up.
+pub fn apply_patch(patch: &str, repo_dir: &Path) -> Result<(), String> {
+    if patch.trim().is_empty() {
+        return Ok(());
+    }
+
+    let mut child = Command::new("git")
+        .args(["apply", "--whitespace=nowarn", "-"])
+        .current_dir(repo_dir)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to run git apply: {}", e))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(patch.as_bytes())
+            .map_err(|e| format!("Failed to write patch to git apply: {}", e))?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("Failed to run git apply: {}", e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("git apply failed: {}", stderr.trim()));
+    }
+
+    Ok(())
+}
+
 /// Run a git command and return its stdout as a string.
 fn run_git_command(args: &[&str], repo_dir: Option<&Path>) -> Result<String, String> {
     let mut cmd = Command::new("git");
@@ -10,6 +10,7 @@ pub mod pg_error;
 pub mod pg_item;
 pub mod preflight;
 pub mod prompt;
+pub mod redact;
 pub mod scheduler;
 pub mod types;
 pub mod worklog;
@@ -1,8 +1,30 @@
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 use crate::log_warn;
 
+/// First wait between attempts in `try_acquire_with_timeout`; doubles per attempt.
+const LOCK_RETRY_INITIAL_MS: u64 = 50;
+
+/// Upper bound on the wait between attempts.
+const LOCK_RETRY_MAX_MS: u64 = 1000;
+
+/// Why the lock could not be acquired.
+#[derive(Debug, thiserror::Error)]
+pub enum LockError {
+    /// Another process holds the lock; waiting may help.
+    #[error("{0}")]
+    Held(String),
+    /// Creating the runtime directory, opening or locking a lock file, or
+    /// writing the PID file failed; retrying won't help.
+    #[error("{0}")]
+    Io(String),
+    /// Shutdown was requested while waiting for the lock.
+    #[error("Interrupted while waiting for the phase-golem lock")]
+    Interrupted,
+}
+
 /// A lock guard that releases the lock file on drop.
 #[must_use = "lock is released when LockGuard is dropped"]
 pub struct LockGuard {
@@ -33,66 +55,194 @@ impl Drop for LockGuard {
     }
 }
 
+/// The process recorded in the PID file: its PID, then (on a second line) the
+/// RFC 3339 time it acquired the lock.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LockHolder {
+    pid: i32,
+    started_at: Option<String>,
+}
+
+impl LockHolder {
+    fn current() -> Self {
+        Self {
+            pid: std::process::id() as i32,
+            started_at: Some(chrono::Utc::now().to_rfc3339()),
+        }
+    }
+
+    fn parse(contents: &str) -> Option<Self> {
+        let mut lines = contents.lines().map(str::trim);
+        let pid = lines.next()?.parse::<i32>().ok()?;
+        let started_at = lines.next().filter(|s| !s.is_empty()).map(str::to_string);
+        Some(Self { pid, started_at })
+    }
+
+    fn to_file_contents(&self) -> String {
+        match &self.started_at {
+            Some(started_at) => format!("{}\n{}\n", self.pid, started_at),
+            None => format!("{}\n", self.pid),
+        }
+    }
+
+    fn describe(&self) -> String {
+        match &self.started_at {
+            Some(started_at) => format!("PID {}, started {}", self.pid, started_at),
+            None => format!("PID {}", self.pid),
+        }
+    }
+}
+
+fn open_lock_file(lock_path: &Path) -> Result<fslock::LockFile, LockError> {
+    fslock::LockFile::open(lock_path).map_err(|e| {
+        LockError::Io(format!(
+            "Failed to open lock file {}: {}",
+            lock_path.display(),
+            e
+        ))
+    })
+}
+
+/// Take the short-lived lock that serializes acquisition attempts: locking
+/// the main lock file and writing the PID file happen as one step under it,
+/// so a contender never sees a fresh holder's lock paired with a stale PID.
+///
+/// Held only inside `try_acquire` (released on drop), so waiting on it is brief.
+fn lock_acquisition(runtime_dir: &Path) -> Result<fslock::LockFile, LockError> {
+    let path = runtime_dir.join("phase-golem.lock.reclaim");
+    let mut lock = open_lock_file(&path)?;
+    lock.lock()
+        .map_err(|e| LockError::Io(format!("Failed to lock {}: {}", path.display(), e)))?;
+    Ok(lock)
+}
+
 /// Attempts to acquire the phase-golem lock.
 ///
 /// Creates the `.phase-golem/` directory if it doesn't exist.
 /// Acquires the file lock first (atomic mutual exclusion), then writes a PID
-/// file for diagnostics. On contention, checks the PID file to provide
-/// actionable error messages about the holding process.
+/// file recording the holder's PID and start time. A PID file left behind by
+/// a holder that died is overwritten with a warning: the kernel released its
+/// lock, so only the PID file is stale. On contention, the PID file names the
+/// holder; if that process is dead, some other process (e.g. an orphaned agent
+/// subprocess) inherited the lock and is still running, so this fails rather
+/// than start next to it. Runs under `phase-golem.lock.reclaim` (see
+/// `lock_acquisition`).
 ///
-/// Returns a `LockGuard` that automatically releases on drop.
-pub fn try_acquire(runtime_dir: &Path) -> Result<LockGuard, String> {
+/// Returns a `LockGuard` that automatically releases on drop. Contention is
+/// reported as `LockError::Held`, anything else as `LockError::Io`.
+pub fn try_acquire(runtime_dir: &Path) -> Result<LockGuard, LockError> {
     fs::create_dir_all(runtime_dir)
-        .map_err(|e| format!("Failed to create {}: {}", runtime_dir.display(), e))?;
+        .map_err(|e| LockError::Io(format!("Failed to create {}: {}", runtime_dir.display(), e)))?;
 
     let lock_path = runtime_dir.join("phase-golem.lock");
     let pid_path = runtime_dir.join("phase-golem.pid");
 
-    let mut lock = fslock::LockFile::open(&lock_path)
-        .map_err(|e| format!("Failed to open lock file {}: {}", lock_path.display(), e))?;
+    let _acquisition = lock_acquisition(runtime_dir)?;
+    let mut lock = open_lock_file(&lock_path)?;
 
     let acquired = lock
         .try_lock()
-        .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+        .map_err(|e| LockError::Io(format!("Failed to acquire lock: {}", e)))?;
 
     if !acquired {
-        // Lock is held — check PID file for a helpful error message
-        let holder_info = fs::read_to_string(&pid_path)
+        // Lock is held — check PID file for the holding process. Every holder
+        // writes it before releasing `_acquisition`, so it names that holder.
+        let holder = fs::read_to_string(&pid_path)
             .ok()
-            .and_then(|s| s.trim().parse::<i32>().ok());
-
-        return match holder_info {
-            Some(pid) if is_pid_alive(pid) => Err(format!(
-                "Another phase-golem instance is running (PID {})",
-                pid
-            )),
-            Some(pid) => {
-                // Lock is held but PID is dead — OS-level flock should have
-                // been released on process death, so this is unexpected.
-                // Report it so the user can investigate.
-                Err(format!(
-                    "Lock file is held but recorded PID {} is not alive. \
-                     Remove {} and {} to recover",
-                    pid,
-                    lock_path.display(),
-                    pid_path.display()
-                ))
+            .and_then(|s| LockHolder::parse(&s));
+
+        match holder {
+            Some(holder) if is_pid_alive(holder.pid) => {
+                return Err(LockError::Held(format!(
+                    "Another phase-golem instance is running ({})",
+                    holder.describe()
+                )));
             }
-            None => Err(format!(
-                "Another phase-golem instance holds the lock. \
-                 If this is stale, remove {}",
-                lock_path.display()
-            )),
-        };
+            Some(holder) => {
+                // The recorded holder is gone but the flock is not: a child it
+                // left behind still holds it and may still be editing the tree
+                return Err(LockError::Held(format!(
+                    "The phase-golem lock was taken by {}, which is no longer running, \
+                     but a leftover child process (e.g. an orphaned agent) still holds {}. \
+                     Stop that process and retry",
+                    holder.describe(),
+                    lock_path.display()
+                )));
+            }
+            None => {
+                return Err(LockError::Held(format!(
+                    "Another phase-golem instance holds the lock. \
+                     If this is stale, remove {}",
+                    lock_path.display()
+                )));
+            }
+        }
     }
 
-    // We hold the lock — safe to write PID
-    fs::write(&pid_path, std::process::id().to_string())
-        .map_err(|e| format!("Failed to write PID file: {}", e))?;
+    // We hold the lock, so any recorded holder released it by exiting
+    if let Some(stale) = fs::read_to_string(&pid_path)
+        .ok()
+        .and_then(|s| LockHolder::parse(&s))
+    {
+        log_warn!(
+            "Warning: Overwriting stale PID file left by {}",
+            stale.describe()
+        );
+    }
+
+    // We hold the lock — safe to write PID (before `_acquisition` is released)
+    fs::write(&pid_path, LockHolder::current().to_file_contents())
+        .map_err(|e| LockError::Io(format!("Failed to write PID file: {}", e)))?;
 
     Ok(LockGuard { lock, pid_path })
 }
 
+/// Like `try_acquire`, but while another instance holds the lock, retries
+/// with exponential backoff until `timeout` elapses. A zero timeout tries
+/// once. Other failures are returned right away. Gives up early if shutdown
+/// is requested (Ctrl-C) while waiting.
+pub async fn try_acquire_with_timeout(
+    runtime_dir: &Path,
+    timeout: Duration,
+) -> Result<LockGuard, LockError> {
+    acquire_with_backoff(runtime_dir, timeout, crate::agent::is_shutdown_requested).await
+}
+
+async fn acquire_with_backoff(
+    runtime_dir: &Path,
+    timeout: Duration,
+    is_cancelled: impl Fn() -> bool,
+) -> Result<LockGuard, LockError> {
+    // A timeout too large to represent never elapses
+    let deadline = Instant::now().checked_add(timeout);
+    let mut delay = Duration::from_millis(LOCK_RETRY_INITIAL_MS);
+    loop {
+        let held = match try_acquire(runtime_dir) {
+            Ok(guard) => return Ok(guard),
+            Err(LockError::Held(held)) => held,
+            Err(e) => return Err(e),
+        };
+        let now = Instant::now();
+        if deadline.is_some_and(|deadline| now >= deadline) {
+            return Err(LockError::Held(if timeout.is_zero() {
+                held
+            } else {
+                format!(
+                    "{} (gave up after waiting {})",
+                    held,
+                    humantime::format_duration(timeout)
+                )
+            }));
+        }
+        if is_cancelled() {
+            return Err(LockError::Interrupted);
+        }
+        let wait = deadline.map_or(delay, |deadline| delay.min(deadline - now));
+        tokio::time::sleep(wait).await;
+        delay = (delay * 2).min(Duration::from_millis(LOCK_RETRY_MAX_MS));
+    }
+}
+
 fn is_pid_alive(pid: i32) -> bool {
     // signal 0 checks if process exists without sending a signal
     nix::sys::signal::kill(nix::unistd::Pid::from_raw(pid), None).is_ok()
@@ -113,4 +263,51 @@ mod tests {
         // PID 99999999 is almost certainly not alive
         assert!(!is_pid_alive(99_999_999));
     }
+
+    #[test]
+    fn test_lock_holder_round_trips_and_accepts_bare_pid() {
+        let holder = LockHolder {
+            pid: 42,
+            started_at: Some("2026-01-02T03:04:05+00:00".to_string()),
+        };
+        assert_eq!(LockHolder::parse(&holder.to_file_contents()), Some(holder));
+        assert_eq!(
+            LockHolder::parse("42"),
+            Some(LockHolder {
+                pid: 42,
+                started_at: None
+            })
+        );
+        assert_eq!(LockHolder::parse("not_a_number"), None);
+    }
+
+    #[tokio::test]
+    async fn test_acquire_with_backoff_stops_when_cancelled() {
+        let dir = tempfile::tempdir().unwrap();
+        let _held = try_acquire(dir.path()).unwrap();
+
+        let started = Instant::now();
+        let err = acquire_with_backoff(dir.path(), Duration::from_secs(30), || true)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, LockError::Interrupted), "{}", err);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_acquire_with_backoff_does_not_retry_io_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        // A file where the runtime directory belongs can't be created over
+        let runtime_dir = dir.path().join("not-a-dir");
+        fs::write(&runtime_dir, "").unwrap();
+
+        let started = Instant::now();
+        let err = acquire_with_backoff(&runtime_dir, Duration::from_secs(30), || false)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, LockError::Io(_)), "{}", err);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
 }
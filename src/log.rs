@@ -1,4 +1,5 @@
-use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::Mutex;
 
 /// Log levels for phase-golem output, ordered by verbosity.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -39,27 +40,128 @@ pub fn parse_log_level(s: &str) -> Result<LogLevel, String> {
     }
 }
 
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Enable `--quiet`: info and debug lines stay off the console regardless of
+/// the log level. Errors, warnings, and `log_summary!` lines still print.
+pub fn set_quiet(enabled: bool) {
+    QUIET.store(enabled, Ordering::Relaxed);
+}
+
+pub fn quiet_enabled() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// Whether a console line at `level` should be written.
+pub fn console_enabled(level: LogLevel) -> bool {
+    current_log_level() >= level && !(quiet_enabled() && level >= LogLevel::Info)
+}
+
+static COMPACT_LOGS: AtomicBool = AtomicBool::new(false);
+static DEDUPER: Mutex<LineDeduper> = Mutex::new(LineDeduper::new());
+
+/// Enable `--compact-logs`: consecutive identical lines are collapsed.
+pub fn set_compact_logs(enabled: bool) {
+    COMPACT_LOGS.store(enabled, Ordering::Relaxed);
+}
+
+pub fn compact_logs_enabled() -> bool {
+    COMPACT_LOGS.load(Ordering::Relaxed)
+}
+
+/// Collapses runs of consecutive identical log lines.
+///
+/// The first line of a run is written immediately so output is never delayed;
+/// repeats are counted and reported as one `line (xN)` entry once a different
+/// line arrives or the log is flushed.
+#[derive(Debug, Default)]
+pub struct LineDeduper {
+    last: Option<String>,
+    repeats: usize,
+}
+
+impl LineDeduper {
+    pub const fn new() -> Self {
+        Self {
+            last: None,
+            repeats: 0,
+        }
+    }
+
+    /// Feed one line and return the lines to write, in order.
+    pub fn push(&mut self, line: &str) -> Vec<String> {
+        if self.last.as_deref() == Some(line) {
+            self.repeats += 1;
+            return Vec::new();
+        }
+        let mut out: Vec<String> = self.flush().into_iter().collect();
+        out.push(line.to_string());
+        self.last = Some(line.to_string());
+        out
+    }
+
+    /// Report the pending repeat count, if any. Returns `None` when the last
+    /// line was not repeated.
+    pub fn flush(&mut self) -> Option<String> {
+        let repeats = std::mem::take(&mut self.repeats);
+        match &self.last {
+            Some(line) if repeats > 0 => Some(format!("{} (x{})", line, repeats + 1)),
+            _ => None,
+        }
+    }
+}
+
+/// Write a log line to stderr, collapsing repeats when compact logs are on.
+pub fn write_line(line: String) {
+    if !compact_logs_enabled() {
+        eprintln!("{}", line);
+        return;
+    }
+    let mut deduper = DEDUPER.lock().unwrap_or_else(|e| e.into_inner());
+    for out in deduper.push(&line) {
+        eprintln!("{}", out);
+    }
+}
+
+/// Write any pending `(xN)` line. Call before exiting.
+pub fn flush_compact_logs() {
+    let mut deduper = DEDUPER.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(out) = deduper.flush() {
+        eprintln!("{}", out);
+    }
+}
+
 #[macro_export]
 macro_rules! log_error {
     ($($arg:tt)*) => {
-        eprintln!($($arg)*)
+        $crate::log::write_line(format!($($arg)*))
     };
 }
 
 #[macro_export]
 macro_rules! log_warn {
     ($($arg:tt)*) => {
-        if $crate::log::current_log_level() >= $crate::log::LogLevel::Warn {
-            eprintln!($($arg)*)
+        if $crate::log::console_enabled($crate::log::LogLevel::Warn) {
+            $crate::log::write_line(format!($($arg)*))
         }
     };
 }
 
 #[macro_export]
 macro_rules! log_info {
+    ($($arg:tt)*) => {
+        if $crate::log::console_enabled($crate::log::LogLevel::Info) {
+            $crate::log::write_line(format!($($arg)*))
+        }
+    };
+}
+
+/// Info-level line that `--quiet` keeps (the end-of-run summary).
+#[macro_export]
+macro_rules! log_summary {
     ($($arg:tt)*) => {
         if $crate::log::current_log_level() >= $crate::log::LogLevel::Info {
-            eprintln!($($arg)*)
+            $crate::log::write_line(format!($($arg)*))
         }
     };
 }
@@ -67,8 +169,8 @@ macro_rules! log_info {
 #[macro_export]
 macro_rules! log_debug {
     ($($arg:tt)*) => {
-        if $crate::log::current_log_level() >= $crate::log::LogLevel::Debug {
-            eprintln!($($arg)*)
+        if $crate::log::console_enabled($crate::log::LogLevel::Debug) {
+            $crate::log::write_line(format!($($arg)*))
         }
     };
 }
@@ -98,6 +200,41 @@ mod tests {
         set_log_level(LogLevel::Info);
     }
 
+    #[test]
+    fn test_quiet_keeps_errors_and_warnings_only() {
+        set_log_level(LogLevel::Debug);
+        set_quiet(true);
+        assert!(console_enabled(LogLevel::Error));
+        assert!(console_enabled(LogLevel::Warn));
+        assert!(!console_enabled(LogLevel::Info));
+        assert!(!console_enabled(LogLevel::Debug));
+        set_quiet(false);
+        assert!(console_enabled(LogLevel::Debug));
+        // Restore defaults for other tests
+        set_log_level(LogLevel::Info);
+    }
+
+    #[test]
+    fn test_line_deduper_collapses_repeats_with_count() {
+        let mut deduper = LineDeduper::new();
+        let mut out = Vec::new();
+        for line in ["a", "a", "a", "b", "c", "c"] {
+            out.extend(deduper.push(line));
+        }
+        out.extend(deduper.flush());
+
+        assert_eq!(out, vec!["a", "a (x3)", "b", "c", "c (x2)"]);
+    }
+
+    #[test]
+    fn test_line_deduper_flush_without_repeats_is_empty() {
+        let mut deduper = LineDeduper::new();
+        assert_eq!(deduper.push("a"), vec!["a"]);
+        assert_eq!(deduper.flush(), None);
+        assert!(deduper.push("a").is_empty());
+        assert_eq!(deduper.flush(), Some("a (x2)".to_string()));
+    }
+
     #[test]
     fn test_log_level_ordering() {
         assert!(LogLevel::Error < LogLevel::Warn);
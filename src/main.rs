@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -7,7 +7,7 @@ use clap::{Parser, Subcommand};
 use tokio_util::sync::CancellationToken;
 
 use phase_golem::agent::{
-    install_signal_handlers, is_shutdown_requested, kill_all_children, AgentRunner, CliAgentRunner,
+    install_signal_handlers, is_shutdown_requested, kill_all_children, CliAgentRunner,
 };
 use task_golem::store::Store;
 
@@ -18,10 +18,13 @@ use phase_golem::lock;
 use phase_golem::log::parse_log_level;
 use phase_golem::pg_item::{self, PgItem};
 use phase_golem::preflight;
-use phase_golem::prompt;
+use phase_golem::redact;
 use phase_golem::scheduler;
-use phase_golem::types::{DimensionLevel, ItemStatus, ItemUpdate};
-use phase_golem::{log_error, log_info, log_warn};
+use phase_golem::types::{
+    parse_dimension_level, parse_size_level, DimensionLevel, ItemStatus, ItemUpdate, PhasePool,
+    SizeLevel, UpdatedAssessments,
+};
+use phase_golem::{log_error, log_info, log_summary, log_warn};
 
 use task_golem::git as tg_git;
 
@@ -34,9 +37,11 @@ struct Cli {
     #[arg(long, default_value = ".")]
     root: PathBuf,
 
-    /// Path to config file (defaults to {root}/phase-golem.toml).
-    /// When specified, config-relative paths (backlog, workflows) resolve
-    /// from the config file's parent directory.
+    /// Path to config file, TOML or YAML by extension (defaults to
+    /// {root}/phase-golem.toml, .yaml or .yml, else the nearest one in a parent
+    /// directory up to the git root).
+    /// When specified or discovered, config-relative paths (backlog, workflows)
+    /// resolve from the config file's parent directory.
     #[arg(long)]
     config: Option<PathBuf>,
 
@@ -44,6 +49,16 @@ struct Cli {
     #[arg(long, default_value = "info")]
     log_level: String,
 
+    /// Collapse consecutive identical log lines into one line with an (xN) count,
+    /// and skip the scheduling line when the scheduled actions are unchanged
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    compact_logs: bool,
+
+    /// Suppress info and debug console output regardless of --log-level; errors,
+    /// warnings, and the run summary still print, and the event log is unaffected
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    quiet: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -55,26 +70,139 @@ enum Commands {
         /// Project prefix for item IDs (e.g., WRK)
         #[arg(long, default_value = "WRK")]
         prefix: String,
+        /// Create placeholder files for workflows the config references but that don't exist yet
+        #[arg(long)]
+        with_workflows: bool,
     },
     /// Run the phase-golem pipeline
     Run {
         /// Target specific backlog items by ID (can be specified multiple times for sequential processing)
         #[arg(long, action = clap::ArgAction::Append)]
         target: Vec<String>,
-        /// Filter items by attribute. Comma-separated values = OR within field; repeated flags = AND across fields. Examples: --only impact=high,medium --only size=small (high or medium impact AND small size). Tag: --only tag=a,b (has either) vs --only tag=a --only tag=b (has both).
-        #[arg(long, conflicts_with = "target", action = clap::ArgAction::Append)]
+        /// Filter items by attribute. Comma-separated values = OR within field; repeated flags = AND across fields. Examples: --only impact=high,medium --only size=small (high or medium impact AND small size). Tag: --only tag=a,b (has either) vs --only tag=a --only tag=b (has both). Age: --only age=today|this-week|older-than:7d (created date, UTC; units h, d, w), or compare a duration since creation: --only age>7d --only age<=24h. Status: --only status=ready,scoping (new, scoping, ready, in-progress, blocked, done). Phase: --only phase=review,build (items currently at either phase; items with no phase never match). Negation: --only tag!=spike,chore excludes items with either tag (repeat flags to exclude more). Ordered fields (impact, size, complexity, risk) also accept comparisons: --only impact>=medium --only size<large. With --target, only the targets that match run; the rest are skipped.
+        #[arg(long, action = clap::ArgAction::Append)]
         only: Vec<String>,
+        /// Read filter criteria from a file: one --only expression per line, blank lines and '#' comments ignored. Combined (AND) with any --only flags.
+        #[arg(long)]
+        filter_file: Option<PathBuf>,
         /// Maximum number of phase executions
         #[arg(long, default_value = "100")]
         cap: u32,
         /// Skip blocked targets and continue to the next (multi-target mode)
         #[arg(long, action = clap::ArgAction::SetTrue)]
         auto_advance: bool,
+        /// Schedule all targets together, concurrently up to max_concurrent, instead of one at a time
+        #[arg(long, requires = "target", conflicts_with = "wait_deps", action = clap::ArgAction::SetTrue)]
+        parallel_targets: bool,
+        /// Print the phases that would run without spawning agents or modifying the backlog
+        #[arg(long, action = clap::ArgAction::SetTrue)]
+        dry_run: bool,
+        /// When a target has unmet dependencies, run those dependencies first instead of halting
+        #[arg(long, requires = "target", action = clap::ArgAction::SetTrue)]
+        wait_deps: bool,
+        /// Maximum number of dependency levels --wait-deps will expand (default: unlimited)
+        #[arg(long, requires = "wait_deps", value_parser = clap::value_parser!(u32).range(1..))]
+        max_dep_depth: Option<u32>,
+        /// Reuse phases already completed in an earlier run instead of re-running their agents
+        #[arg(long, action = clap::ArgAction::SetTrue)]
+        resume: bool,
+        /// Stash uncommitted changes/ output left by an interrupted run instead of refusing to start
+        #[arg(long, conflicts_with = "dry_run", action = clap::ArgAction::SetTrue)]
+        clean_start: bool,
+        /// Wall-clock budget for the run (e.g. "6h", "90m"); in-flight phases finish before halting
+        #[arg(long, value_parser = humantime::parse_duration)]
+        max_duration: Option<std::time::Duration>,
+        /// Agent CLI for this run (claude, opencode, gemini), overriding agent.cli
+        #[arg(long, value_parser = config::parse_cli_tool)]
+        agent: Option<config::CliTool>,
+        /// Model for this run, overriding agent.model
+        #[arg(long)]
+        model: Option<String>,
+        /// Environment variable for spawned agents (repeatable), overriding [agent.env]
+        #[arg(long = "agent-env", value_name = "KEY=VALUE")]
+        agent_env: Vec<String>,
+        /// Log errors only and print the run summary as a single JSON object on stdout
+        #[arg(long, action = clap::ArgAction::SetTrue)]
+        summary_only: bool,
+        /// Omit the per-file changes since the run started from the run summary
+        #[arg(long, action = clap::ArgAction::SetTrue)]
+        no_diff_summary: bool,
+        /// Write a Markdown run report (completed and blocked items, follow-ups, merges) to this path
+        #[arg(long)]
+        report_md: Option<PathBuf>,
+        /// Keep running once idle, picking up items as they are added, until interrupted (--cap is cumulative)
+        #[arg(long, conflicts_with_all = ["target", "dry_run"], action = clap::ArgAction::SetTrue)]
+        watch: bool,
+        /// How often --watch polls the store for new items (e.g. "30s", "5m")
+        #[arg(long, requires = "watch", default_value = "30s", value_parser = humantime::parse_duration)]
+        watch_interval: std::time::Duration,
+        /// Append a JSONL decision trace (one record per scheduler iteration) to this path
+        #[arg(long)]
+        trace: Option<PathBuf>,
+        /// Append a JSONL event log (schedules, phase starts/completions, blocks, halt) to this path; overrides execution.event_log
+        #[arg(long, value_name = "PATH")]
+        event_log: Option<PathBuf>,
+        /// How long to keep retrying if another phase-golem holds the lock (e.g. "30s"; default: fail immediately)
+        #[arg(long, default_value = "0s", value_parser = humantime::parse_duration)]
+        lock_timeout: std::time::Duration,
     },
     /// Show backlog status
-    Status,
+    Status {
+        /// Print a JSON array of items instead of the table
+        #[arg(long, action = clap::ArgAction::SetTrue)]
+        json: bool,
+        /// Only show items matching these filters (same syntax as `run --only`)
+        #[arg(long, action = clap::ArgAction::Append)]
+        only: Vec<String>,
+        /// Only show items whose ID has this prefix (e.g. DOC for DOC-001)
+        #[arg(long)]
+        prefix: Option<String>,
+    },
+    /// Validate the config offline (structure and workflow files) without touching the backlog or git
+    ConfigCheck,
     /// Triage new backlog items
-    Triage,
+    Triage {
+        /// Re-triage just this item, whatever its status (Done items are refused)
+        #[arg(long)]
+        target: Option<String>,
+        /// Environment variable for spawned agents (repeatable), overriding [agent.env]
+        #[arg(long = "agent-env", value_name = "KEY=VALUE")]
+        agent_env: Vec<String>,
+        /// How long to keep retrying if another phase-golem holds the lock (e.g. "30s"; default: fail immediately)
+        #[arg(long, default_value = "0s", value_parser = humantime::parse_duration)]
+        lock_timeout: std::time::Duration,
+    },
+    /// Show where triage would route an item, without running the agent or changing the backlog
+    TriagePreview {
+        /// Item ID to preview
+        item_id: String,
+        /// Size to assume (defaults to the item's current size)
+        #[arg(long, value_parser = parse_size_level)]
+        size: Option<SizeLevel>,
+        /// Risk to assume (defaults to the item's current risk)
+        #[arg(long, value_parser = parse_dimension_level)]
+        risk: Option<DimensionLevel>,
+        /// Impact to assume (defaults to the item's current impact)
+        #[arg(long, value_parser = parse_dimension_level)]
+        impact: Option<DimensionLevel>,
+        /// Pipeline type triage would assign (defaults to the item's current one)
+        #[arg(long)]
+        pipeline: Option<String>,
+    },
+    /// List the Ready items the next run would promote, in priority order
+    Promotable,
+    /// Report which items exceed the guardrails (current or hypothetical thresholds)
+    GuardrailCheck {
+        /// Max size to check against (defaults to guardrails.max_size)
+        #[arg(long, value_parser = parse_size_level)]
+        max_size: Option<SizeLevel>,
+        /// Max complexity to check against (defaults to guardrails.max_complexity)
+        #[arg(long, value_parser = parse_dimension_level)]
+        max_complexity: Option<DimensionLevel>,
+        /// Max risk to check against (defaults to guardrails.max_risk)
+        #[arg(long, value_parser = parse_dimension_level)]
+        max_risk: Option<DimensionLevel>,
+    },
     /// Advance an item to next or specific phase
     Advance {
         /// Item ID to advance
@@ -83,6 +211,113 @@ enum Commands {
         #[arg(long)]
         to: Option<String>,
     },
+    /// Move an in-progress item back to an earlier main phase
+    Rewind {
+        /// Item ID to rewind
+        item_id: String,
+        /// Earlier phase to return to (defaults to the previous main phase)
+        #[arg(long)]
+        to: Option<String>,
+    },
+    /// Print everything recorded on one item: status, phase, assessments, dependencies, description, and phase history
+    Show {
+        /// Item ID (active or archived)
+        item_id: String,
+    },
+    /// Print the phases an item has completed, with results, times, and commits
+    History {
+        /// Item ID (active or archived)
+        item_id: String,
+    },
+    /// Lint the dependency graph (cycles, unknown deps, Done deps, deep chains)
+    Deps {
+        /// Exit nonzero if cycles or unknown dependencies are found
+        #[arg(long, action = clap::ArgAction::SetTrue)]
+        check: bool,
+    },
+    /// Set an item's scheduling priority (larger runs first, ahead of impact)
+    Prioritize {
+        /// Item ID to prioritize
+        item_id: String,
+        /// Priority value; items with any priority are promoted before items without
+        #[arg(allow_negative_numbers = true, required_unless_present = "clear")]
+        priority: Option<i32>,
+        /// Remove the item's priority instead
+        #[arg(long, conflicts_with = "priority", action = clap::ArgAction::SetTrue)]
+        clear: bool,
+    },
+    /// Set the date an item must be done by; items near or past it are scheduled first
+    Deadline {
+        /// Item ID to set the deadline on
+        item_id: String,
+        /// Deadline date (YYYY-MM-DD)
+        #[arg(value_parser = parse_deadline, required_unless_present = "clear")]
+        date: Option<chrono::NaiveDate>,
+        /// Remove the item's deadline instead
+        #[arg(long, conflicts_with = "date", action = clap::ArgAction::SetTrue)]
+        clear: bool,
+    },
+    /// List configured pipelines and their phase sequences
+    Pipelines {
+        /// Show each phase of this pipeline: workflows, destructiveness, and overrides
+        #[arg(long, value_name = "NAME")]
+        describe_pipeline: Option<String>,
+    },
+    /// Print the exact prompt the agent would receive for an item, without running anything
+    DumpPrompt {
+        /// Item ID to build the prompt for
+        item_id: String,
+        /// Phase to build it for, or `triage` (defaults to the item's current phase)
+        #[arg(long)]
+        phase: Option<String>,
+    },
+    /// Print the dependency graph as Graphviz DOT (default) or Mermaid
+    Graph {
+        /// Output format
+        #[arg(long, value_parser = ["dot", "mermaid"])]
+        format: Option<String>,
+    },
+    /// Add dependencies to an item
+    Depend {
+        /// Item ID that gains the dependencies
+        item_id: String,
+        /// Item IDs it should depend on
+        #[arg(required = true, num_args = 1..)]
+        on: Vec<String>,
+    },
+    /// Remove dependencies from an item
+    Undepend {
+        /// Item ID that loses the dependencies
+        item_id: String,
+        /// Item IDs to remove from its dependencies
+        #[arg(required = true, num_args = 1..)]
+        on: Vec<String>,
+    },
+    /// Override an item's assessments without re-triaging (at least one flag required)
+    #[command(group(clap::ArgGroup::new("dimension").required(true).multiple(true)))]
+    Assess {
+        /// Item ID to assess
+        item_id: String,
+        /// New impact (low, medium, high)
+        #[arg(long, group = "dimension", value_parser = parse_dimension_level)]
+        impact: Option<DimensionLevel>,
+        /// New size (small, medium, large)
+        #[arg(long, group = "dimension", value_parser = parse_size_level)]
+        size: Option<SizeLevel>,
+        /// New risk (low, medium, high)
+        #[arg(long, group = "dimension", value_parser = parse_dimension_level)]
+        risk: Option<DimensionLevel>,
+        /// New complexity (low, medium, high)
+        #[arg(long, group = "dimension", value_parser = parse_dimension_level)]
+        complexity: Option<DimensionLevel>,
+    },
+    /// Manually block an item with a reason
+    Block {
+        /// Item ID to block
+        item_id: String,
+        /// Why the item is blocked
+        reason: String,
+    },
     /// Unblock a blocked item
     Unblock {
         /// Item ID to unblock
@@ -90,6 +325,9 @@ enum Commands {
         /// Decision context notes
         #[arg(long)]
         notes: Option<String>,
+        /// Re-run the phase the item was blocked in on the next `run` (InProgress items only)
+        #[arg(long, action = clap::ArgAction::SetTrue)]
+        retry: bool,
     },
 }
 
@@ -104,24 +342,47 @@ async fn main() {
             std::process::exit(1);
         }
     }
+    phase_golem::log::set_compact_logs(cli.compact_logs);
+    phase_golem::log::set_quiet(cli.quiet);
 
     let root = &cli.root;
 
-    let (config_path, config_base) = match &cli.config {
-        Some(p) => (
-            Some(p.clone()),
-            p.parent().unwrap_or(Path::new(".")).to_path_buf(),
-        ),
-        None => (None, root.to_path_buf()),
-    };
+    let (config_path, config_base) = config::resolve_config_location(cli.config.as_deref(), root);
+    if cli.config.is_none() {
+        if let Some(ref discovered) = config_path {
+            log_info!("[config] Using {}", discovered.display());
+        }
+    }
 
     let result = match cli.command {
-        Commands::Init { prefix } => handle_init(root, &prefix),
+        Commands::Init {
+            prefix,
+            with_workflows,
+        } => handle_init(root, &prefix, with_workflows),
         Commands::Run {
             target,
             only,
+            filter_file,
             cap,
             auto_advance,
+            parallel_targets,
+            dry_run,
+            wait_deps,
+            max_dep_depth,
+            resume,
+            clean_start,
+            max_duration,
+            agent,
+            model,
+            agent_env,
+            summary_only,
+            no_diff_summary,
+            report_md,
+            watch,
+            watch_interval,
+            trace,
+            event_log,
+            lock_timeout,
         } => {
             handle_run(
                 root,
@@ -129,39 +390,189 @@ async fn main() {
                 &config_base,
                 target,
                 only,
+                filter_file.as_deref(),
                 cap,
                 auto_advance,
+                parallel_targets,
+                dry_run,
+                wait_deps,
+                max_dep_depth,
+                resume,
+                clean_start,
+                max_duration,
+                agent,
+                model,
+                &agent_env,
+                summary_only,
+                no_diff_summary,
+                report_md.as_deref(),
+                watch.then_some(watch_interval),
+                trace.as_deref(),
+                event_log.as_deref(),
+                lock_timeout,
+            )
+            .await
+        }
+        Commands::Status { json, only, prefix } => handle_status(
+            root,
+            config_path.as_deref(),
+            &config_base,
+            json,
+            only,
+            prefix.as_deref(),
+        ),
+        Commands::ConfigCheck => handle_config_check(root, config_path.as_deref(), &config_base),
+        Commands::Triage {
+            target,
+            agent_env,
+            lock_timeout,
+        } => {
+            handle_triage(
+                root,
+                config_path.as_deref(),
+                &config_base,
+                target.as_deref(),
+                &agent_env,
+                lock_timeout,
             )
             .await
         }
-        Commands::Status => handle_status(root, config_path.as_deref(), &config_base),
-        Commands::Triage => handle_triage(root, config_path.as_deref(), &config_base).await,
+        Commands::TriagePreview {
+            item_id,
+            size,
+            risk,
+            impact,
+            pipeline,
+        } => handle_triage_preview(
+            root,
+            config_path.as_deref(),
+            &config_base,
+            &item_id,
+            UpdatedAssessments {
+                size,
+                complexity: None,
+                risk,
+                impact,
+            },
+            pipeline,
+        ),
+        Commands::Promotable => handle_promotable(root, config_path.as_deref(), &config_base),
+        Commands::GuardrailCheck {
+            max_size,
+            max_complexity,
+            max_risk,
+        } => handle_guardrail_check(
+            root,
+            config_path.as_deref(),
+            max_size,
+            max_complexity,
+            max_risk,
+        ),
         Commands::Advance { item_id, to } => {
             handle_advance(root, config_path.as_deref(), &config_base, &item_id, to)
         }
-        Commands::Unblock { item_id, notes } => {
-            handle_unblock(root, config_path.as_deref(), &config_base, &item_id, notes)
+        Commands::Rewind { item_id, to } => {
+            handle_rewind(root, config_path.as_deref(), &item_id, to.as_deref())
+        }
+        Commands::Show { item_id } => handle_show(root, &item_id),
+        Commands::History { item_id } => handle_history(root, &item_id),
+        Commands::Prioritize {
+            item_id,
+            priority,
+            clear: _,
+        } => handle_prioritize(root, &item_id, priority),
+        Commands::Deadline {
+            item_id,
+            date,
+            clear: _,
+        } => handle_deadline(root, &item_id, date),
+        Commands::Deps { check } => handle_deps(root, config_path.as_deref(), &config_base, check),
+        Commands::Pipelines { describe_pipeline } => {
+            handle_pipelines(root, config_path.as_deref(), describe_pipeline.as_deref())
+        }
+        Commands::DumpPrompt { item_id, phase } => handle_dump_prompt(
+            root,
+            config_path.as_deref(),
+            &config_base,
+            &item_id,
+            phase.as_deref(),
+        ),
+        Commands::Graph { format } => handle_graph(root, format.as_deref()),
+        Commands::Depend { item_id, on } => {
+            handle_depend(root, config_path.as_deref(), &config_base, &item_id, &on)
+        }
+        Commands::Undepend { item_id, on } => {
+            handle_undepend(root, config_path.as_deref(), &config_base, &item_id, &on)
         }
+        Commands::Assess {
+            item_id,
+            impact,
+            size,
+            risk,
+            complexity,
+        } => handle_assess(
+            root,
+            config_path.as_deref(),
+            &config_base,
+            &item_id,
+            assessment_updates(impact, size, risk, complexity),
+        ),
+        Commands::Block { item_id, reason } => handle_block(
+            root,
+            config_path.as_deref(),
+            &config_base,
+            &item_id,
+            &reason,
+        ),
+        Commands::Unblock {
+            item_id,
+            notes,
+            retry,
+        } => handle_unblock(
+            root,
+            config_path.as_deref(),
+            &config_base,
+            &item_id,
+            notes,
+            retry,
+        ),
     };
 
+    phase_golem::log::flush_compact_logs();
+
     if let Err(e) = result {
         eprintln!("Error: {}", e);
         std::process::exit(1);
     }
 }
 
+/// Build the agent runner for `agent` and check its CLI is installed.
+fn verified_runner(agent: &config::AgentConfig) -> Result<CliAgentRunner, String> {
+    let runner = CliAgentRunner::from_config(agent);
+    log_info!("[pre] Verifying {} ...", agent.cli.display_name());
+    runner.verify_cli_available()?;
+    log_agent_config(agent);
+    Ok(runner)
+}
+
 fn log_agent_config(agent: &config::AgentConfig) {
     log_info!(
         "[config] Agent: {} (model: {})",
         agent.cli.display_name(),
         agent.model.as_deref().unwrap_or("default")
     );
-    if agent.cli == config::CliTool::OpenCode {
-        log_info!("[config] Note: OpenCode CLI support is experimental.");
+    if matches!(
+        agent.cli,
+        config::CliTool::OpenCode | config::CliTool::Gemini
+    ) {
+        log_info!(
+            "[config] Note: {} support is experimental.",
+            agent.cli.display_name()
+        );
     }
     // Log resolved binary path for debugging PATH issues
     match std::process::Command::new("which")
-        .arg(agent.cli.binary_name())
+        .arg(agent.binary_name())
         .output()
     {
         Ok(output) if output.status.success() => {
@@ -171,12 +582,26 @@ fn log_agent_config(agent: &config::AgentConfig) {
         _ => {
             log_warn!(
                 "[config] Could not resolve binary path for {}",
-                agent.cli.binary_name()
+                agent.binary_name()
             );
         }
     }
 }
 
+/// Returns the remote to probe during git preconditions, if enabled in config.
+fn verify_remote_target(config: &config::PhaseGolemConfig) -> Option<&str> {
+    config
+        .git
+        .verify_remote
+        .then_some(config.git.remote.as_str())
+}
+
+/// Keep only items whose ID prefix is exactly `prefix` (`DOC` keeps
+/// `DOC-001` but not `DOCS-001`).
+fn retain_prefix(items: &mut Vec<PgItem>, prefix: &str) {
+    items.retain(|item| config::item_id_prefix(item.id()) == Some(prefix));
+}
+
 /// Validates an item ID format: must be `{prefix}-{suffix}` where prefix is
 /// alphanumeric and suffix is either all-numeric (legacy WRK-001) or valid hex
 /// (WRK-a1b2c). Accepts any prefix — the store can contain items with different
@@ -194,45 +619,10 @@ fn is_valid_item_id(id: &str) -> bool {
     suffix.chars().all(|c| c.is_ascii_hexdigit())
 }
 
-fn handle_init(root: &Path, prefix: &str) -> Result<(), String> {
-    // Validate prefix contains only safe characters for TOML and filenames
-    if !prefix
-        .chars()
-        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
-    {
-        return Err(
-            "Prefix must contain only alphanumeric characters, hyphens, and underscores"
-                .to_string(),
-        );
-    }
-
-    // Only check that a git repo exists -- init doesn't require clean tree or branch
-    phase_golem::git::is_git_repo(None)
-        .map_err(|_| "Not a git repository. Run `git init` first.".to_string())?;
-
-    // Check for .task-golem/ directory
-    let task_golem_dir = root.join(".task-golem");
-    if !task_golem_dir.is_dir() {
-        eprintln!(
-            "Warning: .task-golem/ directory not found at {}\n\
-             Run `tg init` to initialize the task-golem store before using phase-golem.",
-            task_golem_dir.display()
-        );
-    }
-
-    // Create directories
-    let dirs = ["_ideas", "_worklog", "changes", ".phase-golem"];
-    for dir in &dirs {
-        let dir_path = root.join(dir);
-        fs::create_dir_all(&dir_path)
-            .map_err(|e| format!("Failed to create {}: {}", dir_path.display(), e))?;
-    }
-
-    // Create phase-golem.toml if it doesn't exist (with default pipelines section)
-    let config_path = root.join("phase-golem.toml");
-    if !config_path.exists() {
-        let config_contents = format!(
-            r#"[project]
+/// The `phase-golem.toml` written by `init`.
+fn init_config_toml(prefix: &str) -> String {
+    format!(
+        r#"[project]
 prefix = "{prefix}"
 
 [guardrails]
@@ -264,8 +654,88 @@ phases = [
     {{ name = "review",         workflows = [".claude/skills/changes/workflows/5-review/change-review.md"],               is_destructive = false }},
 ]
 "#,
-            prefix = prefix
+        prefix = prefix
+    )
+}
+
+/// Write a placeholder for every workflow file `config` references that
+/// doesn't exist yet, so a fresh project passes preflight. Existing files
+/// are never touched. Returns the paths created, relative to `config_base`.
+fn scaffold_workflows(
+    config: &config::PhaseGolemConfig,
+    config_base: &Path,
+) -> Result<Vec<String>, String> {
+    let mut created = Vec::new();
+    for (pipeline_name, pipeline) in &config.pipelines {
+        for phase in pipeline.pre_phases.iter().chain(pipeline.phases.iter()) {
+            for workflow in &phase.workflows {
+                let path = config_base.join(workflow);
+                if path.exists() || created.contains(workflow) {
+                    continue;
+                }
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)
+                        .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+                }
+                fs::write(&path, placeholder_workflow(pipeline_name, &phase.name))
+                    .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+                created.push(workflow.clone());
+            }
+        }
+    }
+    Ok(created)
+}
+
+/// Contents of a scaffolded workflow file.
+fn placeholder_workflow(pipeline: &str, phase: &str) -> String {
+    format!(
+        "<!-- PLACEHOLDER generated by `phase-golem init --with-workflows`. \
+         Replace this file with real instructions before relying on this phase. -->\n\
+         # TODO: `{phase}` workflow ({pipeline} pipeline)\n\
+         \n\
+         This is a placeholder. Describe what the agent must do in the `{phase}` \
+         phase: inputs to read, artifacts to write under `changes/`, and when to \
+         report `PHASE_COMPLETE`, `FAILED`, or `BLOCKED`.\n",
+        pipeline = pipeline,
+        phase = phase
+    )
+}
+
+fn handle_init(root: &Path, prefix: &str, with_workflows: bool) -> Result<(), String> {
+    // Validate prefix contains only safe characters for TOML and filenames
+    if !config::is_valid_prefix(prefix) {
+        return Err(
+            "Prefix must contain only alphanumeric characters, hyphens, and underscores"
+                .to_string(),
+        );
+    }
+
+    // Only check that a git repo exists -- init doesn't require clean tree or branch
+    phase_golem::git::is_git_repo(None)
+        .map_err(|_| "Not a git repository. Run `git init` first.".to_string())?;
+
+    // Check for .task-golem/ directory
+    let task_golem_dir = root.join(".task-golem");
+    if !task_golem_dir.is_dir() {
+        eprintln!(
+            "Warning: .task-golem/ directory not found at {}\n\
+             Run `tg init` to initialize the task-golem store before using phase-golem.",
+            task_golem_dir.display()
         );
+    }
+
+    // Create directories
+    let dirs = ["_ideas", "_worklog", "changes", ".phase-golem"];
+    for dir in &dirs {
+        let dir_path = root.join(dir);
+        fs::create_dir_all(&dir_path)
+            .map_err(|e| format!("Failed to create {}: {}", dir_path.display(), e))?;
+    }
+
+    // Create phase-golem.toml if it doesn't exist (with default pipelines section)
+    let config_path = root.join("phase-golem.toml");
+    if !config_path.exists() {
+        let config_contents = init_config_toml(prefix);
         fs::write(&config_path, config_contents)
             .map_err(|e| format!("Failed to write {}: {}", config_path.display(), e))?;
     }
@@ -297,10 +767,26 @@ phases = [
             .map_err(|e| format!("Failed to write .gitignore: {}", e))?;
     }
 
+    let scaffolded = if with_workflows {
+        let config = config::load_config(root)?;
+        scaffold_workflows(&config, root)?
+    } else {
+        Vec::new()
+    };
+
     println!("Initialized phase-golem in {}", root.display());
     println!("  Created: _ideas/, _worklog/, changes/, .phase-golem/");
     println!("  Config: phase-golem.toml");
     println!("  Updated: .gitignore");
+    if !scaffolded.is_empty() {
+        println!(
+            "  Scaffolded {} placeholder workflow(s) — edit them before running:",
+            scaffolded.len()
+        );
+        for path in &scaffolded {
+            println!("    {}", path);
+        }
+    }
 
     Ok(())
 }
@@ -366,37 +852,69 @@ async fn cleanup_stale_result_files(runtime_dir: &Path, context: &str) {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn handle_run(
     root: &Path,
     config_path: Option<&Path>,
     config_base: &Path,
     target: Vec<String>,
     only: Vec<String>,
+    filter_file: Option<&Path>,
     cap: u32,
     auto_advance: bool,
+    parallel_targets: bool,
+    dry_run: bool,
+    wait_deps: bool,
+    max_dep_depth: Option<u32>,
+    resume: bool,
+    clean_start: bool,
+    max_duration: Option<std::time::Duration>,
+    agent: Option<config::CliTool>,
+    model: Option<String>,
+    agent_env: &[String],
+    summary_only: bool,
+    no_diff_summary: bool,
+    report_md: Option<&Path>,
+    watch: Option<std::time::Duration>,
+    trace: Option<&Path>,
+    event_log: Option<&Path>,
+    lock_timeout: std::time::Duration,
 ) -> Result<(), String> {
-    // Install signal handlers for graceful shutdown
-    install_signal_handlers()?;
+    validate_cap(cap)?;
+    let agent_env = config::parse_agent_env(agent_env)?;
+
+    // Install signal handlers for graceful shutdown (second Ctrl-C force-quits)
+    install_signal_handlers(&root.join(".phase-golem"))?;
+
+    // stdout is reserved for the JSON summary; errors still reach stderr
+    if summary_only {
+        phase_golem::log::set_log_level(phase_golem::log::LogLevel::Error);
+        phase_golem::agent::reserve_stdout();
+    }
+    let run_started = std::time::Instant::now();
 
     log_info!("--- Phase Golem ---");
     log_info!("");
 
-    // Prechecks
-    log_info!("[pre] Acquiring lock...");
+    // Prechecks. A dry run only reads the backlog and spawns nothing, so it
+    // skips the lock, the git preconditions, and the agent CLI check
     let runtime_dir = root.join(".phase-golem");
-    let _lock = lock::try_acquire(&runtime_dir)?;
-    cleanup_stale_result_files(&runtime_dir, "pre").await;
-    log_info!("[pre] Checking git preconditions...");
-    phase_golem::git::check_preconditions(Some(root))?;
+    let _lock = if dry_run {
+        log_info!("[pre] Dry run: skipping lock, git preconditions, and agent CLI check");
+        None
+    } else {
+        log_info!("[pre] Acquiring lock...");
+        let lock = lock::try_acquire_with_timeout(&runtime_dir, lock_timeout)
+            .await
+            .map_err(|e| e.to_string())?;
+        cleanup_stale_result_files(&runtime_dir, "pre").await;
+        Some(lock)
+    };
 
     // Load
-    let config = config::load_config_from(config_path, root)?;
-
-    // Construct runner from config and verify CLI
-    let runner = CliAgentRunner::new(config.agent.cli.clone(), config.agent.model.clone());
-    log_info!("[pre] Verifying {} ...", config.agent.cli.display_name());
-    runner.verify_cli_available()?;
-    log_agent_config(&config.agent);
+    let mut config = config::load_config_from(config_path, root)?;
+    config::apply_agent_overrides(&mut config, agent, model)?;
+    config.agent.env.extend(agent_env);
 
     // Construct Store for task-golem access
     let tg_store_dir = root.join(".task-golem");
@@ -410,11 +928,35 @@ async fn handle_run(
         .map(PgItem)
         .collect();
 
-    // Mutual exclusivity safety net (clap conflicts_with should handle this)
-    if !target.is_empty() && !only.is_empty() {
-        return Err("Cannot combine --target and --only flags. Use one or the other.".to_string());
+    if !dry_run {
+        // Before the clean-tree check, so leftovers get specific guidance
+        handle_interrupted_changes(root, &items, clean_start)?;
+
+        log_info!("[pre] Checking git preconditions...");
+        phase_golem::git::check_preconditions(Some(root), verify_remote_target(&config))?;
     }
 
+    // HEAD before scheduling, for the changed-files section of the run summary
+    // and the commit SHAs in the Markdown report
+    let start_sha = if dry_run || (no_diff_summary && report_md.is_none()) {
+        None
+    } else {
+        match phase_golem::git::get_head_sha(root) {
+            Ok(sha) => Some(sha),
+            Err(e) => {
+                log_warn!("[pre] Run diff summary unavailable: {}", e);
+                None
+            }
+        }
+    };
+
+    // Construct runner from config and verify CLI (a dry run never calls it)
+    let runner = if dry_run {
+        CliAgentRunner::from_config(&config.agent)
+    } else {
+        verified_runner(&config.agent)?
+    };
+
     // Target validation
     let target: Vec<String> = target.iter().map(|t| t.trim().to_string()).collect();
     if !target.is_empty() {
@@ -456,11 +998,19 @@ async fn handle_run(
         }
     }
 
-    // Filter validation
-    let parsed_filters: Vec<filter::FilterCriterion> = only
-        .iter()
-        .map(|raw| filter::parse_filter(raw))
-        .collect::<Result<Vec<_>, _>>()?;
+    // Filter validation (file criteria first, then inline --only)
+    let mut parsed_filters: Vec<filter::FilterCriterion> = match filter_file {
+        Some(path) => {
+            let contents = std::fs::read_to_string(path)
+                .map_err(|e| format!("Failed to read filter file {}: {}", path.display(), e))?;
+            filter::parse_filter_file(&contents)
+                .map_err(|e| format!("Invalid filter file {}: {}", path.display(), e))?
+        }
+        None => Vec::new(),
+    };
+    for raw in &only {
+        parsed_filters.push(filter::parse_filter(raw)?);
+    }
     filter::validate_filter_criteria(&parsed_filters)?;
 
     // Config summary
@@ -481,6 +1031,8 @@ async fn handle_run(
     );
     if target.len() == 1 {
         log_info!("[config] Target: {}", target[0]);
+    } else if parallel_targets {
+        log_info!("[config] Targets (parallel): {}", target.join(", "));
     } else if target.len() > 1 {
         let target_display: Vec<String> = target
             .iter()
@@ -505,22 +1057,42 @@ async fn handle_run(
         );
     }
     log_info!("[config] Phase cap: {}", cap);
+    if let Some(duration) = max_duration {
+        log_info!(
+            "[config] Max duration: {} (deadline {})",
+            humantime::format_duration(duration),
+            format_deadline(chrono::Local::now(), duration)
+        );
+    }
+    if let Some(interval) = watch {
+        log_info!(
+            "[config] Watch: polling every {}",
+            humantime::format_duration(interval)
+        );
+    }
+    let event_log = if dry_run {
+        None
+    } else {
+        event_log_path(
+            event_log,
+            config.execution.event_log,
+            &root.join(".phase-golem"),
+            chrono::Utc::now(),
+        )
+    };
+    if let Some(ref path) = event_log {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(|e| {
+                format!("Failed to create event log dir {}: {}", parent.display(), e)
+            })?;
+        }
+        log_info!("[config] Event log: {}", path.display());
+    }
 
     // Pipeline summary
     log_info!("");
     for (name, pipeline) in &config.pipelines {
-        let pre_names: Vec<&str> = pipeline
-            .pre_phases
-            .iter()
-            .map(|p| p.name.as_str())
-            .collect();
-        let main_names: Vec<&str> = pipeline.phases.iter().map(|p| p.name.as_str()).collect();
-        log_info!(
-            "[pipeline:{}] pre: [{}] -> main: [{}]",
-            name,
-            pre_names.join(" -> "),
-            main_names.join(" -> "),
-        );
+        log_info!("[pipeline:{}] {}", name, format_pipeline_sequence(pipeline));
     }
 
     // Backlog summary
@@ -618,8 +1190,13 @@ async fn handle_run(
 
     let runner = Arc::new(runner);
     log_info!("");
-    let (coord_handle, coord_task) =
-        coordinator::spawn_coordinator(store, root.to_path_buf(), config.project.prefix.clone());
+    let (coord_handle, coord_task) = coordinator::spawn_coordinator_with_worklog(
+        store,
+        root.to_path_buf(),
+        config.project.prefix.clone(),
+        config.project.worklog_naming,
+        config.execution.worklog_format,
+    );
 
     // Set up cancellation for graceful shutdown
     let cancel = CancellationToken::new();
@@ -649,8 +1226,22 @@ async fn handle_run(
         root: root.to_path_buf(),
         config_base: config_base.to_path_buf(),
         auto_advance,
+        dry_run,
+        wait_deps,
+        max_dep_depth,
+        resume,
+        events: None,
+        max_duration,
+        parallel_targets,
+        watch,
+        trace: trace.map(Path::to_path_buf),
+        event_log,
     };
 
+    let git_config = config.git.clone();
+    let branch_per_item = config.execution.branch_per_item;
+    let redact_keys = config.security.redact_keys.clone();
+    let metrics_file = config.execution.metrics_file;
     let summary = scheduler::run_scheduler(coord_handle, runner, config, params, cancel).await?;
 
     // Kill any remaining child processes
@@ -666,7 +1257,7 @@ async fn handle_run(
             "Coordinator task panicked, skipping shutdown commit: {:?}",
             err
         );
-    } else {
+    } else if !dry_run {
         // Commit tasks.jsonl if it has uncommitted changes
         let root_for_commit = root.to_path_buf();
         let tg_store_dir_for_commit = tg_store_dir.clone();
@@ -725,27 +1316,86 @@ async fn handle_run(
         }
     }
 
-    cleanup_stale_result_files(&runtime_dir, "post").await;
+    if !dry_run {
+        cleanup_stale_result_files(&runtime_dir, "post").await;
+    }
+
+    if summary_only {
+        write_summary_json(
+            &mut std::io::stdout().lock(),
+            &summary,
+            dry_run,
+            run_started.elapsed(),
+            &redact_keys,
+        )
+        .map_err(|e| format!("Failed to write run summary: {}", e))?;
+    }
 
     // Print summary
-    log_info!("\n--- Run Summary ---");
-    log_info!("Phases executed: {}", summary.phases_executed);
+    if dry_run {
+        log_summary!("\n--- Dry Run Plan ---");
+        if summary.planned_phases.is_empty() {
+            log_summary!("No phases would run.");
+        }
+        for (item_id, phases) in &summary.planned_phases {
+            log_summary!("{}: {}", item_id, phases.join(" -> "));
+        }
+        log_summary!("Phases planned: {}", summary.phases_executed);
+        log_summary!("Halt reason: {:?}", summary.halt_reason);
+        return Ok(());
+    }
+
+    log_summary!("\n--- Run Summary ---");
+    log_summary!("Phases executed: {}", summary.phases_executed);
     if !summary.items_completed.is_empty() {
-        log_info!("Items completed: {}", summary.items_completed.join(", "));
+        log_summary!("Items completed: {}", summary.items_completed.join(", "));
+    }
+    if branch_per_item {
+        for item_id in &summary.items_completed {
+            let branch = phase_golem::git::item_branch_name(item_id);
+            if phase_golem::git::branch_exists(&branch, root) {
+                log_summary!("  {} -> branch {}", item_id, branch);
+            }
+        }
     }
     if !summary.items_blocked.is_empty() {
-        log_info!("Items blocked: {}", summary.items_blocked.join(", "));
+        log_summary!("Items blocked: {}", summary.items_blocked.join(", "));
+    }
+    if !summary.items_failed_validation.is_empty() {
+        log_summary!(
+            "Items failed validation: {}",
+            summary.items_failed_validation.join(", ")
+        );
     }
     if summary.follow_ups_created > 0 {
-        log_info!("Follow-ups created: {}", summary.follow_ups_created);
+        log_summary!("Follow-ups created: {}", summary.follow_ups_created);
+    }
+    if summary.follow_ups_suppressed > 0 {
+        log_summary!(
+            "Duplicate follow-ups suppressed: {}",
+            summary.follow_ups_suppressed
+        );
     }
     if summary.items_merged > 0 {
-        log_info!("Items merged: {}", summary.items_merged);
+        log_summary!("Items merged: {}", summary.items_merged);
+        for line in format_merges(&summary.merges) {
+            log_summary!("  {}", line);
+        }
+    }
+    if let Some(sha) = start_sha.as_ref().filter(|_| !no_diff_summary) {
+        match phase_golem::git::diff_stat_since(sha, root) {
+            Ok(stats) => {
+                for line in format_diff_summary(sha, &stats) {
+                    log_summary!("{}", line);
+                }
+            }
+            Err(e) => log_warn!("Could not summarize changes since {}: {}", sha, e),
+        }
     }
     match &summary.halt_reason {
         scheduler::HaltReason::FilterExhausted => {
             if let Some(ref filter_str) = filter_display {
-                log_info!(
+                log_summary!(
                     "Filter: all items matching {} are done or blocked",
                     filter_str
                 );
@@ -753,119 +1403,500 @@ async fn handle_run(
         }
         scheduler::HaltReason::NoMatchingItems => {
             if let Some(ref filter_str) = filter_display {
-                log_info!("Filter: no items match {}", filter_str);
+                log_summary!("Filter: no items match {}", filter_str);
             }
         }
+        scheduler::HaltReason::AllDone => {
+            log_summary!("All items done.");
+        }
+        scheduler::HaltReason::AllBlocked => {
+            log_summary!(
+                "No items done: everything remaining is blocked or waiting on dependencies."
+            );
+        }
+        scheduler::HaltReason::Mixed => {
+            log_summary!("Some items done; others remain blocked or waiting on dependencies.");
+        }
+        scheduler::HaltReason::DurationReached => {
+            log_summary!("Max duration reached; remaining work left for the next run.");
+        }
         _ => {}
     }
-    log_info!("Halt reason: {:?}", summary.halt_reason);
+    log_summary!("Halt reason: {:?}", summary.halt_reason);
+
+    if metrics_file {
+        // Write then rename so the textfile collector never reads a partial file
+        let path = runtime_dir.join("metrics.prom");
+        let tmp = runtime_dir.join("metrics.prom.tmp");
+        let metrics = redact::redact_text(&format_metrics_prom(&summary), &redact_keys);
+        match fs::write(&tmp, metrics).and_then(|()| fs::rename(&tmp, &path)) {
+            Ok(()) => log_info!("Metrics written to {}", path.display()),
+            Err(e) => log_warn!("Failed to write metrics to {}: {}", path.display(), e),
+        }
+    }
+
+    if let Some(path) = report_md {
+        let commits = match start_sha {
+            Some(ref sha) => phase_golem::git::commits_since(sha, root).unwrap_or_else(|e| {
+                log_warn!("Run report: could not list commits since {}: {}", sha, e);
+                Vec::new()
+            }),
+            None => Vec::new(),
+        };
+        // Completed items may be archived by now, so titles fall back to the pre-run snapshot
+        let mut report_items: Vec<PgItem> = match Store::new(tg_store_dir.clone()).load_active() {
+            Ok(active) => active.into_iter().map(PgItem).collect(),
+            Err(e) => {
+                log_warn!("Run report: could not reload items: {}", e);
+                Vec::new()
+            }
+        };
+        report_items.extend(items.iter().cloned());
+        let report = format_run_report_md(
+            &summary,
+            &report_items,
+            &commits,
+            chrono::Local::now(),
+            &redact_keys,
+        );
+        match fs::write(path, report) {
+            Ok(()) => log_info!("Run report written to {}", path.display()),
+            Err(e) => log_warn!("Failed to write run report to {}: {}", path.display(), e),
+        }
+    }
 
     if summary.items_completed.is_empty() && !summary.items_blocked.is_empty() {
         return Err("All targets blocked; no items completed".to_string());
     }
 
-    Ok(())
+    push_after_run(&git_config, &summary.halt_reason, root)
 }
 
-async fn handle_triage(
+/// Refuse to start while `changes/` holds uncommitted output of an
+/// `InProgress` item (left by an interrupted run), or stash it with
+/// `--clean-start`.
+fn handle_interrupted_changes(
     root: &Path,
-    config_path: Option<&Path>,
-    _config_base: &Path,
+    items: &[PgItem],
+    clean_start: bool,
 ) -> Result<(), String> {
-    // Install signal handlers for graceful shutdown
-    install_signal_handlers()?;
-
+    // Not a repository: check_preconditions reports that next
+    let Ok(status) = phase_golem::git::get_status(Some(root)) else {
+        return Ok(());
+    };
+    let leftovers = preflight::find_interrupted_changes(&status, items, root);
+    if leftovers.is_empty() {
+        return Ok(());
+    }
+
+    if clean_start {
+        let paths: Vec<&str> = leftovers.iter().map(|(_, path)| path.as_str()).collect();
+        phase_golem::git::stash_paths(
+            &paths,
+            "phase-golem: output of an interrupted run",
+            Some(root),
+        )?;
+        log_info!(
+            "[pre] Stashed uncommitted changes/ output from an interrupted run ({}); restore it with `git stash pop`",
+            paths.join(", ")
+        );
+        return Ok(());
+    }
+
+    log_error!("[pre] Uncommitted changes/ output from an interrupted run:");
+    for (item_id, path) in &leftovers {
+        log_error!("  {} ({})", path, item_id);
+    }
+    Err(
+        "changes/ has output from a phase that never committed. Commit, stash, or remove it, \
+         or rerun with --clean-start to stash it"
+            .to_string(),
+    )
+}
+
+/// A zero cap would halt every run with `CapReached` before a single phase.
+fn validate_cap(cap: u32) -> Result<(), String> {
+    if cap == 0 {
+        return Err(
+            "cap must be at least 1; use --dry-run to preview without executing".to_string(),
+        );
+    }
+    Ok(())
+}
+
+/// Write `summary` as one JSON object on a single line (`run --summary-only`),
+/// with `redact_keys` masked.
+fn write_summary_json(
+    out: &mut impl std::io::Write,
+    summary: &scheduler::RunSummary,
+    dry_run: bool,
+    elapsed: std::time::Duration,
+    redact_keys: &[String],
+) -> std::io::Result<()> {
+    let mut value = serde_json::json!({
+        "phases_executed": summary.phases_executed,
+        "items_completed": summary.items_completed,
+        "items_blocked": summary.items_blocked,
+        "items_failed_validation": summary.items_failed_validation,
+        "follow_ups_created": summary.follow_ups_created,
+        "follow_ups_suppressed": summary.follow_ups_suppressed,
+        "items_merged": summary.items_merged,
+        "merges": summary
+            .merges
+            .iter()
+            .map(|(source, target)| serde_json::json!({ "source": source, "target": target }))
+            .collect::<Vec<_>>(),
+        "halt_reason": format!("{:?}", summary.halt_reason),
+        "dry_run": dry_run,
+        "elapsed_seconds": elapsed.as_secs(),
+    });
+    if dry_run {
+        value["planned_phases"] = summary
+            .planned_phases
+            .iter()
+            .map(|(item_id, phases)| serde_json::json!({ "id": item_id, "phases": phases }))
+            .collect();
+    }
+    redact::redact(&mut value, redact_keys);
+    writeln!(out, "{}", value)
+}
+
+/// Render a run's counts and halt reason in the Prometheus text exposition
+/// format (`execution.metrics_file`). Metric names and labels are part of the
+/// dashboard contract, so keep them stable.
+fn format_metrics_prom(summary: &scheduler::RunSummary) -> String {
+    let gauges: [(&str, &str, usize); 5] = [
+        (
+            "phase_golem_phases_executed",
+            "Phases executed in the last run.",
+            summary.phases_executed as usize,
+        ),
+        (
+            "phase_golem_items_completed",
+            "Items completed in the last run.",
+            summary.items_completed.len(),
+        ),
+        (
+            "phase_golem_items_blocked",
+            "Items blocked in the last run.",
+            summary.items_blocked.len(),
+        ),
+        (
+            "phase_golem_follow_ups_created",
+            "Follow-up items created in the last run.",
+            summary.follow_ups_created as usize,
+        ),
+        (
+            "phase_golem_follow_ups_suppressed",
+            "Duplicate follow-ups suppressed in the last run.",
+            summary.follow_ups_suppressed as usize,
+        ),
+        (
+            "phase_golem_items_merged",
+            "Duplicate items merged in the last run.",
+            summary.items_merged as usize,
+        ),
+    ];
+
+    let mut out = String::new();
+    for (name, help, value) in gauges {
+        out.push_str(&format!(
+            "# HELP {name} {help}\n# TYPE {name} gauge\n{name} {value}\n"
+        ));
+    }
+    out.push_str("# HELP phase_golem_halt_reason Why the last run halted.\n");
+    out.push_str("# TYPE phase_golem_halt_reason gauge\n");
+    out.push_str(&format!(
+        "phase_golem_halt_reason{{reason=\"{:?}\"}} 1\n",
+        summary.halt_reason
+    ));
+    out
+}
+
+/// Render a run as a Markdown report: header, completed items with their
+/// commits, blocked items with reasons, and follow-up/merge counts.
+///
+/// `items` is searched in order, so put the freshest copies first. Commits are
+/// attributed to an item when their subject carries its `[ID]` tag.
+fn format_run_report_md(
+    summary: &scheduler::RunSummary,
+    items: &[PgItem],
+    commits: &[(String, String)],
+    generated_at: chrono::DateTime<chrono::Local>,
+    redact_keys: &[String],
+) -> String {
+    let find = |id: &str| items.iter().find(|item| item.id() == id);
+    let heading = |id: &str| match find(id) {
+        Some(item) => format!("**{}** {}", id, item.title()),
+        None => format!("**{}**", id),
+    };
+
+    let mut out = vec![
+        "# Phase Golem Run Report".to_string(),
+        String::new(),
+        format!("- **Date:** {}", generated_at.format("%Y-%m-%d %H:%M:%S")),
+        format!("- **Halt reason:** {:?}", summary.halt_reason),
+        format!("- **Phases executed:** {}", summary.phases_executed),
+        String::new(),
+        "## Completed Items".to_string(),
+        String::new(),
+    ];
+    if summary.items_completed.is_empty() {
+        out.push("None.".to_string());
+    }
+    for id in &summary.items_completed {
+        let tag = format!("[{}]", id);
+        let shas: Vec<String> = commits
+            .iter()
+            .filter(|(_, subject)| subject.contains(&tag))
+            .map(|(sha, _)| format!("`{}`", &sha[..sha.len().min(7)]))
+            .collect();
+        if shas.is_empty() {
+            out.push(format!("- {}", heading(id)));
+        } else {
+            out.push(format!("- {} ({})", heading(id), shas.join(", ")));
+        }
+    }
+
+    out.push(String::new());
+    out.push("## Blocked Items".to_string());
+    out.push(String::new());
+    if summary.items_blocked.is_empty() {
+        out.push("None.".to_string());
+    }
+    for id in &summary.items_blocked {
+        match find(id).and_then(|item| item.blocked_reason().map(str::to_string)) {
+            Some(reason) => out.push(format!("- {}: {}", heading(id), reason)),
+            None => out.push(format!("- {}", heading(id))),
+        }
+    }
+
+    out.push(String::new());
+    out.push("## Follow-ups and Merges".to_string());
+    out.push(String::new());
+    out.push(format!(
+        "- Follow-ups created: {}",
+        summary.follow_ups_created
+    ));
+    out.push(format!(
+        "- Duplicate follow-ups suppressed: {}",
+        summary.follow_ups_suppressed
+    ));
+    out.push(format!("- Items merged: {}", summary.items_merged));
+    for line in format_merges(&summary.merges) {
+        out.push(format!("  - {}", line));
+    }
+
+    let mut report = redact::redact_text(&out.join("\n"), redact_keys);
+    report.push('\n');
+    report
+}
+
+/// One `source → target` line per merged-away duplicate.
+fn format_merges(merges: &[(String, String)]) -> Vec<String> {
+    merges
+        .iter()
+        .map(|(source, target)| format!("{} → {}", source, target))
+        .collect()
+}
+
+/// Render the run summary's changed-files section: a totals line followed by
+/// one `+added -removed  path` line per file.
+fn format_diff_summary(start_sha: &str, stats: &[phase_golem::git::FileDiffStat]) -> Vec<String> {
+    let short_sha = &start_sha[..start_sha.len().min(7)];
+    if stats.is_empty() {
+        return vec![format!("Changes since {}: none", short_sha)];
+    }
+
+    let added: u64 = stats.iter().filter_map(|s| s.added).sum();
+    let removed: u64 = stats.iter().filter_map(|s| s.removed).sum();
+    let mut lines = vec![format!(
+        "Changes since {}: {} file(s), +{} -{}",
+        short_sha,
+        stats.len(),
+        added,
+        removed
+    )];
+    for stat in stats {
+        let counts = match (stat.added, stat.removed) {
+            (Some(added), Some(removed)) => format!("+{} -{}", added, removed),
+            _ => "binary".to_string(),
+        };
+        lines.push(format!("  {:<12} {}", counts, stat.path));
+    }
+    lines
+}
+
+/// Where this run's event log goes: the `--event-log` path if given, else
+/// `{runtime_dir}/runs/{timestamp}.jsonl` when `execution.event_log` is on.
+fn event_log_path(
+    explicit: Option<&Path>,
+    enabled: bool,
+    runtime_dir: &Path,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Option<PathBuf> {
+    match explicit {
+        Some(path) => Some(path.to_path_buf()),
+        None if enabled => Some(
+            runtime_dir
+                .join("runs")
+                .join(format!("{}.jsonl", now.format("%Y%m%dT%H%M%SZ"))),
+        ),
+        None => None,
+    }
+}
+
+/// Local wall-clock time at which a run started at `start` exhausts `duration`.
+fn format_deadline(
+    start: chrono::DateTime<chrono::Local>,
+    duration: std::time::Duration,
+) -> String {
+    let deadline = chrono::Duration::from_std(duration)
+        .ok()
+        .and_then(|d| start.checked_add_signed(d));
+    match deadline {
+        Some(deadline) => deadline.format("%Y-%m-%d %H:%M:%S").to_string(),
+        None => "never".to_string(),
+    }
+}
+
+/// Push the run's commits when `git.push_on_complete` is set.
+///
+/// Runs halted by a signal or the circuit breaker are not pushed. A failed
+/// push is logged and only fails the run when `git.require_push` is set.
+fn push_after_run(
+    git: &config::GitConfig,
+    halt_reason: &scheduler::HaltReason,
+    root: &Path,
+) -> Result<(), String> {
+    if !git.push_on_complete {
+        return Ok(());
+    }
+    if matches!(
+        halt_reason,
+        scheduler::HaltReason::ShutdownRequested | scheduler::HaltReason::CircuitBreakerTripped
+    ) {
+        log_warn!(
+            "[post] Skipping git push: run halted with {:?}",
+            halt_reason
+        );
+        return Ok(());
+    }
+
+    let branch_display = git.branch.as_deref().unwrap_or("HEAD");
+    log_info!("[post] Pushing {} to {}...", branch_display, git.remote);
+    match phase_golem::git::push(&git.remote, git.branch.as_deref(), Some(root)) {
+        Ok(_) => {
+            log_info!("[post] Pushed {} to {}", branch_display, git.remote);
+            Ok(())
+        }
+        Err(e) if git.require_push => Err(e),
+        Err(e) => {
+            log_warn!(
+                "[post] {} (run result unaffected; set git.require_push = true to fail the run)",
+                e
+            );
+            Ok(())
+        }
+    }
+}
+
+async fn handle_triage(
+    root: &Path,
+    config_path: Option<&Path>,
+    _config_base: &Path,
+    target: Option<&str>,
+    agent_env: &[String],
+    lock_timeout: std::time::Duration,
+) -> Result<(), String> {
+    let agent_env = config::parse_agent_env(agent_env)?;
+    let target = target.map(str::trim);
+    if let Some(id) = target {
+        if !is_valid_item_id(id) {
+            return Err(format!(
+                "Invalid target format '{}': expected <prefix>-<id> (numeric or hex suffix)",
+                id
+            ));
+        }
+    }
+
+    // Install signal handlers for graceful shutdown (second Ctrl-C force-quits)
+    install_signal_handlers(&root.join(".phase-golem"))?;
+
     // Acquire lock
     let runtime_dir = root.join(".phase-golem");
-    let _lock = lock::try_acquire(&runtime_dir)?;
-
-    // Check git preconditions
-    phase_golem::git::check_preconditions(Some(root))?;
+    let _lock = lock::try_acquire_with_timeout(&runtime_dir, lock_timeout)
+        .await
+        .map_err(|e| e.to_string())?;
 
     // Load config
-    let config = config::load_config_from(config_path, root)?;
+    let mut config = config::load_config_from(config_path, root)?;
+    config.agent.env.extend(agent_env);
+
+    // Check git preconditions
+    phase_golem::git::check_preconditions(Some(root), verify_remote_target(&config))?;
 
     // Construct runner from config and verify CLI
-    let runner = CliAgentRunner::new(config.agent.cli.clone(), config.agent.model.clone());
-    log_info!("[pre] Verifying {} ...", config.agent.cli.display_name());
-    runner.verify_cli_available()?;
-    log_agent_config(&config.agent);
+    let runner = verified_runner(&config.agent)?;
 
     // Create Store for coordinator
     let tg_store_dir = root.join(".task-golem");
     let triage_store = Store::new(tg_store_dir);
-    let (coordinator_handle, _coord_task) = coordinator::spawn_coordinator(
+    if let Some(id) = target {
+        let items: Vec<PgItem> = triage_store
+            .load_active()
+            .map_err(|e| format!("Failed to load task-golem store: {}", e))?
+            .into_iter()
+            .map(PgItem)
+            .collect();
+        if let Some(e) = retriage_target_error(&items, id) {
+            return Err(e);
+        }
+    }
+    let (coordinator_handle, _coord_task) = coordinator::spawn_coordinator_with_worklog(
         triage_store,
         root.to_path_buf(),
         config.project.prefix.clone(),
+        config.project.worklog_naming,
+        config.execution.worklog_format,
     );
 
-    // Find New items to triage
-    let pg_snapshot = coordinator_handle.get_snapshot().await?;
-    let new_item_ids: Vec<String> = pg_snapshot
-        .iter()
-        .filter(|item| item.pg_status() == ItemStatus::New)
-        .map(|item| item.id().to_string())
-        .collect();
-
-    let timeout =
-        std::time::Duration::from_secs(config.execution.phase_timeout_minutes as u64 * 60);
-    let mut triaged_count = 0u32;
-
-    for item_id in &new_item_ids {
-        if is_shutdown_requested() {
-            break;
+    // Cancel the triage loop on shutdown signal
+    let cancel = CancellationToken::new();
+    let cancel_clone = cancel.clone();
+    tokio::spawn(async move {
+        loop {
+            if is_shutdown_requested() {
+                cancel_clone.cancel();
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
         }
+    });
 
-        log_info!("[{}][TRIAGE] Starting triage", item_id);
-
-        let result_path = phase_golem::executor::result_file_path(root, item_id, "triage");
-        let current_snapshot = coordinator_handle.get_snapshot().await?;
-        let item = current_snapshot
-            .iter()
-            .find(|i| i.id() == item_id.as_str())
-            .ok_or_else(|| format!("Item {} not found", item_id))?;
-
-        let backlog_summary = prompt::build_backlog_summary(&current_snapshot, item_id);
-        let triage_prompt = prompt::build_triage_prompt(
-            item,
-            &result_path,
-            &config.pipelines,
-            backlog_summary.as_deref(),
-        );
-
-        match runner
-            .run_agent(&triage_prompt, &result_path, timeout)
+    let result = match target {
+        Some(id) => {
+            scheduler::triage_items(
+                &coordinator_handle,
+                Arc::new(runner),
+                &config,
+                root,
+                &cancel,
+                vec![id.to_string()],
+            )
             .await
-        {
-            Ok(phase_result) => {
-                // Stage and commit triage output (immediate commit via destructive flag)
-                coordinator_handle
-                    .complete_phase(item_id, phase_result.clone(), true)
-                    .await?;
-
-                // Apply triage routing
-                scheduler::apply_triage_result(
-                    &coordinator_handle,
-                    item_id,
-                    &phase_result,
-                    &config,
-                )
-                .await?;
-
-                log_info!(
-                    "[{}][TRIAGE] Result: {:?} -- {}",
-                    item_id,
-                    phase_result.result,
-                    phase_result.summary
-                );
-                triaged_count += 1;
-            }
-            Err(e) => {
-                log_error!("[{}][TRIAGE] Failed: {}", item_id, e);
-            }
         }
-    }
+        None => {
+            scheduler::triage_new_items(
+                &coordinator_handle,
+                Arc::new(runner),
+                &config,
+                root,
+                &cancel,
+            )
+            .await
+        }
+    };
 
     // Shutdown coordinator and clean up
     drop(coordinator_handle);
@@ -875,18 +1906,40 @@ async fn handle_triage(
     .await
     .unwrap_or_else(|e| log_warn!("kill_all_children task panicked: {}", e));
 
+    let triaged_count = result?;
     log_info!("Triaged {} item(s)", triaged_count);
 
     Ok(())
 }
 
+/// Why `triage --target` can't re-triage `id`, if it can't.
+fn retriage_target_error(items: &[PgItem], id: &str) -> Option<String> {
+    match items.iter().find(|i| i.id() == id) {
+        None => Some(format!("Item {} not found", id)),
+        Some(item) if item.pg_status() == ItemStatus::Done => Some(format!(
+            "Item {} is done; re-triaging done items is not supported",
+            id
+        )),
+        Some(_) => None,
+    }
+}
+
 fn handle_status(
     root: &Path,
     config_path: Option<&Path>,
     _config_base: &Path,
+    json: bool,
+    only: Vec<String>,
+    prefix: Option<&str>,
 ) -> Result<(), String> {
     let _config = config::load_config_from(config_path, root)?;
 
+    let criteria = only
+        .iter()
+        .map(|raw| filter::parse_filter(raw))
+        .collect::<Result<Vec<_>, _>>()?;
+    filter::validate_filter_criteria(&criteria)?;
+
     // Load items via Store
     let tg_store_dir = root.join(".task-golem");
     let store = Store::new(tg_store_dir);
@@ -895,12 +1948,20 @@ fn handle_status(
         .map_err(|e| format!("Failed to load task-golem store: {}", e))?;
     let items: Vec<PgItem> = raw_items.into_iter().map(PgItem).collect();
 
-    if items.is_empty() {
+    if items.is_empty() && !json {
         println!("No items in backlog.");
         return Ok(());
     }
 
-    let mut sorted_items: Vec<&PgItem> = items.iter().collect();
+    let mut shown_items = if criteria.is_empty() {
+        items.clone()
+    } else {
+        filter::apply_filters(&criteria, &items)
+    };
+    if let Some(prefix) = prefix {
+        retain_prefix(&mut shown_items, prefix);
+    }
+    let mut sorted_items: Vec<&PgItem> = shown_items.iter().collect();
 
     // Sort: in_progress first, then blocked, ready by impact desc, then scoping, new
     sorted_items.sort_by(|a, b| {
@@ -915,6 +1976,13 @@ fn handle_status(
         })
     });
 
+    if json {
+        let rendered = serde_json::to_string_pretty(&status_json(&sorted_items))
+            .map_err(|e| format!("Failed to serialize status: {}", e))?;
+        println!("{}", rendered);
+        return Ok(());
+    }
+
     // Print header
     println!(
         "{:<12} {:<12} {:<12} {:<10} {:<8} {:<8} {:<8} TITLE",
@@ -945,69 +2013,302 @@ fn handle_status(
         );
     }
 
-    println!("\n{} item(s) total", items.len());
+    println!(
+        "\n{}",
+        status_footer(
+            shown_items.len(),
+            items.len(),
+            !criteria.is_empty() || prefix.is_some()
+        )
+    );
 
     Ok(())
 }
 
-fn handle_advance(
+fn status_footer(shown: usize, total: usize, filtered: bool) -> String {
+    if filtered {
+        format!("{} of {} items shown", shown, total)
+    } else {
+        format!("{} item(s) total", total)
+    }
+}
+
+fn handle_config_check(
+    root: &Path,
+    config_path: Option<&Path>,
+    config_base: &Path,
+) -> Result<(), String> {
+    let problems: Vec<String> = match config::load_config_from(config_path, root) {
+        Ok(config) => match preflight::check_config(&config, config_base) {
+            Ok(()) => Vec::new(),
+            Err(errors) => errors.iter().map(|e| e.to_string()).collect(),
+        },
+        Err(e) => config_load_problems(&e),
+    };
+
+    for line in format_config_check(&problems) {
+        println!("{}", line);
+    }
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("{} config problem(s)", problems.len()))
+    }
+}
+
+/// Split a `load_config_from` error into individual problems. Validation
+/// failures list one problem per `  - ` line; anything else is one problem.
+fn config_load_problems(error: &str) -> Vec<String> {
+    let listed: Vec<String> = error
+        .lines()
+        .filter_map(|line| line.strip_prefix("  - "))
+        .map(|problem| problem.to_string())
+        .collect();
+    if listed.is_empty() {
+        vec![error.to_string()]
+    } else {
+        listed
+    }
+}
+
+/// "Config OK", or a numbered list of problems.
+fn format_config_check(problems: &[String]) -> Vec<String> {
+    if problems.is_empty() {
+        return vec!["Config OK".to_string()];
+    }
+    let mut lines = vec![format!("Config has {} problem(s):", problems.len())];
+    for (i, problem) in problems.iter().enumerate() {
+        lines.push(format!("  {}. {}", i + 1, problem));
+    }
+    lines
+}
+
+fn handle_triage_preview(
     root: &Path,
     config_path: Option<&Path>,
     _config_base: &Path,
     item_id: &str,
-    to: Option<String>,
+    assessments: UpdatedAssessments,
+    pipeline: Option<String>,
 ) -> Result<(), String> {
     let config = config::load_config_from(config_path, root)?;
 
-    // Use Store directly with with_lock for single-shot CLI command
     let tg_store_dir = root.join(".task-golem");
     let store = Store::new(tg_store_dir);
+    let raw_items = store
+        .load_active()
+        .map_err(|e| format!("Failed to load task-golem store: {}", e))?;
+    let item = raw_items
+        .into_iter()
+        .map(PgItem)
+        .find(|i| i.id() == item_id)
+        .ok_or_else(|| format!("Item {} not found", item_id))?;
 
-    store
-        .with_lock(|s| {
-            let mut items = s.load_active()?;
-            let idx = items
-                .iter()
-                .position(|i| i.id == item_id)
-                .ok_or_else(|| task_golem::errors::TgError::ItemNotFound(item_id.to_string()))?;
+    let previewed = scheduler::preview_triage(&item, assessments, pipeline, &config);
+    for line in format_triage_preview(&previewed) {
+        println!("{}", line);
+    }
+    Ok(())
+}
 
-            let pg = PgItem(items[idx].clone());
-            if pg.pg_status() != ItemStatus::InProgress {
-                return Err(task_golem::errors::TgError::InvalidInput(format!(
-                    "Cannot advance {}: status is {:?}, expected InProgress",
-                    item_id,
-                    pg.pg_status()
-                )));
-            }
+fn handle_guardrail_check(
+    root: &Path,
+    config_path: Option<&Path>,
+    max_size: Option<SizeLevel>,
+    max_complexity: Option<DimensionLevel>,
+    max_risk: Option<DimensionLevel>,
+) -> Result<(), String> {
+    let config = config::load_config_from(config_path, root)?;
+    let guardrails = config::GuardrailsConfig {
+        max_size: max_size.unwrap_or(config.guardrails.max_size.clone()),
+        max_complexity: max_complexity.unwrap_or(config.guardrails.max_complexity.clone()),
+        max_risk: max_risk.unwrap_or(config.guardrails.max_risk.clone()),
+        ..config.guardrails.clone()
+    };
 
-            let pipeline_type = pg.pipeline_type().unwrap_or_else(|| "feature".to_string());
-            let pipeline = config.pipelines.get(&pipeline_type).ok_or_else(|| {
-                task_golem::errors::TgError::InvalidInput(format!(
-                    "Pipeline type '{}' not found in config",
-                    pipeline_type
-                ))
-            })?;
+    let tg_store_dir = root.join(".task-golem");
+    let store = Store::new(tg_store_dir);
+    let items: Vec<PgItem> = store
+        .load_active()
+        .map_err(|e| format!("Failed to load task-golem store: {}", e))?
+        .into_iter()
+        .map(PgItem)
+        .collect();
 
-            match to {
-                Some(target_phase) => {
-                    // Validate target phase exists in pipeline (main phases only for advance)
-                    let is_main_phase = pipeline.phases.iter().any(|p| p.name == target_phase);
-                    if !is_main_phase {
-                        let valid_names: Vec<&str> =
-                            pipeline.phases.iter().map(|p| p.name.as_str()).collect();
-                        return Err(task_golem::errors::TgError::InvalidInput(format!(
-                            "Invalid phase '{}': expected one of {}",
-                            target_phase,
-                            valid_names.join(", ")
-                        )));
-                    }
-                    pg_item::set_phase(&mut items[idx], Some(&target_phase));
-                    pg_item::set_phase_pool(
-                        &mut items[idx],
-                        Some(&phase_golem::types::PhasePool::Main),
-                    );
-                    s.save_active(&items)?;
-                    println!("Advanced {} to {}", item_id, target_phase);
+    for line in format_guardrail_check(&items, &guardrails) {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+/// One line per item exceeding `guardrails` (with each violated dimension),
+/// then a count against the thresholds used.
+fn format_guardrail_check(items: &[PgItem], guardrails: &config::GuardrailsConfig) -> Vec<String> {
+    let mut lines = Vec::new();
+    for item in items {
+        let violations = phase_golem::executor::guardrail_violations(item, guardrails);
+        if violations.is_empty() {
+            continue;
+        }
+        let described: Vec<String> = violations
+            .iter()
+            .map(|v| format!("{} {} > {}", v.dimension, v.actual, v.max))
+            .collect();
+        lines.push(format!(
+            "{} {}: {}",
+            item.id(),
+            truncate_title(item.title(), 40),
+            described.join(", ")
+        ));
+    }
+    let lowercase = |v: &dyn std::fmt::Debug| format!("{:?}", v).to_lowercase();
+    lines.push(format!(
+        "{} of {} item(s) exceed guardrails (max_size={}, max_complexity={}, max_risk={})",
+        lines.len(),
+        items.len(),
+        lowercase(&guardrails.max_size),
+        lowercase(&guardrails.max_complexity),
+        lowercase(&guardrails.max_risk),
+    ));
+    lines
+}
+
+/// Lines describing where a previewed triage would leave an item.
+fn format_triage_preview(item: &PgItem) -> Vec<String> {
+    let status = format!("{:?}", item.pg_status()).to_lowercase();
+    let mut lines = vec![format!("{} would route to {}", item.id(), status)];
+    if let Some(reason) = item.0.blocked_reason.as_deref() {
+        lines.push(format!("  Reason:   {}", reason));
+    }
+    if let Some(phase) = item.phase() {
+        let pool = match item.phase_pool() {
+            Some(PhasePool::Pre) => "pre_phases",
+            Some(PhasePool::Main) | None => "phases",
+        };
+        lines.push(format!("  Phase:    {} ({})", phase, pool));
+    }
+    lines.push(format!(
+        "  Pipeline: {}",
+        item.pipeline_type()
+            .unwrap_or_else(|| "feature".to_string())
+    ));
+    lines.push(format!(
+        "  Size: {}  Risk: {}  Impact: {}",
+        display_optional_size(item.size()),
+        display_optional_dimension(item.risk()),
+        display_optional_dimension(item.impact())
+    ));
+    lines
+}
+
+fn handle_promotable(
+    root: &Path,
+    config_path: Option<&Path>,
+    _config_base: &Path,
+) -> Result<(), String> {
+    let config = config::load_config_from(config_path, root)?;
+
+    let tg_store_dir = root.join(".task-golem");
+    let store = Store::new(tg_store_dir);
+    let items: Vec<PgItem> = store
+        .load_active()
+        .map_err(|e| format!("Failed to load task-golem store: {}", e))?
+        .into_iter()
+        .map(PgItem)
+        .collect();
+
+    let promotable = scheduler::promotable_item_ids(&items, &config.execution, &config.pipelines);
+    for line in format_promotable(&promotable, &items, config.execution.max_wip) {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+/// Lines listing promotable items, or why none can be promoted.
+fn format_promotable(promotable: &[String], items: &[PgItem], max_wip: u32) -> Vec<String> {
+    if promotable.is_empty() {
+        let in_progress = items
+            .iter()
+            .filter(|i| i.pg_status() == ItemStatus::InProgress)
+            .count();
+        return vec![format!(
+            "No items eligible for promotion ({} in progress, max_wip {}).",
+            in_progress, max_wip
+        )];
+    }
+
+    promotable
+        .iter()
+        .filter_map(|id| items.iter().find(|i| i.id() == id.as_str()))
+        .map(|item| {
+            format!(
+                "{}  impact={}  {}",
+                item.id(),
+                display_optional_dimension(item.impact()),
+                item.title()
+            )
+        })
+        .collect()
+}
+
+fn handle_advance(
+    root: &Path,
+    config_path: Option<&Path>,
+    _config_base: &Path,
+    item_id: &str,
+    to: Option<String>,
+) -> Result<(), String> {
+    let config = config::load_config_from(config_path, root)?;
+
+    // Use Store directly with with_lock for single-shot CLI command
+    let tg_store_dir = root.join(".task-golem");
+    let store = Store::new(tg_store_dir);
+
+    store
+        .with_lock(|s| {
+            let mut items = s.load_active()?;
+            let idx = items
+                .iter()
+                .position(|i| i.id == item_id)
+                .ok_or_else(|| task_golem::errors::TgError::ItemNotFound(item_id.to_string()))?;
+
+            let pg = PgItem(items[idx].clone());
+            if pg.pg_status() != ItemStatus::InProgress {
+                return Err(task_golem::errors::TgError::InvalidInput(format!(
+                    "Cannot advance {}: status is {:?}, expected InProgress",
+                    item_id,
+                    pg.pg_status()
+                )));
+            }
+
+            let pipeline_type = pg.pipeline_type().unwrap_or_else(|| "feature".to_string());
+            let pipeline = config.pipelines.get(&pipeline_type).ok_or_else(|| {
+                task_golem::errors::TgError::InvalidInput(format!(
+                    "Pipeline type '{}' not found in config",
+                    pipeline_type
+                ))
+            })?;
+
+            match to {
+                Some(target_phase) => {
+                    // Validate target phase exists in pipeline (main phases only for advance)
+                    let is_main_phase = pipeline.phases.iter().any(|p| p.name == target_phase);
+                    if !is_main_phase {
+                        let valid_names: Vec<&str> =
+                            pipeline.phases.iter().map(|p| p.name.as_str()).collect();
+                        return Err(task_golem::errors::TgError::InvalidInput(format!(
+                            "Invalid phase '{}': expected one of {}",
+                            target_phase,
+                            valid_names.join(", ")
+                        )));
+                    }
+                    pg_item::set_phase(&mut items[idx], Some(&target_phase));
+                    pg_item::set_phase_pool(
+                        &mut items[idx],
+                        Some(&phase_golem::types::PhasePool::Main),
+                    );
+                    s.save_active(&items)?;
+                    println!("Advanced {} to {}", item_id, target_phase);
                 }
                 None => {
                     let current_phase = pg.phase().ok_or_else(|| {
@@ -1050,14 +2351,13 @@ fn handle_advance(
         .map_err(|e| format!("{}", e))
 }
 
-fn handle_unblock(
+fn handle_rewind(
     root: &Path,
     config_path: Option<&Path>,
-    _config_base: &Path,
     item_id: &str,
-    notes: Option<String>,
+    to: Option<&str>,
 ) -> Result<(), String> {
-    let _config = config::load_config_from(config_path, root)?;
+    let config = config::load_config_from(config_path, root)?;
 
     // Use Store directly with with_lock for single-shot CLI command
     let tg_store_dir = root.join(".task-golem");
@@ -1072,37 +2372,1154 @@ fn handle_unblock(
                 .ok_or_else(|| task_golem::errors::TgError::ItemNotFound(item_id.to_string()))?;
 
             let pg = PgItem(items[idx].clone());
-            if pg.pg_status() != ItemStatus::Blocked {
+            if pg.pg_status() != ItemStatus::InProgress {
                 return Err(task_golem::errors::TgError::InvalidInput(format!(
-                    "Cannot unblock {}: status is {:?}, expected Blocked",
+                    "Cannot rewind {}: status is {:?}, expected InProgress",
                     item_id,
                     pg.pg_status()
                 )));
             }
 
-            // Read the blocked_from_status before clearing
-            let restore_to = pg.pg_blocked_from_status().unwrap_or(ItemStatus::New);
+            let pipeline_type = pg.pipeline_type().unwrap_or_else(|| "feature".to_string());
+            let pipeline = config.pipelines.get(&pipeline_type).ok_or_else(|| {
+                task_golem::errors::TgError::InvalidInput(format!(
+                    "Pipeline type '{}' not found in config",
+                    pipeline_type
+                ))
+            })?;
+            let main_phases: Vec<&str> = pipeline.phases.iter().map(|p| p.name.as_str()).collect();
 
-            // Clear all blocked fields (extension and native) via apply_update(Unblock)
-            pg_item::apply_update(&mut items[idx], ItemUpdate::Unblock);
+            let current_phase = pg.phase();
+            let target_idx = rewind_target(item_id, &main_phases, current_phase.as_deref(), to)
+                .map_err(task_golem::errors::TgError::InvalidInput)?;
+            let target = main_phases[target_idx];
 
-            // Set unblock_context if notes provided
-            if let Some(notes_text) = notes {
-                pg_item::set_unblock_context(&mut items[idx], Some(&notes_text));
+            pg_item::set_phase(&mut items[idx], Some(target));
+            pg_item::set_phase_pool(&mut items[idx], Some(&phase_golem::types::PhasePool::Main));
+            // The phases being redone no longer count as completed, and their
+            // commit is no longer the baseline for staleness checks
+            pg_item::set_last_phase_commit(&mut items[idx], None);
+            let redone = &main_phases[target_idx..];
+            let completed: Vec<String> = pg
+                .completed_phases()
+                .into_iter()
+                .filter(|phase| !redone.contains(&phase.as_str()))
+                .collect();
+            pg_item::set_completed_phases(&mut items[idx], &completed);
+            s.save_active(&items)?;
+            println!(
+                "Rewound {} from {} to {}",
+                item_id,
+                current_phase.as_deref().unwrap_or("none"),
+                target
+            );
+
+            Ok(())
+        })
+        .map_err(|e| format!("{}", e))
+}
+
+/// The phase (and its pool) `unblock --retry` leaves an item at: the phase it
+/// was blocked in. Only items that were `InProgress` have a phase to retry.
+fn retry_position(
+    item_id: &str,
+    restore_to: &ItemStatus,
+    phase: Option<&str>,
+    pipeline: &config::PipelineConfig,
+) -> Result<(String, PhasePool), String> {
+    if *restore_to != ItemStatus::InProgress {
+        return Err(format!(
+            "Cannot retry {}: it was {:?} before blocking, expected InProgress",
+            item_id, restore_to
+        ));
+    }
+    let phase = phase.ok_or_else(|| format!("Cannot retry {}: no current phase set", item_id))?;
+    let pool = if pipeline.phases.iter().any(|p| p.name == phase) {
+        PhasePool::Main
+    } else if pipeline.pre_phases.iter().any(|p| p.name == phase) {
+        PhasePool::Pre
+    } else {
+        return Err(format!("Current phase '{}' not found in pipeline", phase));
+    };
+    Ok((phase.to_string(), pool))
+}
+
+fn handle_prioritize(root: &Path, item_id: &str, priority: Option<i32>) -> Result<(), String> {
+    // Use Store directly with with_lock for single-shot CLI command
+    let tg_store_dir = root.join(".task-golem");
+    let store = Store::new(tg_store_dir);
+
+    store
+        .with_lock(|s| {
+            let mut items = s.load_active()?;
+            let idx = items
+                .iter()
+                .position(|i| i.id == item_id)
+                .ok_or_else(|| task_golem::errors::TgError::ItemNotFound(item_id.to_string()))?;
+
+            pg_item::set_priority(&mut items[idx], priority);
+            s.save_active(&items)?;
+            match priority {
+                Some(value) => println!("Set {} priority to {}", item_id, value),
+                None => println!("Cleared {} priority", item_id),
             }
+            Ok(())
+        })
+        .map_err(|e| format!("{}", e))
+}
 
-            // Reset last_phase_commit for staleness-blocked items
-            pg_item::set_last_phase_commit(&mut items[idx], None);
+/// Parse a `deadline` date argument (`YYYY-MM-DD`).
+fn parse_deadline(s: &str) -> Result<chrono::NaiveDate, String> {
+    chrono::NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d")
+        .map_err(|_| format!("Invalid date '{}': expected YYYY-MM-DD", s))
+}
+
+fn handle_deadline(
+    root: &Path,
+    item_id: &str,
+    deadline: Option<chrono::NaiveDate>,
+) -> Result<(), String> {
+    // Use Store directly with with_lock for single-shot CLI command
+    let tg_store_dir = root.join(".task-golem");
+    let store = Store::new(tg_store_dir);
+
+    store
+        .with_lock(|s| {
+            let mut items = s.load_active()?;
+            let idx = items
+                .iter()
+                .position(|i| i.id == item_id)
+                .ok_or_else(|| task_golem::errors::TgError::ItemNotFound(item_id.to_string()))?;
 
+            pg_item::set_deadline(&mut items[idx], deadline);
             s.save_active(&items)?;
-            println!("Unblocked {} -- restored to {:?}", item_id, restore_to);
+            match deadline {
+                Some(date) => println!("Set {} deadline to {}", item_id, date),
+                None => println!("Cleared {} deadline", item_id),
+            }
             Ok(())
         })
         .map_err(|e| format!("{}", e))
 }
 
-// --- Display helpers ---
-
+fn handle_history(root: &Path, item_id: &str) -> Result<(), String> {
+    let pg = load_item_or_archived(root, item_id)?;
+    print!("{}", format_phase_history(&pg));
+    Ok(())
+}
+
+fn handle_show(root: &Path, item_id: &str) -> Result<(), String> {
+    let pg = load_item_or_archived(root, item_id)?;
+    for line in format_item_detail(&pg) {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+/// Look up `item_id` in the active store, falling back to the archive since
+/// completed items are archived.
+fn load_item_or_archived(root: &Path, item_id: &str) -> Result<PgItem, String> {
+    let tg_store_dir = root.join(".task-golem");
+    let store = Store::new(tg_store_dir.clone());
+    let active = store
+        .load_active()
+        .map_err(|e| format!("Failed to load task-golem store: {}", e))?;
+
+    let item = match active.into_iter().find(|i| i.id == item_id) {
+        Some(item) => item,
+        None => find_archived_item(&tg_store_dir.join("archive.jsonl"), item_id)?
+            .ok_or_else(|| format!("Item {} not found", item_id))?,
+    };
+    Ok(PgItem(item))
+}
+
+/// The last archived copy of `item_id` in `archive_path`, if any.
+fn find_archived_item(
+    archive_path: &Path,
+    item_id: &str,
+) -> Result<Option<task_golem::model::item::Item>, String> {
+    let contents = match fs::read_to_string(archive_path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("Failed to read {}: {}", archive_path.display(), e)),
+    };
+    Ok(contents
+        .lines()
+        .filter_map(|line| serde_json::from_str::<task_golem::model::item::Item>(line).ok())
+        .filter(|item| item.id == item_id)
+        .last())
+}
+
+/// Render an item's phase history as a timeline, oldest first.
+fn format_phase_history(item: &PgItem) -> String {
+    let history = item.phase_history();
+    let mut out = format!("{} {}\n", item.id(), item.title());
+    if history.is_empty() {
+        out.push_str("  No phases recorded.\n");
+        return out;
+    }
+    for entry in history {
+        out.push_str(&format!("  {}\n", format_history_entry(&entry)));
+    }
+    out
+}
+
+/// One phase history entry: local time, phase, result, short commit SHA.
+fn format_history_entry(entry: &phase_golem::types::PhaseHistoryEntry) -> String {
+    let commit = entry
+        .commit
+        .as_deref()
+        .map(|sha| sha.chars().take(7).collect::<String>())
+        .unwrap_or_else(|| "-".to_string());
+    format!(
+        "{}  {:<16} {:<18} {}",
+        entry
+            .completed_at
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M"),
+        entry.phase,
+        format!("{:?}", entry.result),
+        commit
+    )
+}
+
+/// Every field phase-golem tracks on an item, one labelled line each. Unset
+/// fields show `-`; the description and history sections are omitted when empty.
+fn format_item_detail(item: &PgItem) -> Vec<String> {
+    let or_dash = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+    let list = |values: &[String]| {
+        if values.is_empty() {
+            "-".to_string()
+        } else {
+            values.join(", ")
+        }
+    };
+
+    let mut lines = vec![
+        format!("{} {}", item.id(), item.title()),
+        format!(
+            "  Status:       {}",
+            format!("{:?}", item.pg_status()).to_lowercase()
+        ),
+        format!("  Phase:        {}", or_dash(item.phase())),
+        format!(
+            "  Pool:         {}",
+            match item.phase_pool() {
+                Some(PhasePool::Pre) => "pre_phases",
+                Some(PhasePool::Main) => "phases",
+                None => "-",
+            }
+        ),
+        format!("  Pipeline:     {}", or_dash(item.pipeline_type())),
+        format!(
+            "  Size: {}  Complexity: {}  Risk: {}  Impact: {}",
+            display_optional_size(item.size()),
+            display_optional_dimension(item.complexity()),
+            display_optional_dimension(item.risk()),
+            display_optional_dimension(item.impact())
+        ),
+        format!(
+            "  Priority:     {}",
+            or_dash(item.priority().map(|p| p.to_string()))
+        ),
+        format!(
+            "  Deadline:     {}",
+            or_dash(item.deadline().map(|d| d.to_string()))
+        ),
+        format!("  Depends on:   {}", list(item.dependencies())),
+        format!("  Tags:         {}", list(item.tags())),
+        format!("  Origin:       {}", or_dash(item.origin())),
+        format!("  Completed:    {}", list(&item.completed_phases())),
+        format!("  Last commit:  {}", or_dash(item.last_phase_commit())),
+    ];
+    if item.requires_human_review() {
+        lines.push("  Requires human review".to_string());
+    }
+    if item.pg_status() == ItemStatus::Blocked {
+        lines.push(format!(
+            "  Blocked:      {}",
+            item.blocked_reason().unwrap_or("-")
+        ));
+        lines.push(format!(
+            "  Blocked type: {}",
+            or_dash(
+                item.blocked_type()
+                    .map(|t| format!("{:?}", t).to_lowercase())
+            )
+        ));
+        lines.push(format!(
+            "  Blocked from: {}",
+            or_dash(
+                item.pg_blocked_from_status()
+                    .map(|s| format!("{:?}", s).to_lowercase())
+            )
+        ));
+    }
+    if let Some(context) = item.unblock_context() {
+        lines.push(format!("  Unblock note: {}", context));
+    }
+    lines.push(format!(
+        "  Created:      {}",
+        item.created_at()
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M")
+    ));
+    lines.push(format!(
+        "  Updated:      {}",
+        item.updated_at()
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M")
+    ));
+
+    if let Some(description) = item.structured_description() {
+        let fields = [
+            ("Context", &description.context),
+            ("Problem", &description.problem),
+            ("Solution", &description.solution),
+            ("Impact", &description.impact),
+            ("Sizing", &description.sizing_rationale),
+        ];
+        let present: Vec<_> = fields.iter().filter(|(_, v)| !v.is_empty()).collect();
+        if !present.is_empty() {
+            lines.push(String::new());
+            lines.push("Description:".to_string());
+            for (label, value) in present {
+                lines.push(format!("  {}: {}", label, value));
+            }
+        }
+    }
+
+    let history = item.phase_history();
+    if !history.is_empty() {
+        lines.push(String::new());
+        lines.push("Phase history:".to_string());
+        for entry in &history {
+            lines.push(format!("  {}", format_history_entry(entry)));
+        }
+    }
+    lines
+}
+
+/// Index into `main_phases` of the phase `rewind` moves an item back to:
+/// `to` when given, otherwise the phase before `current`.
+fn rewind_target(
+    item_id: &str,
+    main_phases: &[&str],
+    current: Option<&str>,
+    to: Option<&str>,
+) -> Result<usize, String> {
+    let current =
+        current.ok_or_else(|| format!("Cannot rewind {}: no current phase set", item_id))?;
+    let current_idx = main_phases
+        .iter()
+        .position(|&p| p == current)
+        .ok_or_else(|| format!("Current phase '{}' not found in pipeline", current))?;
+
+    match to {
+        Some(target) => {
+            let target_idx = main_phases
+                .iter()
+                .position(|&p| p == target)
+                .ok_or_else(|| {
+                    format!(
+                        "Invalid phase '{}': expected one of {}",
+                        target,
+                        main_phases.join(", ")
+                    )
+                })?;
+            if target_idx >= current_idx {
+                return Err(format!(
+                    "Cannot rewind {} to '{}': it is not before the current phase '{}'",
+                    item_id, target, current
+                ));
+            }
+            Ok(target_idx)
+        }
+        None => current_idx.checked_sub(1).ok_or_else(|| {
+            format!(
+                "Cannot rewind {}: '{}' is the first phase",
+                item_id, current
+            )
+        }),
+    }
+}
+
+fn handle_deps(
+    root: &Path,
+    config_path: Option<&Path>,
+    _config_base: &Path,
+    check: bool,
+) -> Result<(), String> {
+    let _config = config::load_config_from(config_path, root)?;
+
+    let tg_store_dir = root.join(".task-golem");
+    let store = Store::new(tg_store_dir);
+    let raw_items = store
+        .load_active()
+        .map_err(|e| format!("Failed to load task-golem store: {}", e))?;
+    let items: Vec<PgItem> = raw_items.into_iter().map(PgItem).collect();
+
+    let lint = preflight::lint_dependency_graph(&items, preflight::DEEP_CHAIN_THRESHOLD);
+
+    if lint.is_clean() {
+        println!("No dependency problems found.");
+        return Ok(());
+    }
+
+    if !lint.cycles.is_empty() {
+        println!("Cycles ({}):", lint.cycles.len());
+        for cycle in &lint.cycles {
+            println!("  {}", cycle.join(" → "));
+        }
+    }
+    if !lint.unknown.is_empty() {
+        println!("Unknown dependencies ({}):", lint.unknown.len());
+        for (item_id, dep_id) in &lint.unknown {
+            println!("  {} → {} (not in backlog)", item_id, dep_id);
+        }
+    }
+    if !lint.done.is_empty() {
+        println!(
+            "Dependencies on Done items ({}, informational):",
+            lint.done.len()
+        );
+        for (item_id, dep_id) in &lint.done {
+            println!("  {} → {}", item_id, dep_id);
+        }
+    }
+    if !lint.deep_chains.is_empty() {
+        println!(
+            "Deep dependency chains (> {}, informational):",
+            preflight::DEEP_CHAIN_THRESHOLD
+        );
+        for (item_id, depth) in &lint.deep_chains {
+            println!("  {} (depth {})", item_id, depth);
+        }
+    }
+
+    if check && lint.has_hard_problems() {
+        return Err(format!(
+            "Dependency check failed: {} cycle(s), {} unknown dependency reference(s)",
+            lint.cycles.len(),
+            lint.unknown.len()
+        ));
+    }
+
+    Ok(())
+}
+
+fn handle_pipelines(
+    root: &Path,
+    config_path: Option<&Path>,
+    describe: Option<&str>,
+) -> Result<(), String> {
+    let config = config::load_config_from(config_path, root)?;
+
+    let lines = match describe {
+        Some(name) => {
+            let pipeline = config.pipelines.get(name).ok_or_else(|| {
+                format!(
+                    "Unknown pipeline '{}'. Available: {}",
+                    name,
+                    sorted_pipeline_names(&config.pipelines).join(", ")
+                )
+            })?;
+            format_pipeline_description(name, pipeline, &config.execution)
+        }
+        None => format_pipeline_list(&config.pipelines),
+    };
+    for line in lines {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+fn handle_dump_prompt(
+    root: &Path,
+    config_path: Option<&Path>,
+    config_base: &Path,
+    item_id: &str,
+    phase: Option<&str>,
+) -> Result<(), String> {
+    let config = config::load_config_from(config_path, root)?;
+
+    let tg_store_dir = root.join(".task-golem");
+    let store = Store::new(tg_store_dir);
+    let raw_items = store
+        .load_active()
+        .map_err(|e| format!("Failed to load task-golem store: {}", e))?;
+    let items: Vec<PgItem> = raw_items.into_iter().map(PgItem).collect();
+
+    let prompt = build_dump_prompt(&config, &items, root, config_base, item_id, phase)?;
+    println!("{}", prompt);
+    Ok(())
+}
+
+/// Build the prompt `run` would hand the agent for `item_id` in `phase` (or
+/// triage), using the same builders. Run-scoped context such as the previous
+/// phase's summary and retry failure notes is not available and is left out.
+fn build_dump_prompt(
+    config: &config::PhaseGolemConfig,
+    items: &[PgItem],
+    root: &Path,
+    config_base: &Path,
+    item_id: &str,
+    phase: Option<&str>,
+) -> Result<String, String> {
+    let item = items
+        .iter()
+        .find(|i| i.id() == item_id)
+        .ok_or_else(|| format!("Item {} not found", item_id))?;
+
+    if phase == Some("triage") {
+        let backlog_summary = phase_golem::prompt::build_backlog_summary(items, item_id);
+        let result_path = phase_golem::executor::result_file_path(root, item_id, "triage");
+        return Ok(phase_golem::prompt::build_triage_prompt(
+            item,
+            &result_path,
+            &config.pipelines,
+            backlog_summary.as_deref(),
+        ));
+    }
+
+    let phase_name = match phase {
+        Some(name) => name.to_string(),
+        None => item.phase().ok_or_else(|| {
+            format!(
+                "Item {} has no current phase; pass --phase (or --phase triage)",
+                item_id
+            )
+        })?,
+    };
+    let pipeline_type = item
+        .pipeline_type()
+        .unwrap_or_else(|| "feature".to_string());
+    let pipeline = config
+        .pipelines
+        .get(&pipeline_type)
+        .ok_or_else(|| format!("Pipeline type '{}' not found in config", pipeline_type))?;
+    let phase_config = pipeline
+        .pre_phases
+        .iter()
+        .chain(pipeline.phases.iter())
+        .find(|p| p.name == phase_name)
+        .ok_or_else(|| {
+            let valid_names: Vec<&str> = pipeline
+                .pre_phases
+                .iter()
+                .chain(pipeline.phases.iter())
+                .map(|p| p.name.as_str())
+                .collect();
+            format!(
+                "Invalid phase '{}' for pipeline '{}': expected triage or one of {}",
+                phase_name,
+                pipeline_type,
+                valid_names.join(", ")
+            )
+        })?;
+
+    let result_path = phase_golem::executor::result_file_path(root, item_id, &phase_name);
+    let change_folder = phase_golem::executor::change_folder_path(root, item_id, item.title());
+    Ok(phase_golem::executor::build_executor_prompt(
+        &phase_name,
+        phase_config,
+        item,
+        &result_path,
+        &change_folder,
+        None,
+        item.unblock_context().as_deref(),
+        None,
+        config_base,
+    ))
+}
+
+fn sorted_pipeline_names(pipelines: &HashMap<String, config::PipelineConfig>) -> Vec<&str> {
+    let mut names: Vec<&str> = pipelines.keys().map(String::as_str).collect();
+    names.sort_unstable();
+    names
+}
+
+/// `pre: [a -> b] -> main: [c -> d]`, as logged at the start of a run.
+fn format_pipeline_sequence(pipeline: &config::PipelineConfig) -> String {
+    let pre_names: Vec<&str> = pipeline
+        .pre_phases
+        .iter()
+        .map(|p| p.name.as_str())
+        .collect();
+    let main_names: Vec<&str> = pipeline.phases.iter().map(|p| p.name.as_str()).collect();
+    format!(
+        "pre: [{}] -> main: [{}]",
+        pre_names.join(" -> "),
+        main_names.join(" -> ")
+    )
+}
+
+/// One `name: pre: [...] -> main: [...]` line per pipeline, sorted by name.
+fn format_pipeline_list(pipelines: &HashMap<String, config::PipelineConfig>) -> Vec<String> {
+    sorted_pipeline_names(pipelines)
+        .into_iter()
+        .map(|name| format!("{}: {}", name, format_pipeline_sequence(&pipelines[name])))
+        .collect()
+}
+
+/// Lines describing each phase of `pipeline`: workflows, destructiveness, and
+/// the settings it overrides (anything unset falls back to `execution`).
+fn format_pipeline_description(
+    name: &str,
+    pipeline: &config::PipelineConfig,
+    execution: &config::ExecutionConfig,
+) -> Vec<String> {
+    let mut lines = vec![
+        format!("Pipeline: {}", name),
+        format!("  {}", format_pipeline_sequence(pipeline)),
+        format!(
+            "  Retries: {} per phase (execution.max_retries)",
+            execution.max_retries
+        ),
+    ];
+    if let Some(max_wip) = pipeline.max_wip {
+        lines.push(format!("  Max WIP: {}", max_wip));
+    }
+    if let Some(max_concurrent) = pipeline.max_concurrent {
+        lines.push(format!("  Max concurrent: {}", max_concurrent));
+    }
+
+    let phases = pipeline
+        .pre_phases
+        .iter()
+        .map(|p| (p, "pre"))
+        .chain(pipeline.phases.iter().map(|p| (p, "main")));
+    for (phase, pool) in phases {
+        lines.push(String::new());
+        lines.push(format!("{} ({})", phase.name, pool));
+        if phase.workflows.is_empty() {
+            lines.push("  Workflows: none".to_string());
+        } else {
+            lines.push("  Workflows:".to_string());
+            lines.extend(phase.workflows.iter().map(|w| format!("    {}", w)));
+        }
+        lines.push(format!(
+            "  Destructive: {}",
+            if phase.is_destructive { "yes" } else { "no" }
+        ));
+        lines.push(match phase.timeout_minutes {
+            Some(minutes) => format!("  Timeout: {}m (phase override)", minutes),
+            None => format!(
+                "  Timeout: {}m (execution.phase_timeout_minutes)",
+                execution.phase_timeout_minutes
+            ),
+        });
+        if let Some(ref model) = phase.model {
+            lines.push(format!("  Model: {}", model));
+        }
+        if let Some(min_confidence) = phase.min_confidence {
+            lines.push(format!("  Min confidence: {}", min_confidence));
+        }
+        if let Some(ref expr) = phase.skip_if {
+            lines.push(format!("  Skip if: {}", expr));
+        }
+        if phase.checkpoint {
+            lines.push("  Checkpoint: yes".to_string());
+        }
+        if phase.followups_block {
+            lines.push("  Follow-ups block: yes".to_string());
+        }
+    }
+    lines
+}
+
+fn handle_graph(root: &Path, format: Option<&str>) -> Result<(), String> {
+    let store = Store::new(root.join(".task-golem"));
+    let raw_items = store
+        .load_active()
+        .map_err(|e| format!("Failed to load task-golem store: {}", e))?;
+    let items: Vec<PgItem> = raw_items.into_iter().map(PgItem).collect();
+
+    let graph = match format {
+        Some("mermaid") => format_graph_mermaid(&items),
+        _ => format_graph_dot(&items),
+    };
+    print!("{}", graph);
+    Ok(())
+}
+
+/// Node fill color for an item's status in `graph` output; `None` keeps the default.
+fn graph_status_color(status: ItemStatus) -> Option<&'static str> {
+    match status {
+        ItemStatus::Done => Some("#b7e4c7"),
+        ItemStatus::Blocked => Some("#f4a6a6"),
+        ItemStatus::InProgress => Some("#a9d6f5"),
+        _ => None,
+    }
+}
+
+/// Dependencies missing from `items`, which the scheduler treats as archived,
+/// sorted and deduplicated.
+fn archived_dependencies(items: &[PgItem]) -> Vec<&str> {
+    let mut archived: Vec<&str> = items
+        .iter()
+        .flat_map(|item| scheduler::resolve_dependencies(item, items))
+        .filter(|(_, dep)| dep.is_none())
+        .map(|(dep_id, _)| dep_id)
+        .collect();
+    archived.sort_unstable();
+    archived.dedup();
+    archived
+}
+
+/// Render the dependency graph as Graphviz DOT: one node per item labeled with
+/// its ID and title and filled by status, and an edge from each item to each
+/// of its dependencies. Archived dependencies get dashed nodes and edges.
+fn format_graph_dot(items: &[PgItem]) -> String {
+    let escape = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"");
+    let quote = |s: &str| format!("\"{}\"", escape(s));
+
+    let mut out = vec![
+        "digraph dependencies {".to_string(),
+        "  rankdir=LR;".to_string(),
+        "  node [shape=box];".to_string(),
+    ];
+    for item in items {
+        let label = format!("\"{}\\n{}\"", escape(item.id()), escape(item.title()));
+        match graph_status_color(item.pg_status()) {
+            Some(color) => out.push(format!(
+                "  {} [label={}, style=filled, fillcolor=\"{}\"];",
+                quote(item.id()),
+                label,
+                color
+            )),
+            None => out.push(format!("  {} [label={}];", quote(item.id()), label)),
+        }
+    }
+    for dep_id in archived_dependencies(items) {
+        out.push(format!(
+            "  {} [label={}, style=dashed];",
+            quote(dep_id),
+            quote(&format!("{} (archived)", dep_id))
+        ));
+    }
+    for item in items {
+        for (dep_id, dep) in scheduler::resolve_dependencies(item, items) {
+            let style = if dep.is_some() {
+                ""
+            } else {
+                " [style=dashed, label=\"archived\"]"
+            };
+            out.push(format!(
+                "  {} -> {}{};",
+                quote(item.id()),
+                quote(dep_id),
+                style
+            ));
+        }
+    }
+    out.push("}".to_string());
+    out.join("\n") + "\n"
+}
+
+/// Render the dependency graph as a Mermaid flowchart, with the same nodes,
+/// edges, and styling as [`format_graph_dot`].
+fn format_graph_mermaid(items: &[PgItem]) -> String {
+    // Mermaid node IDs can't contain `-`, so IDs are mangled and shown in the label
+    let node = |id: &str| id.replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+    let label = |s: &str| s.replace('"', "#quot;");
+
+    let mut out = vec!["flowchart LR".to_string()];
+    for item in items {
+        out.push(format!(
+            "  {}[\"{}: {}\"]",
+            node(item.id()),
+            item.id(),
+            label(item.title())
+        ));
+    }
+    let archived = archived_dependencies(items);
+    for dep_id in &archived {
+        out.push(format!("  {}[\"{} (archived)\"]", node(dep_id), dep_id));
+    }
+    for item in items {
+        for (dep_id, dep) in scheduler::resolve_dependencies(item, items) {
+            let arrow = if dep.is_some() {
+                "-->"
+            } else {
+                "-.->|archived|"
+            };
+            out.push(format!("  {} {} {}", node(item.id()), arrow, node(dep_id)));
+        }
+    }
+    for item in items {
+        if let Some(color) = graph_status_color(item.pg_status()) {
+            out.push(format!("  style {} fill:{}", node(item.id()), color));
+        }
+    }
+    for dep_id in &archived {
+        out.push(format!("  style {} stroke-dasharray: 5 5", node(dep_id)));
+    }
+    out.join("\n") + "\n"
+}
+
+/// Trims the given dependency IDs and checks their format, rejecting self-references.
+fn parse_dependency_ids(item_id: &str, on: &[String]) -> Result<Vec<String>, String> {
+    let ids: Vec<String> = on.iter().map(|id| id.trim().to_string()).collect();
+    let mut errors = Vec::new();
+    for id in &ids {
+        if !is_valid_item_id(id) {
+            errors.push(format!(
+                "Invalid dependency format '{}': expected <prefix>-<id> (numeric or hex suffix)",
+                id
+            ));
+        } else if id == item_id {
+            errors.push(format!("{} cannot depend on itself", item_id));
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors.join("\n"));
+    }
+    Ok(ids)
+}
+
+fn format_dependency_list(item_id: &str, dependencies: &[String]) -> String {
+    if dependencies.is_empty() {
+        format!("{} has no dependencies", item_id)
+    } else {
+        format!("{} depends on: {}", item_id, dependencies.join(", "))
+    }
+}
+
+fn handle_depend(
+    root: &Path,
+    config_path: Option<&Path>,
+    _config_base: &Path,
+    item_id: &str,
+    on: &[String],
+) -> Result<(), String> {
+    let _config = config::load_config_from(config_path, root)?;
+    let on = parse_dependency_ids(item_id, on)?;
+
+    // Use Store directly with with_lock for single-shot CLI command
+    let tg_store_dir = root.join(".task-golem");
+    let store = Store::new(tg_store_dir);
+
+    store
+        .with_lock(|s| {
+            let mut items = s.load_active()?;
+            let idx = items
+                .iter()
+                .position(|i| i.id == item_id)
+                .ok_or_else(|| task_golem::errors::TgError::ItemNotFound(item_id.to_string()))?;
+
+            let mut errors = Vec::new();
+            for dep_id in &on {
+                match items.iter().find(|i| &i.id == dep_id) {
+                    None => errors.push(format!("Dependency '{}' not found in backlog", dep_id)),
+                    Some(dep) if dep.dependencies.iter().any(|d| d == item_id) => {
+                        errors.push(format!(
+                            "Cannot make {} depend on {}: {} already depends on {}",
+                            item_id, dep_id, dep_id, item_id
+                        ))
+                    }
+                    Some(_) => {}
+                }
+            }
+            if !errors.is_empty() {
+                return Err(task_golem::errors::TgError::InvalidInput(errors.join("\n")));
+            }
+
+            let added = pg_item::add_dependencies(&mut items[idx], &on);
+            if !added.is_empty() {
+                s.save_active(&items)?;
+            }
+            println!(
+                "{}",
+                format_dependency_list(item_id, &items[idx].dependencies)
+            );
+            Ok(())
+        })
+        .map_err(|e| format!("{}", e))
+}
+
+fn handle_undepend(
+    root: &Path,
+    config_path: Option<&Path>,
+    _config_base: &Path,
+    item_id: &str,
+    on: &[String],
+) -> Result<(), String> {
+    let _config = config::load_config_from(config_path, root)?;
+    let on = parse_dependency_ids(item_id, on)?;
+
+    // Use Store directly with with_lock for single-shot CLI command
+    let tg_store_dir = root.join(".task-golem");
+    let store = Store::new(tg_store_dir);
+
+    store
+        .with_lock(|s| {
+            let mut items = s.load_active()?;
+            let idx = items
+                .iter()
+                .position(|i| i.id == item_id)
+                .ok_or_else(|| task_golem::errors::TgError::ItemNotFound(item_id.to_string()))?;
+
+            // Archived dependencies are no longer in the active store, so check
+            // against the item's own list rather than the backlog.
+            let missing: Vec<&str> = on
+                .iter()
+                .filter(|dep_id| !items[idx].dependencies.contains(dep_id))
+                .map(|dep_id| dep_id.as_str())
+                .collect();
+            if !missing.is_empty() {
+                return Err(task_golem::errors::TgError::InvalidInput(format!(
+                    "{} does not depend on: {}",
+                    item_id,
+                    missing.join(", ")
+                )));
+            }
+
+            pg_item::remove_dependencies(&mut items[idx], &on);
+            s.save_active(&items)?;
+            println!(
+                "{}",
+                format_dependency_list(item_id, &items[idx].dependencies)
+            );
+            Ok(())
+        })
+        .map_err(|e| format!("{}", e))
+}
+
+fn handle_block(
+    root: &Path,
+    config_path: Option<&Path>,
+    _config_base: &Path,
+    item_id: &str,
+    reason: &str,
+) -> Result<(), String> {
+    let _config = config::load_config_from(config_path, root)?;
+
+    if reason.trim().is_empty() {
+        return Err("Block reason must not be empty".to_string());
+    }
+
+    // Use Store directly with with_lock for single-shot CLI command
+    let tg_store_dir = root.join(".task-golem");
+    let store = Store::new(tg_store_dir);
+
+    store
+        .with_lock(|s| {
+            let mut items = s.load_active()?;
+            let idx = items
+                .iter()
+                .position(|i| i.id == item_id)
+                .ok_or_else(|| task_golem::errors::TgError::ItemNotFound(item_id.to_string()))?;
+
+            let pg = PgItem(items[idx].clone());
+            let current = pg.pg_status();
+            match current {
+                ItemStatus::Done => {
+                    return Err(task_golem::errors::TgError::InvalidInput(format!(
+                        "Cannot block {}: item is already Done",
+                        item_id
+                    )));
+                }
+                ItemStatus::Blocked => {
+                    return Err(task_golem::errors::TgError::InvalidInput(format!(
+                        "Cannot block {}: item is already Blocked (reason: {})",
+                        item_id,
+                        pg.0.blocked_reason.as_deref().unwrap_or("none")
+                    )));
+                }
+                _ => {}
+            }
+
+            // Records blocked_from_status so `unblock` restores the prior status
+            pg_item::apply_update(&mut items[idx], ItemUpdate::SetBlocked(reason.to_string()));
+
+            s.save_active(&items)?;
+            println!("Blocked {} (was {:?}): {}", item_id, current, reason);
+            Ok(())
+        })
+        .map_err(|e| format!("{}", e))
+}
+
+/// One `ItemUpdate` per dimension given to `assess`, in display order.
+fn assessment_updates(
+    impact: Option<DimensionLevel>,
+    size: Option<SizeLevel>,
+    risk: Option<DimensionLevel>,
+    complexity: Option<DimensionLevel>,
+) -> Vec<ItemUpdate> {
+    let mut updates = Vec::new();
+    if let Some(level) = impact {
+        updates.push(ItemUpdate::SetImpact(level));
+    }
+    if let Some(level) = size {
+        updates.push(ItemUpdate::SetSize(level));
+    }
+    if let Some(level) = risk {
+        updates.push(ItemUpdate::SetRisk(level));
+    }
+    if let Some(level) = complexity {
+        updates.push(ItemUpdate::SetComplexity(level));
+    }
+    updates
+}
+
+/// Describe an assessment update as `dimension=level`.
+fn describe_assessment_update(update: &ItemUpdate) -> String {
+    let (dimension, level) = match update {
+        ItemUpdate::SetImpact(level) => ("impact", format!("{:?}", level)),
+        ItemUpdate::SetSize(size) => ("size", format!("{:?}", size)),
+        ItemUpdate::SetRisk(level) => ("risk", format!("{:?}", level)),
+        ItemUpdate::SetComplexity(level) => ("complexity", format!("{:?}", level)),
+        other => return format!("{:?}", other),
+    };
+    format!("{}={}", dimension, level.to_lowercase())
+}
+
+fn handle_assess(
+    root: &Path,
+    config_path: Option<&Path>,
+    _config_base: &Path,
+    item_id: &str,
+    updates: Vec<ItemUpdate>,
+) -> Result<(), String> {
+    let _config = config::load_config_from(config_path, root)?;
+
+    // Use Store directly with with_lock for single-shot CLI command
+    let tg_store_dir = root.join(".task-golem");
+    let store = Store::new(tg_store_dir);
+
+    store
+        .with_lock(|s| {
+            let mut items = s.load_active()?;
+            let idx = items
+                .iter()
+                .position(|i| i.id == item_id)
+                .ok_or_else(|| task_golem::errors::TgError::ItemNotFound(item_id.to_string()))?;
+
+            // Manual override: guardrails only apply when triage routes an item
+            let described: Vec<String> = updates.iter().map(describe_assessment_update).collect();
+            for update in updates {
+                pg_item::apply_update(&mut items[idx], update);
+            }
+
+            s.save_active(&items)?;
+            println!("Assessed {}: {}", item_id, described.join(", "));
+            Ok(())
+        })
+        .map_err(|e| format!("{}", e))
+}
+
+fn handle_unblock(
+    root: &Path,
+    config_path: Option<&Path>,
+    _config_base: &Path,
+    item_id: &str,
+    notes: Option<String>,
+    retry: bool,
+) -> Result<(), String> {
+    let config = config::load_config_from(config_path, root)?;
+
+    // Use Store directly with with_lock for single-shot CLI command
+    let tg_store_dir = root.join(".task-golem");
+    let store = Store::new(tg_store_dir);
+
+    store
+        .with_lock(|s| {
+            let mut items = s.load_active()?;
+            let idx = items
+                .iter()
+                .position(|i| i.id == item_id)
+                .ok_or_else(|| task_golem::errors::TgError::ItemNotFound(item_id.to_string()))?;
+
+            let pg = PgItem(items[idx].clone());
+            if pg.pg_status() != ItemStatus::Blocked {
+                return Err(task_golem::errors::TgError::InvalidInput(format!(
+                    "Cannot unblock {}: status is {:?}, expected Blocked",
+                    item_id,
+                    pg.pg_status()
+                )));
+            }
+
+            // Read the blocked_from_status before clearing
+            let restore_to = pg.pg_blocked_from_status().unwrap_or(ItemStatus::New);
+
+            // Resolve the phase to re-run before changing anything
+            let retry_at = if retry {
+                let pipeline_type = pg.pipeline_type().unwrap_or_else(|| "feature".to_string());
+                let pipeline = config.pipelines.get(&pipeline_type).ok_or_else(|| {
+                    task_golem::errors::TgError::InvalidInput(format!(
+                        "Pipeline type '{}' not found in config",
+                        pipeline_type
+                    ))
+                })?;
+                Some(
+                    retry_position(item_id, &restore_to, pg.phase().as_deref(), pipeline)
+                        .map_err(task_golem::errors::TgError::InvalidInput)?,
+                )
+            } else {
+                None
+            };
+
+            // Clear all blocked fields (extension and native) via apply_update(Unblock)
+            pg_item::apply_update(&mut items[idx], ItemUpdate::Unblock);
+
+            // Set unblock_context if notes provided
+            if let Some(notes_text) = notes {
+                pg_item::set_unblock_context(&mut items[idx], Some(&notes_text));
+            }
+
+            // Reset last_phase_commit for staleness-blocked items
+            pg_item::set_last_phase_commit(&mut items[idx], None);
+
+            if let Some((ref phase, ref pool)) = retry_at {
+                // Keep the item at its phase, with a pool that matches it, and make
+                // sure `run --resume` doesn't skip the phase being retried
+                pg_item::set_phase(&mut items[idx], Some(phase));
+                pg_item::set_phase_pool(&mut items[idx], Some(pool));
+                let completed: Vec<String> = pg
+                    .completed_phases()
+                    .into_iter()
+                    .filter(|p| p != phase)
+                    .collect();
+                pg_item::set_completed_phases(&mut items[idx], &completed);
+            }
+
+            s.save_active(&items)?;
+            println!("Unblocked {} -- restored to {:?}", item_id, restore_to);
+            if let Some((phase, _)) = retry_at {
+                println!("Next run re-executes phase '{}'", phase);
+            }
+            Ok(())
+        })
+        .map_err(|e| format!("{}", e))
+}
+
+// --- Display helpers ---
+
+/// Build the `status --json` document. Enum fields use the same lowercased
+/// strings as the table; absent values are `null`.
+fn status_json(items: &[&PgItem]) -> serde_json::Value {
+    let lowercase_debug = |v: &dyn std::fmt::Debug| format!("{:?}", v).to_lowercase();
+    serde_json::Value::Array(
+        items
+            .iter()
+            .map(|item| {
+                serde_json::json!({
+                    "id": item.id(),
+                    "status": lowercase_debug(&item.pg_status()),
+                    "phase": item.phase(),
+                    "pipeline": item.pipeline_type(),
+                    "impact": item.impact().map(|v| lowercase_debug(&v)),
+                    "size": item.size().map(|v| lowercase_debug(&v)),
+                    "risk": item.risk().map(|v| lowercase_debug(&v)),
+                    "title": item.title(),
+                    "dependencies": item.dependencies(),
+                })
+            })
+            .collect(),
+    )
+}
+
 fn display_optional_dimension(opt: Option<DimensionLevel>) -> String {
     opt.map(|v| format!("{:?}", v).to_lowercase())
         .unwrap_or_else(|| "-".to_string())
@@ -1198,6 +3615,27 @@ mod tests {
         assert!(is_valid_item_id("OTHER-001"));
     }
 
+    #[test]
+    fn retain_prefix_matches_whole_prefix() {
+        let mut items: Vec<PgItem> = ["WRK-001", "DOC-002", "DOCS-003", "tg-a1b2c"]
+            .iter()
+            .map(|id| {
+                pg_item::new_from_parts(
+                    id.to_string(),
+                    "Item".to_string(),
+                    ItemStatus::New,
+                    vec![],
+                    vec![],
+                )
+            })
+            .collect();
+
+        retain_prefix(&mut items, "DOC");
+
+        let ids: Vec<&str> = items.iter().map(|i| i.id()).collect();
+        assert_eq!(ids, vec!["DOC-002"]);
+    }
+
     #[test]
     fn is_valid_item_id_rejects_invalid() {
         assert!(!is_valid_item_id("WRK-"));
@@ -1206,6 +3644,283 @@ mod tests {
         assert!(!is_valid_item_id("WRK-g1h2")); // 'g' and 'h' are not hex
     }
 
+    #[test]
+    fn parse_deadline_accepts_iso_dates_only() {
+        assert_eq!(
+            parse_deadline("2026-03-10"),
+            Ok(chrono::NaiveDate::from_ymd_opt(2026, 3, 10).unwrap())
+        );
+        assert!(parse_deadline("03/10/2026").is_err());
+        assert!(parse_deadline("2026-02-30").is_err());
+    }
+
+    #[test]
+    fn retriage_target_error_refuses_missing_and_done_items() {
+        let items = vec![
+            pg_item::new_from_parts(
+                "WRK-001".to_string(),
+                "Shipped".to_string(),
+                ItemStatus::Done,
+                vec![],
+                vec![],
+            ),
+            pg_item::new_from_parts(
+                "WRK-002".to_string(),
+                "Underway".to_string(),
+                ItemStatus::InProgress,
+                vec![],
+                vec![],
+            ),
+        ];
+
+        let done = retriage_target_error(&items, "WRK-001").unwrap();
+        assert!(done.contains("is done"), "got: {}", done);
+        assert_eq!(
+            retriage_target_error(&items, "WRK-009"),
+            Some("Item WRK-009 not found".to_string())
+        );
+        assert_eq!(retriage_target_error(&items, "WRK-002"), None);
+    }
+
+    #[test]
+    fn parse_dependency_ids_trims_and_validates() {
+        let ids = parse_dependency_ids("WRK-001", &[" WRK-002 ".to_string()]).unwrap();
+        assert_eq!(ids, vec!["WRK-002"]);
+
+        let err = parse_dependency_ids("WRK-001", &["WRK-001".to_string(), "bogus".to_string()])
+            .unwrap_err();
+        assert!(err.contains("WRK-001 cannot depend on itself"));
+        assert!(err.contains("Invalid dependency format 'bogus'"));
+    }
+
+    #[test]
+    fn agent_override_is_the_cli_verified_and_used() {
+        let mut config = config::PhaseGolemConfig::default();
+        assert_eq!(config.agent.cli, config::CliTool::Claude);
+
+        config::apply_agent_overrides(
+            &mut config,
+            Some(config::CliTool::Gemini),
+            Some("gemini-2.5-pro".to_string()),
+        )
+        .unwrap();
+
+        // verified_runner builds its runner the same way before checking the CLI
+        let runner = CliAgentRunner::from_config(&config.agent);
+        assert_eq!(runner.tool, config::CliTool::Gemini);
+        assert_eq!(runner.model.as_deref(), Some("gemini-2.5-pro"));
+        assert_eq!(config.agent.cli.display_name(), "Gemini CLI");
+    }
+
+    #[test]
+    fn rewind_target_defaults_to_previous_phase() {
+        let phases = ["prd", "spec", "build", "review"];
+        assert_eq!(
+            rewind_target("WRK-001", &phases, Some("build"), None),
+            Ok(1)
+        );
+    }
+
+    #[test]
+    fn rewind_target_accepts_earlier_named_phase() {
+        let phases = ["prd", "spec", "build", "review"];
+        assert_eq!(
+            rewind_target("WRK-001", &phases, Some("review"), Some("prd")),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn rewind_target_rejects_first_phase_and_later_targets() {
+        let phases = ["prd", "spec", "build", "review"];
+
+        let err = rewind_target("WRK-001", &phases, Some("prd"), None).unwrap_err();
+        assert!(err.contains("'prd' is the first phase"), "{}", err);
+
+        let err = rewind_target("WRK-001", &phases, Some("spec"), Some("build")).unwrap_err();
+        assert!(
+            err.contains("not before the current phase 'spec'"),
+            "{}",
+            err
+        );
+
+        let err = rewind_target("WRK-001", &phases, Some("spec"), Some("spec")).unwrap_err();
+        assert!(err.contains("not before"), "{}", err);
+
+        let err = rewind_target("WRK-001", &phases, Some("build"), Some("nope")).unwrap_err();
+        assert!(err.contains("Invalid phase 'nope'"), "{}", err);
+
+        let err = rewind_target("WRK-001", &phases, None, None).unwrap_err();
+        assert!(err.contains("no current phase"), "{}", err);
+    }
+
+    #[test]
+    fn format_item_detail_shows_blocked_item_fields() {
+        let mut item = pg_item::new_from_parts(
+            "WRK-003".to_string(),
+            "Rework billing".to_string(),
+            ItemStatus::InProgress,
+            vec!["WRK-001".to_string()],
+            vec!["billing".to_string()],
+        );
+        pg_item::set_phase(&mut item.0, Some("design"));
+        pg_item::set_phase_pool(&mut item.0, Some(&PhasePool::Main));
+        pg_item::set_pipeline_type(&mut item.0, Some("feature"));
+        pg_item::set_size(&mut item.0, Some(&phase_golem::types::SizeLevel::Large));
+        pg_item::set_risk(&mut item.0, Some(&DimensionLevel::High));
+        pg_item::set_structured_description(
+            &mut item.0,
+            Some(&phase_golem::types::StructuredDescription {
+                problem: "Invoices round wrong".to_string(),
+                ..Default::default()
+            }),
+        );
+        pg_item::apply_update(
+            &mut item.0,
+            ItemUpdate::SetBlocked("Needs a pricing decision".to_string()),
+        );
+
+        let lines = format_item_detail(&item);
+
+        assert_eq!(lines[0], "WRK-003 Rework billing");
+        let has = |needle: &str| lines.iter().any(|l| l.contains(needle));
+        assert!(has("Status:       blocked"));
+        assert!(has("Phase:        design"));
+        assert!(has("Pool:         phases"));
+        assert!(has("Size: large  Complexity: -  Risk: high  Impact: -"));
+        assert!(has("Depends on:   WRK-001"));
+        assert!(has("Tags:         billing"));
+        assert!(has("Blocked:      Needs a pricing decision"));
+        assert!(has("Blocked from: inprogress"));
+        assert!(has("Problem: Invoices round wrong"));
+        assert!(!has("Solution:"));
+        assert!(!has("Phase history:"));
+    }
+
+    #[test]
+    fn format_phase_history_lists_entries_in_order() {
+        let mut item = pg_item::new_from_parts(
+            "WRK-001".to_string(),
+            "Build the thing".to_string(),
+            ItemStatus::InProgress,
+            vec![],
+            vec![],
+        );
+        for (phase, result, commit) in [
+            (
+                "prd",
+                phase_golem::types::ResultCode::PhaseComplete,
+                Some("0123456789abcdef"),
+            ),
+            ("build", phase_golem::types::ResultCode::Failed, None),
+        ] {
+            pg_item::append_phase_history(
+                &mut item.0,
+                &phase_golem::types::PhaseHistoryEntry {
+                    phase: phase.to_string(),
+                    result,
+                    completed_at: chrono::Utc::now(),
+                    commit: commit.map(str::to_string),
+                },
+            );
+        }
+
+        let out = format_phase_history(&item);
+        let lines: Vec<&str> = out.lines().collect();
+
+        assert_eq!(lines[0], "WRK-001 Build the thing");
+        assert!(lines[1].contains("prd") && lines[1].contains("PhaseComplete"));
+        assert!(lines[1].ends_with("0123456"), "{}", lines[1]);
+        assert!(lines[2].contains("build") && lines[2].contains("Failed"));
+        assert!(lines[2].ends_with('-'), "{}", lines[2]);
+    }
+
+    #[test]
+    fn format_phase_history_without_entries() {
+        let item = pg_item::new_from_parts(
+            "WRK-002".to_string(),
+            "Untouched".to_string(),
+            ItemStatus::New,
+            vec![],
+            vec![],
+        );
+
+        assert_eq!(
+            format_phase_history(&item),
+            "WRK-002 Untouched\n  No phases recorded.\n"
+        );
+    }
+
+    #[test]
+    fn format_guardrail_check_lists_only_violating_items() {
+        let mut risky = pg_item::new_from_parts(
+            "WRK-001".to_string(),
+            "Risky".to_string(),
+            ItemStatus::Ready,
+            vec![],
+            vec![],
+        );
+        pg_item::set_risk(&mut risky.0, Some(&DimensionLevel::High));
+        pg_item::set_size(&mut risky.0, Some(&SizeLevel::Large));
+        let mut safe = pg_item::new_from_parts(
+            "WRK-002".to_string(),
+            "Safe".to_string(),
+            ItemStatus::Ready,
+            vec![],
+            vec![],
+        );
+        pg_item::set_risk(&mut safe.0, Some(&DimensionLevel::Low));
+
+        let lines = format_guardrail_check(&[risky, safe], &config::GuardrailsConfig::default());
+
+        assert_eq!(
+            lines,
+            vec![
+                "WRK-001 Risky: size large > medium, risk high > low".to_string(),
+                "1 of 2 item(s) exceed guardrails (max_size=medium, max_complexity=medium, max_risk=low)"
+                    .to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn retry_position_keeps_phase_with_matching_pool() {
+        let pipeline = config::default_feature_pipeline();
+        let main = pipeline.phases[1].name.clone();
+        let pre = pipeline.pre_phases[0].name.clone();
+
+        assert_eq!(
+            retry_position("WRK-001", &ItemStatus::InProgress, Some(&main), &pipeline),
+            Ok((main, PhasePool::Main))
+        );
+        assert_eq!(
+            retry_position("WRK-001", &ItemStatus::InProgress, Some(&pre), &pipeline),
+            Ok((pre, PhasePool::Pre))
+        );
+    }
+
+    #[test]
+    fn retry_position_requires_in_progress_item_with_known_phase() {
+        let pipeline = config::default_feature_pipeline();
+
+        let err =
+            retry_position("WRK-001", &ItemStatus::Ready, Some("build"), &pipeline).unwrap_err();
+        assert!(err.contains("expected InProgress"), "{}", err);
+
+        let err = retry_position("WRK-001", &ItemStatus::InProgress, None, &pipeline).unwrap_err();
+        assert!(err.contains("no current phase"), "{}", err);
+
+        let err = retry_position("WRK-001", &ItemStatus::InProgress, Some("nope"), &pipeline)
+            .unwrap_err();
+        assert!(err.contains("'nope' not found"), "{}", err);
+    }
+
+    #[test]
+    fn status_footer_reports_filtered_count() {
+        assert_eq!(status_footer(3, 3, false), "3 item(s) total");
+        assert_eq!(status_footer(2, 7, true), "2 of 7 items shown");
+    }
+
     #[tokio::test]
     async fn cleanup_deletes_matching_files() {
         let dir = tempfile::tempdir().unwrap();
@@ -1287,4 +4002,798 @@ mod tests {
         // Directory should still exist (remove_file can't delete directories)
         assert!(dir.path().join("phase_result_WRK-003_test.json").exists());
     }
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .expect("Failed to run git");
+        assert!(output.status.success(), "git {:?} failed", args);
+    }
+
+    /// A repo with one commit whose `origin` points at `remote`.
+    fn repo_with_origin(remote: &Path) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        git(dir.path(), &["init"]);
+        git(dir.path(), &["config", "user.email", "test@test.com"]);
+        git(dir.path(), &["config", "user.name", "Test"]);
+        std_fs::write(dir.path().join("README.md"), "# Test\n").unwrap();
+        git(dir.path(), &["add", "."]);
+        git(dir.path(), &["commit", "-m", "Initial commit"]);
+        git(
+            dir.path(),
+            &["remote", "add", "origin", remote.to_str().unwrap()],
+        );
+        dir
+    }
+
+    fn push_config(branch: Option<&str>, require_push: bool) -> config::GitConfig {
+        config::GitConfig {
+            push_on_complete: true,
+            branch: branch.map(str::to_string),
+            require_push,
+            ..config::GitConfig::default()
+        }
+    }
+
+    #[test]
+    fn push_after_run_pushes_configured_branch() {
+        let remote = tempfile::tempdir().unwrap();
+        git(remote.path(), &["init", "--bare"]);
+        let repo = repo_with_origin(remote.path());
+        git(repo.path(), &["branch", "-M", "work"]);
+
+        let result = push_after_run(
+            &push_config(Some("work"), true),
+            &scheduler::HaltReason::AllDone,
+            repo.path(),
+        );
+        assert!(result.is_ok(), "Expected Ok, got: {:?}", result);
+
+        let output = std::process::Command::new("git")
+            .args(["rev-parse", "--verify", "refs/heads/work"])
+            .current_dir(remote.path())
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+    }
+
+    #[test]
+    fn push_after_run_failure_only_warns_without_require_push() {
+        let missing = tempfile::tempdir().unwrap();
+        let repo = repo_with_origin(&missing.path().join("no-such-remote.git"));
+
+        let result = push_after_run(
+            &push_config(None, false),
+            &scheduler::HaltReason::AllDone,
+            repo.path(),
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn push_after_run_failure_surfaces_with_require_push() {
+        let missing = tempfile::tempdir().unwrap();
+        let repo = repo_with_origin(&missing.path().join("no-such-remote.git"));
+
+        let result = push_after_run(
+            &push_config(None, true),
+            &scheduler::HaltReason::AllDone,
+            repo.path(),
+        );
+        let err = result.unwrap_err();
+        assert!(err.contains("git push to 'origin' failed"), "got: {}", err);
+    }
+
+    #[test]
+    fn push_after_run_skipped_when_disabled_or_interrupted() {
+        let missing = tempfile::tempdir().unwrap();
+        let repo = repo_with_origin(&missing.path().join("no-such-remote.git"));
+
+        let disabled = config::GitConfig {
+            require_push: true,
+            ..config::GitConfig::default()
+        };
+        assert!(push_after_run(&disabled, &scheduler::HaltReason::AllDone, repo.path()).is_ok());
+        assert!(push_after_run(
+            &push_config(None, true),
+            &scheduler::HaltReason::ShutdownRequested,
+            repo.path()
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn write_summary_json_emits_exactly_one_object() {
+        let summary = scheduler::RunSummary {
+            phases_executed: 3,
+            items_completed: vec!["WRK-001".to_string()],
+            items_blocked: vec!["WRK-002".to_string()],
+            items_failed_validation: vec![],
+            follow_ups_created: 1,
+            follow_ups_suppressed: 0,
+            items_merged: 0,
+            merges: Vec::new(),
+            halt_reason: scheduler::HaltReason::Mixed,
+            planned_phases: vec![],
+        };
+
+        let mut out = Vec::new();
+        write_summary_json(
+            &mut out,
+            &summary,
+            false,
+            std::time::Duration::from_secs(42),
+            &[],
+        )
+        .unwrap();
+        let stdout = String::from_utf8(out).unwrap();
+
+        let mut values =
+            serde_json::Deserializer::from_str(&stdout).into_iter::<serde_json::Value>();
+        let value = values.next().unwrap().unwrap();
+        assert!(values.next().is_none(), "expected exactly one JSON value");
+        assert_eq!(stdout.lines().count(), 1);
+
+        assert_eq!(value["phases_executed"], 3);
+        assert_eq!(value["items_completed"], serde_json::json!(["WRK-001"]));
+        assert_eq!(value["items_blocked"], serde_json::json!(["WRK-002"]));
+        assert_eq!(value["halt_reason"], "Mixed");
+        assert_eq!(value["elapsed_seconds"], 42);
+        assert!(value.get("planned_phases").is_none());
+    }
+
+    #[test]
+    fn write_summary_json_masks_redact_keys() {
+        let summary = scheduler::RunSummary {
+            phases_executed: 1,
+            items_completed: vec!["WRK-001".to_string()],
+            items_blocked: vec![],
+            items_failed_validation: vec![],
+            follow_ups_created: 0,
+            follow_ups_suppressed: 0,
+            items_merged: 0,
+            merges: Vec::new(),
+            halt_reason: scheduler::HaltReason::AllDone,
+            planned_phases: vec![],
+        };
+
+        let mut out = Vec::new();
+        write_summary_json(
+            &mut out,
+            &summary,
+            false,
+            std::time::Duration::from_secs(1),
+            &["Halt_Reason".to_string()],
+        )
+        .unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&out).unwrap();
+
+        assert_eq!(value["halt_reason"], redact::REDACTED);
+        assert_eq!(value["items_completed"], serde_json::json!(["WRK-001"]));
+        assert_eq!(value["phases_executed"], 1);
+    }
+
+    #[test]
+    fn status_json_uses_table_strings_and_nulls() {
+        let mut in_progress = pg_item::new_from_parts(
+            "WRK-001".to_string(),
+            "Build the thing".to_string(),
+            ItemStatus::InProgress,
+            vec!["WRK-000".to_string()],
+            vec![],
+        );
+        pg_item::set_phase(&mut in_progress.0, Some("build"));
+        pg_item::set_impact(&mut in_progress.0, Some(&DimensionLevel::High));
+        let new_item = pg_item::new_from_parts(
+            "WRK-002".to_string(),
+            "Untriaged".to_string(),
+            ItemStatus::New,
+            vec![],
+            vec![],
+        );
+
+        let value = status_json(&[&in_progress, &new_item]);
+
+        assert_eq!(
+            value,
+            serde_json::json!([
+                {
+                    "id": "WRK-001",
+                    "status": "inprogress",
+                    "phase": "build",
+                    "pipeline": null,
+                    "impact": "high",
+                    "size": null,
+                    "risk": null,
+                    "title": "Build the thing",
+                    "dependencies": ["WRK-000"],
+                },
+                {
+                    "id": "WRK-002",
+                    "status": "new",
+                    "phase": null,
+                    "pipeline": null,
+                    "impact": null,
+                    "size": null,
+                    "risk": null,
+                    "title": "Untriaged",
+                    "dependencies": [],
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn format_triage_preview_shows_phase_and_pool() {
+        let mut item = pg_item::new_from_parts(
+            "WRK-001".to_string(),
+            "Big feature".to_string(),
+            ItemStatus::Scoping,
+            vec![],
+            vec![],
+        );
+        pg_item::set_phase(&mut item.0, Some("research"));
+        pg_item::set_phase_pool(&mut item.0, Some(&PhasePool::Pre));
+        pg_item::set_size(&mut item.0, Some(&SizeLevel::Large));
+
+        assert_eq!(
+            format_triage_preview(&item),
+            vec![
+                "WRK-001 would route to scoping",
+                "  Phase:    research (pre_phases)",
+                "  Pipeline: feature",
+                "  Size: large  Risk: -  Impact: -",
+            ]
+        );
+    }
+
+    #[test]
+    fn config_load_problems_splits_validation_list() {
+        let error = "Config validation failed:\n  - execution.max_wip must be >= 1\n  - project.prefix must not be empty";
+        assert_eq!(
+            config_load_problems(error),
+            vec![
+                "execution.max_wip must be >= 1",
+                "project.prefix must not be empty",
+            ]
+        );
+        assert_eq!(
+            config_load_problems("Failed to parse phase-golem.toml: bad"),
+            vec!["Failed to parse phase-golem.toml: bad"]
+        );
+    }
+
+    #[test]
+    fn format_config_check_numbers_problems() {
+        assert_eq!(format_config_check(&[]), vec!["Config OK"]);
+        assert_eq!(
+            format_config_check(&["first".to_string(), "second".to_string()]),
+            vec!["Config has 2 problem(s):", "  1. first", "  2. second"]
+        );
+    }
+
+    #[test]
+    fn assessment_updates_only_include_given_dimensions() {
+        let updates = assessment_updates(
+            Some(DimensionLevel::High),
+            None,
+            Some(DimensionLevel::Low),
+            None,
+        );
+
+        assert_eq!(
+            updates,
+            vec![
+                ItemUpdate::SetImpact(DimensionLevel::High),
+                ItemUpdate::SetRisk(DimensionLevel::Low),
+            ]
+        );
+        assert_eq!(
+            updates
+                .iter()
+                .map(describe_assessment_update)
+                .collect::<Vec<_>>(),
+            vec!["impact=high", "risk=low"]
+        );
+    }
+
+    #[test]
+    fn format_promotable_lists_items_in_given_order() {
+        let mut first = pg_item::new_from_parts(
+            "WRK-002".to_string(),
+            "Second created".to_string(),
+            ItemStatus::Ready,
+            vec![],
+            vec![],
+        );
+        pg_item::set_impact(&mut first.0, Some(&DimensionLevel::High));
+        let second = pg_item::new_from_parts(
+            "WRK-001".to_string(),
+            "First created".to_string(),
+            ItemStatus::Ready,
+            vec![],
+            vec![],
+        );
+        let items = vec![second, first];
+
+        assert_eq!(
+            format_promotable(&["WRK-002".to_string(), "WRK-001".to_string()], &items, 2),
+            vec![
+                "WRK-002  impact=high  Second created",
+                "WRK-001  impact=-  First created",
+            ]
+        );
+    }
+
+    #[test]
+    fn format_promotable_explains_empty_result() {
+        let items = vec![pg_item::new_from_parts(
+            "WRK-001".to_string(),
+            "Busy".to_string(),
+            ItemStatus::InProgress,
+            vec![],
+            vec![],
+        )];
+
+        assert_eq!(
+            format_promotable(&[], &items, 1),
+            vec!["No items eligible for promotion (1 in progress, max_wip 1)."]
+        );
+    }
+
+    #[test]
+    fn validate_cap_rejects_zero() {
+        let err = validate_cap(0).unwrap_err();
+        assert!(err.contains("cap must be at least 1"));
+        assert!(err.contains("--dry-run"));
+    }
+
+    #[test]
+    fn validate_cap_accepts_positive_caps() {
+        assert!(validate_cap(1).is_ok());
+        assert!(validate_cap(100).is_ok());
+    }
+
+    #[test]
+    fn format_diff_summary_lists_files_with_totals() {
+        let stats = vec![
+            phase_golem::git::FileDiffStat {
+                path: "src/lib.rs".to_string(),
+                added: Some(10),
+                removed: Some(2),
+            },
+            phase_golem::git::FileDiffStat {
+                path: "logo.png".to_string(),
+                added: None,
+                removed: None,
+            },
+        ];
+
+        assert_eq!(
+            format_diff_summary("0123456789abcdef", &stats),
+            vec![
+                "Changes since 0123456: 2 file(s), +10 -2",
+                "  +10 -2       src/lib.rs",
+                "  binary       logo.png",
+            ]
+        );
+    }
+
+    fn graph_items() -> Vec<PgItem> {
+        vec![
+            pg_item::new_from_parts(
+                "WRK-001".to_string(),
+                "Add \"login\"".to_string(),
+                ItemStatus::Done,
+                vec![],
+                vec![],
+            ),
+            pg_item::new_from_parts(
+                "WRK-002".to_string(),
+                "Rework billing".to_string(),
+                ItemStatus::Blocked,
+                vec!["WRK-001".to_string(), "WRK-000".to_string()],
+                vec![],
+            ),
+        ]
+    }
+
+    #[test]
+    fn format_graph_dot_styles_by_status_and_dashes_archived_deps() {
+        let dot = format_graph_dot(&graph_items());
+
+        assert!(dot.starts_with("digraph dependencies {\n"));
+        assert!(dot.contains(
+            r##"  "WRK-001" [label="WRK-001\nAdd \"login\"", style=filled, fillcolor="#b7e4c7"];"##
+        ));
+        assert!(dot.contains(r##"fillcolor="#f4a6a6""##));
+        assert!(dot.contains(r#"  "WRK-002" -> "WRK-001";"#));
+        assert!(dot.contains(r#"  "WRK-000" [label="WRK-000 (archived)", style=dashed];"#));
+        assert!(dot.contains(r#"  "WRK-002" -> "WRK-000" [style=dashed, label="archived"];"#));
+        assert!(dot.ends_with("}\n"));
+    }
+
+    #[test]
+    fn format_graph_mermaid_mangles_ids_and_keeps_archived_edges() {
+        let mermaid = format_graph_mermaid(&graph_items());
+
+        let lines: Vec<&str> = mermaid.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "flowchart LR",
+                r#"  WRK_001["WRK-001: Add #quot;login#quot;"]"#,
+                r#"  WRK_002["WRK-002: Rework billing"]"#,
+                r#"  WRK_000["WRK-000 (archived)"]"#,
+                "  WRK_002 --> WRK_001",
+                "  WRK_002 -.->|archived| WRK_000",
+                "  style WRK_001 fill:#b7e4c7",
+                "  style WRK_002 fill:#f4a6a6",
+                "  style WRK_000 stroke-dasharray: 5 5",
+            ]
+        );
+    }
+
+    #[test]
+    fn build_dump_prompt_uses_item_pipeline_and_rejects_unknown_phase() {
+        let mut config = config::PhaseGolemConfig::default();
+        config
+            .pipelines
+            .insert("feature".to_string(), config::default_feature_pipeline());
+        let mut item = pg_item::new_from_parts(
+            "WRK-001".to_string(),
+            "Add widgets".to_string(),
+            ItemStatus::InProgress,
+            vec![],
+            vec![],
+        );
+        pg_item::set_phase(&mut item.0, Some("design"));
+        let items = vec![item];
+        let root = Path::new("/repo");
+
+        let design = build_dump_prompt(&config, &items, root, root, "WRK-001", None).unwrap();
+        assert!(design.contains("WRK-001"));
+        assert!(design.contains("phase_result_WRK-001_design.json"));
+        assert!(design.contains("changes/WRK-001_add-widgets"));
+
+        let triage =
+            build_dump_prompt(&config, &items, root, root, "WRK-001", Some("triage")).unwrap();
+        assert!(triage.contains("phase_result_WRK-001_triage.json"));
+
+        let err =
+            build_dump_prompt(&config, &items, root, root, "WRK-001", Some("deploy")).unwrap_err();
+        assert!(err.contains("Invalid phase 'deploy' for pipeline 'feature'"));
+        assert!(err.contains("research, prd"));
+
+        let err = build_dump_prompt(&config, &items, root, root, "WRK-999", None).unwrap_err();
+        assert_eq!(err, "Item WRK-999 not found");
+    }
+
+    #[test]
+    fn format_pipeline_list_and_description() {
+        let mut pipelines = HashMap::new();
+        pipelines.insert(
+            "feature".to_string(),
+            config::PipelineConfig {
+                pre_phases: vec![config::PhaseConfig::new("research", false)],
+                phases: vec![
+                    config::PhaseConfig {
+                        workflows: vec!["wf/build.md".to_string()],
+                        timeout_minutes: Some(120),
+                        ..config::PhaseConfig::new("build", true)
+                    },
+                    config::PhaseConfig::new("review", false),
+                ],
+                max_wip: None,
+                max_concurrent: None,
+            },
+        );
+        pipelines.insert(
+            "docs".to_string(),
+            config::PipelineConfig {
+                pre_phases: vec![],
+                phases: vec![config::PhaseConfig::new("draft", false)],
+                max_wip: None,
+                max_concurrent: None,
+            },
+        );
+
+        assert_eq!(
+            format_pipeline_list(&pipelines),
+            vec![
+                "docs: pre: [] -> main: [draft]",
+                "feature: pre: [research] -> main: [build -> review]",
+            ]
+        );
+
+        let execution = config::ExecutionConfig::default();
+        let lines = format_pipeline_description("feature", &pipelines["feature"], &execution);
+        assert_eq!(lines[0], "Pipeline: feature");
+        let build = lines.iter().position(|l| l == "build (main)").unwrap();
+        assert_eq!(
+            lines[build + 1..build + 5],
+            [
+                "  Workflows:",
+                "    wf/build.md",
+                "  Destructive: yes",
+                "  Timeout: 120m (phase override)",
+            ]
+        );
+        let research = lines.iter().position(|l| l == "research (pre)").unwrap();
+        assert_eq!(
+            lines[research + 3],
+            format!(
+                "  Timeout: {}m (execution.phase_timeout_minutes)",
+                execution.phase_timeout_minutes
+            )
+        );
+    }
+
+    #[test]
+    fn format_diff_summary_reports_no_changes() {
+        assert_eq!(
+            format_diff_summary("0123456789abcdef", &[]),
+            vec!["Changes since 0123456: none"]
+        );
+    }
+
+    #[test]
+    fn format_metrics_prom_emits_counts_and_halt_reason() {
+        let summary = scheduler::RunSummary {
+            phases_executed: 4,
+            items_completed: vec!["WRK-001".to_string(), "WRK-003".to_string()],
+            items_blocked: vec!["WRK-002".to_string()],
+            items_failed_validation: vec![],
+            follow_ups_created: 2,
+            follow_ups_suppressed: 3,
+            items_merged: 1,
+            merges: vec![("WRK-009".to_string(), "WRK-002".to_string())],
+            halt_reason: scheduler::HaltReason::CapReached,
+            planned_phases: vec![],
+        };
+
+        let metrics = format_metrics_prom(&summary);
+
+        let samples: Vec<&str> = metrics
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .collect();
+        assert_eq!(
+            samples,
+            vec![
+                "phase_golem_phases_executed 4",
+                "phase_golem_items_completed 2",
+                "phase_golem_items_blocked 1",
+                "phase_golem_follow_ups_created 2",
+                "phase_golem_follow_ups_suppressed 3",
+                "phase_golem_items_merged 1",
+                "phase_golem_halt_reason{reason=\"CapReached\"} 1",
+            ]
+        );
+        assert!(metrics.contains("# TYPE phase_golem_phases_executed gauge\n"));
+        assert!(metrics.ends_with('\n'));
+    }
+
+    #[test]
+    fn format_run_report_md_lists_completed_and_blocked_items() {
+        use chrono::TimeZone;
+        let summary = scheduler::RunSummary {
+            phases_executed: 4,
+            items_completed: vec!["WRK-001".to_string()],
+            items_blocked: vec!["WRK-002".to_string()],
+            items_failed_validation: vec![],
+            follow_ups_created: 2,
+            follow_ups_suppressed: 1,
+            items_merged: 1,
+            merges: vec![("WRK-009".to_string(), "WRK-002".to_string())],
+            halt_reason: scheduler::HaltReason::Mixed,
+            planned_phases: vec![],
+        };
+        let done = pg_item::new_from_parts(
+            "WRK-001".to_string(),
+            "Add login".to_string(),
+            ItemStatus::Done,
+            vec![],
+            vec![],
+        );
+        let mut blocked = pg_item::new_from_parts(
+            "WRK-002".to_string(),
+            "Rework billing".to_string(),
+            ItemStatus::InProgress,
+            vec![],
+            vec![],
+        );
+        pg_item::apply_update(
+            &mut blocked.0,
+            ItemUpdate::SetBlocked("Needs a pricing decision".to_string()),
+        );
+        let commits = vec![
+            (
+                "1111111aaaaaaa".to_string(),
+                "[WRK-001][build] Add login form".to_string(),
+            ),
+            (
+                "2222222bbbbbbb".to_string(),
+                "[WRK-003][prd] Unrelated".to_string(),
+            ),
+            (
+                "3333333ccccccc".to_string(),
+                "[WRK-001][review] Address review".to_string(),
+            ),
+        ];
+        let generated_at = chrono::Local
+            .with_ymd_and_hms(2026, 3, 1, 9, 15, 0)
+            .unwrap();
+
+        let report = format_run_report_md(&summary, &[done, blocked], &commits, generated_at, &[]);
+
+        assert!(report.contains("- **Date:** 2026-03-01 09:15:00"));
+        assert!(report.contains("- **Halt reason:** Mixed"));
+        let completed = report
+            .split("## Completed Items")
+            .nth(1)
+            .unwrap()
+            .split("## Blocked Items")
+            .next()
+            .unwrap();
+        assert!(completed.contains("- **WRK-001** Add login (`1111111`, `3333333`)"));
+        assert!(!completed.contains("2222222"));
+        let blocked_section = report
+            .split("## Blocked Items")
+            .nth(1)
+            .unwrap()
+            .split("## Follow-ups and Merges")
+            .next()
+            .unwrap();
+        assert!(blocked_section.contains("- **WRK-002** Rework billing: Needs a pricing decision"));
+        assert!(report.contains("- Follow-ups created: 2"));
+        assert!(report.contains("- Duplicate follow-ups suppressed: 1"));
+        assert!(report.contains("- Items merged: 1"));
+        assert!(report.contains("  - WRK-009 → WRK-002"));
+    }
+
+    #[test]
+    fn format_run_report_md_marks_empty_sections() {
+        let summary = scheduler::RunSummary {
+            phases_executed: 0,
+            items_completed: vec![],
+            items_blocked: vec![],
+            items_failed_validation: vec![],
+            follow_ups_created: 0,
+            follow_ups_suppressed: 0,
+            items_merged: 0,
+            merges: Vec::new(),
+            halt_reason: scheduler::HaltReason::AllDone,
+            planned_phases: vec![],
+        };
+
+        let report = format_run_report_md(&summary, &[], &[], chrono::Local::now(), &[]);
+
+        assert_eq!(report.matches("None.").count(), 2);
+    }
+
+    #[test]
+    fn format_run_report_md_masks_secrets_in_blocked_reasons() {
+        let summary = scheduler::RunSummary {
+            phases_executed: 1,
+            items_completed: vec![],
+            items_blocked: vec!["WRK-002".to_string()],
+            items_failed_validation: vec![],
+            follow_ups_created: 0,
+            follow_ups_suppressed: 0,
+            items_merged: 0,
+            merges: Vec::new(),
+            halt_reason: scheduler::HaltReason::AllBlocked,
+            planned_phases: vec![],
+        };
+        let mut blocked = pg_item::new_from_parts(
+            "WRK-002".to_string(),
+            "Deploy".to_string(),
+            ItemStatus::InProgress,
+            vec![],
+            vec![],
+        );
+        pg_item::apply_update(
+            &mut blocked.0,
+            ItemUpdate::SetBlocked("Push rejected: GITHUB_TOKEN=ghp_abc123 expired".to_string()),
+        );
+        let redact_keys = config::SecurityConfig::default().redact_keys;
+
+        let report = format_run_report_md(
+            &summary,
+            &[blocked],
+            &[],
+            chrono::Local::now(),
+            &redact_keys,
+        );
+
+        assert!(!report.contains("ghp_abc123"), "{}", report);
+        assert!(report.contains(&format!(
+            "- **WRK-002** Deploy: Push rejected: GITHUB_TOKEN={} expired",
+            redact::REDACTED
+        )));
+    }
+
+    #[test]
+    fn format_deadline_adds_duration_to_start() {
+        use chrono::TimeZone;
+        let start = chrono::Local
+            .with_ymd_and_hms(2026, 3, 1, 22, 30, 0)
+            .unwrap();
+
+        assert_eq!(
+            format_deadline(start, std::time::Duration::from_secs(6 * 3600)),
+            "2026-03-02 04:30:00"
+        );
+    }
+
+    #[test]
+    fn event_log_path_prefers_flag_then_config() {
+        use chrono::TimeZone;
+        let now = chrono::Utc.with_ymd_and_hms(2026, 3, 1, 22, 30, 5).unwrap();
+        let runtime_dir = Path::new("/repo/.phase-golem");
+
+        assert_eq!(
+            event_log_path(Some(Path::new("events.jsonl")), false, runtime_dir, now),
+            Some(PathBuf::from("events.jsonl"))
+        );
+        assert_eq!(
+            event_log_path(None, true, runtime_dir, now),
+            Some(PathBuf::from(
+                "/repo/.phase-golem/runs/20260301T223005Z.jsonl"
+            ))
+        );
+        assert_eq!(event_log_path(None, false, runtime_dir, now), None);
+    }
+    #[test]
+    fn scaffold_workflows_makes_init_config_pass_preflight() {
+        let dir = tempfile::tempdir().unwrap();
+        std_fs::create_dir_all(dir.path().join(".task-golem")).unwrap();
+        std_fs::write(dir.path().join("phase-golem.toml"), init_config_toml("WRK")).unwrap();
+        let config = config::load_config(dir.path()).unwrap();
+        assert!(
+            phase_golem::preflight::run_preflight(&config, &[], dir.path(), dir.path()).is_err()
+        );
+
+        let created = scaffold_workflows(&config, dir.path()).unwrap();
+
+        let referenced: std::collections::BTreeSet<&String> = config
+            .pipelines
+            .values()
+            .flat_map(|p| p.pre_phases.iter().chain(p.phases.iter()))
+            .flat_map(|phase| phase.workflows.iter())
+            .collect();
+        assert_eq!(created.len(), referenced.len());
+        for workflow in referenced {
+            let contents = std_fs::read_to_string(dir.path().join(workflow)).unwrap();
+            assert!(contents.contains("PLACEHOLDER"), "{} not marked", workflow);
+        }
+        assert!(
+            phase_golem::preflight::run_preflight(&config, &[], dir.path(), dir.path()).is_ok()
+        );
+    }
+
+    #[test]
+    fn scaffold_workflows_keeps_existing_files() {
+        let dir = tempfile::tempdir().unwrap();
+        std_fs::write(dir.path().join("phase-golem.toml"), init_config_toml("WRK")).unwrap();
+        let config = config::load_config(dir.path()).unwrap();
+        let build = dir
+            .path()
+            .join(".claude/skills/changes/workflows/4-build/implement-spec-autonomous.md");
+        std_fs::create_dir_all(build.parent().unwrap()).unwrap();
+        std_fs::write(&build, "custom build steps").unwrap();
+
+        let created = scaffold_workflows(&config, dir.path()).unwrap();
+
+        assert!(!created
+            .iter()
+            .any(|p| p.ends_with("implement-spec-autonomous.md")));
+        assert_eq!(
+            std_fs::read_to_string(&build).unwrap(),
+            "custom build steps"
+        );
+    }
 }
@@ -1,12 +1,12 @@
 use std::collections::BTreeMap;
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use task_golem::model::item::Item;
 use task_golem::model::status::Status;
 
 use crate::types::{
-    BlockType, DimensionLevel, ItemStatus, ItemUpdate, PhasePool, SizeLevel, StructuredDescription,
-    UpdatedAssessments,
+    BlockType, DimensionLevel, ItemStatus, ItemUpdate, PhaseHistoryEntry, PhasePool, SizeLevel,
+    StructuredDescription, UpdatedAssessments,
 };
 
 // --- Extension key constants ---
@@ -26,6 +26,10 @@ pub const X_PG_BLOCKED_FROM_STATUS: &str = "x-pg-blocked-from-status";
 pub const X_PG_UNBLOCK_CONTEXT: &str = "x-pg-unblock-context";
 pub const X_PG_LAST_PHASE_COMMIT: &str = "x-pg-last-phase-commit";
 pub const X_PG_DESCRIPTION: &str = "x-pg-description";
+pub const X_PG_COMPLETED_PHASES: &str = "x-pg-completed-phases";
+pub const X_PG_PHASE_HISTORY: &str = "x-pg-phase-history";
+pub const X_PG_PRIORITY: &str = "x-pg-priority";
+pub const X_PG_DEADLINE: &str = "x-pg-deadline";
 
 // --- PgItem newtype ---
 
@@ -171,6 +175,21 @@ impl PgItem {
             .unwrap_or(false)
     }
 
+    /// Explicit scheduling priority; larger values are promoted first.
+    pub fn priority(&self) -> Option<i32> {
+        self.0
+            .extensions
+            .get(X_PG_PRIORITY)
+            .and_then(|v| v.as_i64())
+            .and_then(|v| i32::try_from(v).ok())
+    }
+
+    /// Date the item must be done by (`YYYY-MM-DD`); unparseable values read as unset.
+    pub fn deadline(&self) -> Option<NaiveDate> {
+        self.get_string_ext(X_PG_DEADLINE)
+            .and_then(|s| NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok())
+    }
+
     pub fn pipeline_type(&self) -> Option<String> {
         self.get_string_ext(X_PG_PIPELINE_TYPE)
     }
@@ -228,6 +247,51 @@ impl PgItem {
         self.get_string_ext(X_PG_LAST_PHASE_COMMIT)
     }
 
+    /// Phases that returned `PhaseComplete`, from the `x-pg-completed-phases` array.
+    /// `SubphaseComplete` results are never recorded here.
+    pub fn completed_phases(&self) -> Vec<String> {
+        self.0
+            .extensions
+            .get(X_PG_COMPLETED_PHASES)
+            .and_then(|v| v.as_array())
+            .map(|phases| {
+                phases
+                    .iter()
+                    .filter_map(|p| p.as_str().map(|s| s.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Completed phases in order, from the `x-pg-phase-history` array.
+    /// Entries that fail to deserialize are skipped with a warning.
+    pub fn phase_history(&self) -> Vec<PhaseHistoryEntry> {
+        let Some(entries) = self
+            .0
+            .extensions
+            .get(X_PG_PHASE_HISTORY)
+            .and_then(|v| v.as_array())
+        else {
+            return Vec::new();
+        };
+        entries
+            .iter()
+            .filter_map(
+                |entry| match serde_json::from_value::<PhaseHistoryEntry>(entry.clone()) {
+                    Ok(entry) => Some(entry),
+                    Err(e) => {
+                        crate::log_warn!(
+                            "Item {}: skipping malformed x-pg-phase-history entry: {}",
+                            self.0.id,
+                            e
+                        );
+                        None
+                    }
+                },
+            )
+            .collect()
+    }
+
     /// Deserializes `x-pg-description` JSON object into `StructuredDescription`.
     /// Returns `None` with a warning on deserialization failure.
     pub fn structured_description(&self) -> Option<StructuredDescription> {
@@ -430,6 +494,36 @@ pub fn set_unblock_context(item: &mut Item, context: Option<&str>) {
     set_enum_ext(item, X_PG_UNBLOCK_CONTEXT, context);
 }
 
+/// Sets the `x-pg-deadline` extension field (`YYYY-MM-DD`). `None` removes it.
+pub fn set_deadline(item: &mut Item, deadline: Option<NaiveDate>) {
+    match deadline {
+        Some(date) => {
+            item.extensions.insert(
+                X_PG_DEADLINE.to_string(),
+                serde_json::json!(date.format("%Y-%m-%d").to_string()),
+            );
+        }
+        None => {
+            item.extensions.remove(X_PG_DEADLINE);
+        }
+    }
+    item.updated_at = Utc::now();
+}
+
+/// Sets the `x-pg-priority` extension field. `None` removes it.
+pub fn set_priority(item: &mut Item, priority: Option<i32>) {
+    match priority {
+        Some(value) => {
+            item.extensions
+                .insert(X_PG_PRIORITY.to_string(), serde_json::json!(value));
+        }
+        None => {
+            item.extensions.remove(X_PG_PRIORITY);
+        }
+    }
+    item.updated_at = Utc::now();
+}
+
 /// Sets the `x-pg-requires-human-review` extension field.
 pub fn set_requires_human_review(item: &mut Item, value: bool) {
     if value {
@@ -471,6 +565,69 @@ pub fn set_structured_description(item: &mut Item, desc: Option<&StructuredDescr
     item.updated_at = Utc::now();
 }
 
+/// Sets the `x-pg-completed-phases` extension array. An empty slice clears it.
+pub fn set_completed_phases(item: &mut Item, phases: &[String]) {
+    if phases.is_empty() {
+        item.extensions.remove(X_PG_COMPLETED_PHASES);
+    } else {
+        item.extensions
+            .insert(X_PG_COMPLETED_PHASES.to_string(), serde_json::json!(phases));
+    }
+    item.updated_at = Utc::now();
+}
+
+/// Appends `entry` to the `x-pg-phase-history` extension array.
+pub fn append_phase_history(item: &mut Item, entry: &PhaseHistoryEntry) {
+    let value = serde_json::to_value(entry).expect("PhaseHistoryEntry is always serializable");
+    match item
+        .extensions
+        .get_mut(X_PG_PHASE_HISTORY)
+        .and_then(|v| v.as_array_mut())
+    {
+        Some(entries) => entries.push(value),
+        None => {
+            item.extensions.insert(
+                X_PG_PHASE_HISTORY.to_string(),
+                serde_json::Value::Array(vec![value]),
+            );
+        }
+    }
+    item.updated_at = Utc::now();
+}
+
+/// Appends each ID to `Item.dependencies` unless already present.
+///
+/// Returns the IDs that were actually added, in input order.
+pub fn add_dependencies(item: &mut Item, ids: &[String]) -> Vec<String> {
+    let mut added = Vec::new();
+    for id in ids {
+        if !item.dependencies.contains(id) {
+            item.dependencies.push(id.clone());
+            added.push(id.clone());
+        }
+    }
+    if !added.is_empty() {
+        item.updated_at = Utc::now();
+    }
+    added
+}
+
+/// Removes each ID from `Item.dependencies`, preserving the order of the rest.
+///
+/// Returns the IDs that were actually removed, in input order.
+pub fn remove_dependencies(item: &mut Item, ids: &[String]) -> Vec<String> {
+    let removed: Vec<String> = ids
+        .iter()
+        .filter(|id| item.dependencies.contains(id))
+        .cloned()
+        .collect();
+    if !removed.is_empty() {
+        item.dependencies.retain(|dep| !removed.contains(dep));
+        item.updated_at = Utc::now();
+    }
+    removed
+}
+
 /// Dispatches an `ItemUpdate` variant to the appropriate field mutation.
 ///
 /// This is the central mutation dispatch used by the coordinator's `UpdateItem`
@@ -561,6 +718,18 @@ pub fn apply_update(item: &mut Item, update: ItemUpdate) {
         ItemUpdate::UpdateAssessments(assessments) => {
             apply_assessments(item, &assessments);
         }
+        ItemUpdate::SetImpact(level) => {
+            set_impact(item, Some(&level));
+        }
+        ItemUpdate::SetSize(size) => {
+            set_size(item, Some(&size));
+        }
+        ItemUpdate::SetRisk(level) => {
+            set_risk(item, Some(&level));
+        }
+        ItemUpdate::SetComplexity(level) => {
+            set_complexity(item, Some(&level));
+        }
         ItemUpdate::SetPipelineType(pipeline_type) => {
             set_pipeline_type(item, Some(&pipeline_type));
         }
@@ -570,6 +739,24 @@ pub fn apply_update(item: &mut Item, update: ItemUpdate) {
         ItemUpdate::SetDescription(description) => {
             set_structured_description(item, Some(&description));
         }
+        ItemUpdate::AddTag(tag) => {
+            if !item.tags.contains(&tag) {
+                item.tags.push(tag);
+            }
+        }
+        ItemUpdate::RecordPhaseCompleted(phase) => {
+            let mut phases = PgItem(item.clone()).completed_phases();
+            if !phases.contains(&phase) {
+                phases.push(phase);
+                set_completed_phases(item, &phases);
+            }
+        }
+        ItemUpdate::ReturnToNew => {
+            set_pg_status(item, ItemStatus::New);
+            set_phase(item, None);
+            set_phase_pool(item, None);
+            set_completed_phases(item, &[]);
+        }
     }
 }
 
@@ -2,6 +2,7 @@ use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
 use crate::config::PhaseGolemConfig;
+use crate::git::StatusEntry;
 use crate::pg_item::PgItem;
 use crate::types::{ItemStatus, PhasePool};
 
@@ -30,7 +31,7 @@ impl std::fmt::Display for PreflightError {
 ///
 /// Phases:
 /// 1. Structural validation — config correctness (fast, no I/O)
-/// 2. Workflow probe — verify referenced workflow files exist on disk
+/// 2. Workflow probe — every phase lists workflows, and the referenced files exist on disk
 /// 3. Item validation — in-progress items reference valid pipelines/phases (skipped when Phase 1 finds structural errors)
 /// 4. Duplicate ID validation — ensure no two items share the same ID
 /// 5. Dependency graph validation — detect dangling references and circular dependencies
@@ -61,7 +62,7 @@ pub fn run_preflight(
     // Snapshot before Phase 2; gates Phase 3 on Phase 1 results only
     let structural_ok = errors.is_empty();
 
-    // Phase 2: Workflow probe — verify workflow files exist on disk
+    // Phase 2: Workflow probe — verify phases list workflow files that exist on disk
     if errors.is_empty() {
         errors.extend(probe_workflows(config, config_base));
     }
@@ -84,6 +85,83 @@ pub fn run_preflight(
     }
 }
 
+/// Uncommitted paths under `changes/` that belong to an `InProgress` item:
+/// output of a phase that never reached its commit, e.g. because an earlier
+/// run crashed mid-build. Returns `(item_id, path)` in status order.
+///
+/// Matches the change directory (`changes/<id>_<slug>/`) and loose change
+/// files (`changes/<id>.md`, `changes/<id>_<slug>.md`). When git collapses a
+/// wholly untracked `changes/` into one entry, its contents are listed from
+/// `project_root` instead.
+pub fn find_interrupted_changes(
+    status: &[StatusEntry],
+    items: &[PgItem],
+    project_root: &Path,
+) -> Vec<(String, String)> {
+    let in_progress: Vec<&PgItem> = items
+        .iter()
+        .filter(|item| item.pg_status() == ItemStatus::InProgress)
+        .collect();
+    let paths = status.iter().flat_map(|entry| {
+        let path = entry.path.trim_matches('"');
+        if entry.status_code == "??" && path == "changes/" {
+            untracked_changes_entries(project_root)
+        } else {
+            vec![path.to_string()]
+        }
+    });
+    paths
+        .filter_map(|path| {
+            let name = path.strip_prefix("changes/")?;
+            let item = in_progress.iter().find(|item| {
+                name.starts_with(&format!("{}_", item.id()))
+                    || name.starts_with(&format!("{}.", item.id()))
+            })?;
+            Some((item.id().to_string(), path.clone()))
+        })
+        .collect()
+}
+
+/// Entries of `changes/` as porcelain-style paths (directories end in `/`), sorted.
+fn untracked_changes_entries(project_root: &Path) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(project_root.join("changes")) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if entry.file_type().is_ok_and(|t| t.is_dir()) {
+                format!("changes/{}/", name)
+            } else {
+                format!("changes/{}", name)
+            }
+        })
+        .collect();
+    paths.sort();
+    paths
+}
+
+/// Run the preflight checks that need only the config: structural
+/// validation and the workflow file probe. Used by `config-check`, which
+/// must not touch the backlog or git.
+///
+/// Unlike `run_preflight`, the workflow probe runs even when structural
+/// checks fail, so every problem is reported at once.
+pub fn check_config(
+    config: &PhaseGolemConfig,
+    config_base: &Path,
+) -> Result<(), Vec<PreflightError>> {
+    let mut errors = validate_structure(config);
+    errors.extend(probe_workflows(config, config_base));
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
 // --- Phase 1: Structural validation ---
 
 /// Validate config structure with actionable error messages.
@@ -193,40 +271,54 @@ fn validate_structure(config: &PhaseGolemConfig) -> Vec<PreflightError> {
 
 // --- Phase 2: Workflow file probe ---
 
-/// Collect all unique workflow file paths across all pipelines.
-fn collect_unique_workflows(config: &PhaseGolemConfig) -> Vec<String> {
-    let mut workflows = HashSet::new();
-    for pipeline in config.pipelines.values() {
-        for phase in pipeline.pre_phases.iter().chain(pipeline.phases.iter()) {
-            for workflow in &phase.workflows {
-                workflows.insert(workflow.clone());
-            }
-        }
-    }
-    let mut sorted: Vec<String> = workflows.into_iter().collect();
-    sorted.sort();
-    sorted
-}
-
-/// Verify all referenced workflow files exist on disk.
+/// Verify every phase names at least one workflow and that each referenced
+/// workflow file exists on disk.
 ///
-/// Each workflow entry is a relative file path (relative to project root).
-/// Preflight checks that the file exists and is readable.
-fn probe_workflows(config: &PhaseGolemConfig, project_root: &Path) -> Vec<PreflightError> {
-    let workflows = collect_unique_workflows(config);
+/// Workflow paths are resolved relative to `config_base`. Errors name the
+/// pipeline and phase that reference the path, so a typo shared by several
+/// phases is reported once per phase.
+fn probe_workflows(config: &PhaseGolemConfig, config_base: &Path) -> Vec<PreflightError> {
     let mut errors = Vec::new();
 
-    for workflow_path in &workflows {
-        let absolute_path = project_root.join(workflow_path);
-        if !absolute_path.exists() {
-            errors.push(PreflightError {
-                condition: format!("Workflow file not found: {}", workflow_path),
-                config_location: "phase-golem.toml → pipelines → workflows".to_string(),
-                suggested_fix: format!(
-                    "Create the workflow file at {} or update the path",
-                    workflow_path
-                ),
-            });
+    let mut pipeline_names: Vec<&String> = config.pipelines.keys().collect();
+    pipeline_names.sort();
+
+    for pipeline_name in pipeline_names {
+        let pipeline = &config.pipelines[pipeline_name];
+        for phase in pipeline.pre_phases.iter().chain(pipeline.phases.iter()) {
+            let config_location = format!(
+                "phase-golem.toml → pipelines.{} → {} → workflows",
+                pipeline_name, phase.name
+            );
+
+            if phase.workflows.is_empty() {
+                errors.push(PreflightError {
+                    condition: format!(
+                        "pipeline '{}' phase '{}': no workflows configured",
+                        pipeline_name, phase.name
+                    ),
+                    config_location: config_location.clone(),
+                    suggested_fix:
+                        "List at least one workflow file so the agent receives instructions"
+                            .to_string(),
+                });
+            }
+
+            for workflow_path in &phase.workflows {
+                if !config_base.join(workflow_path).exists() {
+                    errors.push(PreflightError {
+                        condition: format!(
+                            "pipeline '{}' phase '{}': workflow not found: {}",
+                            pipeline_name, phase.name, workflow_path
+                        ),
+                        config_location: config_location.clone(),
+                        suggested_fix: format!(
+                            "Create the workflow file at {} or update the path",
+                            workflow_path
+                        ),
+                    });
+                }
+            }
         }
     }
 
@@ -362,38 +454,21 @@ fn validate_duplicate_ids(items: &[PgItem]) -> Vec<PreflightError> {
 pub fn validate_dependency_graph(items: &[PgItem]) -> Vec<PreflightError> {
     let mut errors = Vec::new();
 
-    // Build set of all item IDs for dangling reference detection
-    let all_ids: HashSet<&str> = items.iter().map(|item| item.id()).collect();
-
-    // Check for dangling references
-    for item in items {
-        for dep_id in item.dependencies() {
-            if !all_ids.contains(dep_id.as_str()) {
-                errors.push(PreflightError {
-                    condition: format!(
-                        "Item '{}' depends on '{}' which does not exist in the backlog",
-                        item.id(), dep_id
-                    ),
-                    config_location: format!(
-                        "items → {} → dependencies",
-                        item.id()
-                    ),
-                    suggested_fix: format!(
-                        "Remove '{}' from {}'s dependencies, or add the missing item to the backlog",
-                        dep_id, item.id()
-                    ),
-                });
-            }
-        }
+    for (item_id, dep_id) in find_unknown_dependencies(items) {
+        errors.push(PreflightError {
+            condition: format!(
+                "Item '{}' depends on '{}' which does not exist in the backlog",
+                item_id, dep_id
+            ),
+            config_location: format!("items → {} → dependencies", item_id),
+            suggested_fix: format!(
+                "Remove '{}' from {}'s dependencies, or add the missing item to the backlog",
+                dep_id, item_id
+            ),
+        });
     }
 
-    // Filter to non-Done items for cycle detection
-    let non_done_items: Vec<&PgItem> = items
-        .iter()
-        .filter(|item| item.pg_status() != ItemStatus::Done)
-        .collect();
-
-    for cycle in detect_cycles(&non_done_items) {
+    for cycle in find_dependency_cycles(items) {
         let path = cycle.join(" → ");
         let cycle_items = cycle[..cycle.len() - 1].join(", ");
         errors.push(PreflightError {
@@ -409,6 +484,148 @@ pub fn validate_dependency_graph(items: &[PgItem]) -> Vec<PreflightError> {
     errors
 }
 
+// --- Dependency graph lint ---
+
+/// Dependency chains longer than this are reported by `lint_dependency_graph`.
+pub const DEEP_CHAIN_THRESHOLD: usize = 5;
+
+/// Findings from `lint_dependency_graph`.
+///
+/// Cycles and unknown dependencies are hard problems; Done dependencies and
+/// deep chains are informational.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DependencyLint {
+    /// Cycles among non-Done items, each as a path like `["A", "B", "A"]`.
+    pub cycles: Vec<Vec<String>>,
+    /// `(item, dependency)` pairs where the dependency is not in the backlog.
+    pub unknown: Vec<(String, String)>,
+    /// `(item, dependency)` pairs where a non-Done item depends on a Done item.
+    pub done: Vec<(String, String)>,
+    /// `(item, depth)` for items whose longest dependency chain exceeds the threshold.
+    pub deep_chains: Vec<(String, usize)>,
+}
+
+impl DependencyLint {
+    pub fn has_hard_problems(&self) -> bool {
+        !self.cycles.is_empty() || !self.unknown.is_empty()
+    }
+
+    pub fn is_clean(&self) -> bool {
+        !self.has_hard_problems() && self.done.is_empty() && self.deep_chains.is_empty()
+    }
+}
+
+/// Run every dependency graph analysis over `items`.
+pub fn lint_dependency_graph(items: &[PgItem], deep_threshold: usize) -> DependencyLint {
+    DependencyLint {
+        cycles: find_dependency_cycles(items),
+        unknown: find_unknown_dependencies(items),
+        done: find_done_dependencies(items),
+        deep_chains: find_deep_chains(items, deep_threshold),
+    }
+}
+
+/// Dependencies that reference an ID not present in `items`, as `(item, dependency)`.
+pub fn find_unknown_dependencies(items: &[PgItem]) -> Vec<(String, String)> {
+    let all_ids: HashSet<&str> = items.iter().map(|item| item.id()).collect();
+    items
+        .iter()
+        .flat_map(|item| {
+            item.dependencies()
+                .iter()
+                .filter(|dep_id| !all_ids.contains(dep_id.as_str()))
+                .map(move |dep_id| (item.id().to_string(), dep_id.clone()))
+        })
+        .collect()
+}
+
+/// Dependencies of non-Done items on items that are already Done, as `(item, dependency)`.
+///
+/// These are always satisfied and can usually be removed.
+pub fn find_done_dependencies(items: &[PgItem]) -> Vec<(String, String)> {
+    let done_ids: HashSet<&str> = items
+        .iter()
+        .filter(|item| item.pg_status() == ItemStatus::Done)
+        .map(|item| item.id())
+        .collect();
+    items
+        .iter()
+        .filter(|item| item.pg_status() != ItemStatus::Done)
+        .flat_map(|item| {
+            item.dependencies()
+                .iter()
+                .filter(|dep_id| done_ids.contains(dep_id.as_str()))
+                .map(move |dep_id| (item.id().to_string(), dep_id.clone()))
+        })
+        .collect()
+}
+
+/// Cycles among non-Done items, each as a path like `["A", "B", "C", "A"]`.
+pub fn find_dependency_cycles(items: &[PgItem]) -> Vec<Vec<String>> {
+    let non_done_items: Vec<&PgItem> = items
+        .iter()
+        .filter(|item| item.pg_status() != ItemStatus::Done)
+        .collect();
+    detect_cycles(&non_done_items)
+}
+
+/// Longest chain of outstanding dependencies for each non-Done item.
+///
+/// Only edges to other non-Done items count, so an item whose dependencies are
+/// all Done or unknown has depth 0. Edges that close a cycle are ignored.
+pub fn dependency_depths(items: &[PgItem]) -> HashMap<String, usize> {
+    let by_id: HashMap<&str, &PgItem> = items
+        .iter()
+        .filter(|item| item.pg_status() != ItemStatus::Done)
+        .map(|item| (item.id(), item))
+        .collect();
+
+    fn depth<'a>(
+        item_id: &'a str,
+        by_id: &HashMap<&'a str, &'a PgItem>,
+        memo: &mut HashMap<&'a str, usize>,
+        in_stack: &mut HashSet<&'a str>,
+    ) -> usize {
+        if let Some(&d) = memo.get(item_id) {
+            return d;
+        }
+        in_stack.insert(item_id);
+        let mut deepest = 0;
+        for dep_id in by_id[item_id].dependencies() {
+            let Some((&dep_key, _)) = by_id.get_key_value(dep_id.as_str()) else {
+                continue;
+            };
+            if in_stack.contains(dep_key) {
+                continue;
+            }
+            deepest = deepest.max(1 + depth(dep_key, by_id, memo, in_stack));
+        }
+        in_stack.remove(item_id);
+        memo.insert(item_id, deepest);
+        deepest
+    }
+
+    let mut memo: HashMap<&str, usize> = HashMap::new();
+    let mut in_stack: HashSet<&str> = HashSet::new();
+    for &item_id in by_id.keys() {
+        depth(item_id, &by_id, &mut memo, &mut in_stack);
+    }
+
+    memo.into_iter()
+        .map(|(id, d)| (id.to_string(), d))
+        .collect()
+}
+
+/// Items whose dependency chain is longer than `threshold`, deepest first.
+pub fn find_deep_chains(items: &[PgItem], threshold: usize) -> Vec<(String, usize)> {
+    let mut deep: Vec<(String, usize)> = dependency_depths(items)
+        .into_iter()
+        .filter(|(_, d)| *d > threshold)
+        .collect();
+    deep.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    deep
+}
+
 /// DFS three-color cycle detection on non-Done items.
 ///
 /// Returns each cycle as a path like `["A", "B", "C", "A"]`.
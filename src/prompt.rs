@@ -334,6 +334,7 @@ fn build_output_suffix(item_id: &str, phase_str: &str, result_path: &Path) -> St
         \x20   \"impact\": \"low | medium | high (optional)\"\n\
         \x20 }},\n\
         \x20 \"commit_summary\": \"One-line summary for git commit message\",\n\
+        \x20 \"confidence\": 0.9,\n\
         \x20 \"follow_ups\": [\n\
         \x20   {{\n\
         \x20     \"title\": \"Follow-up item title\",\n\
@@ -353,6 +354,8 @@ fn build_output_suffix(item_id: &str, phase_str: &str, result_path: &Path) -> St
         **Important:**\n\
         - Update assessments if your work revealed the item is larger/smaller/riskier than expected.\n\
         - Report any follow-up work items discovered during this phase.\n\
+        - Optionally set `confidence` (0.0-1.0) to how sure you are that this output is correct. \
+        Low confidence may send the item to review or to a human.\n\
         - Include a short `commit_summary` (under 72 chars) describing what changed — used as the git commit title.\n\
         - The JSON must be valid — do not include comments or trailing commas.",
         result_path = result_path.display(),
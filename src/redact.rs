@@ -0,0 +1,106 @@
+use serde::Serialize;
+use serde_json::Value;
+
+/// Replacement for values under a redacted key.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Mask every object field whose key matches one of `keys` (case-insensitive),
+/// at any depth, and `key=value` pairs inside string values (see `redact_text`).
+///
+/// Applied to anything phase-golem serializes for output (run summaries,
+/// reports, the event log and trace) so secrets from config, params, or agent
+/// output never leak. The masked field keeps its key; other fields are left
+/// untouched.
+pub fn redact(value: &mut Value, keys: &[String]) {
+    match value {
+        Value::Object(map) => {
+            for (key, field) in map.iter_mut() {
+                if keys.iter().any(|k| k.eq_ignore_ascii_case(key)) {
+                    *field = Value::String(REDACTED.to_string());
+                } else {
+                    redact(field, keys);
+                }
+            }
+        }
+        Value::Array(values) => {
+            for v in values {
+                redact(v, keys);
+            }
+        }
+        Value::String(s) => {
+            let masked = redact_text(s, keys);
+            if masked != *s {
+                *s = masked;
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Serialize `value` to one line of JSON with `redact` applied.
+pub fn to_redacted_json<T: Serialize>(value: &T, keys: &[String]) -> serde_json::Result<String> {
+    let mut value = serde_json::to_value(value)?;
+    redact(&mut value, keys);
+    serde_json::to_string(&value)
+}
+
+/// Mask the value of every `key=value` or `key: value` pair in free text
+/// (block reasons, Markdown reports) whose key is one of `keys`
+/// (case-insensitive), alone or after a separator like `_` (`GITHUB_TOKEN=`
+/// matches `token`). The value runs to the next whitespace, quote, or `,;&)`.
+pub fn redact_text(text: &str, keys: &[String]) -> String {
+    let bytes = text.as_bytes();
+    let mut out = String::with_capacity(text.len());
+    let mut copied = 0;
+    let mut at = 0;
+    while at < bytes.len() {
+        match secret_value_span(bytes, at, keys) {
+            Some((start, end)) => {
+                out.push_str(&text[copied..start]);
+                out.push_str(REDACTED);
+                copied = end;
+                at = end;
+            }
+            None => at += 1,
+        }
+    }
+    out.push_str(&text[copied..]);
+    out
+}
+
+/// Byte range of the value when a pair for one of `keys` starts at `at`.
+fn secret_value_span(bytes: &[u8], at: usize, keys: &[String]) -> Option<(usize, usize)> {
+    if at > 0 && bytes[at - 1].is_ascii_alphanumeric() {
+        return None;
+    }
+    keys.iter().filter(|k| !k.is_empty()).find_map(|key| {
+        let mut pos = at + key.len();
+        if !bytes.get(at..pos)?.eq_ignore_ascii_case(key.as_bytes()) {
+            return None;
+        }
+        // `"key": "value"` as embedded JSON
+        if bytes.get(pos) == Some(&b'"') {
+            pos += 1;
+        }
+        pos = skip_spaces(bytes, pos);
+        if !matches!(bytes.get(pos), Some(b'=') | Some(b':')) {
+            return None;
+        }
+        pos = skip_spaces(bytes, pos + 1);
+        if matches!(bytes.get(pos), Some(b'"') | Some(b'\'')) {
+            pos += 1;
+        }
+        let len = bytes[pos.min(bytes.len())..]
+            .iter()
+            .position(|b| b.is_ascii_whitespace() || b"\"',;&)".contains(b))
+            .unwrap_or(bytes.len().saturating_sub(pos));
+        (len > 0).then_some((pos, pos + len))
+    })
+}
+
+fn skip_spaces(bytes: &[u8], mut pos: usize) -> usize {
+    while bytes.get(pos) == Some(&b' ') {
+        pos += 1;
+    }
+    pos
+}
@@ -1,26 +1,29 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
+use chrono::NaiveDate;
+use serde::Serialize;
+use tokio::sync::mpsc;
 use tokio::task::JoinSet;
 use tokio_util::sync::CancellationToken;
 
 use crate::agent::AgentRunner;
-use crate::config::{ExecutionConfig, PhaseGolemConfig, PipelineConfig};
+use crate::config::{
+    ExecutionConfig, GuardrailViolationPolicy, PhaseConfig, PhaseGolemConfig, PipelineConfig,
+    PrePhaseBlockPolicy,
+};
 use crate::coordinator::CoordinatorHandle;
 use crate::executor;
 use crate::filter;
-use crate::pg_item::PgItem;
+use crate::pg_item::{self, PgItem};
 use crate::prompt;
 use crate::types::{
-    DimensionLevel, ItemStatus, ItemUpdate, PhaseExecutionResult, PhasePool, PhaseResult,
-    ResultCode, SchedulerAction, SizeLevel,
+    DimensionLevel, FollowUp, ItemStatus, ItemUpdate, PhaseExecutionResult, PhasePool, PhaseResult,
+    ResultCode, SchedulerAction, SizeLevel, UpdatedAssessments,
 };
-use crate::{log_debug, log_info, log_warn};
-
-/// Number of consecutive retry exhaustions before circuit breaker trips.
-const CIRCUIT_BREAKER_THRESHOLD: u32 = 2;
+use crate::{log_debug, log_error, log_info, log_warn};
 
 // --- Public types ---
 
@@ -30,15 +33,30 @@ pub struct RunSummary {
     pub phases_executed: u32,
     pub items_completed: Vec<String>,
     pub items_blocked: Vec<String>,
+    /// Items blocked because a validation phase returned `Failed` (also in `items_blocked`).
+    pub items_failed_validation: Vec<String>,
     pub follow_ups_created: u32,
+    /// Follow-ups dropped as duplicates (`execution.dedup_follow_ups`).
+    pub follow_ups_suppressed: u32,
     pub items_merged: u32,
+    /// `(source, target)` for each duplicate merged away this run, in merge order.
+    pub merges: Vec<(String, String)>,
     pub halt_reason: HaltReason,
+    /// Dry-run only: per item (in first-scheduled order), the phases that would run.
+    pub planned_phases: Vec<(String, Vec<String>)>,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum HaltReason {
-    AllDoneOrBlocked,
+    /// Nothing left to run and every remaining item is Done.
+    AllDone,
+    /// Nothing left to run and no item is Done: everything is blocked or waiting on dependencies.
+    AllBlocked,
+    /// Nothing left to run; some items finished while others are blocked or waiting.
+    Mixed,
     CapReached,
+    /// The `--max-duration` wall-clock budget ran out.
+    DurationReached,
     CircuitBreakerTripped,
     ShutdownRequested,
     TargetCompleted,
@@ -58,6 +76,66 @@ pub struct RunParams {
     /// Otherwise, equals `root`.
     pub config_base: PathBuf,
     pub auto_advance: bool,
+    /// Plan against a simulated snapshot instead of spawning agents or mutating the store.
+    pub dry_run: bool,
+    /// When a target has unmet dependencies, run those dependencies first instead of halting.
+    pub wait_deps: bool,
+    /// Limit on how many dependency levels `wait_deps` expands. `None` = unlimited.
+    pub max_dep_depth: Option<u32>,
+    /// Skip the agent for phases the item has already completed (see `resumed_phase_result`).
+    pub resume: bool,
+    /// Receives a `SchedulerEvent` for each scheduler milestone. `None` = logs only.
+    /// Events that find the channel full are dropped, except the final `Halted`,
+    /// which waits for room; keep receiving until the channel closes.
+    pub events: Option<mpsc::Sender<SchedulerEvent>>,
+    /// Wall-clock budget for the run. Once exceeded no new phases start;
+    /// in-flight phases finish before the run halts. `None` = unlimited.
+    pub max_duration: Option<Duration>,
+    /// Schedule all `targets` together as a set, concurrently up to
+    /// `max_concurrent`, instead of one at a time.
+    pub parallel_targets: bool,
+    /// Instead of halting once nothing is actionable, poll the store at this
+    /// interval and resume scheduling when new work appears. The run then ends
+    /// on a signal, the phase cap, or `max_duration`. `None` = halt when idle.
+    pub watch: Option<Duration>,
+    /// Append a JSONL record per loop iteration (snapshot counts, selected
+    /// actions, what was spawned or skipped and why) to this file.
+    pub trace: Option<PathBuf>,
+    /// Append each `SchedulerEvent`, plus the actions scheduled per
+    /// iteration, to this file as one JSON object per line.
+    pub event_log: Option<PathBuf>,
+}
+
+/// Structured progress event, sent on `RunParams.events` at the points the
+/// scheduler logs. Events are dropped rather than stalling the run if the
+/// receiver falls behind; `RunParams.event_log` records every one.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", content = "data", rename_all = "snake_case")]
+pub enum SchedulerEvent {
+    /// A phase (or triage, with phase `"triage"`) was spawned.
+    PhaseStarted {
+        item_id: String,
+        phase: String,
+    },
+    /// A phase (or triage) returned `PhaseComplete`.
+    PhaseCompleted {
+        item_id: String,
+        phase: String,
+    },
+    ItemBlocked {
+        item_id: String,
+        reason: String,
+    },
+    /// The item reached Done and was archived.
+    ItemCompleted {
+        item_id: String,
+    },
+    FollowUpsCreated {
+        item_id: String,
+        count: u32,
+    },
+    CircuitBreakerTripped,
+    Halted(HaltReason),
 }
 
 // --- Running task tracking ---
@@ -145,6 +223,9 @@ impl RunningTasks {
 ///
 /// Constraints:
 /// - Fill up to max_concurrent slots
+/// - Per-pipeline `max_wip`/`max_concurrent` overrides further limit promotions
+///   and phase slots for that pipeline; a saturated pipeline is skipped so
+///   others can still be scheduled. Triage is not counted against a pipeline.
 /// - If next phase is destructive, it must be the ONLY action
 /// - Items already running are excluded
 pub fn select_actions(
@@ -153,9 +234,85 @@ pub fn select_actions(
     config: &ExecutionConfig,
     pipelines: &HashMap<String, PipelineConfig>,
 ) -> Vec<SchedulerAction> {
+    select_actions_with_reasons(items, running, config, pipelines).0
+}
+
+/// Why `select_actions` passed over an item that isn't running.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SkipReason {
+    /// Summary from `unmet_dep_summary`.
+    UnmetDependencies(String),
+    /// Ready, but `max_wip` (global or per-pipeline) is reached.
+    WipLimit,
+    /// No `max_concurrent` slot (global or per-pipeline) was left.
+    ConcurrencyLimit,
+    /// A destructive phase is running or queued, or this item's destructive
+    /// phase is waiting for running phases to finish.
+    DestructiveExclusive,
+    /// In progress or scoping, but its phase isn't in its pipeline.
+    NoRunnablePhase,
+}
+
+impl std::fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SkipReason::UnmetDependencies(summary) => {
+                write!(f, "waiting on dependencies: {}", summary)
+            }
+            SkipReason::WipLimit => write!(f, "waiting for a WIP slot (max_wip reached)"),
+            SkipReason::ConcurrencyLimit => {
+                write!(f, "waiting for a concurrency slot (max_concurrent reached)")
+            }
+            SkipReason::DestructiveExclusive => {
+                write!(
+                    f,
+                    "waiting on a destructive phase (destructive phases run alone)"
+                )
+            }
+            SkipReason::NoRunnablePhase => {
+                write!(
+                    f,
+                    "no runnable phase (current phase not found in its pipeline)"
+                )
+            }
+        }
+    }
+}
+
+impl SkipReason {
+    /// Compact form for the `Skipped: [...]` part of the scheduling log line.
+    pub fn short(&self) -> String {
+        match self {
+            SkipReason::UnmetDependencies(summary) => format!("unmet dep {}", summary),
+            SkipReason::WipLimit => "max_wip".to_string(),
+            SkipReason::ConcurrencyLimit => "max_concurrent".to_string(),
+            SkipReason::DestructiveExclusive => "destructive phase exclusive".to_string(),
+            SkipReason::NoRunnablePhase => "no runnable phase".to_string(),
+        }
+    }
+}
+
+/// `select_actions`, plus why each schedulable (not Done or Blocked, not
+/// running) item that got no action was passed over. Diagnostics only.
+pub fn select_actions_with_reasons(
+    items: &[PgItem],
+    running: &RunningTasks,
+    config: &ExecutionConfig,
+    pipelines: &HashMap<String, PipelineConfig>,
+) -> (Vec<SchedulerAction>, Vec<(String, SkipReason)>) {
+    let mut skipped: Vec<(String, SkipReason)> = Vec::new();
+    let schedulable = || {
+        items.iter().filter(|i| {
+            !matches!(i.pg_status(), ItemStatus::Done | ItemStatus::Blocked)
+                && !running.is_item_running(i.id())
+        })
+    };
+
     // (1) If a destructive task is running, return empty
     if running.has_destructive() {
-        return Vec::new();
+        skipped
+            .extend(schedulable().map(|i| (i.id().to_string(), SkipReason::DestructiveExclusive)));
+        return (Vec::new(), skipped);
     }
 
     let available_slots = config
@@ -163,7 +320,8 @@ pub fn select_actions(
         .saturating_sub(running.non_destructive_count() as u32) as usize;
 
     if available_slots == 0 {
-        return Vec::new();
+        skipped.extend(schedulable().map(|i| (i.id().to_string(), SkipReason::ConcurrencyLimit)));
+        return (Vec::new(), skipped);
     }
 
     let mut actions: Vec<SchedulerAction> = Vec::new();
@@ -173,55 +331,79 @@ pub fn select_actions(
         .iter()
         .filter(|i| i.pg_status() == ItemStatus::InProgress)
         .count() as u32;
+    let mut in_progress_by_pipeline: HashMap<String, u32> = HashMap::new();
+    for item in items
+        .iter()
+        .filter(|i| i.pg_status() == ItemStatus::InProgress)
+    {
+        *in_progress_by_pipeline
+            .entry(item_pipeline_type(item))
+            .or_insert(0) += 1;
+    }
 
     // (2) Promote Ready → InProgress when under max_wip
     // Promotions don't consume executor slots — they're instant state transitions
     let promotions_needed = config.max_wip.saturating_sub(in_progress_count) as usize;
-    let ready_items = sorted_ready_items(items);
+    let today = chrono::Utc::now().date_naive();
+    let ready_items = sorted_ready_items(items, today, config.deadline_window_days);
     let mut promoted = 0usize;
     for item in &ready_items {
-        if promoted >= promotions_needed {
-            break;
-        }
-        if skip_for_unmet_deps(item, items) {
+        if running.is_item_running(item.id()) {
             continue;
         }
-        if !running.is_item_running(item.id()) {
-            actions.push(SchedulerAction::Promote(item.id().to_string()));
-            promoted += 1;
-        }
-    }
-
-    // (3 & 4) Build phase actions: InProgress first, then Scoping
-    let mut phase_actions = Vec::new();
-
-    // InProgress items with phases to run
-    let in_progress_runnable = sorted_in_progress_items(items, pipelines);
-    for item in &in_progress_runnable {
-        if running.is_item_running(item.id()) {
+        if let Some(summary) = unmet_deps(item, items) {
+            skipped.push((
+                item.id().to_string(),
+                SkipReason::UnmetDependencies(summary),
+            ));
             continue;
         }
-        if skip_for_unmet_deps(item, items) {
+        if promoted >= promotions_needed {
+            skipped.push((item.id().to_string(), SkipReason::WipLimit));
             continue;
         }
-        if let Some(action) = build_run_phase_action(item, pipelines) {
-            phase_actions.push(action);
+        let pipeline_type = item_pipeline_type(item);
+        let limit = pipeline_limit(pipelines, &pipeline_type, |p| p.max_wip, config.max_wip);
+        if !take_pipeline_slot(&mut in_progress_by_pipeline, pipeline_type, limit) {
+            skipped.push((item.id().to_string(), SkipReason::WipLimit));
+            continue;
         }
+        actions.push(SchedulerAction::Promote(item.id().to_string()));
+        promoted += 1;
     }
 
-    // Scoping items with phases to run
+    // (3 & 4) Build phase actions: InProgress first, then Scoping
+    let mut phase_actions = Vec::new();
+
+    // InProgress items with phases to run, then Scoping items with phases to run
+    let in_progress_runnable =
+        sorted_in_progress_items(items, pipelines, today, config.deadline_window_days);
     let scoping_runnable = sorted_scoping_items(items, pipelines);
-    for item in &scoping_runnable {
+    for item in in_progress_runnable.iter().chain(scoping_runnable.iter()) {
         if running.is_item_running(item.id()) {
             continue;
         }
-        if skip_for_unmet_deps(item, items) {
+        if let Some(summary) = unmet_deps(item, items) {
+            skipped.push((
+                item.id().to_string(),
+                SkipReason::UnmetDependencies(summary),
+            ));
             continue;
         }
-        if let Some(action) = build_run_phase_action(item, pipelines) {
-            phase_actions.push(action);
+        match build_run_phase_action(item, pipelines) {
+            Some(action) => phase_actions.push(action),
+            None => skipped.push((item.id().to_string(), SkipReason::NoRunnablePhase)),
         }
     }
+    // In progress or scoping without a phase never reach the sorted lists
+    skipped.extend(
+        schedulable()
+            .filter(|i| {
+                matches!(i.pg_status(), ItemStatus::InProgress | ItemStatus::Scoping)
+                    && i.phase().is_none()
+            })
+            .map(|i| (i.id().to_string(), SkipReason::NoRunnablePhase)),
+    );
 
     // (5) Triage New items (lowest priority)
     let new_items = sorted_new_items(items);
@@ -229,16 +411,33 @@ pub fn select_actions(
         if running.is_item_running(item.id()) {
             continue;
         }
-        if skip_for_unmet_deps(item, items) {
+        if let Some(summary) = unmet_deps(item, items) {
+            skipped.push((
+                item.id().to_string(),
+                SkipReason::UnmetDependencies(summary),
+            ));
             continue;
         }
         phase_actions.push(SchedulerAction::Triage(item.id().to_string()));
     }
 
+    // Phases already running per pipeline (triage of New items is not counted)
+    let mut running_by_pipeline: HashMap<String, u32> = HashMap::new();
+    for item in items {
+        if running.is_item_running(item.id()) && item.pg_status() != ItemStatus::New {
+            *running_by_pipeline
+                .entry(item_pipeline_type(item))
+                .or_insert(0) += 1;
+        }
+    }
+
     // Fill slots respecting destructive exclusion
     let mut slots_remaining = available_slots;
-    for action in phase_actions {
+    let mut pending = phase_actions.into_iter();
+    while let Some(action) = pending.next() {
         if slots_remaining == 0 {
+            skipped.push((action_item_id(&action), SkipReason::ConcurrencyLimit));
+            skipped.extend(pending.map(|a| (action_item_id(&a), SkipReason::ConcurrencyLimit)));
             break;
         }
 
@@ -252,10 +451,15 @@ pub fn select_actions(
                 {
                     // Only promotions so far (no executor tasks) and nothing running — safe
                     actions.push(action);
-                    break; // No more actions after destructive
+                } else {
+                    // Can't run destructive yet — stop filling slots so running tasks
+                    // drain naturally and the destructive action isn't starved.
+                    skipped.push((action_item_id(&action), SkipReason::DestructiveExclusive));
                 }
-                // Can't run destructive yet — stop filling slots so running tasks
-                // drain naturally and the destructive action isn't starved.
+                // No more actions after destructive
+                skipped.extend(
+                    pending.map(|a| (action_item_id(&a), SkipReason::DestructiveExclusive)),
+                );
                 break;
             }
             _ => {
@@ -270,7 +474,28 @@ pub fn select_actions(
                     )
                 });
                 if has_queued_destructive {
-                    break; // Can't add anything after a destructive action
+                    // Can't add anything after a destructive action
+                    skipped.push((action_item_id(&action), SkipReason::DestructiveExclusive));
+                    skipped.extend(
+                        pending.map(|a| (action_item_id(&a), SkipReason::DestructiveExclusive)),
+                    );
+                    break;
+                }
+                if let SchedulerAction::RunPhase { item_id, .. } = &action {
+                    if let Some(item) = items.iter().find(|i| i.id() == item_id) {
+                        let pipeline_type = item_pipeline_type(item);
+                        let limit = pipeline_limit(
+                            pipelines,
+                            &pipeline_type,
+                            |p| p.max_concurrent,
+                            config.max_concurrent,
+                        );
+                        if !take_pipeline_slot(&mut running_by_pipeline, pipeline_type, limit) {
+                            // Pipeline saturated — leave the slot for others
+                            skipped.push((item_id.clone(), SkipReason::ConcurrencyLimit));
+                            continue;
+                        }
+                    }
                 }
                 actions.push(action);
                 slots_remaining -= 1;
@@ -278,32 +503,234 @@ pub fn select_actions(
         }
     }
 
-    actions
+    (actions, skipped)
+}
+
+/// One `"<id>: <reason>"` line per non-Done, non-Blocked item that
+/// `select_actions` would pass over with nothing running, sorted by ID.
+fn stuck_item_lines(
+    items: &[PgItem],
+    config: &ExecutionConfig,
+    pipelines: &HashMap<String, PipelineConfig>,
+) -> Vec<String> {
+    let (_, mut skipped) =
+        select_actions_with_reasons(items, &RunningTasks::new(), config, pipelines);
+    skipped.sort_by(|a, b| a.0.cmp(&b.0));
+    skipped
+        .into_iter()
+        .map(|(id, reason)| format!("{}: {}", id, reason))
+        .collect()
+}
+
+/// The item a scheduler action applies to.
+fn action_item_id(action: &SchedulerAction) -> String {
+    match action {
+        SchedulerAction::Promote(id) | SchedulerAction::Triage(id) => id.clone(),
+        SchedulerAction::RunPhase { item_id, .. } => item_id.clone(),
+    }
+}
+
+/// Pipeline type an item is scheduled under (`feature` when unset).
+fn item_pipeline_type(item: &PgItem) -> String {
+    item.pipeline_type()
+        .unwrap_or_else(|| "feature".to_string())
+}
+
+/// A pipeline's override for one limit, capped at the global `ceiling`.
+/// `None` when the pipeline sets no override (only the global limit applies).
+fn pipeline_limit(
+    pipelines: &HashMap<String, PipelineConfig>,
+    pipeline_type: &str,
+    limit: impl Fn(&PipelineConfig) -> Option<u32>,
+    ceiling: u32,
+) -> Option<u32> {
+    pipelines
+        .get(pipeline_type)
+        .and_then(limit)
+        .map(|l| l.min(ceiling))
+}
+
+/// Count one more item against a per-pipeline limit.
+///
+/// Returns false, without counting, when the pipeline is already at `limit`.
+/// Always succeeds when there is no limit.
+fn take_pipeline_slot(
+    counts: &mut HashMap<String, u32>,
+    pipeline_type: String,
+    limit: Option<u32>,
+) -> bool {
+    let limit = match limit {
+        Some(l) => l,
+        None => return true,
+    };
+    let count = counts.entry(pipeline_type).or_insert(0);
+    if *count >= limit {
+        return false;
+    }
+    *count += 1;
+    true
+}
+
+/// Cap a batch of selected actions at `max` entries.
+///
+/// The kept window starts at `rotation` (mod len) and wraps, so when the
+/// cap bites repeatedly the same leading actions aren't always the ones
+/// kept. Dropped actions are simply re-selected on a later iteration.
+/// Any subset of a `select_actions` batch is valid: a destructive action
+/// is only ever accompanied by promotions.
+pub fn limit_actions(
+    actions: Vec<SchedulerAction>,
+    max: Option<u32>,
+    rotation: usize,
+) -> Vec<SchedulerAction> {
+    let max = match max {
+        Some(m) if (m as usize) < actions.len() => m as usize,
+        _ => return actions,
+    };
+    let start = rotation % actions.len();
+    actions.into_iter().cycle().skip(start).take(max).collect()
+}
+
+/// IDs of the Ready items the next scheduler pass would promote, in
+/// priority order, assuming nothing is running.
+///
+/// Delegates to `select_actions`, so `max_wip` (global and per-pipeline),
+/// dependencies, and the promotion ordering all apply.
+pub fn promotable_item_ids(
+    items: &[PgItem],
+    config: &ExecutionConfig,
+    pipelines: &HashMap<String, PipelineConfig>,
+) -> Vec<String> {
+    select_actions(items, &RunningTasks::new(), config, pipelines)
+        .into_iter()
+        .filter_map(|action| match action {
+            SchedulerAction::Promote(id) => Some(id),
+            _ => None,
+        })
+        .collect()
 }
 
 // --- Sorting helpers ---
 
-/// Sort Ready items by impact (desc), then created date (asc, FIFO).
-fn sorted_ready_items(items: &[PgItem]) -> Vec<&PgItem> {
+/// Sort Ready items by dependency layer (asc), then deadline urgency, then
+/// priority (desc), then impact (desc), then created date (asc, FIFO).
+///
+/// The layer ordering guarantees an item never sorts ahead of a Ready item it
+/// transitively depends on, so dependencies are promoted first.
+fn sorted_ready_items(items: &[PgItem], today: NaiveDate, window_days: u32) -> Vec<&PgItem> {
+    let layers = ready_dependency_layers(items);
     let mut ready: Vec<&PgItem> = items
         .iter()
         .filter(|i| i.pg_status() == ItemStatus::Ready)
         .collect();
     ready.sort_by(|a, b| {
+        let layer_a = layers.get(a.id()).copied().unwrap_or(0);
+        let layer_b = layers.get(b.id()).copied().unwrap_or(0);
         let impact_a = impact_sort_value(&a.impact());
         let impact_b = impact_sort_value(&b.impact());
-        impact_b
-            .cmp(&impact_a)
+        // Higher explicit priority first; `Some` always sorts above `None`
+        layer_a
+            .cmp(&layer_b)
+            .then_with(|| {
+                deadline_rank(a, today, window_days).cmp(&deadline_rank(b, today, window_days))
+            })
+            .then_with(|| b.priority().cmp(&a.priority()))
+            .then_with(|| impact_b.cmp(&impact_a))
             .then_with(|| a.created_at().cmp(&b.created_at()))
     });
     ready
 }
 
+/// Split `targets` into those matching every filter criterion (order kept)
+/// and those that don't, each with the reason it was skipped.
+pub fn scope_targets_to_filter(
+    targets: &[String],
+    criteria: &[filter::FilterCriterion],
+    items: &[PgItem],
+) -> (Vec<String>, Vec<(String, String)>) {
+    let mut kept = Vec::new();
+    let mut skipped = Vec::new();
+    for target_id in targets {
+        match items.iter().find(|i| i.id() == target_id) {
+            None => skipped.push((target_id.clone(), "not in the backlog".to_string())),
+            Some(item) if criteria.iter().all(|c| filter::matches_item(c, item)) => {
+                kept.push(target_id.clone())
+            }
+            Some(_) => skipped.push((
+                target_id.clone(),
+                format!(
+                    "does not match filter {}",
+                    filter::format_filter_criteria(criteria)
+                ),
+            )),
+        }
+    }
+    (kept, skipped)
+}
+
+/// Sort key for deadline urgency: overdue items first (earliest deadline
+/// first), then those due within `window_days`, then everything else.
+fn deadline_rank(item: &PgItem, today: NaiveDate, window_days: u32) -> (u8, Option<NaiveDate>) {
+    match item.deadline() {
+        Some(deadline) if deadline < today => (0, Some(deadline)),
+        Some(deadline) if (deadline - today).num_days() <= i64::from(window_days) => {
+            (1, Some(deadline))
+        }
+        _ => (2, None),
+    }
+}
+
+/// Compute each item's topological layer among Ready items: the largest
+/// number of Ready items on any dependency path below it. Paths through
+/// non-Ready items still count, so an indirect dependency on a Ready item
+/// raises the layer. Cycles are cut by ignoring back edges.
+fn ready_dependency_layers(items: &[PgItem]) -> HashMap<String, usize> {
+    fn layer_of(
+        id: &str,
+        by_id: &HashMap<&str, &PgItem>,
+        layers: &mut HashMap<String, usize>,
+        visiting: &mut HashSet<String>,
+    ) -> usize {
+        if let Some(&layer) = layers.get(id) {
+            return layer;
+        }
+        let item = match by_id.get(id) {
+            Some(item) => *item,
+            None => return 0, // Absent = archived
+        };
+        if !visiting.insert(id.to_string()) {
+            return 0;
+        }
+        let mut layer = 0;
+        for dep_id in item.dependencies() {
+            let dep = match by_id.get(dep_id.as_str()) {
+                Some(dep) => *dep,
+                None => continue,
+            };
+            let ready_step = usize::from(dep.pg_status() == ItemStatus::Ready);
+            layer = layer.max(layer_of(dep_id, by_id, layers, visiting) + ready_step);
+        }
+        visiting.remove(id);
+        layers.insert(id.to_string(), layer);
+        layer
+    }
+
+    let by_id: HashMap<&str, &PgItem> = items.iter().map(|i| (i.id(), i)).collect();
+    let mut layers = HashMap::new();
+    let mut visiting = HashSet::new();
+    for item in items.iter().filter(|i| i.pg_status() == ItemStatus::Ready) {
+        layer_of(item.id(), &by_id, &mut layers, &mut visiting);
+    }
+    layers
+}
+
 /// Sort InProgress items by advance-furthest-first: higher phase index first,
 /// then created date asc (FIFO).
 fn sorted_in_progress_items<'a>(
     items: &'a [PgItem],
     pipelines: &HashMap<String, PipelineConfig>,
+    today: NaiveDate,
+    window_days: u32,
 ) -> Vec<&'a PgItem> {
     let mut in_progress: Vec<&PgItem> = items
         .iter()
@@ -312,8 +739,9 @@ fn sorted_in_progress_items<'a>(
     in_progress.sort_by(|a, b| {
         let idx_a = phase_index(a, pipelines);
         let idx_b = phase_index(b, pipelines);
-        idx_b
-            .cmp(&idx_a) // Higher index first (furthest-first)
+        deadline_rank(a, today, window_days)
+            .cmp(&deadline_rank(b, today, window_days))
+            .then_with(|| idx_b.cmp(&idx_a)) // Higher index first (furthest-first)
             .then_with(|| a.created_at().cmp(&b.created_at()))
     });
     in_progress
@@ -361,16 +789,13 @@ pub fn unmet_dep_summary(item: &PgItem, all_items: &[PgItem]) -> Option<String>
     if item.dependencies().is_empty() {
         return None;
     }
-    let unmet: Vec<String> = item
-        .dependencies()
-        .iter()
-        .filter_map(|dep_id| {
-            match all_items.iter().find(|i| i.id() == dep_id) {
-                Some(dep_item) if dep_item.pg_status() != ItemStatus::Done => {
-                    Some(format!("{} ({:?})", dep_id, dep_item.pg_status()))
-                }
-                _ => None, // Done or absent = met
+    let unmet: Vec<String> = resolve_dependencies(item, all_items)
+        .into_iter()
+        .filter_map(|(dep_id, dep_item)| match dep_item {
+            Some(dep_item) if dep_item.pg_status() != ItemStatus::Done => {
+                Some(format!("{} ({:?})", dep_id, dep_item.pg_status()))
             }
+            _ => None, // Done or absent = met
         })
         .collect();
     if unmet.is_empty() {
@@ -380,17 +805,28 @@ pub fn unmet_dep_summary(item: &PgItem, all_items: &[PgItem]) -> Option<String>
     }
 }
 
-/// Check and log if item has unmet dependencies. Returns true if unmet deps exist.
-fn skip_for_unmet_deps(item: &PgItem, all_items: &[PgItem]) -> bool {
-    if let Some(summary) = unmet_dep_summary(item, all_items) {
-        log_debug!(
-            "Item {} skipped: unmet dependencies: {}",
-            item.id(),
-            summary
-        );
-        return true;
-    }
-    false
+/// Pair each of `item`'s dependency IDs with the item it names in `all_items`,
+/// in declaration order. `None` means the dependency is absent from the
+/// snapshot, which the scheduler treats as archived.
+pub fn resolve_dependencies<'a>(
+    item: &'a PgItem,
+    all_items: &'a [PgItem],
+) -> Vec<(&'a str, Option<&'a PgItem>)> {
+    item.dependencies()
+        .iter()
+        .map(|dep_id| (dep_id.as_str(), all_items.iter().find(|i| i.id() == dep_id)))
+        .collect()
+}
+
+/// Check and log if item has unmet dependencies. Returns the summary if unmet deps exist.
+fn unmet_deps(item: &PgItem, all_items: &[PgItem]) -> Option<String> {
+    let summary = unmet_dep_summary(item, all_items)?;
+    log_debug!(
+        "Item {} skipped: unmet dependencies: {}",
+        item.id(),
+        summary
+    );
+    Some(summary)
 }
 
 /// Compute phase index for advance-furthest-first sorting.
@@ -540,11 +976,14 @@ pub fn advance_to_next_active_target(
 /// 6. Process results: apply transitions, commit, handle follow-ups
 /// 7. Batch commit non-destructive outputs
 /// 8. Loop until all done/blocked, cap reached, or shutdown
+///
+/// When `params.dry_run` is set, the loop is replaced by `simulate_run` and
+/// no agents are spawned and no backlog mutations are made.
 pub async fn run_scheduler(
     coordinator: CoordinatorHandle,
     runner: Arc<impl AgentRunner + 'static>,
     config: PhaseGolemConfig,
-    params: RunParams,
+    mut params: RunParams,
     cancel: CancellationToken,
 ) -> Result<RunSummary, String> {
     let mut state = SchedulerState {
@@ -553,9 +992,20 @@ pub async fn run_scheduler(
         consecutive_exhaustions: 0,
         items_completed: Vec::new(),
         items_blocked: Vec::new(),
+        items_failed_validation: Vec::new(),
         follow_ups_created: 0,
+        follow_ups_suppressed: 0,
         items_merged: 0,
+        merges: Vec::new(),
         current_target_index: 0,
+        action_rotation: 0,
+        events: params.events.clone(),
+        returned_to_new: HashSet::new(),
+        breaker_targets: params.targets.clone(),
+        circuit_breaker_threshold: config.execution.circuit_breaker_threshold,
+        event_log: None,
+        redact_keys: config.security.redact_keys.clone(),
+        dep_depth_warned: HashSet::new(),
     };
 
     let mut running = RunningTasks::new();
@@ -564,11 +1014,52 @@ pub async fn run_scheduler(
     let mut previous_summaries: HashMap<String, String> = HashMap::new();
 
     log_info!(
-        "Scheduler started (max_wip={}, max_concurrent={}).",
+        "Scheduler started (max_wip={}, max_concurrent={}, circuit_breaker_threshold={}).",
         config.execution.max_wip,
-        config.execution.max_concurrent
+        config.execution.max_concurrent,
+        match config.execution.circuit_breaker_threshold {
+            0 => "disabled".to_string(),
+            n => n.to_string(),
+        }
     );
 
+    // --target with --only: run only the targets that match the filter
+    if !params.targets.is_empty() && !params.filter.is_empty() {
+        let snapshot = coordinator.get_snapshot().await?;
+        let (kept, skipped) = scope_targets_to_filter(&params.targets, &params.filter, &snapshot);
+        for (target_id, reason) in &skipped {
+            log_info!("[target] {} skipped: {}", target_id, reason);
+        }
+        if kept.is_empty() {
+            log_info!(
+                "[target] No targets match filter criteria: {}",
+                filter::format_filter_criteria(&params.filter)
+            );
+            return Ok(finish_run(state, HaltReason::NoMatchingItems).await);
+        }
+        params.targets = kept;
+        params.filter.clear();
+        state.breaker_targets = params.targets.clone();
+    }
+
+    if params.dry_run {
+        let snapshot = coordinator.get_snapshot().await?;
+        return simulate_run(snapshot, &config, &params);
+    }
+    state.event_log = params.event_log.clone();
+
+    // A budget too large to represent as an Instant never elapses
+    let deadline = params
+        .max_duration
+        .and_then(|d| Instant::now().checked_add(d));
+    let parallel_targets = params.parallel_targets && !params.targets.is_empty();
+    // Whether the watch-mode idle line was logged for the current idle stretch
+    let mut watching = false;
+    // Last scheduling line, suppressed when unchanged under --compact-logs
+    let mut last_schedule: Option<String> = None;
+    // Loop iterations that reached action selection, numbered in `--trace` records
+    let mut iteration: u64 = 0;
+
     loop {
         if cancel.is_cancelled() {
             // Drain remaining tasks and commit before exiting
@@ -582,14 +1073,15 @@ pub async fn run_scheduler(
             )
             .await;
             let _ = coordinator.batch_commit().await;
-            return Ok(build_summary(state, HaltReason::ShutdownRequested));
+            return Ok(finish_run(state, HaltReason::ShutdownRequested).await);
         }
 
         if state.is_circuit_breaker_tripped() {
             log_warn!(
                 "Circuit breaker tripped: {} consecutive items exhausted retries",
-                CIRCUIT_BREAKER_THRESHOLD
+                state.circuit_breaker_threshold
             );
+            state.emit(SchedulerEvent::CircuitBreakerTripped);
             drain_join_set(
                 &mut join_set,
                 &mut running,
@@ -600,14 +1092,50 @@ pub async fn run_scheduler(
             )
             .await;
             let _ = coordinator.batch_commit().await;
-            return Ok(build_summary(state, HaltReason::CircuitBreakerTripped));
+            return Ok(finish_run(state, HaltReason::CircuitBreakerTripped).await);
+        }
+
+        if deadline.is_some_and(|d| Instant::now() >= d) {
+            log_info!(
+                "Max duration reached; waiting for {} in-flight phase(s) to finish",
+                join_set.len()
+            );
+            drain_join_set(
+                &mut join_set,
+                &mut running,
+                &mut state,
+                &coordinator,
+                &config,
+                &mut previous_summaries,
+            )
+            .await;
+            if let Err(e) = coordinator.batch_commit().await {
+                log_warn!("Warning: batch commit failed: {}", e);
+            }
+            return Ok(finish_run(state, HaltReason::DurationReached).await);
         }
 
         // Get current snapshot
         let snapshot = coordinator.get_snapshot().await?;
 
         // Check target completion/block (multi-target with cursor advancement)
-        if !params.targets.is_empty() {
+        if parallel_targets {
+            if let Some(halt_reason) =
+                parallel_targets_halt_reason(&params.targets, &snapshot, &state)
+            {
+                drain_join_set(
+                    &mut join_set,
+                    &mut running,
+                    &mut state,
+                    &coordinator,
+                    &config,
+                    &mut previous_summaries,
+                )
+                .await;
+                let _ = coordinator.batch_commit().await;
+                return Ok(finish_run(state, halt_reason).await);
+            }
+        } else if !params.targets.is_empty() {
             // Check if current target was blocked during this run (before advancement)
             if state.current_target_index < params.targets.len() {
                 let target_id = &params.targets[state.current_target_index];
@@ -649,7 +1177,7 @@ pub async fn run_scheduler(
                         )
                         .await;
                         let _ = coordinator.batch_commit().await;
-                        return Ok(build_summary(state, HaltReason::TargetBlocked));
+                        return Ok(finish_run(state, HaltReason::TargetBlocked).await);
                     }
                 }
             }
@@ -671,7 +1199,7 @@ pub async fn run_scheduler(
                 )
                 .await;
                 let _ = coordinator.batch_commit().await;
-                return Ok(build_summary(state, HaltReason::TargetCompleted));
+                return Ok(finish_run(state, HaltReason::TargetCompleted).await);
             }
         }
 
@@ -680,7 +1208,7 @@ pub async fn run_scheduler(
             let filtered = filter::apply_filters(&params.filter, &snapshot);
             let criteria_display = filter::format_filter_criteria(&params.filter);
             // Check halt conditions based on filter results
-            if filtered.is_empty() {
+            if filtered.is_empty() && params.watch.is_none() {
                 // Determine if no items match at all, or all matching are Done/Blocked/archived.
                 // Check both the current snapshot and items we've already completed/blocked
                 // (which may have been archived and removed from the snapshot).
@@ -704,7 +1232,7 @@ pub async fn run_scheduler(
                     )
                     .await;
                     let _ = coordinator.batch_commit().await;
-                    return Ok(build_summary(state, HaltReason::NoMatchingItems));
+                    return Ok(finish_run(state, HaltReason::NoMatchingItems).await);
                 } else {
                     log_info!(
                         "[filter] All items matching {} are done or blocked.",
@@ -720,14 +1248,14 @@ pub async fn run_scheduler(
                     )
                     .await;
                     let _ = coordinator.batch_commit().await;
-                    return Ok(build_summary(state, HaltReason::FilterExhausted));
+                    return Ok(finish_run(state, HaltReason::FilterExhausted).await);
                 }
             }
             // Check if all remaining filtered items are Done or Blocked
             let all_done_or_blocked = filtered
                 .iter()
                 .all(|i| matches!(i.pg_status(), ItemStatus::Done | ItemStatus::Blocked));
-            if all_done_or_blocked {
+            if all_done_or_blocked && params.watch.is_none() {
                 log_info!(
                     "[filter] All items matching {} are done or blocked.",
                     criteria_display
@@ -742,74 +1270,151 @@ pub async fn run_scheduler(
                 )
                 .await;
                 let _ = coordinator.batch_commit().await;
-                return Ok(build_summary(state, HaltReason::FilterExhausted));
+                return Ok(finish_run(state, HaltReason::FilterExhausted).await);
             }
             Some(filtered)
         } else {
             None
         };
 
-        // Select actions (three-way dispatch: targets, filter, normal)
-        let actions = if !params.targets.is_empty() {
-            select_targeted_actions(
+        // Select actions (dispatch: parallel targets, targets, filter, normal)
+        // Skip reasons are only tracked for untargeted runs
+        let (actions, skipped) = if parallel_targets {
+            let actions = select_parallel_target_actions(
                 &snapshot,
                 &running,
                 &config.execution,
                 &config.pipelines,
-                &params.targets[state.current_target_index],
-            )
+                &params.targets,
+            );
+            (actions, Vec::new())
+        } else if !params.targets.is_empty() {
+            let actions = select_targeted_actions(
+                &snapshot,
+                &running,
+                &config.execution,
+                &config.pipelines,
+                &effective_target_id(
+                    &snapshot,
+                    &params.targets[state.current_target_index],
+                    params.wait_deps,
+                    params.max_dep_depth,
+                    &mut state.dep_depth_warned,
+                ),
+            );
+            (actions, Vec::new())
         } else if let Some(ref filtered) = filtered_snapshot {
-            select_actions(filtered, &running, &config.execution, &config.pipelines)
+            select_actions_with_reasons(filtered, &running, &config.execution, &config.pipelines)
         } else {
-            select_actions(&snapshot, &running, &config.execution, &config.pipelines)
+            select_actions_with_reasons(&snapshot, &running, &config.execution, &config.pipelines)
         };
+        let selected: Vec<String> = actions.iter().map(describe_action).collect();
+        let actions = cap_iteration_actions(actions, &config, &mut state);
+        iteration += 1;
+        let mut trace = params.trace.as_ref().map(|_| {
+            let kept: Vec<String> = actions.iter().map(describe_action).collect();
+            TraceRecord::new(iteration, &snapshot, &running, &selected, &kept)
+        });
 
         if actions.is_empty() && running.is_empty() {
+            if let (Some(path), Some(record)) = (params.trace.as_deref(), trace.take()) {
+                append_trace(path, &record, &state.redact_keys);
+            }
             // Nothing to do and nothing running
-            // Log items blocked by unmet dependencies for diagnostics
-            let dep_blocked: Vec<String> = snapshot
-                .iter()
-                .filter(|i| i.pg_status() != ItemStatus::Done)
-                .filter_map(|i| {
-                    unmet_dep_summary(i, &snapshot)
-                        .map(|summary| format!("{} (waiting on: {})", i.id(), summary))
-                })
-                .collect();
-            if !dep_blocked.is_empty() {
-                log_info!(
-                    "Items blocked by unmet dependencies: {}",
-                    dep_blocked.join("; ")
+            if let Some(interval) = params.watch {
+                if state.is_cap_reached() {
+                    return Ok(finish_run(state, HaltReason::CapReached).await);
+                }
+                if !watching {
+                    log_info!(
+                        "No actionable items — watching for new items (polling every {}).",
+                        humantime::format_duration(interval)
+                    );
+                    watching = true;
+                }
+                // Items added with `tg add` show up on the next snapshot
+                tokio::select! {
+                    _ = coordinator.wait_for_state_change() => {}
+                    _ = tokio::time::sleep(interval) => {}
+                    _ = cancel.cancelled() => {}
+                }
+                continue;
+            }
+            if parallel_targets {
+                for target_id in &params.targets {
+                    log_target_unmet_deps(&snapshot, target_id, false);
+                }
+            } else if !params.targets.is_empty() {
+                log_target_unmet_deps(
+                    &snapshot,
+                    &params.targets[state.current_target_index],
+                    params.wait_deps,
                 );
             }
+            // Explain why each remaining item got no action
+            let stuck = stuck_item_lines(
+                filtered_snapshot.as_deref().unwrap_or(&snapshot),
+                &config.execution,
+                &config.pipelines,
+            );
+            if !stuck.is_empty() {
+                log_info!("Items not actionable:");
+                for line in &stuck {
+                    log_info!("  {}", line);
+                }
+            }
             log_info!("No actionable items — all done or blocked.");
-            return Ok(build_summary(state, HaltReason::AllDoneOrBlocked));
+            let halt_reason = idle_halt_reason(&snapshot, &state);
+            return Ok(finish_run(state, halt_reason).await);
         }
 
         if !actions.is_empty() {
-            let action_descriptions: Vec<String> = actions
-                .iter()
-                .map(|a| match a {
-                    SchedulerAction::Promote(id) => format!("promote {}", id),
-                    SchedulerAction::Triage(id) => format!("triage {}", id),
-                    SchedulerAction::RunPhase { item_id, phase, .. } => {
-                        format!("{} → {}", item_id, phase)
-                    }
-                })
-                .collect();
-            log_info!("\nScheduling: [{}]", action_descriptions.join(", "));
+            watching = false;
+            let action_descriptions: Vec<String> = actions.iter().map(describe_action).collect();
+            let schedule = format_schedule(&action_descriptions, &skipped);
+            if !(crate::log::compact_logs_enabled()
+                && last_schedule.as_deref() == Some(schedule.as_str()))
+            {
+                log_info!("\nScheduling: {}", schedule);
+            }
+            last_schedule = Some(schedule);
+            if let Some(path) = state.event_log.as_deref() {
+                append_event(
+                    path,
+                    &EventLogRecord::LogOnly(LogOnlyEvent::ActionsScheduled {
+                        actions: action_descriptions,
+                    }),
+                    &state.redact_keys,
+                );
+            }
         }
 
         // Process actions
-        for action in actions {
+        let mut pending = actions.into_iter();
+        while let Some(action) = pending.next() {
+            let description = describe_action(&action);
+            if state.is_cap_reached() && !matches!(action, SchedulerAction::Promote(_)) {
+                if let Some(ref mut record) = trace {
+                    record.skip(description, "phase cap reached");
+                    for rest in pending.by_ref() {
+                        record.skip(describe_action(&rest), "phase cap reached");
+                    }
+                }
+                break;
+            }
+            if let Some(ref mut record) = trace {
+                record.spawned.push(description);
+            }
             match action {
                 SchedulerAction::Promote(item_id) => {
                     handle_promote(&snapshot, &coordinator, &item_id, &config).await?;
                 }
                 SchedulerAction::Triage(item_id) => {
-                    if state.is_cap_reached() {
-                        break;
-                    }
                     state.phases_executed += 1;
+                    state.emit(SchedulerEvent::PhaseStarted {
+                        item_id: item_id.clone(),
+                        phase: "triage".to_string(),
+                    });
                     spawn_triage(
                         &mut join_set,
                         &mut running,
@@ -827,9 +1432,6 @@ pub async fn run_scheduler(
                     phase_pool,
                     is_destructive,
                 } => {
-                    if state.is_cap_reached() {
-                        break;
-                    }
                     state.phases_executed += 1;
 
                     log_info!(
@@ -847,6 +1449,10 @@ pub async fn run_scheduler(
                         state.phases_executed,
                         state.cap
                     );
+                    state.emit(SchedulerEvent::PhaseStarted {
+                        item_id: item_id.clone(),
+                        phase: phase.clone(),
+                    });
 
                     running.insert(
                         item_id.clone(),
@@ -864,6 +1470,7 @@ pub async fn run_scheduler(
                     let config_base = params.config_base.clone();
                     let prev_summary = previous_summaries.get(&item_id).cloned();
                     let cancel_clone = cancel.clone();
+                    let resume = params.resume;
 
                     join_set.spawn(async move {
                         // Get a fresh snapshot of the item for execution
@@ -891,6 +1498,17 @@ pub async fn run_scheduler(
                             }
                         };
 
+                        if resume {
+                            if let Some(resumed) = resumed_phase_result(&item, &phase) {
+                                log_info!(
+                                    "[{}][{}] Resuming: phase already completed, skipping agent",
+                                    item_id,
+                                    phase.to_uppercase()
+                                );
+                                return (item_id, PhaseExecutionResult::Success(resumed));
+                            }
+                        }
+
                         let pipeline_type_owned = item
                             .pipeline_type()
                             .unwrap_or_else(|| "feature".to_string());
@@ -926,6 +1544,16 @@ pub async fn run_scheduler(
                             }
                         };
 
+                        if let Some(skipped) = skipped_phase_result(&item, phase_config) {
+                            log_info!(
+                                "[{}][{}] {}",
+                                item_id,
+                                phase.to_uppercase(),
+                                skipped.summary
+                            );
+                            return (item_id, PhaseExecutionResult::Success(skipped));
+                        }
+
                         let result = executor::execute_phase(
                             &item,
                             phase_config,
@@ -945,12 +1573,16 @@ pub async fn run_scheduler(
             }
         }
 
+        if let (Some(path), Some(record)) = (params.trace.as_deref(), trace.take()) {
+            append_trace(path, &record, &state.redact_keys);
+        }
+
         // If cap is reached and all in-flight work is done, exit cleanly
         if state.is_cap_reached() && join_set.is_empty() {
             if let Err(e) = coordinator.batch_commit().await {
                 log_warn!("Warning: batch commit failed: {}", e);
             }
-            return Ok(build_summary(state, HaltReason::CapReached));
+            return Ok(finish_run(state, HaltReason::CapReached).await);
         }
 
         // Wait for at least one task completion (or timeout if nothing is running)
@@ -977,14 +1609,17 @@ pub async fn run_scheduler(
                 _ = cancel.cancelled() => {
                     drain_join_set(&mut join_set, &mut running, &mut state, &coordinator, &config, &mut previous_summaries).await;
                     let _ = coordinator.batch_commit().await;
-                    return Ok(build_summary(state, HaltReason::ShutdownRequested));
+                    return Ok(finish_run(state, HaltReason::ShutdownRequested).await);
                 }
             }
         } else if running.is_empty() {
-            // No tasks running and nothing to spawn — check if we need to wait
-            // for promotions to take effect (next loop iteration will see updated snapshot)
-            // Give coordinator a moment to process updates
-            tokio::time::sleep(Duration::from_millis(10)).await;
+            // No tasks running and nothing to spawn (e.g. only promotions this pass).
+            // Wait for the next store change, bounded by execution.idle_poll_ms.
+            tokio::select! {
+                _ = coordinator.wait_for_state_change() => {}
+                _ = tokio::time::sleep(Duration::from_millis(config.execution.idle_poll_ms)) => {}
+                _ = cancel.cancelled() => {}
+            }
         }
 
         // Batch commit non-destructive outputs
@@ -996,6 +1631,52 @@ pub async fn run_scheduler(
 
 // --- Targeted selection ---
 
+/// `select_actions` restricted to the `targets` set (`run --parallel-targets`).
+///
+/// Dependencies are checked against the whole backlog, so a target waiting on
+/// an item outside the set is not scheduled.
+pub fn select_parallel_target_actions(
+    items: &[PgItem],
+    running: &RunningTasks,
+    config: &ExecutionConfig,
+    pipelines: &HashMap<String, PipelineConfig>,
+    targets: &[String],
+) -> Vec<SchedulerAction> {
+    let target_items: Vec<PgItem> = items
+        .iter()
+        .filter(|item| targets.iter().any(|t| t == item.id()))
+        .filter(|item| unmet_dep_summary(item, items).is_none())
+        .cloned()
+        .collect();
+    select_actions(&target_items, running, config, pipelines)
+}
+
+/// Halt check for `--parallel-targets`: `None` while any target is still
+/// open (not Done, Blocked, or archived). Reports `TargetBlocked` only when
+/// targets blocked during the run and none completed.
+fn parallel_targets_halt_reason(
+    targets: &[String],
+    snapshot: &[PgItem],
+    state: &SchedulerState,
+) -> Option<HaltReason> {
+    let any_open = targets.iter().any(|id| {
+        snapshot.iter().any(|item| {
+            item.id() == id && !matches!(item.pg_status(), ItemStatus::Done | ItemStatus::Blocked)
+        })
+    });
+    if any_open {
+        return None;
+    }
+
+    let any_completed = targets.iter().any(|id| state.items_completed.contains(id));
+    let any_blocked = targets.iter().any(|id| state.items_blocked.contains(id));
+    Some(if any_blocked && !any_completed {
+        HaltReason::TargetBlocked
+    } else {
+        HaltReason::TargetCompleted
+    })
+}
+
 /// Like `select_actions` but restricted to a specific target item.
 pub fn select_targeted_actions(
     items: &[PgItem],
@@ -1011,7 +1692,7 @@ pub fn select_targeted_actions(
     };
 
     // If target has unmet dependencies, skip it
-    if skip_for_unmet_deps(target, items) {
+    if unmet_deps(target, items).is_some() {
         return Vec::new();
     }
 
@@ -1053,17 +1734,200 @@ pub fn select_targeted_actions(
     actions
 }
 
-// --- Task completion handling ---
+/// Find the first dependency that must run before `target_id` can proceed.
+///
+/// Walks unmet dependencies depth-first in declaration order and returns the
+/// first one whose own dependencies are all met. Blocked dependencies are
+/// skipped (the scheduler cannot make progress on them), and cycles are cut
+/// by visiting each item at most once. Direct dependencies are depth 1; with
+/// `max_depth` set, dependencies deeper than the limit are not expanded.
+/// Returns `None` if the target has no unmet dependencies or none of them are
+/// runnable within the limit.
+pub fn next_runnable_dependency(
+    items: &[PgItem],
+    target_id: &str,
+    max_depth: Option<u32>,
+) -> Option<String> {
+    find_runnable_dependency(items, target_id, max_depth).0
+}
 
-/// Handle the result of a completed executor task.
-async fn handle_task_completion(
-    item_id: &str,
-    exec_result: PhaseExecutionResult,
-    coordinator: &CoordinatorHandle,
-    config: &PhaseGolemConfig,
-    state: &mut SchedulerState,
-    previous_summaries: &mut HashMap<String, String>,
-) -> Result<(), String> {
+/// `next_runnable_dependency`, plus whether `max_depth` cut off any unmet
+/// dependency that was left unexpanded.
+fn find_runnable_dependency(
+    items: &[PgItem],
+    target_id: &str,
+    max_depth: Option<u32>,
+) -> (Option<String>, bool) {
+    fn visit(
+        items: &[PgItem],
+        id: &str,
+        depth: u32,
+        max_depth: Option<u32>,
+        visited: &mut HashSet<String>,
+        truncated: &mut bool,
+    ) -> Option<String> {
+        let item = items.iter().find(|i| i.id() == id)?;
+        for dep_id in item.dependencies() {
+            if !visited.insert(dep_id.clone()) {
+                continue;
+            }
+            let dep = match items.iter().find(|i| i.id() == dep_id) {
+                Some(dep) => dep,
+                None => continue, // Absent = met
+            };
+            match dep.pg_status() {
+                ItemStatus::Done | ItemStatus::Blocked => continue,
+                _ => {}
+            }
+            if max_depth.is_some_and(|max| depth > max) {
+                *truncated = true;
+                continue;
+            }
+            if unmet_dep_summary(dep, items).is_none() {
+                return Some(dep_id.clone());
+            }
+            if let Some(found) = visit(items, dep_id, depth + 1, max_depth, visited, truncated) {
+                return Some(found);
+            }
+        }
+        None
+    }
+
+    let mut visited = HashSet::new();
+    visited.insert(target_id.to_string());
+    let mut truncated = false;
+    let found = visit(items, target_id, 1, max_depth, &mut visited, &mut truncated);
+    (found, truncated)
+}
+
+/// Build a synthetic `PhaseComplete` result for a phase the item already finished.
+///
+/// Used by `run --resume`: returns `Some` only when `phase` is in the item's
+/// persisted completed-phase list. That list is kept in the store rather than
+/// inferred from git history because `SubphaseComplete` commits share the same
+/// `[item][phase]` prefix as final ones; only `PhaseComplete` records a phase.
+pub fn resumed_phase_result(item: &PgItem, phase: &str) -> Option<PhaseResult> {
+    if !item.completed_phases().iter().any(|p| p == phase) {
+        return None;
+    }
+    Some(PhaseResult {
+        item_id: item.id().to_string(),
+        phase: phase.to_string(),
+        result: ResultCode::PhaseComplete,
+        summary: "Resumed from previously completed output".to_string(),
+        context: None,
+        updated_assessments: None,
+        follow_ups: Vec::new(),
+        based_on_commit: item.last_phase_commit(),
+        pipeline_type: None,
+        commit_summary: None,
+        duplicates: Vec::new(),
+        description: None,
+        confidence: None,
+    })
+}
+
+/// A synthetic PHASE_COMPLETE for a phase whose `skip_if` matches `item`, so
+/// the item advances without running the agent. The summary, which lands in
+/// the worklog, names the matching expression.
+pub fn skipped_phase_result(item: &PgItem, phase_config: &PhaseConfig) -> Option<PhaseResult> {
+    let expr = phase_config.skip_if.as_deref()?;
+    // Validated when the config is loaded
+    let criterion = filter::parse_filter(expr).ok()?;
+    if !filter::matches_item(&criterion, item) {
+        return None;
+    }
+    Some(PhaseResult {
+        item_id: item.id().to_string(),
+        phase: phase_config.name.clone(),
+        result: ResultCode::PhaseComplete,
+        summary: format!("Skipped: item matches skip_if \"{}\"", expr),
+        context: None,
+        updated_assessments: None,
+        follow_ups: Vec::new(),
+        based_on_commit: item.last_phase_commit(),
+        pipeline_type: None,
+        commit_summary: None,
+        duplicates: Vec::new(),
+        description: None,
+        confidence: None,
+    })
+}
+
+/// Resolve which item targeted selection should act on this iteration.
+///
+/// Normally this is the target itself. With `wait_deps`, a target whose
+/// dependencies are unmet is swapped for its next runnable dependency, so the
+/// run works through the dependency chain before returning to the target.
+/// When `max_dep_depth` leaves nothing runnable, the target is warned about
+/// once and recorded in `depth_warned`, since this runs every iteration.
+pub fn effective_target_id(
+    items: &[PgItem],
+    target_id: &str,
+    wait_deps: bool,
+    max_dep_depth: Option<u32>,
+    depth_warned: &mut HashSet<String>,
+) -> String {
+    if wait_deps {
+        let has_unmet = items
+            .iter()
+            .find(|i| i.id() == target_id)
+            .is_some_and(|target| unmet_dep_summary(target, items).is_some());
+        if has_unmet {
+            let (found, truncated) = find_runnable_dependency(items, target_id, max_dep_depth);
+            if found.is_none() && truncated && depth_warned.insert(target_id.to_string()) {
+                log_warn!(
+                    "[target] {} has unmet dependencies beyond --max-dep-depth {}; not expanding further",
+                    target_id,
+                    max_dep_depth.unwrap_or_default()
+                );
+            }
+            if let Some(dep_id) = found {
+                log_debug!(
+                    "[target] {} waiting on dependencies; running {} first",
+                    target_id,
+                    dep_id
+                );
+                return dep_id;
+            }
+        }
+    }
+    target_id.to_string()
+}
+
+/// Log why a target with unmet dependencies produced no work.
+fn log_target_unmet_deps(items: &[PgItem], target_id: &str, wait_deps: bool) {
+    let Some(target) = items.iter().find(|i| i.id() == target_id) else {
+        return;
+    };
+    if let Some(summary) = unmet_dep_summary(target, items) {
+        if wait_deps {
+            log_info!(
+                "[target] {} waiting on unmet dependencies with nothing runnable: {}",
+                target_id,
+                summary
+            );
+        } else {
+            log_info!(
+                "[target] {} has unmet dependencies: {}. Re-run with --wait-deps to process them first.",
+                target_id,
+                summary
+            );
+        }
+    }
+}
+
+// --- Task completion handling ---
+
+/// Handle the result of a completed executor task.
+async fn handle_task_completion(
+    item_id: &str,
+    exec_result: PhaseExecutionResult,
+    coordinator: &CoordinatorHandle,
+    config: &PhaseGolemConfig,
+    state: &mut SchedulerState,
+    previous_summaries: &mut HashMap<String, String>,
+) -> Result<(), String> {
     // Snapshot freshness contract:
     // - Handlers that read the backlog before mutating (subphase_complete, failed,
     //   blocked, cancelled) use the pre-fetched snapshot passed by reference.
@@ -1126,6 +1990,7 @@ async fn handle_task_completion(
                 item_id,
                 &reason,
                 coordinator,
+                config,
                 state,
                 previous_summaries,
             )
@@ -1170,13 +2035,69 @@ async fn handle_phase_success(
     let phase = phase_result.phase.clone();
     let summary = phase_result.summary.clone();
 
+    let result_label = if phase_result.result == ResultCode::Failed {
+        "FAILED (validation)"
+    } else {
+        "PHASE_COMPLETE"
+    };
     log_info!(
-        "[{}][{}] Result: PHASE_COMPLETE — {}",
+        "[{}][{}] Result: {} — {}",
         item_id,
         phase.to_uppercase(),
+        result_label,
         summary
     );
 
+    // Output derived from a commit HEAD no longer contains (e.g. history was
+    // rewritten by a concurrent run mid-phase) must not be committed on top of it
+    if let Some(ref sha) = phase_result.based_on_commit {
+        let stale_reason = match coordinator.is_ancestor(sha).await {
+            Ok(true) => None,
+            Ok(false) => Some(format!(
+                "Stale — rebase/rerun: {} output is based on commit {}, which is no longer an ancestor of HEAD",
+                phase, sha
+            )),
+            // Unknown commit or git error — block, as check_staleness does
+            Err(e) => Some(format!(
+                "Stale — rebase/rerun: could not check commit {} against HEAD: {}",
+                sha, e
+            )),
+        };
+        if let Some(reason) = stale_reason {
+            log_info!("[{}] Blocked: {}", item_id, reason);
+            let snapshot = coordinator.get_snapshot().await?;
+            if let Some(item) = snapshot.iter().find(|i| i.id() == item_id) {
+                let _ = coordinator
+                    .write_worklog(item.id(), item.title(), &phase, "Blocked", &reason)
+                    .await;
+            }
+            coordinator
+                .update_item(item_id, ItemUpdate::SetBlocked(reason.clone()))
+                .await?;
+            state.record_blocked(item_id, &reason);
+            cleanup_terminal_summary(item_id, previous_summaries);
+            return Ok(());
+        }
+    }
+
+    // The executor only passes a Failed result through for validation phases
+    if phase_result.result == ResultCode::Failed {
+        return handle_validation_failed(
+            item_id,
+            &phase_result,
+            coordinator,
+            config,
+            state,
+            previous_summaries,
+        )
+        .await;
+    }
+
+    state.emit(SchedulerEvent::PhaseCompleted {
+        item_id: item_id.to_string(),
+        phase: phase.clone(),
+    });
+
     // Apply assessment updates
     if let Some(ref assessments) = phase_result.updated_assessments {
         coordinator
@@ -1185,8 +2106,8 @@ async fn handle_phase_success(
     }
 
     // Ingest follow-ups
-    let fu_count = ingest_follow_ups(coordinator, &phase_result, config).await;
-    state.follow_ups_created += fu_count;
+    let (fu_count, fu_suppressed) = ingest_follow_ups(coordinator, &phase_result, config).await;
+    state.record_follow_ups(item_id, fu_count, fu_suppressed);
     if fu_count > 0 {
         log_info!("Follow-ups: {} new items added to backlog", fu_count);
     }
@@ -1214,36 +2135,56 @@ async fn handle_phase_success(
         .chain(pipeline.phases.iter())
         .find(|p| p.name == phase);
     let is_destructive = phase_config.map(|pc| pc.is_destructive).unwrap_or(false);
+    let is_checkpoint = phase_config.is_some_and(|pc| pc.checkpoint);
+
+    // Resolve transitions
+    let updates = executor::resolve_transition(item, &phase_result, pipeline, &config.guardrails);
+
+    // Record the phase before committing so the marker lands with its output.
+    // Output that blocks the item (follow-up or confidence gate) must be
+    // re-run, so `--resume` may not reuse it.
+    if !updates
+        .iter()
+        .any(|u| matches!(u, ItemUpdate::SetBlocked(_)))
+    {
+        coordinator
+            .update_item(item_id, ItemUpdate::RecordPhaseCompleted(phase.clone()))
+            .await?;
+    }
 
     // Write worklog entry
-    let _ = coordinator
-        .write_worklog(item.id(), item.title(), &phase, "Complete", &summary)
-        .await;
+    write_result_worklog(coordinator, config, item, "Complete", &phase_result).await;
 
     // Complete phase (stage + commit for destructive, stage for non-destructive)
     coordinator
         .complete_phase(item_id, phase_result.clone(), is_destructive)
         .await?;
 
-    // Resolve transitions
-    let updates = executor::resolve_transition(item, &phase_result, pipeline, &config.guardrails);
+    if let Some(warning) = executor::guardrail_warning(item, &updates, &config.guardrails) {
+        log_warn!("{}", warning);
+    }
     let mut is_terminal = false;
     for update in updates {
         match &update {
             ItemUpdate::TransitionStatus(ItemStatus::Done) => {
                 is_terminal = true;
                 coordinator.update_item(item_id, update).await?;
-                // Archive the item
-                coordinator.archive_item(item_id).await?;
-                state.items_completed.push(item_id.to_string());
+                if config.execution.archive_on_done {
+                    coordinator.archive_item(item_id).await?;
+                }
+                state.record_completed(item_id);
                 state.consecutive_exhaustions = 0;
-                log_info!("{} completed and archived", item_id);
+                if config.execution.archive_on_done {
+                    log_info!("{} completed and archived", item_id);
+                } else {
+                    log_info!("{} completed", item_id);
+                }
             }
             ItemUpdate::SetBlocked(reason) => {
                 is_terminal = true;
                 log_info!("[{}] Blocked: {}", item_id, reason);
+                state.record_blocked(item_id, reason);
                 coordinator.update_item(item_id, update).await?;
-                state.items_blocked.push(item_id.to_string());
             }
             _ => {
                 coordinator.update_item(item_id, update).await?;
@@ -1251,6 +2192,13 @@ async fn handle_phase_success(
         }
     }
 
+    // Checkpoint after transitions so the commit captures the item's new state
+    if is_checkpoint {
+        if let Err(e) = coordinator.commit_checkpoint(item_id, &phase).await {
+            log_warn!("[{}][{}] Checkpoint commit failed: {}", item_id, phase, e);
+        }
+    }
+
     if is_terminal {
         cleanup_terminal_summary(item_id, previous_summaries);
     } else {
@@ -1266,6 +2214,45 @@ async fn handle_phase_success(
     Ok(())
 }
 
+/// A validation phase's `Failed` verdict: nothing is recorded as completed,
+/// and the item is blocked and tagged per `resolve_transition`.
+async fn handle_validation_failed(
+    item_id: &str,
+    phase_result: &PhaseResult,
+    coordinator: &CoordinatorHandle,
+    config: &PhaseGolemConfig,
+    state: &mut SchedulerState,
+    previous_summaries: &mut HashMap<String, String>,
+) -> Result<(), String> {
+    let snapshot = coordinator.get_snapshot().await?;
+    let item = snapshot
+        .iter()
+        .find(|i| i.id() == item_id)
+        .ok_or_else(|| format!("Item {} not found after validation", item_id))?;
+    let pipeline_type = item
+        .pipeline_type()
+        .unwrap_or_else(|| "feature".to_string());
+    let pipeline = config
+        .pipelines
+        .get(&pipeline_type)
+        .ok_or_else(|| format!("Pipeline '{}' not found", pipeline_type))?;
+
+    write_result_worklog(coordinator, config, item, "Validation Failed", phase_result).await;
+
+    let updates = executor::resolve_transition(item, phase_result, pipeline, &config.guardrails);
+    for update in updates {
+        if let ItemUpdate::SetBlocked(ref reason) = update {
+            log_info!("[{}] Blocked: {}", item_id, reason);
+            state.record_blocked(item_id, reason);
+            state.items_failed_validation.push(item_id.to_string());
+        }
+        coordinator.update_item(item_id, update).await?;
+    }
+
+    cleanup_terminal_summary(item_id, previous_summaries);
+    Ok(())
+}
+
 async fn handle_subphase_complete(
     snapshot: &[PgItem],
     item_id: &str,
@@ -1287,15 +2274,14 @@ async fn handle_subphase_complete(
 
     // Write worklog entry
     if let Some(item) = snapshot.iter().find(|i| i.id() == item_id) {
-        let _ = coordinator
-            .write_worklog(
-                item.id(),
-                item.title(),
-                &phase,
-                "Subphase Complete",
-                &summary,
-            )
-            .await;
+        write_result_worklog(
+            coordinator,
+            config,
+            item,
+            "Subphase Complete",
+            &phase_result,
+        )
+        .await;
     }
 
     // Apply assessment updates
@@ -1306,8 +2292,8 @@ async fn handle_subphase_complete(
     }
 
     // Ingest follow-ups
-    let fu_count = ingest_follow_ups(coordinator, &phase_result, config).await;
-    state.follow_ups_created += fu_count;
+    let (fu_count, fu_suppressed) = ingest_follow_ups(coordinator, &phase_result, config).await;
+    state.record_follow_ups(item_id, fu_count, fu_suppressed);
 
     // Complete phase (commit subphase output)
     coordinator
@@ -1349,8 +2335,8 @@ async fn handle_phase_failed(
         .update_item(item_id, ItemUpdate::SetBlocked(reason.to_string()))
         .await?;
 
-    state.items_blocked.push(item_id.to_string());
-    state.consecutive_exhaustions += 1;
+    state.record_blocked(item_id, reason);
+    state.record_exhaustion(item_id);
 
     cleanup_terminal_summary(item_id, previous_summaries);
     Ok(())
@@ -1361,13 +2347,42 @@ async fn handle_phase_blocked(
     item_id: &str,
     reason: &str,
     coordinator: &CoordinatorHandle,
+    config: &PhaseGolemConfig,
     state: &mut SchedulerState,
     previous_summaries: &mut HashMap<String, String>,
 ) -> Result<(), String> {
     log_info!("[{}] Phase blocked: {}", item_id, reason);
 
+    let item = snapshot.iter().find(|i| i.id() == item_id);
+    let in_pre_phase = item.is_some_and(|i| i.phase_pool() == Some(PhasePool::Pre));
+    // Returning an item that already came back once this run would loop
+    // triage and scoping until the cap
+    let return_to_new = in_pre_phase
+        && config.execution.on_prephase_block == PrePhaseBlockPolicy::ReturnToNew
+        && !state.returned_to_new.contains(item_id);
+
+    if return_to_new {
+        log_info!(
+            "[{}] Returning to New for re-triage (execution.on_prephase_block = return_to_new)",
+            item_id
+        );
+        if let Some(item) = item {
+            let phase = item.phase().unwrap_or_else(|| "unknown".to_string());
+            let _ = coordinator
+                .write_worklog(item.id(), item.title(), &phase, "Returned to New", reason)
+                .await;
+        }
+        coordinator
+            .update_item(item_id, ItemUpdate::ReturnToNew)
+            .await?;
+        state.returned_to_new.insert(item_id.to_string());
+        state.consecutive_exhaustions = 0;
+        cleanup_terminal_summary(item_id, previous_summaries);
+        return Ok(());
+    }
+
     // Write worklog entry
-    if let Some(item) = snapshot.iter().find(|i| i.id() == item_id) {
+    if let Some(item) = item {
         let phase = item.phase().unwrap_or_else(|| "unknown".to_string());
         let _ = coordinator
             .write_worklog(item.id(), item.title(), &phase, "Blocked", reason)
@@ -1378,7 +2393,7 @@ async fn handle_phase_blocked(
         .update_item(item_id, ItemUpdate::SetBlocked(reason.to_string()))
         .await?;
 
-    state.items_blocked.push(item_id.to_string());
+    state.record_blocked(item_id, reason);
     state.consecutive_exhaustions = 0;
 
     cleanup_terminal_summary(item_id, previous_summaries);
@@ -1468,6 +2483,9 @@ async fn process_merges(
                     target_id
                 );
                 state.items_merged += 1;
+                state
+                    .merges
+                    .push((source_id.to_string(), target_id.to_string()));
 
                 // If current item was merged away, signal caller to stop processing
                 if source_id == item_id {
@@ -1517,20 +2535,12 @@ async fn handle_triage_success(
             ResultCode::Blocked => "Blocked",
             ResultCode::SubphaseComplete => "Subphase Complete",
         };
-        let _ = coordinator
-            .write_worklog(
-                item.id(),
-                item.title(),
-                "triage",
-                outcome,
-                &phase_result.summary,
-            )
-            .await;
+        write_result_worklog(coordinator, config, item, outcome, phase_result).await;
     }
 
     // Ingest follow-ups from triage
-    let fu_count = ingest_follow_ups(coordinator, phase_result, config).await;
-    state.follow_ups_created += fu_count;
+    let (fu_count, fu_suppressed) = ingest_follow_ups(coordinator, phase_result, config).await;
+    state.record_follow_ups(item_id, fu_count, fu_suppressed);
 
     // Process duplicate merges before committing
     let is_merged = process_merges(item_id, &phase_result.duplicates, coordinator, state).await?;
@@ -1555,13 +2565,43 @@ async fn handle_triage_success(
     let triage_snap = coordinator.get_snapshot().await?;
     if let Some(item) = triage_snap.iter().find(|i| i.id() == item_id) {
         if item.pg_status() == ItemStatus::Blocked {
-            state.items_blocked.push(item_id.to_string());
+            let reason = item.0.blocked_reason.clone().unwrap_or_default();
+            state.record_blocked(item_id, &reason);
         }
     }
 
     Ok(())
 }
 
+// --- Worklog ---
+
+/// Write the worklog entry for a phase result. When
+/// `execution.worklog_include_full_result` is set, the serialized result is
+/// also recorded in the JSONL audit log. Worklog failures are non-fatal.
+async fn write_result_worklog(
+    coordinator: &CoordinatorHandle,
+    config: &PhaseGolemConfig,
+    item: &PgItem,
+    outcome: &str,
+    result: &PhaseResult,
+) {
+    let _ = if config.execution.worklog_include_full_result {
+        coordinator
+            .write_worklog_with_result(item.id(), item.title(), outcome, result)
+            .await
+    } else {
+        coordinator
+            .write_worklog(
+                item.id(),
+                item.title(),
+                &result.phase,
+                outcome,
+                &result.summary,
+            )
+            .await
+    };
+}
+
 // --- Promotion ---
 
 async fn handle_promote(
@@ -1676,171 +2716,828 @@ async fn spawn_triage(
 
 // --- Triage result handling ---
 
-pub async fn apply_triage_result(
+/// Triage every `New` item, up to `execution.max_concurrent` at a time,
+/// applying each result's routing.
+///
+/// Agents run concurrently; store mutations still go through the coordinator,
+/// which serializes them. An agent error (or a failure applying its result) is
+/// logged and only that item skipped. After `execution.triage_failure_threshold`
+/// consecutive agent errors no more triages start; in-flight ones finish and an
+/// error is returned, since a systemic agent problem would otherwise fail every
+/// item. Returns the number of items triaged.
+pub async fn triage_new_items(
     coordinator: &CoordinatorHandle,
-    item_id: &str,
-    result: &PhaseResult,
+    runner: Arc<impl AgentRunner + 'static>,
     config: &PhaseGolemConfig,
-) -> Result<(), String> {
-    // Apply assessment updates
-    if let Some(ref assessments) = result.updated_assessments {
-        coordinator
-            .update_item(item_id, ItemUpdate::UpdateAssessments(assessments.clone()))
-            .await?;
-    }
-
-    // Apply structured description if provided and non-empty
-    if let Some(ref description) = result.description {
-        if !description.is_empty() {
-            coordinator
-                .update_item(item_id, ItemUpdate::SetDescription(description.clone()))
-                .await?;
-        }
-    }
+    root: &Path,
+    cancel: &CancellationToken,
+) -> Result<u32, String> {
+    let new_ids: Vec<String> = coordinator
+        .get_snapshot()
+        .await?
+        .iter()
+        .filter(|item| item.pg_status() == ItemStatus::New)
+        .map(|item| item.id().to_string())
+        .collect();
+    triage_items(coordinator, runner, config, root, cancel, new_ids).await
+}
 
-    // Apply pipeline_type if provided
-    if let Some(ref pipeline_type) = result.pipeline_type {
-        // Validate pipeline type exists
-        if config.pipelines.contains_key(pipeline_type) {
-            coordinator
-                .update_item(item_id, ItemUpdate::SetPipelineType(pipeline_type.clone()))
-                .await?;
-        } else {
-            // Invalid pipeline type — block
-            coordinator
-                .update_item(
-                    item_id,
-                    ItemUpdate::SetBlocked(format!(
-                        "Triage assigned invalid pipeline type '{}'. Available: {}",
-                        pipeline_type,
-                        config
-                            .pipelines
-                            .keys()
-                            .cloned()
-                            .collect::<Vec<_>>()
-                            .join(", ")
-                    )),
-                )
-                .await?;
-            return Ok(());
-        }
-    }
+/// Triage the given items whatever their status (`triage --target`).
+///
+/// A successful triage of an item that is no longer New first returns it to
+/// New (unblocking it if needed), so routing starts over from scratch: its
+/// phase and completed-phase record are cleared.
+pub async fn triage_items(
+    coordinator: &CoordinatorHandle,
+    runner: Arc<impl AgentRunner + 'static>,
+    config: &PhaseGolemConfig,
+    root: &Path,
+    cancel: &CancellationToken,
+    item_ids: Vec<String>,
+) -> Result<u32, String> {
+    let mut pending: VecDeque<String> = item_ids.into();
+
+    let timeout = Duration::from_secs(config.execution.phase_timeout_minutes as u64 * 60);
+    let threshold = config.execution.triage_failure_threshold;
+    let max_concurrent = config.execution.max_concurrent.max(1) as usize;
+    let mut triaged_count = 0u32;
+    let mut consecutive_failures = 0u32;
+    let mut last_error: Option<String> = None;
+    let mut join_set: JoinSet<(String, Result<PhaseResult, String>)> = JoinSet::new();
 
-    match result.result {
-        ResultCode::PhaseComplete => {
-            // Get current item state to check routing
-            let route_snap = coordinator.get_snapshot().await?;
-            let item = route_snap
-                .iter()
-                .find(|i| i.id() == item_id)
-                .ok_or_else(|| format!("Item {} not found after triage", item_id))?;
-
-            let is_small_low_risk = matches!(item.size(), Some(SizeLevel::Small))
-                && matches!(item.risk(), Some(DimensionLevel::Low) | None);
-
-            let pipeline_type_owned = item
-                .pipeline_type()
-                .unwrap_or_else(|| "feature".to_string());
-            let pipeline_type = pipeline_type_owned.as_str();
-            let pipeline = config.pipelines.get(pipeline_type);
-            let has_pre_phases = pipeline.map(|p| !p.pre_phases.is_empty()).unwrap_or(false);
-
-            if is_small_low_risk || !has_pre_phases {
-                // Small+low-risk or no pre_phases: promote to Scoping then Ready
-                coordinator
-                    .update_item(item_id, ItemUpdate::TransitionStatus(ItemStatus::Scoping))
-                    .await?;
-                coordinator
-                    .update_item(item_id, ItemUpdate::TransitionStatus(ItemStatus::Ready))
-                    .await?;
-            } else {
-                // Needs pre-phases: transition to Scoping and set first pre_phase
-                coordinator
-                    .update_item(item_id, ItemUpdate::TransitionStatus(ItemStatus::Scoping))
-                    .await?;
-
-                if let Some(p) = pipeline {
-                    if let Some(first_pre) = p.pre_phases.first() {
-                        coordinator
-                            .update_item(item_id, ItemUpdate::SetPhase(first_pre.name.clone()))
-                            .await?;
-                        coordinator
-                            .update_item(item_id, ItemUpdate::SetPhasePool(PhasePool::Pre))
-                            .await?;
-                    }
-                }
+    loop {
+        // Spawn up to the concurrency limit, re-checking for shutdown between spawns
+        while join_set.len() < max_concurrent && consecutive_failures < threshold {
+            if cancel.is_cancelled() {
+                break;
             }
-        }
-        ResultCode::Blocked => {
-            let reason = result
-                .context
-                .as_deref()
-                .unwrap_or(&result.summary)
-                .to_string();
-            coordinator
-                .update_item(item_id, ItemUpdate::SetBlocked(reason))
-                .await?;
-        }
-        _ => {
-            // Failed or SubphaseComplete — stay in New
-        }
-    }
+            let Some(item_id) = pending.pop_front() else {
+                break;
+            };
 
-    Ok(())
-}
+            log_info!("[{}][TRIAGE] Starting triage", item_id);
 
-// --- Follow-up ingestion ---
+            let result_path = executor::result_file_path(root, &item_id, "triage");
+            let current_snapshot = coordinator.get_snapshot().await?;
+            let Some(item) = current_snapshot.iter().find(|i| i.id() == item_id) else {
+                log_warn!("[{}][TRIAGE] Item not found; skipping", item_id);
+                continue;
+            };
 
-async fn ingest_follow_ups(
-    coordinator: &CoordinatorHandle,
-    result: &PhaseResult,
-    _config: &PhaseGolemConfig,
-) -> u32 {
-    if result.follow_ups.is_empty() {
-        return 0;
-    }
+            let backlog_summary = prompt::build_backlog_summary(&current_snapshot, &item_id);
+            let triage_prompt = prompt::build_triage_prompt(
+                item,
+                &result_path,
+                &config.pipelines,
+                backlog_summary.as_deref(),
+            );
 
-    let origin = format!("{}/{}", result.item_id, result.phase);
-    match coordinator
-        .ingest_follow_ups(result.follow_ups.clone(), &origin)
-        .await
+            let runner = Arc::clone(&runner);
+            join_set.spawn(async move {
+                let result = runner
+                    .run_agent(&triage_prompt, &result_path, timeout)
+                    .await;
+                (item_id, result)
+            });
+        }
+
+        let Some(joined) = join_set.join_next().await else {
+            break;
+        };
+        let (item_id, result) = match joined {
+            Ok(outcome) => outcome,
+            Err(e) => {
+                log_error!("[TRIAGE] Triage task panicked: {}", e);
+                continue;
+            }
+        };
+
+        match result {
+            Ok(phase_result) => {
+                consecutive_failures = 0;
+
+                // Stage and commit triage output (immediate commit via destructive flag),
+                // then apply triage routing
+                let applied = match coordinator
+                    .complete_phase(&item_id, phase_result.clone(), true)
+                    .await
+                {
+                    Ok(_) => match reset_for_retriage(coordinator, &item_id, &phase_result).await {
+                        Ok(()) => {
+                            apply_triage_result(coordinator, &item_id, &phase_result, config).await
+                        }
+                        Err(e) => Err(e),
+                    },
+                    Err(e) => Err(e.to_string()),
+                };
+                if let Err(e) = applied {
+                    log_error!("[{}][TRIAGE] Failed to apply result: {}", item_id, e);
+                    continue;
+                }
+
+                log_info!(
+                    "[{}][TRIAGE] Result: {:?} -- {}",
+                    item_id,
+                    phase_result.result,
+                    phase_result.summary
+                );
+                triaged_count += 1;
+            }
+            Err(e) => {
+                log_error!("[{}][TRIAGE] Failed: {}", item_id, e);
+                consecutive_failures += 1;
+                last_error = Some(e);
+            }
+        }
+    }
+
+    if consecutive_failures >= threshold {
+        return Err(format!(
+            "Triage halted after {} consecutive failures \
+             (execution.triage_failure_threshold = {}); {} item(s) triaged. \
+             Last error: {}",
+            consecutive_failures,
+            threshold,
+            triaged_count,
+            last_error.unwrap_or_default()
+        ));
+    }
+
+    Ok(triaged_count)
+}
+
+/// Return a re-triaged item to New so a successful result can route it again.
+/// No-op for New items and for results that don't route.
+async fn reset_for_retriage(
+    coordinator: &CoordinatorHandle,
+    item_id: &str,
+    result: &PhaseResult,
+) -> Result<(), String> {
+    if result.result != ResultCode::PhaseComplete {
+        return Ok(());
+    }
+    let snapshot = coordinator.get_snapshot().await?;
+    let Some(item) = snapshot.iter().find(|i| i.id() == item_id) else {
+        return Ok(());
+    };
+    match item.pg_status() {
+        ItemStatus::New => return Ok(()),
+        ItemStatus::Blocked => {
+            coordinator
+                .update_item(item_id, ItemUpdate::Unblock)
+                .await?
+        }
+        _ => {}
+    }
+    log_info!(
+        "[{}][TRIAGE] Re-triaging {:?} item; returning it to New",
+        item_id,
+        item.pg_status()
+    );
+    coordinator
+        .update_item(item_id, ItemUpdate::ReturnToNew)
+        .await?;
+    Ok(())
+}
+
+pub async fn apply_triage_result(
+    coordinator: &CoordinatorHandle,
+    item_id: &str,
+    result: &PhaseResult,
+    config: &PhaseGolemConfig,
+) -> Result<(), String> {
+    let snapshot = coordinator.get_snapshot().await?;
+    let item = snapshot
+        .iter()
+        .find(|i| i.id() == item_id)
+        .ok_or_else(|| format!("Item {} not found for triage", item_id))?;
+
+    if let Some((tag, pipeline_type)) = routed_pipeline_type(item, config) {
+        log_info!(
+            "[{}] Routing: tag '{}' pins pipeline '{}' (triage suggested {})",
+            item_id,
+            tag,
+            pipeline_type,
+            result.pipeline_type.as_deref().unwrap_or("none")
+        );
+    }
+
+    let updates = triage_updates(item, result, config);
+    let mut routed = item.clone();
+    for update in &updates {
+        pg_item::apply_update(&mut routed.0, update.clone());
+    }
+    if let Some(warning) = executor::guardrail_warning(&routed, &updates, &config.guardrails) {
+        log_warn!("{}", warning);
+    }
+    for update in updates {
+        coordinator.update_item(item_id, update).await?;
+    }
+
+    Ok(())
+}
+
+/// The pipeline type `config.routing` pins `item` to, as `(tag, pipeline_type)`:
+/// the rule for the first of the item's tags that has one.
+pub fn routed_pipeline_type<'a>(
+    item: &'a PgItem,
+    config: &'a PhaseGolemConfig,
+) -> Option<(&'a str, &'a str)> {
+    item.tags().iter().find_map(|tag| {
+        config
+            .routing
+            .get(tag)
+            .map(|pipeline_type| (tag.as_str(), pipeline_type.as_str()))
+    })
+}
+
+/// Every update `apply_triage_result` makes for `result`, in order.
+///
+/// Pure: routing is decided against a copy of `item` with the result's
+/// assessments and pipeline type applied, so callers can preview the outcome
+/// without touching the store.
+pub fn triage_updates(
+    item: &PgItem,
+    result: &PhaseResult,
+    config: &PhaseGolemConfig,
+) -> Vec<ItemUpdate> {
+    let mut updates = Vec::new();
+
+    // Apply assessment updates
+    if let Some(ref assessments) = result.updated_assessments {
+        updates.push(ItemUpdate::UpdateAssessments(assessments.clone()));
+    }
+
+    // Apply structured description if provided and non-empty
+    if let Some(ref description) = result.description {
+        if !description.is_empty() {
+            updates.push(ItemUpdate::SetDescription(description.clone()));
+        }
+    }
+
+    // Apply pipeline_type if provided; a `[routing]` rule for the item's tags wins
+    let pipeline_type = routed_pipeline_type(item, config)
+        .map(|(_, pipeline_type)| pipeline_type.to_string())
+        .or_else(|| result.pipeline_type.clone());
+    if let Some(ref pipeline_type) = pipeline_type {
+        // Validate pipeline type exists
+        if config.pipelines.contains_key(pipeline_type) {
+            updates.push(ItemUpdate::SetPipelineType(pipeline_type.clone()));
+        } else {
+            // Invalid pipeline type — block
+            updates.push(ItemUpdate::SetBlocked(format!(
+                "Triage assigned invalid pipeline type '{}'. Available: {}",
+                pipeline_type,
+                config
+                    .pipelines
+                    .keys()
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )));
+            return updates;
+        }
+    }
+
+    match result.result {
+        ResultCode::PhaseComplete => {
+            // Route against the item as it will look after the updates above
+            let mut routed = item.clone();
+            for update in &updates {
+                pg_item::apply_update(&mut routed.0, update.clone());
+            }
+            updates.extend(triage_routing_updates(&routed, config));
+        }
+        ResultCode::Blocked => {
+            let reason = result
+                .context
+                .as_deref()
+                .unwrap_or(&result.summary)
+                .to_string();
+            updates.push(ItemUpdate::SetBlocked(reason));
+        }
+        _ => {
+            // Failed or SubphaseComplete — stay in New
+        }
+    }
+
+    updates
+}
+
+/// The item as it would look after a successful triage reporting
+/// `assessments` and (optionally) `pipeline_type`. Nothing is persisted.
+pub fn preview_triage(
+    item: &PgItem,
+    assessments: UpdatedAssessments,
+    pipeline_type: Option<String>,
+    config: &PhaseGolemConfig,
+) -> PgItem {
+    let result = PhaseResult {
+        item_id: item.id().to_string(),
+        phase: "triage".to_string(),
+        result: ResultCode::PhaseComplete,
+        summary: "Triage preview".to_string(),
+        context: None,
+        updated_assessments: Some(assessments),
+        follow_ups: Vec::new(),
+        based_on_commit: None,
+        pipeline_type,
+        commit_summary: None,
+        duplicates: Vec::new(),
+        description: None,
+        confidence: None,
+    };
+
+    let mut previewed = item.clone();
+    for update in triage_updates(item, &result, config) {
+        pg_item::apply_update(&mut previewed.0, update);
+    }
+    previewed
+}
+
+/// Status/phase updates that route an item after a successful triage.
+///
+/// Small+low-risk items, or items whose pipeline has no pre_phases, go straight
+/// to Ready (via Scoping). Everything else enters Scoping at the first pre_phase.
+fn triage_routing_updates(item: &PgItem, config: &PhaseGolemConfig) -> Vec<ItemUpdate> {
+    let is_small_low_risk = matches!(item.size(), Some(SizeLevel::Small))
+        && matches!(item.risk(), Some(DimensionLevel::Low) | None);
+
+    let pipeline_type_owned = item
+        .pipeline_type()
+        .unwrap_or_else(|| "feature".to_string());
+    let pipeline = config.pipelines.get(pipeline_type_owned.as_str());
+    let has_pre_phases = pipeline.map(|p| !p.pre_phases.is_empty()).unwrap_or(false);
+
+    let mut go_ready = is_small_low_risk || !has_pre_phases;
+    if go_ready {
+        // Skipping the pre-phases also skips their guardrail check, so apply it here
+        let violations = executor::guardrail_violations(item, &config.guardrails);
+        if !violations.is_empty() {
+            match config.guardrails.on_violation {
+                GuardrailViolationPolicy::Warn => {}
+                GuardrailViolationPolicy::Scope if has_pre_phases => go_ready = false,
+                GuardrailViolationPolicy::Block | GuardrailViolationPolicy::Scope => {
+                    return vec![ItemUpdate::SetBlocked(executor::guardrail_block_reason(
+                        &violations,
+                    ))];
+                }
+            }
+        }
+    }
+
+    let mut updates = vec![ItemUpdate::TransitionStatus(ItemStatus::Scoping)];
+    if go_ready {
+        updates.push(ItemUpdate::TransitionStatus(ItemStatus::Ready));
+    } else if let Some(first_pre) = pipeline.and_then(|p| p.pre_phases.first()) {
+        updates.push(ItemUpdate::SetPhase(first_pre.name.clone()));
+        updates.push(ItemUpdate::SetPhasePool(PhasePool::Pre));
+    }
+    updates
+}
+
+// --- Follow-up ingestion ---
+
+/// Returns `(created, suppressed)`: follow-up items added to the backlog and
+/// follow-ups dropped as duplicates.
+async fn ingest_follow_ups(
+    coordinator: &CoordinatorHandle,
+    result: &PhaseResult,
+    config: &PhaseGolemConfig,
+) -> (u32, u32) {
+    if result.follow_ups.is_empty() {
+        return (0, 0);
+    }
+
+    let follow_ups = if config.execution.dedup_follow_ups {
+        match coordinator.get_snapshot().await {
+            Ok(snapshot) => dedup_follow_ups(
+                &result.item_id,
+                &result.follow_ups,
+                &snapshot,
+                config.execution.follow_up_similarity,
+            ),
+            Err(e) => {
+                log_warn!("Warning: could not check follow-ups for duplicates: {}", e);
+                result.follow_ups.clone()
+            }
+        }
+    } else {
+        result.follow_ups.clone()
+    };
+    let suppressed = (result.follow_ups.len() - follow_ups.len()) as u32;
+    if suppressed > 0 {
+        log_info!(
+            "[{}][{}] Suppressed {} duplicate follow-up(s)",
+            result.item_id,
+            result.phase.to_uppercase(),
+            suppressed
+        );
+    }
+    if follow_ups.is_empty() {
+        return (0, suppressed);
+    }
+
+    let origin = format!("{}/{}", result.item_id, result.phase);
+    let prefix = config.project.prefix_for(&result.item_id);
+    match coordinator
+        .ingest_follow_ups(follow_ups, &origin, Some(prefix))
+        .await
     {
-        Ok(new_ids) => new_ids.len() as u32,
+        Ok(new_ids) => (new_ids.len() as u32, suppressed),
         Err(e) => {
             log_warn!("Warning: failed to ingest follow-ups: {}", e);
-            0
+            (0, suppressed)
+        }
+    }
+}
+
+/// Drop follow-ups whose normalized title matches a New item, an active item
+/// created from the same origin item (any phase), or an earlier follow-up in
+/// the batch. Titles match when equal, or when their word overlap reaches
+/// `similarity` (see `follow_up_title_similarity`).
+pub fn dedup_follow_ups(
+    origin_item: &str,
+    follow_ups: &[FollowUp],
+    snapshot: &[PgItem],
+    similarity: f64,
+) -> Vec<FollowUp> {
+    let mut seen: Vec<String> = snapshot
+        .iter()
+        .filter(|item| {
+            item.pg_status() == ItemStatus::New
+                || item
+                    .origin()
+                    .is_some_and(|origin| origin.split('/').next() == Some(origin_item))
+        })
+        .map(|item| normalize_follow_up_title(item.title()))
+        .collect();
+
+    follow_ups
+        .iter()
+        .filter(|fu| {
+            let title = normalize_follow_up_title(&fu.title);
+            let is_new = !seen.iter().any(|existing| {
+                *existing == title || follow_up_title_similarity(existing, &title) >= similarity
+            });
+            if is_new {
+                seen.push(title);
+            } else {
+                log_info!(
+                    "[{}] Skipping duplicate follow-up: {}",
+                    origin_item,
+                    fu.title
+                );
+            }
+            is_new
+        })
+        .cloned()
+        .collect()
+}
+
+/// Lowercase, collapse whitespace, and drop trailing periods.
+fn normalize_follow_up_title(title: &str) -> String {
+    title
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_end_matches('.')
+        .to_lowercase()
+}
+
+/// Jaccard similarity of two normalized titles' word sets: shared words over
+/// all distinct words, from 0.0 (disjoint) to 1.0 (same words).
+pub fn follow_up_title_similarity(a: &str, b: &str) -> f64 {
+    let a: HashSet<&str> = a.split_whitespace().collect();
+    let b: HashSet<&str> = b.split_whitespace().collect();
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 1.0;
+    }
+    a.intersection(&b).count() as f64 / union as f64
+}
+
+// --- Drain helper ---
+
+async fn drain_join_set(
+    join_set: &mut JoinSet<(String, PhaseExecutionResult)>,
+    running: &mut RunningTasks,
+    state: &mut SchedulerState,
+    coordinator: &CoordinatorHandle,
+    config: &PhaseGolemConfig,
+    previous_summaries: &mut HashMap<String, String>,
+) {
+    while let Some(result) = join_set.join_next().await {
+        match result {
+            Ok((item_id, exec_result)) => {
+                running.remove(&item_id);
+                let _ = handle_task_completion(
+                    &item_id,
+                    exec_result,
+                    coordinator,
+                    config,
+                    state,
+                    previous_summaries,
+                )
+                .await;
+            }
+            Err(e) => {
+                log_debug!("Task join error during drain: {}", e);
+            }
+        }
+    }
+}
+
+// --- Dry run ---
+
+/// Simulate a run against an in-memory copy of the snapshot.
+///
+/// Mirrors the halt checks and action selection of `run_scheduler`, but every
+/// scheduled batch "completes" immediately with PHASE_COMPLETE (triage routes
+/// as if no assessments were returned). Nothing is spawned and the coordinator
+/// is never mutated. Each batch is selected with no running tasks, so a
+/// destructive phase still appears alone in its batch.
+fn simulate_run(
+    mut snapshot: Vec<PgItem>,
+    config: &PhaseGolemConfig,
+    params: &RunParams,
+) -> Result<RunSummary, String> {
+    let mut state = SchedulerState {
+        phases_executed: 0,
+        cap: params.cap,
+        consecutive_exhaustions: 0,
+        items_completed: Vec::new(),
+        items_blocked: Vec::new(),
+        items_failed_validation: Vec::new(),
+        follow_ups_created: 0,
+        follow_ups_suppressed: 0,
+        items_merged: 0,
+        merges: Vec::new(),
+        current_target_index: 0,
+        action_rotation: 0,
+        // Nothing actually runs in a dry run, so no events are emitted
+        events: None,
+        returned_to_new: HashSet::new(),
+        breaker_targets: params.targets.clone(),
+        circuit_breaker_threshold: config.execution.circuit_breaker_threshold,
+        event_log: None,
+        redact_keys: config.security.redact_keys.clone(),
+        dep_depth_warned: HashSet::new(),
+    };
+    let mut plan: Vec<(String, Vec<String>)> = Vec::new();
+    let idle = RunningTasks::new();
+
+    let parallel_targets = params.parallel_targets && !params.targets.is_empty();
+
+    let halt_reason = loop {
+        if parallel_targets {
+            if let Some(halt_reason) =
+                parallel_targets_halt_reason(&params.targets, &snapshot, &state)
+            {
+                break halt_reason;
+            }
+        } else if !params.targets.is_empty() {
+            if state.current_target_index < params.targets.len() {
+                let target_id = &params.targets[state.current_target_index];
+                if state.items_blocked.contains(target_id) {
+                    if !params.auto_advance {
+                        break HaltReason::TargetBlocked;
+                    }
+                    state.current_target_index += 1;
+                    continue;
+                }
+            }
+            state.current_target_index = advance_to_next_active_target(
+                &params.targets,
+                state.current_target_index,
+                &state.items_completed,
+                &snapshot,
+            );
+            if state.current_target_index >= params.targets.len() {
+                break HaltReason::TargetCompleted;
+            }
+        }
+
+        let filtered_snapshot = if !params.filter.is_empty() {
+            let filtered = filter::apply_filters(&params.filter, &snapshot);
+            if filtered.is_empty() {
+                let has_prior_progress =
+                    !state.items_completed.is_empty() || !state.items_blocked.is_empty();
+                break if has_prior_progress {
+                    HaltReason::FilterExhausted
+                } else {
+                    HaltReason::NoMatchingItems
+                };
+            }
+            if filtered
+                .iter()
+                .all(|i| matches!(i.pg_status(), ItemStatus::Done | ItemStatus::Blocked))
+            {
+                break HaltReason::FilterExhausted;
+            }
+            Some(filtered)
+        } else {
+            None
+        };
+
+        let (actions, skipped) = if parallel_targets {
+            let actions = select_parallel_target_actions(
+                &snapshot,
+                &idle,
+                &config.execution,
+                &config.pipelines,
+                &params.targets,
+            );
+            (actions, Vec::new())
+        } else if !params.targets.is_empty() {
+            let actions = select_targeted_actions(
+                &snapshot,
+                &idle,
+                &config.execution,
+                &config.pipelines,
+                &effective_target_id(
+                    &snapshot,
+                    &params.targets[state.current_target_index],
+                    params.wait_deps,
+                    params.max_dep_depth,
+                    &mut state.dep_depth_warned,
+                ),
+            );
+            (actions, Vec::new())
+        } else if let Some(ref filtered) = filtered_snapshot {
+            select_actions_with_reasons(filtered, &idle, &config.execution, &config.pipelines)
+        } else {
+            select_actions_with_reasons(&snapshot, &idle, &config.execution, &config.pipelines)
+        };
+        let actions = cap_iteration_actions(actions, config, &mut state);
+
+        if actions.is_empty() {
+            if parallel_targets {
+                for target_id in &params.targets {
+                    log_target_unmet_deps(&snapshot, target_id, false);
+                }
+            } else if !params.targets.is_empty() {
+                log_target_unmet_deps(
+                    &snapshot,
+                    &params.targets[state.current_target_index],
+                    params.wait_deps,
+                );
+            }
+            break idle_halt_reason(&snapshot, &state);
+        }
+
+        let action_descriptions: Vec<String> = actions.iter().map(describe_action).collect();
+        log_info!(
+            "[dry-run] Scheduling: {}",
+            format_schedule(&action_descriptions, &skipped)
+        );
+
+        for action in actions {
+            let (item_id, phase) = match action {
+                SchedulerAction::Promote(item_id) => {
+                    simulate_promote(&mut snapshot, &item_id, config)?;
+                    continue;
+                }
+                SchedulerAction::Triage(item_id) => (item_id, "triage".to_string()),
+                SchedulerAction::RunPhase { item_id, phase, .. } => (item_id, phase),
+            };
+            if state.is_cap_reached() {
+                break;
+            }
+            state.phases_executed += 1;
+            // The real run completes a matching skip_if phase without the agent
+            let planned = if simulated_skip(&snapshot, &item_id, &phase, config) {
+                format!("{} (skipped)", phase)
+            } else {
+                phase.clone()
+            };
+            match plan.iter_mut().find(|(id, _)| *id == item_id) {
+                Some((_, phases)) => phases.push(planned),
+                None => plan.push((item_id.clone(), vec![planned])),
+            }
+            simulate_phase_complete(&mut snapshot, &item_id, &phase, config, &mut state);
         }
+
+        if state.is_cap_reached() {
+            break HaltReason::CapReached;
+        }
+    };
+
+    let mut summary = build_summary(state, halt_reason);
+    summary.planned_phases = plan;
+    Ok(summary)
+}
+
+fn simulate_promote(
+    snapshot: &mut [PgItem],
+    item_id: &str,
+    config: &PhaseGolemConfig,
+) -> Result<(), String> {
+    let item = snapshot
+        .iter_mut()
+        .find(|i| i.id() == item_id)
+        .ok_or_else(|| format!("Item {} not found for promotion", item_id))?;
+    let pipeline_type = item
+        .pipeline_type()
+        .unwrap_or_else(|| "feature".to_string());
+    let first_phase = config
+        .pipelines
+        .get(&pipeline_type)
+        .and_then(|p| p.phases.first())
+        .ok_or_else(|| format!("Pipeline '{}' has no main phases", pipeline_type))?;
+
+    let updates = [
+        ItemUpdate::TransitionStatus(ItemStatus::InProgress),
+        ItemUpdate::SetPhase(first_phase.name.clone()),
+        ItemUpdate::SetPhasePool(PhasePool::Main),
+    ];
+    for update in updates {
+        pg_item::apply_update(&mut item.0, update);
     }
+    Ok(())
+}
+
+/// Whether the item's `skip_if` for `phase` matches, as checked before a real
+/// run spawns the agent (see `skipped_phase_result`).
+fn simulated_skip(
+    snapshot: &[PgItem],
+    item_id: &str,
+    phase: &str,
+    config: &PhaseGolemConfig,
+) -> bool {
+    let Some(item) = snapshot.iter().find(|i| i.id() == item_id) else {
+        return false;
+    };
+    let pipeline_type = item
+        .pipeline_type()
+        .unwrap_or_else(|| "feature".to_string());
+    config
+        .pipelines
+        .get(&pipeline_type)
+        .and_then(|pipeline| {
+            pipeline
+                .pre_phases
+                .iter()
+                .chain(pipeline.phases.iter())
+                .find(|p| p.name == phase)
+        })
+        .is_some_and(|phase_config| skipped_phase_result(item, phase_config).is_some())
 }
 
-// --- Drain helper ---
-
-async fn drain_join_set(
-    join_set: &mut JoinSet<(String, PhaseExecutionResult)>,
-    running: &mut RunningTasks,
-    state: &mut SchedulerState,
-    coordinator: &CoordinatorHandle,
+fn simulate_phase_complete(
+    snapshot: &mut Vec<PgItem>,
+    item_id: &str,
+    phase: &str,
     config: &PhaseGolemConfig,
-    previous_summaries: &mut HashMap<String, String>,
+    state: &mut SchedulerState,
 ) {
-    while let Some(result) = join_set.join_next().await {
-        match result {
-            Ok((item_id, exec_result)) => {
-                running.remove(&item_id);
-                let _ = handle_task_completion(
-                    &item_id,
-                    exec_result,
-                    coordinator,
-                    config,
-                    state,
-                    previous_summaries,
-                )
-                .await;
+    let Some(index) = snapshot.iter().position(|i| i.id() == item_id) else {
+        return;
+    };
+
+    let updates = if phase == "triage" {
+        triage_routing_updates(&snapshot[index], config)
+    } else {
+        let pipeline_type = snapshot[index]
+            .pipeline_type()
+            .unwrap_or_else(|| "feature".to_string());
+        let Some(pipeline) = config.pipelines.get(&pipeline_type) else {
+            return;
+        };
+        let result = PhaseResult {
+            item_id: item_id.to_string(),
+            phase: phase.to_string(),
+            result: ResultCode::PhaseComplete,
+            summary: "Dry run".to_string(),
+            context: None,
+            updated_assessments: None,
+            follow_ups: Vec::new(),
+            based_on_commit: None,
+            pipeline_type: None,
+            commit_summary: None,
+            duplicates: Vec::new(),
+            description: None,
+            confidence: None,
+        };
+        executor::resolve_transition(&snapshot[index], &result, pipeline, &config.guardrails)
+    };
+
+    for update in updates {
+        match &update {
+            ItemUpdate::TransitionStatus(ItemStatus::Done) => {
+                state.items_completed.push(item_id.to_string());
+                // Real runs archive Done items, which removes them from the snapshot.
+                if config.execution.archive_on_done {
+                    snapshot.remove(index);
+                    return;
+                }
             }
-            Err(e) => {
-                log_debug!("Task join error during drain: {}", e);
+            ItemUpdate::SetBlocked(_) => {
+                state.items_blocked.push(item_id.to_string());
             }
+            _ => {}
         }
+        pg_item::apply_update(&mut snapshot[index].0, update);
     }
 }
 
@@ -1852,9 +3549,27 @@ struct SchedulerState {
     consecutive_exhaustions: u32,
     items_completed: Vec<String>,
     items_blocked: Vec<String>,
+    items_failed_validation: Vec<String>,
     follow_ups_created: u32,
+    follow_ups_suppressed: u32,
     items_merged: u32,
+    merges: Vec<(String, String)>,
     current_target_index: usize,
+    /// Start offset for `limit_actions`, advanced each capped iteration.
+    action_rotation: usize,
+    events: Option<mpsc::Sender<SchedulerEvent>>,
+    /// Items sent back to New by `on_prephase_block = "return_to_new"` this run.
+    returned_to_new: HashSet<String>,
+    /// Items whose failures count toward the circuit breaker; empty means all.
+    breaker_targets: Vec<String>,
+    /// `execution.circuit_breaker_threshold`; 0 never trips.
+    circuit_breaker_threshold: u32,
+    /// `RunParams.event_log`; `None` for dry runs.
+    event_log: Option<PathBuf>,
+    /// `security.redact_keys`, masked in events and everything written to disk.
+    redact_keys: Vec<String>,
+    /// Targets already warned about dependencies beyond `--max-dep-depth`.
+    dep_depth_warned: HashSet<String>,
 }
 
 impl SchedulerState {
@@ -1863,20 +3578,312 @@ impl SchedulerState {
     }
 
     fn is_circuit_breaker_tripped(&self) -> bool {
-        self.consecutive_exhaustions >= CIRCUIT_BREAKER_THRESHOLD
+        self.circuit_breaker_threshold > 0
+            && self.consecutive_exhaustions >= self.circuit_breaker_threshold
+    }
+
+    /// Count a retry exhaustion toward the circuit breaker, unless this is a
+    /// targeted run and `item_id` isn't one of the targets.
+    fn record_exhaustion(&mut self, item_id: &str) {
+        if self.breaker_targets.is_empty() || self.breaker_targets.iter().any(|t| t == item_id) {
+            self.consecutive_exhaustions += 1;
+        } else {
+            log_debug!(
+                "[{}] Failure not counted toward the circuit breaker (not a target)",
+                item_id
+            );
+        }
+    }
+
+    /// Record an event in the event log and send it if a listener is
+    /// attached. Never waits on the receiver.
+    fn emit(&self, event: SchedulerEvent) {
+        let event = redact_event(event, &self.redact_keys);
+        if let Some(path) = self.event_log.as_deref() {
+            append_event(path, &EventLogRecord::Scheduler(&event), &self.redact_keys);
+        }
+        if let Some(ref events) = self.events {
+            if let Err(e) = events.try_send(event) {
+                log_debug!("Scheduler event dropped: {}", e);
+            }
+        }
+    }
+
+    fn record_completed(&mut self, item_id: &str) {
+        self.items_completed.push(item_id.to_string());
+        self.emit(SchedulerEvent::ItemCompleted {
+            item_id: item_id.to_string(),
+        });
+    }
+
+    fn record_blocked(&mut self, item_id: &str, reason: &str) {
+        self.items_blocked.push(item_id.to_string());
+        self.emit(SchedulerEvent::ItemBlocked {
+            item_id: item_id.to_string(),
+            reason: reason.to_string(),
+        });
+    }
+
+    fn record_follow_ups(&mut self, item_id: &str, count: u32, suppressed: u32) {
+        self.follow_ups_created += count;
+        self.follow_ups_suppressed += suppressed;
+        if count > 0 {
+            self.emit(SchedulerEvent::FollowUpsCreated {
+                item_id: item_id.to_string(),
+                count,
+            });
+        }
+    }
+}
+
+/// Body of the "Scheduling:" log line: the actions, then the items passed
+/// over with a short reason, e.g.
+/// `[WRK-002 → build]; Skipped: [WRK-003 (unmet dep WRK-002 (Ready))]`.
+fn format_schedule(action_descriptions: &[String], skipped: &[(String, SkipReason)]) -> String {
+    let mut line = format!("[{}]", action_descriptions.join(", "));
+    if !skipped.is_empty() {
+        let skipped: Vec<String> = skipped
+            .iter()
+            .map(|(id, reason)| format!("{} ({})", id, reason.short()))
+            .collect();
+        line.push_str(&format!("; Skipped: [{}]", skipped.join(", ")));
+    }
+    line
+}
+
+/// Short human-readable form of an action, as logged on the "Scheduling:" line.
+fn describe_action(action: &SchedulerAction) -> String {
+    match action {
+        SchedulerAction::Promote(id) => format!("promote {}", id),
+        SchedulerAction::Triage(id) => format!("triage {}", id),
+        SchedulerAction::RunPhase { item_id, phase, .. } => format!("{} → {}", item_id, phase),
+    }
+}
+
+// --- Decision trace ---
+
+/// One scheduler loop iteration, written as a line of the `run --trace` file.
+#[derive(Serialize)]
+struct TraceRecord {
+    iteration: u64,
+    timestamp: String,
+    /// Item count per status in the snapshot the actions were selected from.
+    snapshot: BTreeMap<String, usize>,
+    /// Items with a phase in flight at selection time.
+    running: Vec<String>,
+    /// Actions `select_actions` (or the targeted variant) returned.
+    selected: Vec<String>,
+    /// Actions started this iteration (promotions apply immediately).
+    spawned: Vec<String>,
+    skipped: Vec<TraceSkip>,
+}
+
+#[derive(Serialize)]
+struct TraceSkip {
+    action: String,
+    reason: String,
+}
+
+impl TraceRecord {
+    fn new(
+        iteration: u64,
+        snapshot: &[PgItem],
+        running: &RunningTasks,
+        selected: &[String],
+        kept: &[String],
+    ) -> Self {
+        let mut counts = BTreeMap::new();
+        for item in snapshot {
+            *counts
+                .entry(format!("{:?}", item.pg_status()).to_lowercase())
+                .or_insert(0) += 1;
+        }
+        let mut running_ids: Vec<String> = running.active.keys().cloned().collect();
+        running_ids.sort();
+        let mut record = TraceRecord {
+            iteration,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            snapshot: counts,
+            running: running_ids,
+            selected: selected.to_vec(),
+            spawned: Vec::new(),
+            skipped: Vec::new(),
+        };
+        for action in selected.iter().filter(|a| !kept.contains(a)) {
+            record.skip(action.clone(), "max_actions_per_iteration");
+        }
+        record
+    }
+
+    fn skip(&mut self, action: String, reason: &str) {
+        self.skipped.push(TraceSkip {
+            action,
+            reason: reason.to_string(),
+        });
+    }
+}
+
+/// Append `record` to the trace file, with `redact_keys` masked. Failures
+/// are logged, never fatal.
+fn append_trace(path: &Path, record: &TraceRecord, redact_keys: &[String]) {
+    use std::io::Write;
+
+    let line = match crate::redact::to_redacted_json(record, redact_keys) {
+        Ok(line) => line,
+        Err(e) => {
+            log_warn!("Failed to serialize trace record: {}", e);
+            return;
+        }
+    };
+    let written = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| writeln!(file, "{}", line));
+    if let Err(e) = written {
+        log_warn!("Failed to write trace to {}: {}", path.display(), e);
+    }
+}
+
+// --- Event log ---
+
+/// `event` with `redact_keys` masked in its free-text fields, before it is
+/// sent to listeners or logged.
+fn redact_event(event: SchedulerEvent, redact_keys: &[String]) -> SchedulerEvent {
+    match event {
+        SchedulerEvent::ItemBlocked { item_id, reason } => SchedulerEvent::ItemBlocked {
+            item_id,
+            reason: crate::redact::redact_text(&reason, redact_keys),
+        },
+        other => other,
+    }
+}
+
+/// Payload of one `RunParams.event_log` line.
+#[derive(Serialize)]
+#[serde(untagged)]
+enum EventLogRecord<'a> {
+    Scheduler(&'a SchedulerEvent),
+    LogOnly(LogOnlyEvent),
+}
+
+/// Events recorded in the event log but not sent on `RunParams.events`.
+#[derive(Serialize)]
+#[serde(tag = "event", content = "data", rename_all = "snake_case")]
+enum LogOnlyEvent {
+    ActionsScheduled { actions: Vec<String> },
+}
+
+#[derive(Serialize)]
+struct EventLogLine<'a> {
+    timestamp: String,
+    #[serde(flatten)]
+    record: &'a EventLogRecord<'a>,
+}
+
+/// Append one timestamped event line. Each line is written with a single
+/// unbuffered write, so a crash leaves every earlier line intact.
+fn append_event(path: &Path, record: &EventLogRecord, redact_keys: &[String]) {
+    use std::io::Write;
+
+    let line = EventLogLine {
+        timestamp: chrono::Utc::now().to_rfc3339(),
+        record,
+    };
+    let mut line = match crate::redact::to_redacted_json(&line, redact_keys) {
+        Ok(line) => line,
+        Err(e) => {
+            log_warn!("Failed to serialize event log record: {}", e);
+            return;
+        }
+    };
+    line.push('\n');
+    let written = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(line.as_bytes()));
+    if let Err(e) = written {
+        log_warn!("Failed to write event log {}: {}", path.display(), e);
+    }
+}
+
+/// Apply `execution.max_actions_per_iteration`, advancing the rotation
+/// whenever the cap actually drops actions.
+fn cap_iteration_actions(
+    actions: Vec<SchedulerAction>,
+    config: &PhaseGolemConfig,
+    state: &mut SchedulerState,
+) -> Vec<SchedulerAction> {
+    let selected = actions.len();
+    let limited = limit_actions(
+        actions,
+        config.execution.max_actions_per_iteration,
+        state.action_rotation,
+    );
+    if limited.len() < selected {
+        log_debug!(
+            "Capped actions this iteration: {} of {} (max_actions_per_iteration)",
+            limited.len(),
+            selected
+        );
+        state.action_rotation = state.action_rotation.wrapping_add(limited.len());
+    }
+    limited
+}
+
+/// Classify why the scheduler ran out of work.
+///
+/// Items that are not Done in the snapshot are stuck (Blocked, or waiting on
+/// dependencies that cannot progress). Done items still in the snapshot and
+/// items completed this run (which may already be archived) count as done.
+pub fn classify_idle_halt(items: &[PgItem], items_completed: &[String]) -> HaltReason {
+    let any_stuck = items.iter().any(|i| i.pg_status() != ItemStatus::Done);
+    let any_done =
+        !items_completed.is_empty() || items.iter().any(|i| i.pg_status() == ItemStatus::Done);
+    match (any_stuck, any_done) {
+        (false, _) => HaltReason::AllDone,
+        (true, false) => HaltReason::AllBlocked,
+        (true, true) => HaltReason::Mixed,
+    }
+}
+
+fn idle_halt_reason(items: &[PgItem], state: &SchedulerState) -> HaltReason {
+    classify_idle_halt(items, &state.items_completed)
+}
+
+/// `build_summary` for a real run. `Halted` is the listener's end-of-run
+/// signal, so unlike other events it waits for channel room instead of being dropped.
+async fn finish_run(mut state: SchedulerState, halt_reason: HaltReason) -> RunSummary {
+    let events = state.events.take();
+    let summary = build_summary(state, halt_reason.clone());
+    if let Some(events) = events {
+        if events
+            .send(SchedulerEvent::Halted(halt_reason))
+            .await
+            .is_err()
+        {
+            log_debug!("Scheduler event dropped: receiver closed");
+        }
     }
+    summary
 }
 
 fn build_summary(mut state: SchedulerState, halt_reason: HaltReason) -> RunSummary {
+    state.emit(SchedulerEvent::Halted(halt_reason.clone()));
     state.items_blocked.sort();
     state.items_blocked.dedup();
     RunSummary {
         phases_executed: state.phases_executed,
         items_completed: state.items_completed,
         items_blocked: state.items_blocked,
+        items_failed_validation: state.items_failed_validation,
         follow_ups_created: state.follow_ups_created,
+        follow_ups_suppressed: state.follow_ups_suppressed,
         items_merged: state.items_merged,
+        merges: state.merges,
         halt_reason,
+        planned_phases: Vec::new(),
     }
 }
 
@@ -1897,9 +3904,20 @@ mod tests {
                 "WRK-002".to_string(),
                 "WRK-001".to_string(),
             ],
+            items_failed_validation: Vec::new(),
             follow_ups_created: 0,
+            follow_ups_suppressed: 0,
             items_merged: 0,
+            merges: Vec::new(),
             current_target_index: 0,
+            action_rotation: 0,
+            events: None,
+            returned_to_new: HashSet::new(),
+            breaker_targets: Vec::new(),
+            circuit_breaker_threshold: 2,
+            event_log: None,
+            redact_keys: Vec::new(),
+            dep_depth_warned: HashSet::new(),
         };
 
         let summary = build_summary(state, HaltReason::TargetCompleted);
@@ -1907,4 +3925,422 @@ mod tests {
         assert_eq!(summary.items_blocked.len(), 3);
         assert_eq!(summary.items_blocked, vec!["WRK-001", "WRK-002", "WRK-003"]);
     }
+
+    fn state_with_breaker_targets(targets: &[&str]) -> SchedulerState {
+        SchedulerState {
+            phases_executed: 0,
+            cap: 100,
+            consecutive_exhaustions: 0,
+            items_completed: Vec::new(),
+            items_blocked: Vec::new(),
+            items_failed_validation: Vec::new(),
+            follow_ups_created: 0,
+            follow_ups_suppressed: 0,
+            items_merged: 0,
+            merges: Vec::new(),
+            current_target_index: 0,
+            action_rotation: 0,
+            events: None,
+            returned_to_new: HashSet::new(),
+            breaker_targets: targets.iter().map(|t| t.to_string()).collect(),
+            circuit_breaker_threshold: 2,
+            event_log: None,
+            redact_keys: Vec::new(),
+            dep_depth_warned: HashSet::new(),
+        }
+    }
+
+    #[test]
+    fn targeted_circuit_breaker_counts_only_target_failures() {
+        let mut state = state_with_breaker_targets(&["WRK-001"]);
+
+        for _ in 0..state.circuit_breaker_threshold {
+            state.record_exhaustion("WRK-009");
+        }
+        assert!(!state.is_circuit_breaker_tripped());
+
+        for _ in 0..state.circuit_breaker_threshold {
+            state.record_exhaustion("WRK-001");
+        }
+        assert!(state.is_circuit_breaker_tripped());
+    }
+
+    #[test]
+    fn untargeted_circuit_breaker_counts_every_failure() {
+        let mut state = state_with_breaker_targets(&[]);
+
+        for _ in 0..state.circuit_breaker_threshold {
+            state.record_exhaustion("WRK-009");
+        }
+        assert!(state.is_circuit_breaker_tripped());
+    }
+
+    #[test]
+    fn circuit_breaker_trips_at_configured_threshold() {
+        let mut state = state_with_breaker_targets(&[]);
+        state.circuit_breaker_threshold = 3;
+
+        state.record_exhaustion("WRK-001");
+        state.record_exhaustion("WRK-002");
+        assert!(!state.is_circuit_breaker_tripped());
+
+        state.record_exhaustion("WRK-003");
+        assert!(state.is_circuit_breaker_tripped());
+    }
+
+    #[test]
+    fn circuit_breaker_threshold_zero_never_trips() {
+        let mut state = state_with_breaker_targets(&[]);
+        state.circuit_breaker_threshold = 0;
+
+        for _ in 0..10 {
+            state.record_exhaustion("WRK-001");
+        }
+        assert!(!state.is_circuit_breaker_tripped());
+    }
+
+    fn ready_item(id: &str, impact: DimensionLevel, deps: &[&str]) -> PgItem {
+        let mut item = pg_item::new_from_parts(
+            id.to_string(),
+            format!("Item {}", id),
+            ItemStatus::Ready,
+            deps.iter().map(|d| d.to_string()).collect(),
+            Vec::new(),
+        );
+        pg_item::set_impact(&mut item.0, Some(&impact));
+        item
+    }
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, 10).unwrap()
+    }
+
+    fn with_deadline(mut item: PgItem, deadline: &str) -> PgItem {
+        let date = NaiveDate::parse_from_str(deadline, "%Y-%m-%d").unwrap();
+        pg_item::set_deadline(&mut item.0, Some(date));
+        item
+    }
+
+    fn sorted_ids(items: &[PgItem]) -> Vec<&str> {
+        sorted_ready_items(items, today(), 3)
+            .iter()
+            .map(|i| i.id())
+            .collect()
+    }
+
+    #[test]
+    fn sorted_ready_items_orders_diamond_dependencies_first() {
+        // WRK-001 depends on WRK-002 and WRK-003, which both depend on WRK-004.
+        let items = vec![
+            ready_item("WRK-001", DimensionLevel::High, &["WRK-002", "WRK-003"]),
+            ready_item("WRK-002", DimensionLevel::Low, &["WRK-004"]),
+            ready_item("WRK-003", DimensionLevel::High, &["WRK-004"]),
+            ready_item("WRK-004", DimensionLevel::Low, &[]),
+        ];
+
+        assert_eq!(
+            sorted_ids(&items),
+            vec!["WRK-004", "WRK-003", "WRK-002", "WRK-001"]
+        );
+    }
+
+    #[test]
+    fn sorted_ready_items_keeps_impact_order_within_a_layer() {
+        let items = vec![
+            ready_item("WRK-001", DimensionLevel::Low, &[]),
+            ready_item("WRK-002", DimensionLevel::High, &["WRK-001"]),
+            ready_item("WRK-003", DimensionLevel::Medium, &[]),
+        ];
+
+        assert_eq!(sorted_ids(&items), vec!["WRK-003", "WRK-001", "WRK-002"]);
+    }
+
+    #[test]
+    fn sorted_ready_items_follows_dependencies_through_non_ready_items() {
+        let mut middle = pg_item::new_from_parts(
+            "WRK-002".to_string(),
+            "Middle".to_string(),
+            ItemStatus::InProgress,
+            vec!["WRK-003".to_string()],
+            Vec::new(),
+        );
+        pg_item::set_impact(&mut middle.0, Some(&DimensionLevel::Low));
+        let items = vec![
+            ready_item("WRK-001", DimensionLevel::High, &["WRK-002"]),
+            middle,
+            ready_item("WRK-003", DimensionLevel::Low, &[]),
+        ];
+
+        assert_eq!(sorted_ids(&items), vec!["WRK-003", "WRK-001"]);
+    }
+
+    fn prioritized(mut item: PgItem, priority: i32) -> PgItem {
+        pg_item::set_priority(&mut item.0, Some(priority));
+        item
+    }
+
+    #[test]
+    fn sorted_ready_items_puts_explicit_priority_before_impact() {
+        let items = vec![
+            ready_item("WRK-001", DimensionLevel::High, &[]),
+            prioritized(ready_item("WRK-002", DimensionLevel::Low, &[]), 1),
+            prioritized(ready_item("WRK-003", DimensionLevel::Low, &[]), 5),
+            ready_item("WRK-004", DimensionLevel::Medium, &[]),
+        ];
+
+        assert_eq!(
+            sorted_ids(&items),
+            vec!["WRK-003", "WRK-002", "WRK-001", "WRK-004"]
+        );
+    }
+
+    #[test]
+    fn sorted_ready_items_breaks_priority_ties_by_impact_then_age() {
+        let items = vec![
+            prioritized(ready_item("WRK-001", DimensionLevel::Low, &[]), 2),
+            prioritized(ready_item("WRK-002", DimensionLevel::High, &[]), 2),
+            prioritized(ready_item("WRK-003", DimensionLevel::Low, &[]), 2),
+            prioritized(ready_item("WRK-004", DimensionLevel::Low, &[]), -1),
+        ];
+
+        assert_eq!(
+            sorted_ids(&items),
+            vec!["WRK-002", "WRK-001", "WRK-003", "WRK-004"]
+        );
+    }
+
+    #[test]
+    fn sorted_ready_items_keeps_dependencies_ahead_of_priority() {
+        let items = vec![
+            prioritized(ready_item("WRK-001", DimensionLevel::High, &["WRK-002"]), 9),
+            ready_item("WRK-002", DimensionLevel::Low, &[]),
+        ];
+
+        assert_eq!(sorted_ids(&items), vec!["WRK-002", "WRK-001"]);
+    }
+
+    #[test]
+    fn sorted_ready_items_tolerates_dependency_cycles() {
+        let items = vec![
+            ready_item("WRK-001", DimensionLevel::High, &["WRK-002"]),
+            ready_item("WRK-002", DimensionLevel::Low, &["WRK-001"]),
+        ];
+
+        assert_eq!(sorted_ready_items(&items, today(), 3).len(), 2);
+    }
+
+    fn in_progress_item(id: &str, phase: &str) -> PgItem {
+        let mut item = pg_item::new_from_parts(
+            id.to_string(),
+            format!("Item {}", id),
+            ItemStatus::InProgress,
+            Vec::new(),
+            Vec::new(),
+        );
+        pg_item::set_phase(&mut item.0, Some(phase));
+        pg_item::set_phase_pool(&mut item.0, Some(&PhasePool::Main));
+        item
+    }
+
+    fn build_review_pipelines() -> HashMap<String, PipelineConfig> {
+        let mut pipelines = HashMap::new();
+        pipelines.insert(
+            "feature".to_string(),
+            PipelineConfig {
+                pre_phases: vec![],
+                phases: vec![
+                    PhaseConfig::new("build", true),
+                    PhaseConfig::new("review", false),
+                ],
+                max_wip: None,
+                max_concurrent: None,
+            },
+        );
+        pipelines
+    }
+
+    fn skip_reasons(items: &[PgItem], config: &ExecutionConfig) -> HashMap<String, SkipReason> {
+        let (_, skipped) = select_actions_with_reasons(
+            items,
+            &RunningTasks::new(),
+            config,
+            &build_review_pipelines(),
+        );
+        skipped.into_iter().collect()
+    }
+
+    #[test]
+    fn skip_reasons_report_unmet_dependencies() {
+        let items = vec![
+            ready_item("WRK-001", DimensionLevel::High, &["WRK-002"]),
+            pg_item::new_from_parts(
+                "WRK-002".to_string(),
+                "Dep".to_string(),
+                ItemStatus::Blocked,
+                Vec::new(),
+                Vec::new(),
+            ),
+        ];
+
+        let reasons = skip_reasons(&items, &ExecutionConfig::default());
+
+        assert_eq!(
+            reasons.get("WRK-001"),
+            Some(&SkipReason::UnmetDependencies(
+                "WRK-002 (Blocked)".to_string()
+            ))
+        );
+        assert!(!reasons.contains_key("WRK-002"));
+    }
+
+    #[test]
+    fn skip_reasons_report_wip_limit() {
+        let items = vec![
+            in_progress_item("WRK-001", "review"),
+            ready_item("WRK-002", DimensionLevel::High, &[]),
+        ];
+        let config = ExecutionConfig {
+            max_wip: 1,
+            max_concurrent: 2,
+            ..ExecutionConfig::default()
+        };
+
+        let reasons = skip_reasons(&items, &config);
+
+        assert_eq!(reasons.get("WRK-002"), Some(&SkipReason::WipLimit));
+        assert!(!reasons.contains_key("WRK-001"));
+    }
+
+    #[test]
+    fn skip_reasons_report_destructive_exclusivity() {
+        let items = vec![
+            in_progress_item("WRK-001", "build"),
+            in_progress_item("WRK-002", "review"),
+        ];
+        let config = ExecutionConfig {
+            max_wip: 2,
+            max_concurrent: 2,
+            ..ExecutionConfig::default()
+        };
+
+        // Review sorts first (furthest-first), so build must wait for it
+        let reasons = skip_reasons(&items, &config);
+
+        assert_eq!(
+            reasons.get("WRK-001"),
+            Some(&SkipReason::DestructiveExclusive)
+        );
+        assert!(!reasons.contains_key("WRK-002"));
+    }
+
+    #[test]
+    fn format_schedule_appends_skipped_items_with_short_reasons() {
+        let actions = vec!["WRK-002 → build".to_string()];
+        let skipped = vec![
+            (
+                "WRK-003".to_string(),
+                SkipReason::UnmetDependencies("WRK-002 (InProgress)".to_string()),
+            ),
+            ("WRK-004".to_string(), SkipReason::WipLimit),
+        ];
+
+        assert_eq!(
+            format_schedule(&actions, &skipped),
+            "[WRK-002 → build]; Skipped: [WRK-003 (unmet dep WRK-002 (InProgress)), WRK-004 (max_wip)]"
+        );
+        assert_eq!(format_schedule(&actions, &[]), "[WRK-002 → build]");
+    }
+
+    #[test]
+    fn stuck_item_lines_are_sorted_and_explain_each_item() {
+        let items = vec![
+            ready_item("WRK-003", DimensionLevel::High, &["WRK-001"]),
+            in_progress_item("WRK-001", "missing"),
+        ];
+
+        let lines = stuck_item_lines(
+            &items,
+            &ExecutionConfig::default(),
+            &build_review_pipelines(),
+        );
+
+        assert_eq!(
+            lines,
+            vec![
+                "WRK-001: no runnable phase (current phase not found in its pipeline)",
+                "WRK-003: waiting on dependencies: WRK-001 (InProgress)",
+            ]
+        );
+    }
+
+    #[test]
+    fn sorted_ready_items_puts_overdue_medium_ahead_of_high_without_deadline() {
+        let items = vec![
+            ready_item("WRK-001", DimensionLevel::High, &[]),
+            with_deadline(
+                ready_item("WRK-002", DimensionLevel::Medium, &[]),
+                "2026-03-09",
+            ),
+        ];
+
+        assert_eq!(sorted_ids(&items), vec!["WRK-002", "WRK-001"]);
+    }
+
+    #[test]
+    fn sorted_ready_items_orders_overdue_then_due_soon_then_rest() {
+        let items = vec![
+            // Outside the 3-day window: no boost
+            with_deadline(
+                ready_item("WRK-001", DimensionLevel::High, &[]),
+                "2026-03-20",
+            ),
+            with_deadline(
+                ready_item("WRK-002", DimensionLevel::Low, &[]),
+                "2026-03-13",
+            ),
+            with_deadline(
+                ready_item("WRK-003", DimensionLevel::Low, &[]),
+                "2026-03-11",
+            ),
+            with_deadline(
+                ready_item("WRK-004", DimensionLevel::Low, &[]),
+                "2026-03-01",
+            ),
+            prioritized(ready_item("WRK-005", DimensionLevel::High, &[]), 10),
+        ];
+
+        assert_eq!(
+            sorted_ids(&items),
+            vec!["WRK-004", "WRK-003", "WRK-002", "WRK-005", "WRK-001"]
+        );
+    }
+
+    #[test]
+    fn sorted_ready_items_keeps_dependencies_ahead_of_deadlines() {
+        let items = vec![
+            with_deadline(
+                ready_item("WRK-001", DimensionLevel::High, &["WRK-002"]),
+                "2026-03-01",
+            ),
+            ready_item("WRK-002", DimensionLevel::Low, &[]),
+        ];
+
+        assert_eq!(sorted_ids(&items), vec!["WRK-002", "WRK-001"]);
+    }
+
+    #[test]
+    fn sorted_in_progress_items_puts_overdue_ahead_of_furthest_phase() {
+        let items = vec![
+            in_progress_item("WRK-001", "review"),
+            with_deadline(in_progress_item("WRK-002", "build"), "2026-03-09"),
+        ];
+
+        let sorted: Vec<&str> =
+            sorted_in_progress_items(&items, &build_review_pipelines(), today(), 3)
+                .iter()
+                .map(|i| i.id())
+                .collect();
+
+        assert_eq!(sorted, vec!["WRK-002", "WRK-001"]);
+    }
 }
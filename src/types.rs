@@ -151,9 +151,21 @@ pub enum ItemUpdate {
     SetBlocked(String),
     Unblock,
     UpdateAssessments(UpdatedAssessments),
+    /// Manual single-dimension overrides (`phase-golem assess`).
+    SetImpact(DimensionLevel),
+    SetSize(SizeLevel),
+    SetRisk(DimensionLevel),
+    SetComplexity(DimensionLevel),
     SetPipelineType(String),
     SetLastPhaseCommit(String),
     SetDescription(StructuredDescription),
+    /// Adds a tag unless the item already has it.
+    AddTag(String),
+    /// Marks a phase as finished (`PhaseComplete`) for `run --resume`.
+    RecordPhaseCompleted(String),
+    /// Sends an item back to `New` for re-triage, clearing its phase
+    /// (`execution.on_prephase_block = "return_to_new"`, `triage --target`).
+    ReturnToNew,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
@@ -203,6 +215,21 @@ pub struct PhaseResult {
     pub duplicates: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<StructuredDescription>,
+    /// Agent's self-reported confidence in this output, 0.0–1.0.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
+}
+
+/// One completed phase in an item's `x-pg-phase-history`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhaseHistoryEntry {
+    pub phase: String,
+    pub result: ResultCode,
+    pub completed_at: chrono::DateTime<chrono::Utc>,
+    /// HEAD when the phase completed, i.e. the commit its output was built
+    /// on (the phase's own commit follows it).
+    #[serde(default)]
+    pub commit: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
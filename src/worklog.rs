@@ -1,11 +1,75 @@
 use std::fs::{self, OpenOptions};
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::config::{WorklogFormat, WorklogNaming};
+use crate::types::PhaseResult;
+
+/// Path of the worklog file an entry for `item_id` at `now` belongs in.
+///
+/// `extension` is `md` for the readable log and `jsonl` for the audit log.
+pub fn worklog_path(
+    worklog_dir: &Path,
+    naming: WorklogNaming,
+    item_id: &str,
+    now: DateTime<Utc>,
+    extension: &str,
+) -> PathBuf {
+    let stem = match naming {
+        WorklogNaming::PerMonth => now.format("%Y-%m").to_string(),
+        WorklogNaming::PerDay => now.format("%Y-%m-%d").to_string(),
+        WorklogNaming::PerItem => item_id.to_string(),
+        WorklogNaming::Single => "worklog".to_string(),
+    };
+    worklog_dir.join(format!("{}.{}", stem, extension))
+}
+
+/// Append `entry` to `worklog_path` in a single write, creating parent dirs.
+///
+/// The file is opened with `O_APPEND`, so each entry lands whole at the end of
+/// the file even when several tasks route entries into the same file.
+pub fn append_entry(worklog_path: &Path, entry: &str) -> Result<(), String> {
+    let worklog_dir = worklog_path
+        .parent()
+        .ok_or_else(|| "Cannot determine worklog directory".to_string())?;
+
+    fs::create_dir_all(worklog_dir).map_err(|e| {
+        format!(
+            "Failed to create worklog directory {}: {}",
+            worklog_dir.display(),
+            e
+        )
+    })?;
+
+    let mut file = OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(worklog_path)
+        .map_err(|e| {
+            format!(
+                "Failed to open worklog at {}: {}",
+                worklog_path.display(),
+                e
+            )
+        })?;
+
+    file.write_all(entry.as_bytes()).map_err(|e| {
+        format!(
+            "Failed to write worklog at {}: {}",
+            worklog_path.display(),
+            e
+        )
+    })
+}
 
 /// Write a worklog entry for a phase execution.
 ///
-/// Appends an entry to `_worklog/YYYY-MM.md`.
-/// Creates the file and parent directories if missing.
+/// Appends an entry to the markdown worklog chosen by `naming`
+/// (`_worklog/YYYY-MM.md` by default). Creates the file and parent
+/// directories if missing.
 ///
 /// Format:
 /// ```text
@@ -19,23 +83,15 @@ use std::path::Path;
 /// ```
 pub fn write_entry(
     worklog_dir: &Path,
+    naming: WorklogNaming,
     id: &str,
     title: &str,
     phase: &str,
     outcome: &str,
     result_summary: &str,
 ) -> Result<(), String> {
-    let now = chrono::Utc::now();
-    let filename = now.format("%Y-%m").to_string();
-    let worklog_path = worklog_dir.join(format!("{}.md", filename));
-
-    fs::create_dir_all(worklog_dir).map_err(|e| {
-        format!(
-            "Failed to create worklog directory {}: {}",
-            worklog_dir.display(),
-            e
-        )
-    })?;
+    let now = Utc::now();
+    let worklog_path = worklog_path(worklog_dir, naming, id, now, "md");
 
     let datetime = now.to_rfc3339();
     let entry = format!(
@@ -43,25 +99,136 @@ pub fn write_entry(
         datetime, id, title, phase, outcome, result_summary,
     );
 
-    let mut file = OpenOptions::new()
-        .append(true)
-        .create(true)
-        .open(&worklog_path)
-        .map_err(|e| {
-            format!(
-                "Failed to open worklog at {}: {}",
-                worklog_path.display(),
-                e
-            )
-        })?;
+    append_entry(&worklog_path, &entry)
+}
 
-    file.write_all(entry.as_bytes()).map_err(|e| {
-        format!(
-            "Failed to write worklog at {}: {}",
-            worklog_path.display(),
-            e
-        )
-    })?;
+/// A single line of the worklog under `execution.worklog_format = "jsonl"`.
+#[derive(Serialize)]
+struct JsonlEntry<'a> {
+    timestamp: String,
+    item_id: &'a str,
+    title: &'a str,
+    phase: &'a str,
+    outcome: &'a str,
+    summary: &'a str,
+    /// The full result, when `execution.worklog_include_full_result` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<&'a PhaseResult>,
+}
+
+/// Append a worklog entry as one JSON object per line to the `.jsonl` file
+/// chosen by `naming` (`_worklog/YYYY-MM.jsonl` by default).
+///
+/// Used instead of `write_entry` under `execution.worklog_format = "jsonl"`.
+/// `full_result` is embedded in the same line rather than written separately.
+#[allow(clippy::too_many_arguments)]
+pub fn write_jsonl_entry(
+    worklog_dir: &Path,
+    naming: WorklogNaming,
+    id: &str,
+    title: &str,
+    phase: &str,
+    outcome: &str,
+    result_summary: &str,
+    full_result: Option<&PhaseResult>,
+) -> Result<(), String> {
+    let now = Utc::now();
+    let worklog_path = worklog_path(worklog_dir, naming, id, now, "jsonl");
+
+    let entry = JsonlEntry {
+        timestamp: now.to_rfc3339(),
+        item_id: id,
+        title,
+        phase,
+        outcome,
+        summary: result_summary,
+        result: full_result,
+    };
+    let mut line = serde_json::to_string(&entry)
+        .map_err(|e| format!("Failed to serialize worklog entry for {}: {}", id, e))?;
+    line.push('\n');
+
+    append_entry(&worklog_path, &line)
+}
+
+/// Write a phase entry in `format`, with `full_result` recorded as well when given.
+#[allow(clippy::too_many_arguments)]
+pub fn write_formatted_entry(
+    worklog_dir: &Path,
+    naming: WorklogNaming,
+    format: WorklogFormat,
+    id: &str,
+    title: &str,
+    phase: &str,
+    outcome: &str,
+    result_summary: &str,
+    full_result: Option<&PhaseResult>,
+) -> Result<(), String> {
+    match format {
+        WorklogFormat::Markdown => {
+            write_entry(
+                worklog_dir,
+                naming,
+                id,
+                title,
+                phase,
+                outcome,
+                result_summary,
+            )?;
+            match full_result {
+                Some(result) => write_result_entry(worklog_dir, naming, id, title, outcome, result),
+                None => Ok(()),
+            }
+        }
+        WorklogFormat::Jsonl => write_jsonl_entry(
+            worklog_dir,
+            naming,
+            id,
+            title,
+            phase,
+            outcome,
+            result_summary,
+            full_result,
+        ),
+    }
+}
+
+/// A single line of the JSONL audit worklog.
+#[derive(Serialize)]
+struct ResultEntry<'a> {
+    datetime: String,
+    item_id: &'a str,
+    title: &'a str,
+    outcome: &'a str,
+    result: &'a PhaseResult,
+}
+
+/// Append the full `PhaseResult` for a phase execution to the JSONL worklog
+/// alongside the markdown one (`_worklog/YYYY-MM.jsonl` by default).
+///
+/// Written alongside the markdown entry from `write_entry` when
+/// `execution.worklog_include_full_result` is enabled. One JSON object per line.
+pub fn write_result_entry(
+    worklog_dir: &Path,
+    naming: WorklogNaming,
+    id: &str,
+    title: &str,
+    outcome: &str,
+    result: &PhaseResult,
+) -> Result<(), String> {
+    let now = Utc::now();
+    let worklog_path = worklog_path(worklog_dir, naming, id, now, "jsonl");
+
+    let entry = ResultEntry {
+        datetime: now.to_rfc3339(),
+        item_id: id,
+        title,
+        outcome,
+        result,
+    };
+    let mut line = serde_json::to_string(&entry)
+        .map_err(|e| format!("Failed to serialize worklog result for {}: {}", id, e))?;
+    line.push('\n');
 
-    Ok(())
+    append_entry(&worklog_path, &line)
 }
@@ -1,12 +1,18 @@
 mod common;
 
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 use std::time::Duration;
 
 use tempfile::TempDir;
 
-use phase_golem::agent::{read_result_file, run_subprocess_agent, AgentRunner, MockAgentRunner};
+use phase_golem::agent::{
+    agent_log_path, agent_output_label, prompt_file_path, read_result_file, run_subprocess_agent,
+    run_subprocess_agent_with_log, AgentRunner, CliAgentRunner, MockAgentRunner,
+    AGENT_LOG_TAIL_LINES,
+};
+use phase_golem::config::{AgentConfig, CliTool, CustomAgentConfig};
 use phase_golem::types::{PhaseResult, ResultCode};
 
 /// Create a valid PhaseResult JSON string.
@@ -24,6 +30,7 @@ fn valid_result_json() -> String {
         commit_summary: None,
         duplicates: Vec::new(),
         description: None,
+        confidence: None,
     })
     .unwrap()
 }
@@ -42,6 +49,7 @@ fn make_result(result_code: ResultCode, summary: &str) -> PhaseResult {
         commit_summary: None,
         duplicates: Vec::new(),
         description: None,
+        confidence: None,
     }
 }
 
@@ -314,7 +322,8 @@ async fn mock_runner_empty_sequence() {
 
 #[test]
 fn install_signal_handlers_succeeds() {
-    let result = phase_golem::agent::install_signal_handlers();
+    let dir = tempfile::tempdir().unwrap();
+    let result = phase_golem::agent::install_signal_handlers(dir.path());
     assert!(
         result.is_ok(),
         "Signal handler installation should succeed: {:?}",
@@ -352,3 +361,202 @@ async fn process_group_kill_cleans_up_subprocess() {
         elapsed.as_secs()
     );
 }
+
+// --- CliAgentRunner command construction ---
+
+#[test]
+fn cli_runner_builds_gemini_command() {
+    let runner = CliAgentRunner::new(CliTool::Gemini, Some("gemini-2.5-pro".to_string()));
+    let cmd = runner.build_command("do stuff");
+    let std_cmd = cmd.as_std();
+
+    assert_eq!(std_cmd.get_program(), "gemini");
+    let args: Vec<&std::ffi::OsStr> = std_cmd.get_args().collect();
+    assert_eq!(
+        args,
+        vec![
+            "--yolo",
+            "--model",
+            "gemini-2.5-pro",
+            "--prompt",
+            "do stuff"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn cli_runner_custom_template_spawns_command() {
+    let dir = TempDir::new().unwrap();
+    let result_path = dir.path().join("result.json");
+    let script = common::fixtures_dir().join("mock_agent_success.sh");
+
+    let agent = AgentConfig {
+        cli: CliTool::Custom,
+        model: None,
+        custom: Some(CustomAgentConfig {
+            command: "bash".to_string(),
+            args: vec![
+                script.to_string_lossy().to_string(),
+                "{result_file}".to_string(),
+                "{prompt_file}".to_string(),
+            ],
+        }),
+        env: BTreeMap::new(),
+    };
+    let runner = CliAgentRunner::from_config(&agent);
+    runner.verify_cli_available().unwrap();
+
+    let result = runner
+        .run_agent("do stuff", &result_path, Duration::from_secs(30))
+        .await;
+    assert!(result.is_ok(), "Expected Ok, got: {:?}", result);
+    assert_eq!(result.unwrap().item_id, "WRK-001");
+    assert!(
+        !prompt_file_path(&result_path).exists(),
+        "Prompt file should be removed after the run"
+    );
+}
+
+#[test]
+fn cli_runner_sets_agent_env_on_spawned_commands() {
+    let agent = AgentConfig {
+        cli: CliTool::Custom,
+        model: None,
+        custom: Some(CustomAgentConfig {
+            command: "wrapper".to_string(),
+            args: vec!["{result_file}".to_string()],
+        }),
+        env: BTreeMap::from([(
+            "ANTHROPIC_BASE_URL".to_string(),
+            "http://localhost:8080".to_string(),
+        )]),
+    };
+    let runner = CliAgentRunner::from_config(&agent);
+    let expected = (
+        std::ffi::OsStr::new("ANTHROPIC_BASE_URL"),
+        Some(std::ffi::OsStr::new("http://localhost:8080")),
+    );
+
+    let custom = runner
+        .build_custom_command(Path::new("prompt.md"), Path::new("result.json"))
+        .unwrap();
+    assert!(custom.as_std().get_envs().any(|env| env == expected));
+
+    let builtin = CliAgentRunner {
+        tool: CliTool::Claude,
+        ..runner
+    }
+    .build_command("do stuff");
+    assert!(builtin.as_std().get_envs().any(|env| env == expected));
+}
+
+#[test]
+fn cli_runner_custom_command_missing_is_reported() {
+    let agent = AgentConfig {
+        cli: CliTool::Custom,
+        model: None,
+        custom: Some(CustomAgentConfig {
+            command: "phase-golem-no-such-agent".to_string(),
+            args: vec!["{result_file}".to_string()],
+        }),
+        env: BTreeMap::new(),
+    };
+    let err = CliAgentRunner::from_config(&agent)
+        .verify_cli_available()
+        .unwrap_err();
+    assert!(err.contains("phase-golem-no-such-agent"), "got: {}", err);
+}
+
+// --- Agent output capture ---
+
+#[test]
+fn agent_log_path_uses_item_and_phase() {
+    let result_path = Path::new("/proj/.phase-golem/phase_result_WRK-001_build.json");
+    assert_eq!(
+        agent_log_path(result_path),
+        Path::new("/proj/.phase-golem/logs/WRK-001_build.log")
+    );
+}
+
+#[test]
+fn agent_output_label_uses_item_and_phase() {
+    let result_path = Path::new("/proj/.phase-golem/phase_result_WRK-001_build.json");
+    assert_eq!(agent_output_label(result_path), "[WRK-001][build]");
+    let triage_path = Path::new("/proj/.phase-golem/phase_result_WRK-a1b2c_triage.json");
+    assert_eq!(agent_output_label(triage_path), "[WRK-a1b2c][triage]");
+    let underscored = Path::new("/proj/.phase-golem/phase_result_MY_APP-001_tech-research.json");
+    assert_eq!(
+        agent_output_label(underscored),
+        "[MY_APP-001][tech-research]"
+    );
+}
+
+#[tokio::test]
+async fn subprocess_output_captured_to_log_and_tail_in_error() {
+    let dir = TempDir::new().unwrap();
+    let result_path = dir.path().join("phase_result_WRK-001_build.json");
+    let log_path = agent_log_path(&result_path);
+
+    let script_path = dir.path().join("noisy_fail.sh");
+    fs::write(
+        &script_path,
+        "#!/bin/bash\nfor i in $(seq 1 30); do echo \"out $i\"; done\necho 'boom' >&2\nexit 1\n",
+    )
+    .unwrap();
+
+    let mut cmd = tokio::process::Command::new("bash");
+    cmd.arg(&script_path);
+
+    let result =
+        run_subprocess_agent_with_log(cmd, &result_path, Duration::from_secs(30), Some(&log_path))
+            .await;
+    let err = result.unwrap_err();
+    assert!(err.contains("Agent failed"), "got: {}", err);
+    assert!(
+        err.contains("boom"),
+        "stderr should be in the tail: {}",
+        err
+    );
+    assert!(
+        err.contains("out 30"),
+        "latest stdout should be in the tail: {}",
+        err
+    );
+    assert!(
+        !err.contains("out 1\n"),
+        "tail should be limited to {} lines: {}",
+        AGENT_LOG_TAIL_LINES,
+        err
+    );
+
+    let log = fs::read_to_string(&log_path).unwrap();
+    assert!(log.contains("out 1\n"));
+    assert!(log.contains("boom"));
+}
+
+#[tokio::test]
+async fn subprocess_log_overwritten_per_attempt() {
+    let dir = TempDir::new().unwrap();
+    let result_path = dir.path().join("phase_result_WRK-001_build.json");
+    let log_path = agent_log_path(&result_path);
+    let script = common::fixtures_dir().join("mock_agent_success.sh");
+
+    for attempt in ["first", "second"] {
+        let mut cmd = tokio::process::Command::new("bash");
+        cmd.arg("-c")
+            .arg(format!("echo {} && bash \"$0\" \"$1\"", attempt))
+            .arg(&script)
+            .arg(&result_path);
+        let result = run_subprocess_agent_with_log(
+            cmd,
+            &result_path,
+            Duration::from_secs(30),
+            Some(&log_path),
+        )
+        .await;
+        assert!(result.is_ok(), "Expected Ok, got: {:?}", result);
+    }
+
+    let log = fs::read_to_string(&log_path).unwrap();
+    assert_eq!(log, "second\n");
+}
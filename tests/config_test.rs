@@ -1,3 +1,6 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
 use phase_golem::config::*;
 use phase_golem::config::{AgentConfig, CliTool};
 use phase_golem::types::*;
@@ -12,6 +15,9 @@ fn phase_config_new_sets_correct_defaults() {
     assert!(!phase.is_destructive);
     assert!(phase.workflows.is_empty());
     assert_eq!(phase.staleness, StalenessAction::Ignore);
+    assert_eq!(phase.timeout_minutes, None);
+    assert!(!phase.followups_block);
+    assert!(!phase.checkpoint);
 }
 
 #[test]
@@ -51,6 +57,22 @@ fn load_config_defaults_when_file_missing() {
     assert_eq!(config.execution.phase_timeout_minutes, 30);
     assert_eq!(config.execution.max_retries, 2);
     assert_eq!(config.execution.default_phase_cap, 100);
+    assert!(config.execution.dedup_follow_ups);
+    assert_eq!(config.execution.follow_up_similarity, 1.0);
+    assert_eq!(config.execution.isolation, Isolation::None);
+    assert_eq!(config.execution.triage_failure_threshold, 3);
+    assert_eq!(
+        config.execution.on_prephase_block,
+        PrePhaseBlockPolicy::Block
+    );
+    assert!(!config.execution.guard_external_dirty);
+    assert_eq!(config.execution.circuit_breaker_threshold, 2);
+    assert!(!config.execution.event_log);
+    assert_eq!(config.execution.deadline_window_days, 3);
+    assert!(!config.execution.metrics_file);
+    assert!(config.execution.archive_on_done);
+    assert_eq!(config.execution.post_phase_hook, None);
+    assert_eq!(config.execution.worklog_format, WorklogFormat::Markdown);
 }
 
 #[test]
@@ -62,6 +84,7 @@ fn load_config_full() {
         r#"
 [project]
 prefix = "APP"
+worklog_naming = "per_item"
 
 [guardrails]
 max_size = "large"
@@ -72,6 +95,9 @@ max_risk = "medium"
 phase_timeout_minutes = 60
 max_retries = 5
 default_phase_cap = 50
+dedup_follow_ups = false
+isolation = "clone"
+on_prephase_block = "return_to_new"
 "#,
     )
     .unwrap();
@@ -79,12 +105,19 @@ default_phase_cap = 50
     let config = load_config(dir.path()).unwrap();
 
     assert_eq!(config.project.prefix, "APP");
+    assert_eq!(config.project.worklog_naming, WorklogNaming::PerItem);
     assert_eq!(config.guardrails.max_size, SizeLevel::Large);
     assert_eq!(config.guardrails.max_complexity, DimensionLevel::High);
     assert_eq!(config.guardrails.max_risk, DimensionLevel::Medium);
     assert_eq!(config.execution.phase_timeout_minutes, 60);
     assert_eq!(config.execution.max_retries, 5);
     assert_eq!(config.execution.default_phase_cap, 50);
+    assert!(!config.execution.dedup_follow_ups);
+    assert_eq!(config.execution.isolation, Isolation::Clone);
+    assert_eq!(
+        config.execution.on_prephase_block,
+        PrePhaseBlockPolicy::ReturnToNew
+    );
 }
 
 #[test]
@@ -110,6 +143,23 @@ prefix = "CUSTOM"
     assert_eq!(config.execution.max_retries, 2);
 }
 
+#[test]
+fn load_config_accepts_follow_up_dedup_alias() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("phase-golem.toml"),
+        r#"
+[execution]
+follow_up_dedup = false
+"#,
+    )
+    .unwrap();
+
+    let config = load_config(dir.path()).unwrap();
+
+    assert!(!config.execution.dedup_follow_ups);
+}
+
 #[test]
 fn load_config_empty_file_uses_all_defaults() {
     let dir = tempfile::tempdir().unwrap();
@@ -164,13 +214,14 @@ fn load_config_with_full_pipeline() {
         &config_path,
         r#"
 [pipelines.blog-post]
+max_concurrent = 4
 pre_phases = [
     { name = "research", workflows = ["research/scope"], is_destructive = false },
 ]
 phases = [
     { name = "draft", workflows = ["writing/draft"], is_destructive = false },
-    { name = "edit", workflows = ["writing/edit"], is_destructive = false },
-    { name = "publish", workflows = ["writing/publish"], is_destructive = true, staleness = "warn" },
+    { name = "edit", workflows = ["writing/edit"], is_destructive = false, followups_block = true, checkpoint = true },
+    { name = "publish", workflows = ["writing/publish"], is_destructive = true, staleness = "warn", timeout_minutes = 90 },
 ]
 "#,
     )
@@ -189,6 +240,14 @@ phases = [
     assert_eq!(pipeline.phases[2].name, "publish");
     assert!(pipeline.phases[2].is_destructive);
     assert_eq!(pipeline.phases[2].staleness, StalenessAction::Warn);
+    assert_eq!(pipeline.phases[2].timeout_minutes, Some(90));
+    assert_eq!(pipeline.phases[0].timeout_minutes, None);
+    assert!(pipeline.phases[1].followups_block);
+    assert_eq!(pipeline.max_concurrent, Some(4));
+    assert_eq!(pipeline.max_wip, None);
+    assert!(!pipeline.phases[0].followups_block);
+    assert!(pipeline.phases[1].checkpoint);
+    assert!(!pipeline.phases[0].checkpoint);
 }
 
 #[test]
@@ -275,6 +334,7 @@ fn load_config_with_max_wip_and_max_concurrent() {
 [execution]
 max_wip = 3
 max_concurrent = 2
+max_actions_per_iteration = 5
 "#,
     )
     .unwrap();
@@ -283,6 +343,7 @@ max_concurrent = 2
 
     assert_eq!(config.execution.max_wip, 3);
     assert_eq!(config.execution.max_concurrent, 2);
+    assert_eq!(config.execution.max_actions_per_iteration, Some(5));
 }
 
 #[test]
@@ -292,6 +353,7 @@ fn load_config_defaults_max_wip_and_max_concurrent() {
 
     assert_eq!(config.execution.max_wip, 1);
     assert_eq!(config.execution.max_concurrent, 1);
+    assert_eq!(config.execution.max_actions_per_iteration, None);
 }
 
 // --- Validation tests ---
@@ -312,6 +374,8 @@ fn validate_max_wip_zero_fails() {
         PipelineConfig {
             pre_phases: vec![],
             phases: vec![PhaseConfig::new("build", false)],
+            max_wip: None,
+            max_concurrent: None,
         },
     );
 
@@ -330,6 +394,8 @@ fn validate_max_concurrent_zero_fails() {
         PipelineConfig {
             pre_phases: vec![],
             phases: vec![PhaseConfig::new("build", false)],
+            max_wip: None,
+            max_concurrent: None,
         },
     );
 
@@ -339,6 +405,84 @@ fn validate_max_concurrent_zero_fails() {
     assert!(errors.iter().any(|e| e.contains("max_concurrent")));
 }
 
+#[test]
+fn validate_max_actions_per_iteration_zero_fails() {
+    let mut config = PhaseGolemConfig::default();
+    config.execution.max_actions_per_iteration = Some(0);
+    config.pipelines.insert(
+        "test".to_string(),
+        PipelineConfig {
+            pre_phases: vec![],
+            phases: vec![PhaseConfig::new("build", false)],
+            max_wip: None,
+            max_concurrent: None,
+        },
+    );
+
+    let errors = validate(&config).unwrap_err();
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("max_actions_per_iteration")));
+}
+
+#[test]
+fn validate_follow_up_similarity_out_of_range_fails() {
+    for similarity in [0.0, 1.5] {
+        let mut config = PhaseGolemConfig::default();
+        config.execution.follow_up_similarity = similarity;
+        config.pipelines.insert(
+            "test".to_string(),
+            PipelineConfig {
+                pre_phases: vec![],
+                phases: vec![PhaseConfig::new("build", false)],
+                max_wip: None,
+                max_concurrent: None,
+            },
+        );
+
+        let errors = validate(&config).unwrap_err();
+        assert!(
+            errors.contains(&"execution.follow_up_similarity must be > 0.0 and <= 1.0".to_string())
+        );
+    }
+}
+
+#[test]
+fn validate_zero_triage_failure_threshold_fails() {
+    let mut config = PhaseGolemConfig::default();
+    config.execution.triage_failure_threshold = 0;
+    config.pipelines.insert(
+        "test".to_string(),
+        PipelineConfig {
+            pre_phases: vec![],
+            phases: vec![PhaseConfig::new("build", false)],
+            max_wip: None,
+            max_concurrent: None,
+        },
+    );
+
+    let errors = validate(&config).unwrap_err();
+    assert!(errors.contains(&"execution.triage_failure_threshold must be >= 1".to_string()));
+}
+
+#[test]
+fn validate_pipeline_zero_limits_fail() {
+    let mut config = PhaseGolemConfig::default();
+    config.pipelines.insert(
+        "docs".to_string(),
+        PipelineConfig {
+            pre_phases: vec![],
+            phases: vec![PhaseConfig::new("draft", false)],
+            max_wip: Some(0),
+            max_concurrent: Some(0),
+        },
+    );
+
+    let errors = validate(&config).unwrap_err();
+    assert!(errors.contains(&"pipelines.docs: max_wip must be >= 1".to_string()));
+    assert!(errors.contains(&"pipelines.docs: max_concurrent must be >= 1".to_string()));
+}
+
 #[test]
 fn validate_pipeline_no_main_phases_fails() {
     let mut config = PhaseGolemConfig::default();
@@ -347,6 +491,8 @@ fn validate_pipeline_no_main_phases_fails() {
         PipelineConfig {
             pre_phases: vec![PhaseConfig::new("research", false)],
             phases: vec![],
+            max_wip: None,
+            max_concurrent: None,
         },
     );
 
@@ -356,6 +502,29 @@ fn validate_pipeline_no_main_phases_fails() {
     assert!(errors.iter().any(|e| e.contains("at least one main phase")));
 }
 
+#[test]
+fn validate_phase_name_with_underscore_fails() {
+    let mut config = PhaseGolemConfig::default();
+    config.pipelines.insert(
+        "feature".to_string(),
+        PipelineConfig {
+            pre_phases: vec![],
+            phases: vec![
+                PhaseConfig::new("build", true),
+                PhaseConfig::new("code_review", false),
+            ],
+            max_wip: None,
+            max_concurrent: None,
+        },
+    );
+
+    let errors = validate(&config).unwrap_err();
+    assert!(errors.contains(
+        &"pipelines.feature: phase name 'code_review' must not contain '_' (use '-' instead)"
+            .to_string()
+    ));
+}
+
 #[test]
 fn validate_duplicate_phase_names_fails() {
     let mut config = PhaseGolemConfig::default();
@@ -367,6 +536,8 @@ fn validate_duplicate_phase_names_fails() {
                 PhaseConfig::new("research", false),
                 PhaseConfig::new("build", false),
             ],
+            max_wip: None,
+            max_concurrent: None,
         },
     );
 
@@ -384,6 +555,8 @@ fn validate_destructive_pre_phase_fails() {
         PipelineConfig {
             pre_phases: vec![PhaseConfig::new("research", true)],
             phases: vec![PhaseConfig::new("build", false)],
+            max_wip: None,
+            max_concurrent: None,
         },
     );
 
@@ -405,6 +578,8 @@ fn validate_staleness_block_with_max_wip_greater_than_one_fails() {
                 staleness: StalenessAction::Block,
                 ..PhaseConfig::new("build", true)
             }],
+            max_wip: None,
+            max_concurrent: None,
         },
     );
 
@@ -428,6 +603,8 @@ fn validate_staleness_block_with_max_wip_one_passes() {
                 staleness: StalenessAction::Block,
                 ..PhaseConfig::new("build", true)
             }],
+            max_wip: None,
+            max_concurrent: None,
         },
     );
 
@@ -435,6 +612,139 @@ fn validate_staleness_block_with_max_wip_one_passes() {
     assert!(result.is_ok());
 }
 
+#[test]
+fn phase_effective_timeout_prefers_override() {
+    let execution = ExecutionConfig::default();
+    let phase = PhaseConfig {
+        timeout_minutes: Some(120),
+        ..PhaseConfig::new("build", true)
+    };
+
+    assert_eq!(phase.effective_timeout_minutes(&execution), 120);
+    assert_eq!(
+        PhaseConfig::new("prd", false).effective_timeout_minutes(&execution),
+        execution.phase_timeout_minutes
+    );
+}
+
+#[test]
+fn validate_phase_timeout_zero_fails() {
+    let mut config = PhaseGolemConfig::default();
+    config.pipelines.insert(
+        "test".to_string(),
+        PipelineConfig {
+            pre_phases: vec![],
+            phases: vec![PhaseConfig {
+                timeout_minutes: Some(0),
+                ..PhaseConfig::new("build", false)
+            }],
+            max_wip: None,
+            max_concurrent: None,
+        },
+    );
+
+    let errors = validate(&config).unwrap_err();
+    assert!(errors.iter().any(|e| e.contains("timeout_minutes")));
+}
+
+#[test]
+fn validate_min_confidence_out_of_range_fails() {
+    let mut config = PhaseGolemConfig::default();
+    config.guardrails.min_confidence = Some(1.5);
+    config.pipelines.insert(
+        "test".to_string(),
+        PipelineConfig {
+            pre_phases: vec![],
+            phases: vec![PhaseConfig {
+                min_confidence: Some(-0.1),
+                ..PhaseConfig::new("build", false)
+            }],
+            max_wip: None,
+            max_concurrent: None,
+        },
+    );
+
+    let errors = validate(&config).unwrap_err();
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("guardrails.min_confidence")));
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("phase 'build' min_confidence")));
+}
+
+#[test]
+fn validate_skip_if_must_parse_as_filter() {
+    let mut config = PhaseGolemConfig::default();
+    config.pipelines.insert(
+        "test".to_string(),
+        PipelineConfig {
+            pre_phases: vec![],
+            phases: vec![
+                PhaseConfig {
+                    skip_if: Some("size=small".to_string()),
+                    ..PhaseConfig::new("design", false)
+                },
+                PhaseConfig {
+                    skip_if: Some("bogus".to_string()),
+                    ..PhaseConfig::new("build", true)
+                },
+            ],
+            max_wip: None,
+            max_concurrent: None,
+        },
+    );
+
+    let errors = validate(&config).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("phase 'build' skip_if is invalid"));
+}
+
+#[test]
+fn validate_routing_requires_defined_pipeline() {
+    let mut config = PhaseGolemConfig::default();
+    config
+        .pipelines
+        .insert("feature".to_string(), default_feature_pipeline());
+    config
+        .routing
+        .insert("ui".to_string(), "feature".to_string());
+    config
+        .routing
+        .insert("docs".to_string(), "docs".to_string());
+
+    let errors = validate(&config).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("routing.docs: pipeline 'docs' is not defined"));
+}
+
+#[test]
+fn validate_phase_model_rejects_flag_like_value() {
+    let mut config = PhaseGolemConfig::default();
+    config.pipelines.insert(
+        "test".to_string(),
+        PipelineConfig {
+            pre_phases: vec![],
+            phases: vec![
+                PhaseConfig {
+                    model: Some("opus".to_string()),
+                    ..PhaseConfig::new("design", false)
+                },
+                PhaseConfig {
+                    model: Some("--flag".to_string()),
+                    ..PhaseConfig::new("build", true)
+                },
+            ],
+            max_wip: None,
+            max_concurrent: None,
+        },
+    );
+
+    let errors = validate(&config).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("phase 'build' model must not start with '-'"));
+}
+
 #[test]
 fn validate_multiple_errors_reported() {
     let mut config = PhaseGolemConfig::default();
@@ -445,6 +755,8 @@ fn validate_multiple_errors_reported() {
         PipelineConfig {
             pre_phases: vec![],
             phases: vec![],
+            max_wip: None,
+            max_concurrent: None,
         },
     );
 
@@ -535,6 +847,59 @@ default_phase_cap = 75
     assert_eq!(config.execution.default_phase_cap, 75);
 }
 
+#[test]
+fn resolve_config_location_discovers_config_in_parent_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = dir.path().canonicalize().unwrap();
+    std::fs::create_dir(repo.join(".git")).unwrap();
+    std::fs::write(
+        repo.join("phase-golem.toml"),
+        "[project]\nprefix = \"UP\"\n",
+    )
+    .unwrap();
+    let nested = repo.join("src").join("deep");
+    std::fs::create_dir_all(&nested).unwrap();
+
+    let (config_path, config_base) = resolve_config_location(None, &nested);
+
+    assert_eq!(config_path, Some(repo.join("phase-golem.toml")));
+    assert_eq!(config_base, repo);
+    let config = load_config_from(config_path.as_deref(), &nested).unwrap();
+    assert_eq!(config.project.prefix, "UP");
+}
+
+#[test]
+fn resolve_config_location_explicit_path_disables_search() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = dir.path().canonicalize().unwrap();
+    std::fs::create_dir(repo.join(".git")).unwrap();
+    std::fs::write(repo.join("phase-golem.toml"), "").unwrap();
+    let nested = repo.join("sub");
+    std::fs::create_dir(&nested).unwrap();
+    let explicit = nested.join("custom.toml");
+
+    let (config_path, config_base) = resolve_config_location(Some(&explicit), &nested);
+
+    assert_eq!(config_path, Some(explicit));
+    assert_eq!(config_base, nested);
+}
+
+#[test]
+fn resolve_config_location_stops_at_git_root() {
+    let dir = tempfile::tempdir().unwrap();
+    let outer = dir.path().canonicalize().unwrap();
+    std::fs::write(outer.join("phase-golem.toml"), "").unwrap();
+    let repo = outer.join("repo");
+    std::fs::create_dir_all(repo.join(".git")).unwrap();
+    let nested = repo.join("sub");
+    std::fs::create_dir(&nested).unwrap();
+
+    let (config_path, config_base) = resolve_config_location(None, &nested);
+
+    assert_eq!(config_path, None);
+    assert_eq!(config_base, nested);
+}
+
 #[test]
 fn load_config_from_explicit_path_missing() {
     let dir = tempfile::tempdir().unwrap();
@@ -550,8 +915,73 @@ fn load_config_from_explicit_path_missing() {
     );
 }
 
+#[test]
+fn load_config_from_rejects_prefix_unsafe_for_filenames() {
+    let dir = tempfile::tempdir().unwrap();
+    for prefix in ["MY APP", "team/wrk", ""] {
+        let config_path = dir.path().join("phase-golem.toml");
+        std::fs::write(
+            &config_path,
+            format!("[project]\nprefix = \"{}\"\n", prefix),
+        )
+        .unwrap();
+
+        let err = load_config_from(Some(config_path.as_path()), dir.path()).unwrap_err();
+        assert!(
+            err.contains("must contain only alphanumeric characters, hyphens, and underscores"),
+            "prefix {:?}: {}",
+            prefix,
+            err
+        );
+    }
+}
+
+#[test]
+fn is_valid_prefix_accepts_safe_characters() {
+    assert!(is_valid_prefix("WRK"));
+    assert!(is_valid_prefix("my_app-2"));
+    assert!(!is_valid_prefix("a.b"));
+}
+
 // --- CliTool tests ---
 
+#[test]
+fn parse_cli_tool_accepts_builtin_tools_only() {
+    assert_eq!(parse_cli_tool("claude").unwrap(), CliTool::Claude);
+    assert_eq!(parse_cli_tool("OpenCode").unwrap(), CliTool::OpenCode);
+    assert_eq!(parse_cli_tool("gemini").unwrap(), CliTool::Gemini);
+    assert!(parse_cli_tool("custom").is_err());
+}
+
+#[test]
+fn apply_agent_overrides_switching_cli_drops_config_model() {
+    let mut config = PhaseGolemConfig::default();
+    config.agent.model = Some("opus".to_string());
+
+    apply_agent_overrides(&mut config, Some(CliTool::OpenCode), None).unwrap();
+
+    assert_eq!(config.agent.cli, CliTool::OpenCode);
+    assert_eq!(config.agent.model, None);
+}
+
+#[test]
+fn apply_agent_overrides_model_only_keeps_cli() {
+    let mut config = PhaseGolemConfig::default();
+    config.agent.model = Some("opus".to_string());
+
+    apply_agent_overrides(&mut config, None, Some(" sonnet ".to_string())).unwrap();
+
+    assert_eq!(config.agent.cli, CliTool::Claude);
+    assert_eq!(config.agent.model.as_deref(), Some("sonnet"));
+}
+
+#[test]
+fn apply_agent_overrides_rejects_flag_like_model() {
+    let mut config = PhaseGolemConfig::default();
+    let err = apply_agent_overrides(&mut config, None, Some("--danger".to_string())).unwrap_err();
+    assert!(err.contains("agent.model must not start with '-'"));
+}
+
 #[test]
 fn cli_tool_default_is_claude() {
     assert_eq!(CliTool::default(), CliTool::Claude);
@@ -561,12 +991,14 @@ fn cli_tool_default_is_claude() {
 fn cli_tool_binary_name() {
     assert_eq!(CliTool::Claude.binary_name(), "claude");
     assert_eq!(CliTool::OpenCode.binary_name(), "opencode");
+    assert_eq!(CliTool::Gemini.binary_name(), "gemini");
 }
 
 #[test]
 fn cli_tool_display_name() {
     assert_eq!(CliTool::Claude.display_name(), "Claude CLI");
     assert_eq!(CliTool::OpenCode.display_name(), "OpenCode CLI");
+    assert_eq!(CliTool::Gemini.display_name(), "Gemini CLI");
 }
 
 #[test]
@@ -605,6 +1037,27 @@ fn cli_tool_build_args_opencode_with_model() {
     assert_eq!(args, vec!["run", "--model", "gpt-4", "--quiet", "do stuff"]);
 }
 
+#[test]
+fn cli_tool_build_args_gemini_without_model() {
+    let args = CliTool::Gemini.build_args("do stuff", None);
+    assert_eq!(args, vec!["--yolo", "--prompt", "do stuff"]);
+}
+
+#[test]
+fn cli_tool_build_args_gemini_with_model() {
+    let args = CliTool::Gemini.build_args("do stuff", Some("gemini-2.5-pro"));
+    assert_eq!(
+        args,
+        vec![
+            "--yolo",
+            "--model",
+            "gemini-2.5-pro",
+            "--prompt",
+            "do stuff"
+        ]
+    );
+}
+
 #[test]
 fn cli_tool_build_args_with_special_chars_in_prompt() {
     let prompt = "line1\nline2\n\"quoted\"\nspecial: $HOME & stuff; rm -rf /";
@@ -612,18 +1065,22 @@ fn cli_tool_build_args_with_special_chars_in_prompt() {
     assert_eq!(args[args.len() - 1], prompt);
     let args_oc = CliTool::OpenCode.build_args(prompt, None);
     assert_eq!(args_oc[args_oc.len() - 1], prompt);
+    let args_gm = CliTool::Gemini.build_args(prompt, None);
+    assert_eq!(args_gm[args_gm.len() - 1], prompt);
 }
 
 #[test]
 fn cli_tool_version_args() {
     assert_eq!(CliTool::Claude.version_args(), vec!["--version"]);
     assert_eq!(CliTool::OpenCode.version_args(), vec!["--version"]);
+    assert_eq!(CliTool::Gemini.version_args(), vec!["--version"]);
 }
 
 #[test]
 fn cli_tool_install_hint_non_empty() {
     assert!(!CliTool::Claude.install_hint().is_empty());
     assert!(!CliTool::OpenCode.install_hint().is_empty());
+    assert!(!CliTool::Gemini.install_hint().is_empty());
 }
 
 #[test]
@@ -650,6 +1107,104 @@ cli = "opencode"
     assert_eq!(config.agent.cli, CliTool::OpenCode);
 }
 
+#[test]
+fn cli_tool_serde_gemini() {
+    let config: PhaseGolemConfig = toml::from_str(
+        r#"
+[agent]
+cli = "gemini"
+"#,
+    )
+    .unwrap();
+    assert_eq!(config.agent.cli, CliTool::Gemini);
+}
+
+#[test]
+fn cli_tool_serde_custom_with_table() {
+    let config: PhaseGolemConfig = toml::from_str(
+        r#"
+[agent]
+cli = "custom"
+
+[agent.custom]
+command = "aider"
+args = ["--message-file", "{prompt_file}", "--result", "{result_file}"]
+"#,
+    )
+    .unwrap();
+    assert_eq!(config.agent.cli, CliTool::Custom);
+    assert_eq!(config.agent.binary_name(), "aider");
+    assert_eq!(
+        config.agent.custom.unwrap().args,
+        vec![
+            "--message-file",
+            "{prompt_file}",
+            "--result",
+            "{result_file}"
+        ]
+    );
+}
+
+#[test]
+fn custom_agent_build_args_substitutes_placeholders() {
+    let custom = CustomAgentConfig {
+        command: "wrapper".to_string(),
+        args: vec![
+            "--prompt={prompt_file}".to_string(),
+            "{result_file}".to_string(),
+            "--model".to_string(),
+            "{model}".to_string(),
+        ],
+    };
+    let args = custom.build_args(
+        Path::new("/tmp/p.md"),
+        Path::new("/tmp/r.json"),
+        Some("gpt-4"),
+    );
+    assert_eq!(
+        args,
+        vec!["--prompt=/tmp/p.md", "/tmp/r.json", "--model", "gpt-4"]
+    );
+
+    let args = custom.build_args(Path::new("/tmp/p.md"), Path::new("/tmp/r.json"), None);
+    assert_eq!(args[3], "");
+}
+
+#[test]
+fn validate_custom_cli_requires_table() {
+    let mut config = PhaseGolemConfig::default();
+    config.agent.cli = CliTool::Custom;
+    config
+        .pipelines
+        .insert("t".to_string(), default_feature_pipeline());
+    let errors = validate(&config).unwrap_err();
+    assert!(errors.iter().any(|e| e.contains("[agent.custom]")));
+}
+
+#[test]
+fn validate_custom_cli_requires_result_file_placeholder() {
+    let mut config = PhaseGolemConfig::default();
+    config.agent.cli = CliTool::Custom;
+    config.agent.custom = Some(CustomAgentConfig {
+        command: "aider".to_string(),
+        args: vec!["{prompt_file}".to_string()],
+    });
+    config
+        .pipelines
+        .insert("t".to_string(), default_feature_pipeline());
+    let errors = validate(&config).unwrap_err();
+    assert!(errors.iter().any(|e| e.contains("{result_file}")));
+
+    config
+        .agent
+        .custom
+        .as_mut()
+        .unwrap()
+        .args
+        .push("{result_file}".to_string());
+    assert!(validate(&config).is_ok());
+}
+
 #[test]
 fn cli_tool_serde_invalid_value_rejected() {
     let result = toml::from_str::<PhaseGolemConfig>(
@@ -708,6 +1263,149 @@ cli_tool = "claude"
     assert!(result.is_err());
 }
 
+// --- GitConfig tests ---
+
+#[test]
+fn git_config_missing_section_defaults() {
+    let config: PhaseGolemConfig = toml::from_str("").unwrap();
+    assert!(!config.git.verify_remote);
+    assert_eq!(config.git.remote, "origin");
+    assert!(!config.git.push_on_complete);
+    assert_eq!(config.git.branch, None);
+    assert!(!config.git.require_push);
+}
+
+#[test]
+fn git_config_full_section_parses() {
+    let config: PhaseGolemConfig = toml::from_str(
+        r#"
+[git]
+verify_remote = true
+remote = "upstream"
+push_on_complete = true
+branch = "autonomous"
+require_push = true
+"#,
+    )
+    .unwrap();
+    assert!(config.git.verify_remote);
+    assert_eq!(config.git.remote, "upstream");
+    assert!(config.git.push_on_complete);
+    assert_eq!(config.git.branch.as_deref(), Some("autonomous"));
+    assert!(config.git.require_push);
+}
+
+#[test]
+fn security_config_defaults_and_overrides() {
+    let config: PhaseGolemConfig = toml::from_str("").unwrap();
+    assert!(config
+        .security
+        .redact_keys
+        .iter()
+        .any(|k| k == "webhook_url"));
+
+    let config: PhaseGolemConfig = toml::from_str(
+        r#"
+[security]
+redact_keys = ["slack_token"]
+"#,
+    )
+    .unwrap();
+    assert_eq!(config.security.redact_keys, vec!["slack_token"]);
+}
+
+#[test]
+fn validate_git_remote_flag_like_rejected_when_verifying() {
+    let mut config = PhaseGolemConfig::default();
+    config.git.verify_remote = true;
+    config.git.remote = "--upload-pack=evil".to_string();
+    config
+        .pipelines
+        .insert("t".to_string(), default_feature_pipeline());
+    let errors = validate(&config).unwrap_err();
+    assert!(errors.iter().any(|e| e.contains("git.remote")));
+}
+
+#[test]
+fn validate_git_branch_flag_like_rejected() {
+    let mut config = PhaseGolemConfig::default();
+    config.git.push_on_complete = true;
+    config.git.branch = Some("--force".to_string());
+    config
+        .pipelines
+        .insert("t".to_string(), default_feature_pipeline());
+    let errors = validate(&config).unwrap_err();
+    assert!(errors.iter().any(|e| e.contains("git.branch")));
+}
+
+#[test]
+fn parse_agent_env_accepts_key_value_pairs() {
+    let env = parse_agent_env(&[
+        "ANTHROPIC_BASE_URL=http://localhost:8080/v1?a=b".to_string(),
+        "EMPTY=".to_string(),
+        "EMPTY=set".to_string(),
+    ])
+    .unwrap();
+
+    assert_eq!(env["ANTHROPIC_BASE_URL"], "http://localhost:8080/v1?a=b");
+    assert_eq!(env["EMPTY"], "set");
+}
+
+#[test]
+fn parse_agent_env_rejects_malformed_entries() {
+    let err = parse_agent_env(&[
+        "NO_EQUALS".to_string(),
+        "=value".to_string(),
+        "1BAD=x".to_string(),
+        "OK=fine".to_string(),
+    ])
+    .unwrap_err();
+
+    assert!(err.contains("'NO_EQUALS'"), "got: {}", err);
+    assert!(err.contains("'=value'"), "got: {}", err);
+    assert!(err.contains("'1BAD=x'"), "got: {}", err);
+    assert!(!err.contains("OK=fine"), "got: {}", err);
+}
+
+#[test]
+fn load_config_reads_agent_env_table() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("phase-golem.toml"),
+        r#"
+[agent.env]
+ANTHROPIC_BASE_URL = "http://proxy:8080"
+"#,
+    )
+    .unwrap();
+
+    let config = load_config(dir.path()).unwrap();
+    assert_eq!(
+        config
+            .agent
+            .env
+            .get("ANTHROPIC_BASE_URL")
+            .map(String::as_str),
+        Some("http://proxy:8080")
+    );
+}
+
+#[test]
+fn validate_rejects_invalid_agent_env_key() {
+    let mut config = PhaseGolemConfig::default();
+    config
+        .agent
+        .env
+        .insert("BAD-KEY".to_string(), "x".to_string());
+
+    let errors = validate(&config).unwrap_err();
+    assert!(
+        errors.iter().any(|e| e.contains("agent.env key 'BAD-KEY'")),
+        "got: {:?}",
+        errors
+    );
+}
+
 // --- Normalization tests ---
 
 #[test]
@@ -716,6 +1414,8 @@ fn normalize_empty_string_model_to_none() {
         agent: AgentConfig {
             cli: CliTool::Claude,
             model: Some("".to_string()),
+            custom: None,
+            env: BTreeMap::new(),
         },
         ..PhaseGolemConfig::default()
     };
@@ -729,6 +1429,8 @@ fn normalize_whitespace_model_to_none() {
         agent: AgentConfig {
             cli: CliTool::Claude,
             model: Some("   ".to_string()),
+            custom: None,
+            env: BTreeMap::new(),
         },
         ..PhaseGolemConfig::default()
     };
@@ -742,6 +1444,8 @@ fn normalize_tab_newline_model_to_none() {
         agent: AgentConfig {
             cli: CliTool::Claude,
             model: Some("\t\n".to_string()),
+            custom: None,
+            env: BTreeMap::new(),
         },
         ..PhaseGolemConfig::default()
     };
@@ -755,6 +1459,8 @@ fn normalize_valid_model_preserved() {
         agent: AgentConfig {
             cli: CliTool::Claude,
             model: Some("opus".to_string()),
+            custom: None,
+            env: BTreeMap::new(),
         },
         ..PhaseGolemConfig::default()
     };
@@ -797,6 +1503,8 @@ fn load_config_no_file_agent_defaults() {
         AgentConfig {
             cli: CliTool::Claude,
             model: None,
+            custom: None,
+            env: BTreeMap::new(),
         }
     );
 }
@@ -895,6 +1603,29 @@ fn validate_none_model_passes() {
     assert!(validate(&config).is_ok());
 }
 
+#[test]
+fn project_prefix_for_keeps_configured_item_prefix() {
+    let mut config = PhaseGolemConfig::default();
+    config.project.prefixes = vec!["DOC".to_string()];
+
+    assert_eq!(config.project.prefix_for("DOC-004"), "DOC");
+    assert_eq!(config.project.prefix_for("WRK-001"), "WRK");
+    // Unlisted prefixes (e.g. items from `tg add`) fall back to the project prefix
+    assert_eq!(config.project.prefix_for("tg-a1b2c"), "WRK");
+}
+
+#[test]
+fn validate_invalid_extra_prefix_fails() {
+    let mut config = PhaseGolemConfig::default();
+    config.project.prefixes = vec!["DOC".to_string(), "bad prefix".to_string()];
+    config
+        .pipelines
+        .insert("t".to_string(), default_feature_pipeline());
+
+    let errors = validate(&config).unwrap_err();
+    assert!(errors.iter().any(|e| e.contains("project.prefixes")));
+}
+
 // --- PhaseConfig backward compat tests ---
 
 #[test]
@@ -1001,3 +1732,124 @@ phases = [
     assert_eq!(config.pipelines["feature"].phases.len(), 6);
     assert!(config.pipelines["feature"].phases[4].is_destructive);
 }
+
+// --- YAML config tests ---
+
+const EQUIVALENT_TOML: &str = r#"
+[project]
+prefix = "YML"
+
+[guardrails]
+max_size = "large"
+max_risk = "medium"
+on_violation = "warn"
+
+[execution]
+max_wip = 2
+max_concurrent = 3
+archive_on_done = false
+
+[agent]
+cli = "opencode"
+model = "gpt-5"
+
+[agent.env]
+FOO = "bar"
+
+[pipelines.feature]
+pre_phases = [
+    { name = "research", workflows = ["research.md"], is_destructive = false },
+]
+phases = [
+    { name = "build", workflows = ["build.md"], is_destructive = true },
+    { name = "review", workflows = ["review.md"], is_destructive = false },
+]
+"#;
+
+const EQUIVALENT_YAML: &str = r#"
+project:
+  prefix: YML
+guardrails:
+  max_size: large
+  max_risk: medium
+  on_violation: warn
+execution:
+  max_wip: 2
+  max_concurrent: 3
+  archive_on_done: false
+agent:
+  cli: opencode
+  model: gpt-5
+  env:
+    FOO: bar
+pipelines:
+  feature:
+    pre_phases:
+      - name: research
+        workflows: [research.md]
+        is_destructive: false
+    phases:
+      - name: build
+        workflows: [build.md]
+        is_destructive: true
+      - name: review
+        workflows: [review.md]
+        is_destructive: false
+"#;
+
+#[test]
+fn load_config_from_yaml_path_matches_equivalent_toml() {
+    let dir = tempfile::tempdir().unwrap();
+    let toml_path = dir.path().join("phase-golem.toml");
+    let yaml_path = dir.path().join("custom.yaml");
+    std::fs::write(&toml_path, EQUIVALENT_TOML).unwrap();
+    std::fs::write(&yaml_path, EQUIVALENT_YAML).unwrap();
+
+    let from_toml = load_config_from(Some(toml_path.as_path()), dir.path()).unwrap();
+    let from_yaml = load_config_from(Some(yaml_path.as_path()), dir.path()).unwrap();
+
+    assert_eq!(from_yaml, from_toml);
+    assert_eq!(from_yaml.project.prefix, "YML");
+    assert_eq!(from_yaml.pipelines["feature"].phases.len(), 2);
+}
+
+#[test]
+fn load_config_finds_default_yml_file() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("phase-golem.yml"), EQUIVALENT_YAML).unwrap();
+
+    let config = load_config(dir.path()).unwrap();
+
+    assert_eq!(config.project.prefix, "YML");
+    assert_eq!(config.execution.max_wip, 2);
+}
+
+#[test]
+fn load_config_prefers_toml_over_yaml() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("phase-golem.yaml"), EQUIVALENT_YAML).unwrap();
+    std::fs::write(
+        dir.path().join("phase-golem.toml"),
+        "[project]\nprefix = \"TOML\"\n",
+    )
+    .unwrap();
+
+    let config = load_config(dir.path()).unwrap();
+
+    assert_eq!(config.project.prefix, "TOML");
+}
+
+#[test]
+fn load_config_reports_yaml_parse_errors_with_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("phase-golem.yaml");
+    std::fs::write(&path, "execution:\n  max_wip: [not, a, number]\n").unwrap();
+
+    let err = load_config(dir.path()).unwrap_err();
+
+    assert!(
+        err.starts_with(&format!("Failed to parse {}", path.display())),
+        "{}",
+        err
+    );
+}
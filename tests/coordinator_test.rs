@@ -6,7 +6,10 @@ use std::process::Command;
 use task_golem::model::item::Item;
 use task_golem::store::Store;
 
-use phase_golem::coordinator::spawn_coordinator;
+use phase_golem::config::{WorklogFormat, WorklogNaming};
+use phase_golem::coordinator::{
+    spawn_coordinator, spawn_coordinator_with_worklog, spawn_coordinator_with_worklog_naming,
+};
 use phase_golem::pg_error::PgError;
 use phase_golem::pg_item::{self, PgItem};
 use phase_golem::types::{
@@ -31,6 +34,7 @@ fn make_phase_result(item_id: &str, phase: &str, summary: &str) -> PhaseResult {
         commit_summary: None,
         duplicates: vec![],
         description: None,
+        confidence: None,
     }
 }
 
@@ -196,6 +200,45 @@ async fn update_item_invalid_transition_logged_and_skipped() {
     assert_eq!(snapshot[0].pg_status(), ItemStatus::New);
 }
 
+#[tokio::test]
+async fn wait_for_state_change_wakes_on_update() {
+    let (handle, _task, _dir) =
+        setup_coordinator_with_items(vec![common::make_pg_item("WRK-001", ItemStatus::New)]);
+
+    let waiter_handle = handle.clone();
+    let waiter = tokio::spawn(async move { waiter_handle.wait_for_state_change().await });
+    // Let the waiter register before the update lands
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+    handle
+        .update_item("WRK-001", ItemUpdate::TransitionStatus(ItemStatus::Scoping))
+        .await
+        .unwrap();
+
+    tokio::time::timeout(std::time::Duration::from_secs(2), waiter)
+        .await
+        .expect("waiter should be woken by the update")
+        .unwrap();
+}
+
+#[tokio::test]
+async fn wait_for_state_change_ignores_reads() {
+    let (handle, _task, _dir) =
+        setup_coordinator_with_items(vec![common::make_pg_item("WRK-001", ItemStatus::New)]);
+
+    let waiter_handle = handle.clone();
+    let waiter = tokio::spawn(async move { waiter_handle.wait_for_state_change().await });
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+    handle.get_snapshot().await.unwrap();
+
+    let result = tokio::time::timeout(std::time::Duration::from_millis(200), waiter).await;
+    assert!(
+        result.is_err(),
+        "snapshot reads must not signal a state change"
+    );
+}
+
 #[tokio::test]
 async fn update_item_set_phase() {
     let (handle, _task, _dir) =
@@ -307,6 +350,30 @@ async fn update_item_update_assessments() {
     assert_eq!(snapshot[0].complexity(), Some(DimensionLevel::Low));
 }
 
+#[tokio::test]
+async fn update_item_single_dimension_persists_to_store() {
+    let (handle, _task, dir) =
+        setup_coordinator_with_items(vec![common::make_pg_item("WRK-001", ItemStatus::Ready)]);
+
+    handle
+        .update_item("WRK-001", ItemUpdate::SetImpact(DimensionLevel::High))
+        .await
+        .unwrap();
+    handle
+        .update_item("WRK-001", ItemUpdate::SetComplexity(DimensionLevel::Medium))
+        .await
+        .unwrap();
+
+    // Reload straight from disk
+    let store = Store::new(dir.path().join(".task-golem"));
+    let items = store.load_active().unwrap();
+    let item = PgItem(items[0].clone());
+    assert_eq!(item.impact(), Some(DimensionLevel::High));
+    assert_eq!(item.complexity(), Some(DimensionLevel::Medium));
+    assert!(item.size().is_none());
+    assert!(item.risk().is_none());
+}
+
 #[tokio::test]
 async fn update_item_set_pipeline_type() {
     let (handle, _task, _dir) =
@@ -472,6 +539,43 @@ async fn complete_phase_destructive_commits_immediately() {
     );
 }
 
+#[tokio::test]
+async fn complete_phase_destructive_commits_loose_change_file() {
+    let dir = common::setup_test_env();
+    let store = common::setup_task_golem_store(dir.path());
+
+    let pg = common::make_in_progress_pg_item("WRK-001", "build");
+    save_and_commit_store(dir.path(), &store, &[pg.0]);
+
+    // Agent wrote a loose file instead of a changes/WRK-001_<slug>/ directory
+    let changes_dir = dir.path().join("changes");
+    fs::create_dir_all(&changes_dir).unwrap();
+    fs::write(changes_dir.join("WRK-001.md"), "loose output").unwrap();
+
+    let (handle, _task) = spawn_coordinator(store, dir.path().to_path_buf(), "WRK".to_string());
+
+    handle
+        .complete_phase(
+            "WRK-001",
+            make_phase_result("WRK-001", "build", "Build complete"),
+            true,
+        )
+        .await
+        .unwrap();
+
+    let show = Command::new("git")
+        .args(["show", "--name-only", "--pretty=format:", "HEAD"])
+        .current_dir(dir.path())
+        .output()
+        .expect("git show");
+    let committed = String::from_utf8_lossy(&show.stdout).to_string();
+    assert!(
+        committed.lines().any(|l| l == "changes/WRK-001.md"),
+        "Loose change file should be committed, got: {}",
+        committed
+    );
+}
+
 #[tokio::test]
 async fn complete_phase_non_destructive_stages_only() {
     let dir = common::setup_test_env();
@@ -525,6 +629,80 @@ async fn complete_phase_non_destructive_stages_only() {
     );
 }
 
+#[tokio::test]
+async fn unexpected_dirty_paths_reports_externally_staged_file_but_not_batch_output() {
+    let dir = common::setup_test_env();
+    let store = common::setup_task_golem_store(dir.path());
+
+    let pg = common::make_in_progress_pg_item("WRK-001", "prd");
+    save_and_commit_store(dir.path(), &store, &[pg.0]);
+
+    // Non-destructive phase output outside changes/, staged by the coordinator
+    fs::create_dir_all(dir.path().join("docs")).unwrap();
+    fs::write(dir.path().join("docs/prd.md"), "prd output").unwrap();
+
+    let (handle, _task) = spawn_coordinator(store, dir.path().to_path_buf(), "WRK".to_string());
+    handle
+        .complete_phase(
+            "WRK-001",
+            make_phase_result("WRK-001", "prd", "PRD complete"),
+            false,
+        )
+        .await
+        .unwrap();
+
+    // Someone else stages a file while the batch is pending
+    fs::write(dir.path().join("notes.txt"), "scratch").unwrap();
+    Command::new("git")
+        .args(["add", "notes.txt"])
+        .current_dir(dir.path())
+        .output()
+        .expect("stage notes");
+
+    assert_eq!(
+        handle.unexpected_dirty_paths().await.unwrap(),
+        vec!["notes.txt".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn complete_phase_appends_phase_history() {
+    let dir = common::setup_test_env();
+    let store = common::setup_task_golem_store(dir.path());
+
+    let pg = common::make_in_progress_pg_item("WRK-001", "prd");
+    save_and_commit_store(dir.path(), &store, &[pg.0]);
+    let sha_before = head_sha(dir.path());
+
+    let (handle, _task) =
+        spawn_coordinator(store.clone(), dir.path().to_path_buf(), "WRK".to_string());
+
+    handle
+        .complete_phase(
+            "WRK-001",
+            make_phase_result("WRK-001", "prd", "PRD complete"),
+            false,
+        )
+        .await
+        .unwrap();
+    handle
+        .complete_phase(
+            "WRK-001",
+            make_phase_result("WRK-001", "build", "Build complete"),
+            true,
+        )
+        .await
+        .unwrap();
+
+    let items = store.with_lock(|s| s.load_active()).unwrap();
+    let history = PgItem(items[0].clone()).phase_history();
+    let phases: Vec<&str> = history.iter().map(|e| e.phase.as_str()).collect();
+    assert_eq!(phases, vec!["prd", "build"]);
+    assert_eq!(history[0].result, ResultCode::PhaseComplete);
+    assert_eq!(history[0].commit.as_deref(), Some(sha_before.as_str()));
+    assert!(history[0].completed_at <= history[1].completed_at);
+}
+
 #[tokio::test]
 async fn complete_phase_destructive_git_failure_preserves_jsonl() {
     let dir = common::setup_test_env();
@@ -648,6 +826,121 @@ async fn batch_commit_noop_when_nothing_staged() {
     assert_eq!(sha_before, sha_after, "No commit when nothing staged");
 }
 
+// =============================================================================
+// CommitCheckpoint tests
+// =============================================================================
+
+fn head_sha(root: &Path) -> String {
+    let output = Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .current_dir(root)
+        .output()
+        .expect("git rev-parse");
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[tokio::test]
+async fn commit_checkpoint_commits_store_only_for_checkpoint_phase() {
+    let dir = common::setup_test_env();
+    let store = common::setup_task_golem_store(dir.path());
+
+    let pg = common::make_in_progress_pg_item("WRK-001", "prd");
+    save_and_commit_store(dir.path(), &store, &[pg.0]);
+
+    let (handle, _task) = spawn_coordinator(store, dir.path().to_path_buf(), "WRK".to_string());
+
+    // Non-checkpoint phase: the store changes but nothing is committed
+    let sha_before = head_sha(dir.path());
+    handle
+        .complete_phase(
+            "WRK-001",
+            make_phase_result("WRK-001", "prd", "PRD done"),
+            false,
+        )
+        .await
+        .unwrap();
+    handle
+        .update_item("WRK-001", ItemUpdate::SetPhase("design".to_string()))
+        .await
+        .unwrap();
+    assert_eq!(
+        sha_before,
+        head_sha(dir.path()),
+        "Non-checkpoint phase should not commit the store"
+    );
+
+    // Checkpoint phase: the store is committed along with the pending batch
+    handle
+        .complete_phase(
+            "WRK-001",
+            make_phase_result("WRK-001", "design", "Design done"),
+            false,
+        )
+        .await
+        .unwrap();
+    handle
+        .update_item("WRK-001", ItemUpdate::SetPhase("build".to_string()))
+        .await
+        .unwrap();
+    handle.commit_checkpoint("WRK-001", "design").await.unwrap();
+    assert_ne!(
+        sha_before,
+        head_sha(dir.path()),
+        "Checkpoint should create a commit"
+    );
+
+    let show = Command::new("git")
+        .args(["show", "--name-only", "--format=%s", "HEAD"])
+        .current_dir(dir.path())
+        .output()
+        .expect("git show");
+    let show = String::from_utf8_lossy(&show.stdout).to_string();
+    assert!(
+        show.contains(".task-golem/tasks.jsonl"),
+        "Checkpoint commit should include the store: {}",
+        show
+    );
+    assert!(show.contains("[WRK-001][prd]"));
+    assert!(show.contains("[WRK-001][design]"));
+
+    let status = Command::new("git")
+        .args(["status", "--porcelain", ".task-golem"])
+        .current_dir(dir.path())
+        .output()
+        .expect("git status");
+    assert!(
+        String::from_utf8_lossy(&status.stdout).trim().is_empty(),
+        "Store should be clean after a checkpoint"
+    );
+}
+
+#[tokio::test]
+async fn commit_checkpoint_without_pending_phases_uses_checkpoint_message() {
+    let dir = common::setup_test_env();
+    let store = common::setup_task_golem_store(dir.path());
+
+    let pg = common::make_in_progress_pg_item("WRK-001", "build");
+    save_and_commit_store(dir.path(), &store, &[pg.0]);
+
+    let (handle, _task) = spawn_coordinator(store, dir.path().to_path_buf(), "WRK".to_string());
+
+    handle
+        .update_item("WRK-001", ItemUpdate::SetPhase("review".to_string()))
+        .await
+        .unwrap();
+    handle.commit_checkpoint("WRK-001", "build").await.unwrap();
+
+    let subject = Command::new("git")
+        .args(["log", "-1", "--format=%s"])
+        .current_dir(dir.path())
+        .output()
+        .expect("git log");
+    assert_eq!(
+        String::from_utf8_lossy(&subject.stdout).trim(),
+        "[WRK-001][build] Checkpoint"
+    );
+}
+
 // =============================================================================
 // GetHeadSha and IsAncestor tests
 // =============================================================================
@@ -841,6 +1134,200 @@ async fn write_worklog_creates_entry() {
     );
 }
 
+#[tokio::test]
+async fn write_worklog_with_result_records_full_phase_result() {
+    let dir = common::setup_test_env();
+    let store = common::setup_task_golem_store(dir.path());
+
+    let pg = common::make_in_progress_pg_item("WRK-001", "build");
+    save_and_commit_store(dir.path(), &store, &[pg.0]);
+
+    let (handle, _task) = spawn_coordinator(store, dir.path().to_path_buf(), "WRK".to_string());
+
+    let result = PhaseResult {
+        context: Some("Touched the parser".to_string()),
+        updated_assessments: Some(UpdatedAssessments {
+            size: Some(SizeLevel::Large),
+            complexity: None,
+            risk: Some(DimensionLevel::High),
+            impact: None,
+        }),
+        follow_ups: vec![FollowUp {
+            title: "Add parser fuzzing".to_string(),
+            context: None,
+            suggested_size: None,
+            suggested_risk: None,
+        }],
+        duplicates: vec!["WRK-007".to_string()],
+        ..make_phase_result("WRK-001", "build", "All done")
+    };
+
+    handle
+        .write_worklog_with_result("WRK-001", "Test item WRK-001", "Complete", &result)
+        .await
+        .unwrap();
+
+    let worklog_dir = dir.path().join("_worklog");
+    let paths: Vec<_> = fs::read_dir(&worklog_dir)
+        .expect("read worklog dir")
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .collect();
+    assert_eq!(paths.len(), 2, "Should have markdown and JSONL worklogs");
+    let path_with_ext = |ext: &str| {
+        paths
+            .iter()
+            .find(|p| p.extension().is_some_and(|e| e == ext))
+            .unwrap_or_else(|| panic!("missing .{} worklog", ext))
+    };
+
+    let markdown = fs::read_to_string(path_with_ext("md")).unwrap();
+    assert!(markdown.contains("All done"), "Prose entry still written");
+
+    let jsonl = fs::read_to_string(path_with_ext("jsonl")).unwrap();
+    assert_eq!(jsonl.lines().count(), 1);
+    let entry: serde_json::Value = serde_json::from_str(jsonl.trim()).unwrap();
+    assert_eq!(entry["item_id"], "WRK-001");
+    assert_eq!(entry["outcome"], "Complete");
+    let logged: PhaseResult = serde_json::from_value(entry["result"].clone()).unwrap();
+    assert_eq!(logged, result);
+}
+
+/// Write one worklog entry for each of two items concurrently under `naming`,
+/// returning the sorted worklog file names and their combined markdown content.
+async fn write_worklogs_with_naming(naming: WorklogNaming) -> (Vec<String>, String) {
+    let dir = common::setup_test_env();
+    let store = common::setup_task_golem_store(dir.path());
+
+    let items = [
+        common::make_in_progress_pg_item("WRK-001", "build"),
+        common::make_in_progress_pg_item("WRK-002", "build"),
+    ];
+    save_and_commit_store(
+        dir.path(),
+        &store,
+        &[items[0].0.clone(), items[1].0.clone()],
+    );
+
+    let (handle, _task) = spawn_coordinator_with_worklog_naming(
+        store,
+        dir.path().to_path_buf(),
+        "WRK".to_string(),
+        naming,
+    );
+
+    let (first, second) = tokio::join!(
+        handle.write_worklog("WRK-001", "First", "build", "Complete", "one"),
+        handle.write_worklog("WRK-002", "Second", "build", "Complete", "two"),
+    );
+    first.unwrap();
+    second.unwrap();
+
+    let mut names: Vec<String> = fs::read_dir(dir.path().join("_worklog"))
+        .expect("read worklog dir")
+        .filter_map(|e| e.ok())
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .collect();
+    names.sort();
+
+    let content: String = names
+        .iter()
+        .map(|n| fs::read_to_string(dir.path().join("_worklog").join(n)).unwrap())
+        .collect();
+    (names, content)
+}
+
+#[tokio::test]
+async fn worklog_naming_per_month_uses_month_file() {
+    let (names, content) = write_worklogs_with_naming(WorklogNaming::PerMonth).await;
+
+    let month = chrono::Utc::now().format("%Y-%m").to_string();
+    assert_eq!(names, vec![format!("{}.md", month)]);
+    assert_eq!(content.matches("\n---\n").count(), 2);
+}
+
+#[tokio::test]
+async fn worklog_naming_per_day_uses_day_file() {
+    let (names, content) = write_worklogs_with_naming(WorklogNaming::PerDay).await;
+
+    let day = chrono::Utc::now().format("%Y-%m-%d").to_string();
+    assert_eq!(names, vec![format!("{}.md", day)]);
+    assert_eq!(content.matches("\n---\n").count(), 2);
+}
+
+#[tokio::test]
+async fn worklog_naming_per_item_splits_by_item() {
+    let (names, _) = write_worklogs_with_naming(WorklogNaming::PerItem).await;
+
+    assert_eq!(names, vec!["WRK-001.md", "WRK-002.md"]);
+}
+
+#[tokio::test]
+async fn worklog_naming_single_uses_one_file() {
+    let (names, content) = write_worklogs_with_naming(WorklogNaming::Single).await;
+
+    assert_eq!(names, vec!["worklog.md"]);
+    assert!(content.contains("WRK-001 (First)"));
+    assert!(content.contains("WRK-002 (Second)"));
+}
+
+#[tokio::test]
+async fn worklog_format_jsonl_writes_one_object_per_entry() {
+    let dir = common::setup_test_env();
+    let store = common::setup_task_golem_store(dir.path());
+
+    let pg = common::make_in_progress_pg_item("WRK-001", "build");
+    save_and_commit_store(dir.path(), &store, &[pg.0]);
+
+    let (handle, _task) = spawn_coordinator_with_worklog(
+        store,
+        dir.path().to_path_buf(),
+        "WRK".to_string(),
+        WorklogNaming::Single,
+        WorklogFormat::Jsonl,
+    );
+
+    handle
+        .write_worklog("WRK-001", "First", "build", "Complete", "one")
+        .await
+        .unwrap();
+    handle.archive_item("WRK-001").await.unwrap();
+
+    let worklog_dir = dir.path().join("_worklog");
+    assert!(!worklog_dir.join("worklog.md").exists());
+    let content = fs::read_to_string(worklog_dir.join("worklog.jsonl")).unwrap();
+    let entries: Vec<serde_json::Value> = content
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line is a JSON object"))
+        .collect();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0]["item_id"], "WRK-001");
+    assert_eq!(entries[0]["phase"], "build");
+    assert_eq!(entries[0]["outcome"], "Complete");
+    assert_eq!(entries[0]["summary"], "one");
+    assert_eq!(entries[1]["outcome"], "Archived");
+}
+
+#[tokio::test]
+async fn archive_item_follows_worklog_naming() {
+    let dir = common::setup_test_env();
+    let store = common::setup_task_golem_store(dir.path());
+
+    let pg = common::make_pg_item("WRK-001", ItemStatus::New);
+    save_and_commit_store(dir.path(), &store, &[pg.0]);
+
+    let (handle, _task) = spawn_coordinator_with_worklog_naming(
+        store,
+        dir.path().to_path_buf(),
+        "WRK".to_string(),
+        WorklogNaming::PerItem,
+    );
+
+    handle.archive_item("WRK-001").await.unwrap();
+
+    let content = fs::read_to_string(dir.path().join("_worklog").join("WRK-001.md")).unwrap();
+    assert!(content.contains("**Outcome:** Archived"));
+}
+
 // =============================================================================
 // ArchiveItem tests
 // =============================================================================
@@ -928,7 +1415,7 @@ async fn ingest_follow_ups_creates_new_items() {
     ];
 
     let new_ids = handle
-        .ingest_follow_ups(follow_ups, "WRK-001/build")
+        .ingest_follow_ups(follow_ups, "WRK-001/build", None)
         .await
         .unwrap();
 
@@ -953,13 +1440,33 @@ async fn ingest_follow_ups_creates_new_items() {
     assert_eq!(fu1.origin(), Some("WRK-001/build".to_string()));
 }
 
+#[tokio::test]
+async fn ingest_follow_ups_uses_given_prefix() {
+    let (handle, _task, _dir) =
+        setup_coordinator_with_items(vec![common::make_pg_item("DOC-001", ItemStatus::New)]);
+
+    let follow_ups = vec![FollowUp {
+        title: "Fix typo".to_string(),
+        context: None,
+        suggested_size: None,
+        suggested_risk: None,
+    }];
+    let new_ids = handle
+        .ingest_follow_ups(follow_ups, "DOC-001/build", Some("DOC"))
+        .await
+        .unwrap();
+
+    assert_eq!(new_ids.len(), 1);
+    assert!(new_ids[0].starts_with("DOC-"), "got {}", new_ids[0]);
+}
+
 #[tokio::test]
 async fn ingest_follow_ups_empty_list_returns_empty() {
     let (handle, _task, _dir) =
         setup_coordinator_with_items(vec![common::make_pg_item("WRK-001", ItemStatus::New)]);
 
     let new_ids = handle
-        .ingest_follow_ups(vec![], "WRK-001/build")
+        .ingest_follow_ups(vec![], "WRK-001/build", None)
         .await
         .unwrap();
 
@@ -985,7 +1492,7 @@ async fn ingest_follow_ups_batch_generates_unique_ids() {
         .collect();
 
     let new_ids = handle
-        .ingest_follow_ups(follow_ups, "WRK-001/build")
+        .ingest_follow_ups(follow_ups, "WRK-001/build", None)
         .await
         .unwrap();
 
@@ -1024,7 +1531,7 @@ async fn ingest_follow_ups_persists_to_disk() {
     }];
 
     let new_ids = handle
-        .ingest_follow_ups(follow_ups, "WRK-001/build")
+        .ingest_follow_ups(follow_ups, "WRK-001/build", None)
         .await
         .unwrap();
 
@@ -1092,6 +1599,60 @@ async fn merge_item_self_merge_returns_error() {
     );
 }
 
+// =============================================================================
+// ReassignPipeline tests
+// =============================================================================
+
+#[tokio::test]
+async fn reassign_pipeline_resets_phase_state_in_one_write() {
+    let dir = common::setup_test_env();
+    let store = common::setup_task_golem_store(dir.path());
+
+    let mut pg = common::make_in_progress_pg_item("WRK-001", "build");
+    pg_item::set_last_phase_commit(&mut pg.0, Some("abc123"));
+    save_and_commit_store(dir.path(), &store, &[pg.0]);
+
+    let (handle, _task) = spawn_coordinator(store, dir.path().to_path_buf(), "WRK".to_string());
+
+    handle.reassign_pipeline("WRK-001", "bugfix").await.unwrap();
+
+    let snapshot = handle.get_snapshot().await.unwrap();
+    let item = &snapshot[0];
+    assert_eq!(item.pipeline_type(), Some("bugfix".to_string()));
+    assert_eq!(item.pg_status(), ItemStatus::Ready);
+    assert_eq!(item.phase(), None);
+    assert_eq!(item.phase_pool(), None);
+    assert_eq!(item.last_phase_commit(), None);
+}
+
+#[tokio::test]
+async fn reassign_pipeline_blocked_item_restores_to_ready() {
+    let (handle, _task, _dir) = setup_coordinator_with_items(vec![common::make_blocked_pg_item(
+        "WRK-001",
+        ItemStatus::InProgress,
+    )]);
+
+    handle.reassign_pipeline("WRK-001", "bugfix").await.unwrap();
+    handle.unblock_item("WRK-001", None).await.unwrap();
+
+    let snapshot = handle.get_snapshot().await.unwrap();
+    assert_eq!(snapshot[0].pg_status(), ItemStatus::Ready);
+    assert_eq!(snapshot[0].pipeline_type(), Some("bugfix".to_string()));
+}
+
+#[tokio::test]
+async fn reassign_pipeline_done_item_returns_error() {
+    let (handle, _task, _dir) =
+        setup_coordinator_with_items(vec![common::make_pg_item("WRK-001", ItemStatus::Done)]);
+
+    let result = handle.reassign_pipeline("WRK-001", "bugfix").await;
+    let err_str = result.unwrap_err().to_string();
+    assert!(err_str.contains("already Done"), "got: {}", err_str);
+
+    let snapshot = handle.get_snapshot().await.unwrap();
+    assert_eq!(snapshot[0].pipeline_type(), None);
+}
+
 // =============================================================================
 // UnblockItem tests
 // =============================================================================
@@ -1398,15 +1959,12 @@ fn halt_commit_message_cap_reached() {
 }
 
 #[test]
-fn halt_commit_message_all_done_or_blocked() {
+fn halt_commit_message_all_done() {
     let msg = format!(
         "[phase-golem] Save backlog state on halt ({:?})",
-        HaltReason::AllDoneOrBlocked
-    );
-    assert_eq!(
-        msg,
-        "[phase-golem] Save backlog state on halt (AllDoneOrBlocked)"
+        HaltReason::AllDone
     );
+    assert_eq!(msg, "[phase-golem] Save backlog state on halt (AllDone)");
 }
 
 #[test]
@@ -1557,7 +2115,7 @@ async fn persistence_round_trip_ingest_follow_ups() {
     }];
 
     let new_ids = handle
-        .ingest_follow_ups(follow_ups, "WRK-001/build")
+        .ingest_follow_ups(follow_ups, "WRK-001/build", None)
         .await
         .unwrap();
 
@@ -1566,3 +2124,84 @@ async fn persistence_round_trip_ingest_follow_ups() {
     assert_eq!(items.len(), 2);
     assert!(items.iter().any(|i| i.id == new_ids[0]));
 }
+
+// --- Item branch tests ---
+
+#[tokio::test]
+async fn destructive_phase_on_item_branch_commits_there_and_returns_to_base() {
+    let dir = common::setup_test_env();
+    let store = common::setup_task_golem_store(dir.path());
+
+    let pg = common::make_in_progress_pg_item("WRK-001", "build");
+    save_and_commit_store(dir.path(), &store, &[pg.0]);
+
+    let (handle, _task) = spawn_coordinator(store, dir.path().to_path_buf(), "WRK".to_string());
+    let base = phase_golem::git::current_branch(dir.path()).unwrap();
+
+    let branch = handle.enter_item_branch("WRK-001").await.unwrap();
+    assert_eq!(branch, "phase-golem/WRK-001");
+    assert_eq!(
+        phase_golem::git::current_branch(dir.path()).unwrap(),
+        branch
+    );
+
+    fs::write(dir.path().join("built.txt"), "output\n").unwrap();
+    handle
+        .complete_phase(
+            "WRK-001",
+            make_phase_result("WRK-001", "build", "Built"),
+            true,
+        )
+        .await
+        .unwrap();
+    handle
+        .update_item("WRK-001", ItemUpdate::SetPhase("review".to_string()))
+        .await
+        .unwrap();
+
+    assert_eq!(phase_golem::git::current_branch(dir.path()).unwrap(), base);
+    assert!(
+        !dir.path().join("built.txt").exists(),
+        "Destructive output should live on the item branch only"
+    );
+
+    let files = Command::new("git")
+        .args(["show", "--name-only", "--format=%s", &branch])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    let files = String::from_utf8_lossy(&files.stdout).to_string();
+    assert!(files.starts_with("[WRK-001][build]"), "got: {}", files);
+    assert!(files.contains("built.txt"));
+    assert!(!files.contains(".task-golem"), "got: {}", files);
+
+    // The item store stays with the base branch
+    let snapshot = handle.get_snapshot().await.unwrap();
+    assert_eq!(snapshot[0].phase().as_deref(), Some("review"));
+}
+
+#[tokio::test]
+async fn leave_item_branch_returns_to_base_without_committing() {
+    let dir = common::setup_test_env();
+    let store = common::setup_task_golem_store(dir.path());
+
+    let pg = common::make_in_progress_pg_item("WRK-001", "build");
+    save_and_commit_store(dir.path(), &store, &[pg.0]);
+
+    let (handle, _task) = spawn_coordinator(store, dir.path().to_path_buf(), "WRK".to_string());
+    let base = phase_golem::git::current_branch(dir.path()).unwrap();
+    let base_sha = head_sha(dir.path());
+
+    handle.enter_item_branch("WRK-001").await.unwrap();
+    handle.leave_item_branch().await.unwrap();
+
+    assert_eq!(phase_golem::git::current_branch(dir.path()).unwrap(), base);
+    assert_eq!(head_sha(dir.path()), base_sha);
+    assert!(phase_golem::git::branch_exists(
+        "phase-golem/WRK-001",
+        dir.path()
+    ));
+
+    // Leaving again is a no-op
+    handle.leave_item_branch().await.unwrap();
+}
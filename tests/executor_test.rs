@@ -6,16 +6,21 @@ use std::process::Command;
 use task_golem::model::item::Item;
 use tokio_util::sync::CancellationToken;
 
-use phase_golem::agent::MockAgentRunner;
-use phase_golem::config::{GuardrailsConfig, PhaseConfig, PipelineConfig, StalenessAction};
+use phase_golem::agent::{AgentRunner, MockAgentRunner};
+use phase_golem::config::{
+    ExecutionConfig, GuardrailViolationPolicy, GuardrailsConfig, Isolation, PhaseConfig,
+    PipelineConfig, StalenessAction,
+};
 use phase_golem::coordinator::spawn_coordinator;
 use phase_golem::executor::{
-    check_staleness, execute_phase, passes_guardrails, resolve_transition,
-    validate_result_identity, StalenessResult,
+    change_folder_path, check_staleness, execute_phase, find_change_folder, guardrail_violations,
+    guardrail_warning, is_review_phase, isolated_clone_dir, passes_guardrails, resolve_transition,
+    retry_backoff_delay, validate_result_identity, GuardrailViolation, StalenessResult,
+    VALIDATION_FAILED_TAG,
 };
 use phase_golem::pg_item::{self, PgItem};
 use phase_golem::types::{
-    DimensionLevel, ItemStatus, ItemUpdate, PhaseExecutionResult, PhasePool, PhaseResult,
+    DimensionLevel, FollowUp, ItemStatus, ItemUpdate, PhaseExecutionResult, PhasePool, PhaseResult,
     ResultCode, SizeLevel,
 };
 
@@ -54,6 +59,7 @@ fn make_phase_result(item_id: &str, phase: &str, result: ResultCode) -> PhaseRes
         commit_summary: None,
         duplicates: Vec::new(),
         description: None,
+        confidence: None,
     }
 }
 
@@ -62,6 +68,8 @@ fn default_guardrails() -> GuardrailsConfig {
         max_size: SizeLevel::Medium,
         max_complexity: DimensionLevel::Medium,
         max_risk: DimensionLevel::Low,
+        min_confidence: None,
+        on_violation: GuardrailViolationPolicy::Block,
     }
 }
 
@@ -92,6 +100,8 @@ fn make_simple_pipeline() -> PipelineConfig {
                 ..PhaseConfig::new("review", false)
             },
         ],
+        max_wip: None,
+        max_concurrent: None,
     }
 }
 
@@ -166,6 +176,88 @@ fn resolve_transition_last_pre_phase_fails_guardrails_blocks() {
     }
 }
 
+fn scoped_item_with_size(size: SizeLevel) -> PgItem {
+    let mut item = make_scoping_item("WRK-001", "research");
+    pg_item::set_size(&mut item.0, Some(&size));
+    item
+}
+
+fn resolve_last_pre_phase(item: &PgItem, policy: GuardrailViolationPolicy) -> Vec<ItemUpdate> {
+    let result = make_phase_result("WRK-001", "research", ResultCode::PhaseComplete);
+    let guardrails = GuardrailsConfig {
+        on_violation: policy,
+        ..default_guardrails()
+    };
+    resolve_transition(item, &result, &make_simple_pipeline(), &guardrails)
+}
+
+#[test]
+fn resolve_transition_size_at_max_promotes_under_every_policy() {
+    let item = scoped_item_with_size(SizeLevel::Medium); // Exactly max_size
+    for policy in [
+        GuardrailViolationPolicy::Block,
+        GuardrailViolationPolicy::Scope,
+        GuardrailViolationPolicy::Warn,
+    ] {
+        assert_eq!(
+            resolve_last_pre_phase(&item, policy),
+            vec![
+                ItemUpdate::ClearPhase,
+                ItemUpdate::TransitionStatus(ItemStatus::Ready)
+            ],
+            "policy {:?}",
+            policy
+        );
+    }
+}
+
+#[test]
+fn resolve_transition_size_over_max_blocks_under_block_and_scope() {
+    let item = scoped_item_with_size(SizeLevel::Large); // One above max_size
+    for policy in [
+        GuardrailViolationPolicy::Block,
+        GuardrailViolationPolicy::Scope,
+    ] {
+        assert_eq!(
+            resolve_last_pre_phase(&item, policy),
+            vec![ItemUpdate::SetBlocked(
+                "Exceeds autonomous guardrail thresholds (size large > medium)".to_string()
+            )],
+            "policy {:?}",
+            policy
+        );
+    }
+}
+
+#[test]
+fn resolve_transition_size_over_max_proceeds_with_warning_under_warn() {
+    let item = scoped_item_with_size(SizeLevel::Large);
+    let guardrails = GuardrailsConfig {
+        on_violation: GuardrailViolationPolicy::Warn,
+        ..default_guardrails()
+    };
+
+    let updates = resolve_last_pre_phase(&item, GuardrailViolationPolicy::Warn);
+
+    assert_eq!(
+        updates,
+        vec![
+            ItemUpdate::ClearPhase,
+            ItemUpdate::TransitionStatus(ItemStatus::Ready)
+        ]
+    );
+    let warning = guardrail_warning(&item, &updates, &guardrails).expect("should warn");
+    assert!(warning.contains("size large > medium"), "got: {}", warning);
+    assert_eq!(
+        guardrail_warning(
+            &scoped_item_with_size(SizeLevel::Medium),
+            &updates,
+            &guardrails
+        ),
+        None
+    );
+}
+
 #[test]
 fn resolve_transition_last_pre_phase_requires_human_review_blocks() {
     let mut item = make_scoping_item("WRK-001", "research");
@@ -242,6 +334,201 @@ fn resolve_transition_mid_main_pipeline_advances_build_to_review() {
     assert_eq!(updates[0], ItemUpdate::SetPhase("review".to_string()));
 }
 
+// --- Confidence routing ---
+
+fn confident_result(phase: &str, confidence: f32) -> PhaseResult {
+    let mut result = make_phase_result("WRK-001", phase, ResultCode::PhaseComplete);
+    result.confidence = Some(confidence);
+    result
+}
+
+fn confidence_guardrails(min_confidence: f32) -> GuardrailsConfig {
+    GuardrailsConfig {
+        min_confidence: Some(min_confidence),
+        ..default_guardrails()
+    }
+}
+
+#[test]
+fn resolve_transition_low_confidence_build_routes_to_review() {
+    let item = make_in_progress_item("WRK-001", "build");
+    let result = confident_result("build", 0.3);
+
+    let updates = resolve_transition(
+        &item,
+        &result,
+        &make_simple_pipeline(),
+        &confidence_guardrails(0.7),
+    );
+
+    assert_eq!(updates, vec![ItemUpdate::SetPhase("review".to_string())]);
+}
+
+#[test]
+fn resolve_transition_low_confidence_without_following_review_blocks() {
+    let item = make_in_progress_item("WRK-001", "prd");
+    let result = confident_result("prd", 0.3);
+
+    let updates = resolve_transition(
+        &item,
+        &result,
+        &make_simple_pipeline(),
+        &confidence_guardrails(0.7),
+    );
+
+    assert_eq!(updates.len(), 1);
+    match &updates[0] {
+        ItemUpdate::SetBlocked(reason) => {
+            assert!(
+                reason.contains("Low confidence in prd output"),
+                "{}",
+                reason
+            );
+            assert!(reason.contains("0.30 < 0.70"), "{}", reason);
+        }
+        other => panic!("Expected SetBlocked, got {:?}", other),
+    }
+}
+
+#[test]
+fn resolve_transition_low_confidence_last_phase_blocks_instead_of_done() {
+    let item = make_in_progress_item("WRK-001", "review");
+    let result = confident_result("review", 0.1);
+
+    let updates = resolve_transition(
+        &item,
+        &result,
+        &make_simple_pipeline(),
+        &confidence_guardrails(0.5),
+    );
+
+    assert!(matches!(updates.as_slice(), [ItemUpdate::SetBlocked(_)]));
+}
+
+#[test]
+fn resolve_transition_high_confidence_advances_normally() {
+    let item = make_in_progress_item("WRK-001", "prd");
+    let result = confident_result("prd", 0.95);
+
+    let updates = resolve_transition(
+        &item,
+        &result,
+        &make_simple_pipeline(),
+        &confidence_guardrails(0.7),
+    );
+
+    assert_eq!(updates, vec![ItemUpdate::SetPhase("build".to_string())]);
+}
+
+#[test]
+fn resolve_transition_missing_confidence_or_threshold_advances_normally() {
+    let item = make_in_progress_item("WRK-001", "prd");
+    let pipeline = make_simple_pipeline();
+
+    let unreported = make_phase_result("WRK-001", "prd", ResultCode::PhaseComplete);
+    let updates = resolve_transition(&item, &unreported, &pipeline, &confidence_guardrails(0.7));
+    assert_eq!(updates, vec![ItemUpdate::SetPhase("build".to_string())]);
+
+    let ungated = confident_result("prd", 0.1);
+    let updates = resolve_transition(&item, &ungated, &pipeline, &default_guardrails());
+    assert_eq!(updates, vec![ItemUpdate::SetPhase("build".to_string())]);
+}
+
+#[test]
+fn resolve_transition_phase_min_confidence_overrides_guardrail() {
+    let item = make_in_progress_item("WRK-001", "prd");
+    let mut pipeline = make_simple_pipeline();
+    pipeline.phases[0].min_confidence = Some(0.2);
+    let result = confident_result("prd", 0.5);
+
+    // Guardrail would block at 0.7, but the prd phase only requires 0.2
+    let updates = resolve_transition(&item, &result, &pipeline, &confidence_guardrails(0.7));
+
+    assert_eq!(updates, vec![ItemUpdate::SetPhase("build".to_string())]);
+}
+
+// --- Blocking follow-ups ---
+
+fn review_result_with_follow_ups() -> PhaseResult {
+    let mut result = make_phase_result("WRK-001", "review", ResultCode::PhaseComplete);
+    result.follow_ups = vec![
+        FollowUp {
+            title: "Fix null deref in parser".to_string(),
+            context: None,
+            suggested_size: None,
+            suggested_risk: None,
+        },
+        FollowUp {
+            title: "Missing migration".to_string(),
+            context: None,
+            suggested_size: None,
+            suggested_risk: None,
+        },
+    ];
+    result
+}
+
+#[test]
+fn resolve_transition_followups_block_blocks_review_with_follow_ups() {
+    let item = make_in_progress_item("WRK-001", "review");
+    let mut pipeline = make_simple_pipeline();
+    pipeline.phases[2].followups_block = true;
+
+    let updates = resolve_transition(
+        &item,
+        &review_result_with_follow_ups(),
+        &pipeline,
+        &default_guardrails(),
+    );
+
+    assert_eq!(
+        updates,
+        vec![ItemUpdate::SetBlocked(
+            "Phase review reported 2 blocking follow-up(s): Fix null deref in parser; Missing migration"
+                .to_string()
+        )]
+    );
+}
+
+#[test]
+fn resolve_transition_follow_ups_without_flag_complete_normally() {
+    let item = make_in_progress_item("WRK-001", "review");
+
+    let updates = resolve_transition(
+        &item,
+        &review_result_with_follow_ups(),
+        &make_simple_pipeline(),
+        &default_guardrails(),
+    );
+
+    assert_eq!(
+        updates,
+        vec![ItemUpdate::TransitionStatus(ItemStatus::Done)]
+    );
+}
+
+#[test]
+fn resolve_transition_followups_block_without_follow_ups_completes() {
+    let item = make_in_progress_item("WRK-001", "review");
+    let mut pipeline = make_simple_pipeline();
+    pipeline.phases[2].followups_block = true;
+    let result = make_phase_result("WRK-001", "review", ResultCode::PhaseComplete);
+
+    let updates = resolve_transition(&item, &result, &pipeline, &default_guardrails());
+
+    assert_eq!(
+        updates,
+        vec![ItemUpdate::TransitionStatus(ItemStatus::Done)]
+    );
+}
+
+#[test]
+fn is_review_phase_matches_review_names() {
+    assert!(is_review_phase("review"));
+    assert!(is_review_phase("change-review"));
+    assert!(!is_review_phase("build"));
+}
+
 #[test]
 fn resolve_transition_failed_result_blocks_with_reason() {
     let item = make_in_progress_item("WRK-001", "prd");
@@ -261,6 +548,28 @@ fn resolve_transition_failed_result_blocks_with_reason() {
     }
 }
 
+#[test]
+fn resolve_transition_failed_validation_phase_blocks_and_tags() {
+    let item = make_in_progress_item("WRK-001", "review");
+    let result = make_phase_result("WRK-001", "review", ResultCode::Failed);
+    let mut pipeline = make_simple_pipeline();
+    pipeline.phases.last_mut().unwrap().is_validation = true;
+    let guardrails = default_guardrails();
+
+    let updates = resolve_transition(&item, &result, &pipeline, &guardrails);
+
+    assert_eq!(updates.len(), 2);
+    assert!(
+        matches!(&updates[0], ItemUpdate::SetBlocked(reason) if reason.starts_with("Validation failed in phase review")),
+        "Expected SetBlocked, got {:?}",
+        updates[0]
+    );
+    assert_eq!(
+        updates[1],
+        ItemUpdate::AddTag(VALIDATION_FAILED_TAG.to_string())
+    );
+}
+
 #[test]
 fn resolve_transition_blocked_result_uses_context() {
     let item = make_in_progress_item("WRK-001", "prd");
@@ -383,6 +692,52 @@ fn passes_guardrails_at_exact_limit_passes() {
     assert!(passes_guardrails(&item, &guardrails));
 }
 
+// --- guardrail_violations tests ---
+
+#[test]
+fn guardrail_violations_names_each_exceeded_dimension() {
+    let mut item = make_feature_item("WRK-001", ItemStatus::Ready);
+    pg_item::set_size(&mut item.0, Some(&SizeLevel::Large));
+    pg_item::set_complexity(&mut item.0, Some(&DimensionLevel::Medium));
+    pg_item::set_risk(&mut item.0, Some(&DimensionLevel::High));
+    let guardrails = default_guardrails(); // Medium / Medium / Low
+
+    assert_eq!(
+        guardrail_violations(&item, &guardrails),
+        vec![
+            GuardrailViolation {
+                dimension: "size",
+                actual: "large".to_string(),
+                max: "medium".to_string(),
+            },
+            GuardrailViolation {
+                dimension: "risk",
+                actual: "high".to_string(),
+                max: "low".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn guardrail_violations_follow_hypothetical_thresholds() {
+    let mut item = make_feature_item("WRK-001", ItemStatus::Ready);
+    pg_item::set_size(&mut item.0, Some(&SizeLevel::Medium));
+    pg_item::set_complexity(&mut item.0, Some(&DimensionLevel::Medium));
+    let tightened = GuardrailsConfig {
+        max_size: SizeLevel::Small,
+        max_complexity: DimensionLevel::Low,
+        ..default_guardrails()
+    };
+
+    let dimensions: Vec<&str> = guardrail_violations(&item, &tightened)
+        .iter()
+        .map(|v| v.dimension)
+        .collect();
+    assert_eq!(dimensions, vec!["size", "complexity"]);
+    assert!(guardrail_violations(&item, &default_guardrails()).is_empty());
+}
+
 // --- check_staleness tests ---
 
 #[tokio::test]
@@ -652,6 +1007,7 @@ async fn execute_phase_success_returns_success() {
         commit_summary: None,
         duplicates: Vec::new(),
         description: None,
+        confidence: None,
     };
 
     let mock = MockAgentRunner::new(vec![Ok(phase_result)]);
@@ -702,6 +1058,7 @@ async fn execute_phase_failure_with_retry_returns_failed_after_exhaustion() {
         commit_summary: None,
         duplicates: Vec::new(),
         description: None,
+        confidence: None,
     };
     let fail_result2 = PhaseResult {
         item_id: "WRK-001".to_string(),
@@ -716,6 +1073,7 @@ async fn execute_phase_failure_with_retry_returns_failed_after_exhaustion() {
         commit_summary: None,
         duplicates: Vec::new(),
         description: None,
+        confidence: None,
     };
 
     let mock = MockAgentRunner::new(vec![Ok(fail_result1), Ok(fail_result2)]);
@@ -744,6 +1102,223 @@ async fn execute_phase_failure_with_retry_returns_failed_after_exhaustion() {
     }
 }
 
+async fn execute_prd_phase_with_hook(
+    hook: &str,
+    responses: usize,
+) -> (PhaseExecutionResult, tempfile::TempDir) {
+    let item = make_in_progress_item("WRK-001", "prd");
+    let (handle, _coord_task, dir) = setup_coordinator_with_items(vec![item.clone()]);
+
+    let mut config = common::default_config();
+    config.execution.max_retries = responses as u32 - 1;
+    config.execution.post_phase_hook = Some(hook.to_string());
+    let mock = MockAgentRunner::new(
+        (0..responses)
+            .map(|_| {
+                Ok(make_phase_result(
+                    "WRK-001",
+                    "prd",
+                    ResultCode::PhaseComplete,
+                ))
+            })
+            .collect(),
+    );
+    let phase_config = config.pipelines["feature"].phases[0].clone();
+
+    let result = execute_phase(
+        &item,
+        &phase_config,
+        &config,
+        &handle,
+        &mock,
+        &CancellationToken::new(),
+        dir.path(),
+        None,
+        dir.path(),
+    )
+    .await;
+    (result, dir)
+}
+
+#[tokio::test]
+async fn execute_phase_post_phase_hook_success_keeps_success() {
+    let (result, dir) = execute_prd_phase_with_hook("touch hook-{item_id}-{phase}", 1).await;
+
+    assert!(
+        matches!(result, PhaseExecutionResult::Success(_)),
+        "Expected Success, got {:?}",
+        result
+    );
+    assert!(dir.path().join("hook-WRK-001-prd").exists());
+}
+
+#[tokio::test]
+async fn execute_phase_post_phase_hook_failure_fails_with_stderr_after_retries() {
+    let (result, _dir) = execute_prd_phase_with_hook("echo 'lint: 3 errors' >&2; exit 1", 2).await;
+
+    match result {
+        PhaseExecutionResult::Failed(reason) => {
+            assert!(reason.contains("failed after 2 attempts"), "{}", reason);
+            assert!(
+                reason.contains("Post-phase hook failed: lint: 3 errors"),
+                "{}",
+                reason
+            );
+        }
+        other => panic!("Expected Failed, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn execute_phase_post_phase_hook_is_cancellable() {
+    let item = make_in_progress_item("WRK-001", "prd");
+    let (handle, _coord_task, dir) = setup_coordinator_with_items(vec![item.clone()]);
+
+    let mut config = common::default_config();
+    config.execution.post_phase_hook = Some("touch hook-started; sleep 30".to_string());
+    let mock = MockAgentRunner::new(vec![Ok(make_phase_result(
+        "WRK-001",
+        "prd",
+        ResultCode::PhaseComplete,
+    ))]);
+    let phase_config = config.pipelines["feature"].phases[0].clone();
+
+    // Cancel once the hook is running
+    let cancel = CancellationToken::new();
+    let canceller = cancel.clone();
+    let marker = dir.path().join("hook-started");
+    tokio::spawn(async move {
+        while !marker.exists() {
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        canceller.cancel();
+    });
+
+    let started = std::time::Instant::now();
+    let result = execute_phase(
+        &item,
+        &phase_config,
+        &config,
+        &handle,
+        &mock,
+        &cancel,
+        dir.path(),
+        None,
+        dir.path(),
+    )
+    .await;
+
+    assert!(
+        matches!(result, PhaseExecutionResult::Cancelled),
+        "Expected Cancelled, got {:?}",
+        result
+    );
+    assert!(started.elapsed() < std::time::Duration::from_secs(10));
+}
+
+#[test]
+fn retry_backoff_delay_doubles_up_to_cap() {
+    let execution = ExecutionConfig {
+        retry_backoff_seconds: 2,
+        retry_backoff_max_seconds: 10,
+        ..ExecutionConfig::default()
+    };
+
+    let delays: Vec<u64> = (1..=5)
+        .map(|attempt| retry_backoff_delay(&execution, attempt).as_secs())
+        .collect();
+    assert_eq!(delays, vec![2, 4, 8, 10, 10]);
+}
+
+#[test]
+fn retry_backoff_delay_disabled_by_default() {
+    let execution = ExecutionConfig::default();
+    assert!(retry_backoff_delay(&execution, 1).is_zero());
+    assert!(retry_backoff_delay(&execution, 100).is_zero());
+}
+
+#[tokio::test]
+async fn execute_phase_waits_for_backoff_between_attempts() {
+    let item = make_in_progress_item("WRK-001", "prd");
+    let (handle, _coord_task, dir) = setup_coordinator_with_items(vec![item.clone()]);
+
+    let mut config = common::default_config();
+    config.execution.max_retries = 1;
+    config.execution.retry_backoff_seconds = 1;
+    config.execution.retry_backoff_max_seconds = 1;
+
+    let mock = MockAgentRunner::new(vec![
+        Err("rate limited".to_string()),
+        Ok(make_phase_result(
+            "WRK-001",
+            "prd",
+            ResultCode::PhaseComplete,
+        )),
+    ]);
+    let cancel = CancellationToken::new();
+    let phase_config = config.pipelines["feature"].phases[0].clone();
+
+    let started = tokio::time::Instant::now();
+    let result = execute_phase(
+        &item,
+        &phase_config,
+        &config,
+        &handle,
+        &mock,
+        &cancel,
+        dir.path(),
+        None,
+        dir.path(),
+    )
+    .await;
+
+    assert!(matches!(result, PhaseExecutionResult::Success(_)));
+    assert!(
+        started.elapsed() >= std::time::Duration::from_secs(1),
+        "expected a 1s backoff, took {:?}",
+        started.elapsed()
+    );
+}
+
+#[tokio::test]
+async fn execute_phase_cancellation_during_backoff_returns_cancelled() {
+    let item = make_in_progress_item("WRK-001", "prd");
+    let (handle, _coord_task, dir) = setup_coordinator_with_items(vec![item.clone()]);
+
+    let mut config = common::default_config();
+    config.execution.max_retries = 1;
+    config.execution.retry_backoff_seconds = 60;
+
+    let mock = MockAgentRunner::new(vec![Err("rate limited".to_string())]);
+    let cancel = CancellationToken::new();
+    let phase_config = config.pipelines["feature"].phases[0].clone();
+
+    let canceller = cancel.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+        canceller.cancel();
+    });
+
+    let result = tokio::time::timeout(
+        std::time::Duration::from_secs(10),
+        execute_phase(
+            &item,
+            &phase_config,
+            &config,
+            &handle,
+            &mock,
+            &cancel,
+            dir.path(),
+            None,
+            dir.path(),
+        ),
+    )
+    .await
+    .expect("cancellation should cut the 60s backoff short");
+
+    assert_eq!(result, PhaseExecutionResult::Cancelled);
+}
+
 #[tokio::test]
 async fn execute_phase_subphase_complete_returns_immediately() {
     let item = make_in_progress_item("WRK-001", "build");
@@ -762,6 +1337,7 @@ async fn execute_phase_subphase_complete_returns_immediately() {
         commit_summary: None,
         duplicates: Vec::new(),
         description: None,
+        confidence: None,
     };
 
     let mock = MockAgentRunner::new(vec![Ok(subphase_result)]);
@@ -837,6 +1413,7 @@ async fn execute_phase_blocked_result_returns_blocked() {
         commit_summary: None,
         duplicates: Vec::new(),
         description: None,
+        confidence: None,
     };
 
     let mock = MockAgentRunner::new(vec![Ok(blocked_result)]);
@@ -1003,3 +1580,350 @@ fn validate_result_identity_both_mismatched_returns_err() {
     assert!(err.contains("item_id"));
     assert!(err.contains("phase"));
 }
+
+// --- isolation tests ---
+
+/// Agent that writes a file into its working directory before returning a result.
+struct WritingAgentRunner {
+    result: PhaseResult,
+    workdirs: std::sync::Mutex<Vec<std::path::PathBuf>>,
+    /// Commit the written file in the workdir, as an agent running git would.
+    commit: bool,
+}
+
+impl AgentRunner for WritingAgentRunner {
+    async fn run_agent(
+        &self,
+        _prompt: &str,
+        _result_path: &std::path::Path,
+        _timeout: std::time::Duration,
+    ) -> Result<PhaseResult, String> {
+        Err("WritingAgentRunner requires a workdir".to_string())
+    }
+
+    async fn run_agent_in(
+        &self,
+        _prompt: &str,
+        _result_path: &std::path::Path,
+        _timeout: std::time::Duration,
+        workdir: &std::path::Path,
+        _model: Option<&str>,
+    ) -> Result<PhaseResult, String> {
+        fs::write(workdir.join("agent_output.txt"), "from agent\n").map_err(|e| e.to_string())?;
+        if self.commit {
+            for args in [
+                vec!["add", "agent_output.txt"],
+                vec![
+                    "-c",
+                    "user.name=Agent",
+                    "-c",
+                    "user.email=agent@example.com",
+                    "commit",
+                    "--quiet",
+                    "--no-gpg-sign",
+                    "-m",
+                    "agent commit",
+                ],
+            ] {
+                let status = std::process::Command::new("git")
+                    .args(&args)
+                    .current_dir(workdir)
+                    .status()
+                    .map_err(|e| e.to_string())?;
+                if !status.success() {
+                    return Err(format!("git {:?} failed", args));
+                }
+            }
+        }
+        self.workdirs.lock().unwrap().push(workdir.to_path_buf());
+        Ok(self.result.clone())
+    }
+}
+
+/// Agent that records the model it was asked to run with.
+struct ModelRecordingAgentRunner {
+    result: PhaseResult,
+    models: std::sync::Mutex<Vec<Option<String>>>,
+}
+
+impl AgentRunner for ModelRecordingAgentRunner {
+    async fn run_agent(
+        &self,
+        _prompt: &str,
+        _result_path: &std::path::Path,
+        _timeout: std::time::Duration,
+    ) -> Result<PhaseResult, String> {
+        Err("ModelRecordingAgentRunner expects run_agent_in".to_string())
+    }
+
+    async fn run_agent_in(
+        &self,
+        _prompt: &str,
+        _result_path: &std::path::Path,
+        _timeout: std::time::Duration,
+        _workdir: &std::path::Path,
+        model: Option<&str>,
+    ) -> Result<PhaseResult, String> {
+        self.models.lock().unwrap().push(model.map(str::to_string));
+        Ok(self.result.clone())
+    }
+}
+
+async fn model_passed_for(phase_model: Option<&str>) -> Option<String> {
+    let item = make_in_progress_item("WRK-001", "prd");
+    let (handle, _coord_task, dir) = setup_coordinator_with_items(vec![item.clone()]);
+
+    let mut config = common::default_config();
+    config.agent.model = Some("sonnet".to_string());
+    let phase_config = PhaseConfig {
+        model: phase_model.map(str::to_string),
+        ..PhaseConfig::new("prd", false)
+    };
+
+    let runner = ModelRecordingAgentRunner {
+        result: make_phase_result("WRK-001", "prd", ResultCode::PhaseComplete),
+        models: std::sync::Mutex::new(Vec::new()),
+    };
+    let cancel = CancellationToken::new();
+
+    let outcome = execute_phase(
+        &item,
+        &phase_config,
+        &config,
+        &handle,
+        &runner,
+        &cancel,
+        dir.path(),
+        None,
+        dir.path(),
+    )
+    .await;
+    assert!(
+        matches!(outcome, PhaseExecutionResult::Success(_)),
+        "Expected Success, got {:?}",
+        outcome
+    );
+
+    let models = runner.models.into_inner().unwrap();
+    assert_eq!(models.len(), 1);
+    models.into_iter().next().unwrap()
+}
+
+#[tokio::test]
+async fn execute_phase_passes_phase_model_override_to_runner() {
+    assert_eq!(
+        model_passed_for(Some("opus")).await.as_deref(),
+        Some("opus")
+    );
+}
+
+#[tokio::test]
+async fn execute_phase_falls_back_to_agent_model_without_override() {
+    assert_eq!(model_passed_for(None).await.as_deref(), Some("sonnet"));
+}
+
+async fn run_isolated_build(
+    result: ResultCode,
+    commit: bool,
+) -> (
+    PhaseExecutionResult,
+    tempfile::TempDir,
+    Vec<std::path::PathBuf>,
+) {
+    let item = make_in_progress_item("WRK-001", "build");
+    let (handle, _coord_task, dir) = setup_coordinator_with_items(vec![item.clone()]);
+
+    let mut config = common::default_config();
+    config.execution.isolation = Isolation::Clone;
+    config.execution.max_retries = 0;
+    let phase_config = PhaseConfig::new("build", true);
+
+    let runner = WritingAgentRunner {
+        result: make_phase_result("WRK-001", "build", result),
+        workdirs: std::sync::Mutex::new(Vec::new()),
+        commit,
+    };
+    let cancel = CancellationToken::new();
+
+    let outcome = execute_phase(
+        &item,
+        &phase_config,
+        &config,
+        &handle,
+        &runner,
+        &cancel,
+        dir.path(),
+        None,
+        dir.path(),
+    )
+    .await;
+
+    let workdirs = runner.workdirs.into_inner().unwrap();
+    (outcome, dir, workdirs)
+}
+
+#[tokio::test]
+async fn execute_phase_clone_isolation_applies_changes_on_success() {
+    let (outcome, dir, workdirs) = run_isolated_build(ResultCode::PhaseComplete, false).await;
+
+    assert!(
+        matches!(outcome, PhaseExecutionResult::Success(_)),
+        "Expected Success, got {:?}",
+        outcome
+    );
+    assert_eq!(workdirs.len(), 1);
+    assert_ne!(workdirs[0], dir.path());
+    assert_eq!(workdirs[0], isolated_clone_dir("WRK-001", "build"));
+    assert_eq!(
+        fs::read_to_string(dir.path().join("agent_output.txt")).unwrap(),
+        "from agent\n"
+    );
+    assert!(!workdirs[0].exists(), "clone should be removed");
+}
+
+#[tokio::test]
+async fn execute_phase_clone_isolation_applies_commits_made_in_clone() {
+    let (outcome, dir, workdirs) = run_isolated_build(ResultCode::PhaseComplete, true).await;
+
+    assert!(
+        matches!(outcome, PhaseExecutionResult::Success(_)),
+        "Expected Success, got {:?}",
+        outcome
+    );
+    assert_eq!(workdirs.len(), 1);
+    assert_eq!(
+        fs::read_to_string(dir.path().join("agent_output.txt")).unwrap(),
+        "from agent\n"
+    );
+}
+
+#[tokio::test]
+async fn execute_phase_clone_isolation_discards_changes_on_failure() {
+    let (outcome, dir, workdirs) = run_isolated_build(ResultCode::Failed, false).await;
+
+    assert!(
+        matches!(outcome, PhaseExecutionResult::Failed(_)),
+        "Expected Failed, got {:?}",
+        outcome
+    );
+    assert_eq!(workdirs.len(), 1);
+    assert!(!dir.path().join("agent_output.txt").exists());
+    assert!(!workdirs[0].exists(), "clone should be removed");
+}
+
+#[tokio::test]
+async fn execute_phase_blocks_destructive_phase_on_unexpected_dirty_file() {
+    let item = make_in_progress_item("WRK-001", "build");
+    let (handle, _coord_task, dir) = setup_coordinator_with_items(vec![item.clone()]);
+    fs::write(dir.path().join("scratch.txt"), "edited by another tool").unwrap();
+
+    let mut config = common::default_config();
+    config.execution.guard_external_dirty = true;
+    let phase_config = config.pipelines["feature"]
+        .phases
+        .iter()
+        .find(|p| p.is_destructive)
+        .expect("feature pipeline has a destructive phase")
+        .clone();
+
+    // No agent results queued: the phase must not reach the runner
+    let mock = MockAgentRunner::new(vec![]);
+    let cancel = CancellationToken::new();
+
+    let result = execute_phase(
+        &item,
+        &phase_config,
+        &config,
+        &handle,
+        &mock,
+        &cancel,
+        dir.path(),
+        None,
+        dir.path(),
+    )
+    .await;
+
+    match result {
+        PhaseExecutionResult::Blocked(reason) => {
+            assert!(
+                reason.contains("Working tree has changes phase-golem did not make"),
+                "unexpected reason: {}",
+                reason
+            );
+            assert!(
+                reason.contains("scratch.txt"),
+                "unexpected reason: {}",
+                reason
+            );
+        }
+        other => panic!("Expected Blocked, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn execute_phase_guard_ignores_phase_golem_paths() {
+    let item = make_in_progress_item("WRK-001", "build");
+    let (handle, _coord_task, dir) = setup_coordinator_with_items(vec![item.clone()]);
+    fs::create_dir_all(dir.path().join("changes/WRK-001_feature")).unwrap();
+    fs::write(dir.path().join("changes/WRK-001_feature/SPEC.md"), "# Spec").unwrap();
+
+    let mut config = common::default_config();
+    config.execution.guard_external_dirty = true;
+    let phase_config = config.pipelines["feature"]
+        .phases
+        .iter()
+        .find(|p| p.is_destructive)
+        .expect("feature pipeline has a destructive phase")
+        .clone();
+
+    let mock = MockAgentRunner::new(vec![Ok(make_phase_result(
+        "WRK-001",
+        &phase_config.name,
+        ResultCode::PhaseComplete,
+    ))]);
+    let cancel = CancellationToken::new();
+
+    let result = execute_phase(
+        &item,
+        &phase_config,
+        &config,
+        &handle,
+        &mock,
+        &cancel,
+        dir.path(),
+        None,
+        dir.path(),
+    )
+    .await;
+
+    assert!(
+        matches!(result, PhaseExecutionResult::Success(_)),
+        "expected Success, got {:?}",
+        result
+    );
+}
+
+#[test]
+fn find_change_folder_returns_existing_prefixed_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    assert_eq!(find_change_folder(dir.path(), "WRK-001"), Ok(None));
+
+    let changes = dir.path().join("changes");
+    fs::create_dir_all(changes.join("WRK-0012_other")).unwrap();
+    fs::write(changes.join("WRK-001_notes.md"), "loose").unwrap();
+    assert_eq!(find_change_folder(dir.path(), "WRK-001"), Ok(None));
+    assert_eq!(
+        change_folder_path(dir.path(), "WRK-001", "Add Login!"),
+        changes.join("WRK-001_add-login")
+    );
+
+    fs::create_dir_all(changes.join("WRK-001_old-title")).unwrap();
+    assert_eq!(
+        find_change_folder(dir.path(), "WRK-001"),
+        Ok(Some(changes.join("WRK-001_old-title")))
+    );
+    assert_eq!(
+        change_folder_path(dir.path(), "WRK-001", "Add Login!"),
+        changes.join("WRK-001_old-title")
+    );
+}
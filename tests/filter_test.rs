@@ -1,8 +1,10 @@
 mod common;
 
+use chrono::{Duration, TimeZone, Utc};
+
 use phase_golem::filter::{
-    apply_filters, format_filter_criteria, parse_filter, validate_filter_criteria, FilterField,
-    FilterValue,
+    apply_filters, format_filter_criteria, matches_item_at, parse_filter, validate_filter_criteria,
+    AgeUnit, AgeWindow, FilterField, FilterOp, FilterValue,
 };
 use phase_golem::pg_item::{self, PgItem};
 use phase_golem::types::{DimensionLevel, ItemStatus, SizeLevel};
@@ -72,6 +74,13 @@ fn parse_filter_pipeline_type() {
     );
 }
 
+#[test]
+fn parse_filter_phase() {
+    let f = parse_filter("phase=review").unwrap();
+    assert_eq!(f.field, FilterField::Phase);
+    assert_eq!(f.values, vec![FilterValue::Phase("review".to_string())]);
+}
+
 // --- Invalid field name ---
 
 #[test]
@@ -296,6 +305,16 @@ fn none_complexity_never_matches() {
     assert!(filtered.is_empty());
 }
 
+#[test]
+fn none_phase_never_matches() {
+    let f = parse_filter("phase=review").unwrap();
+    let item = make_pg_item("WRK-001", ItemStatus::Ready);
+
+    let snapshot = vec![item];
+    let filtered = apply_filters(&[f.clone()], &snapshot);
+    assert!(filtered.is_empty());
+}
+
 #[test]
 fn none_pipeline_type_never_matches() {
     let f = parse_filter("pipeline_type=feature").unwrap();
@@ -634,6 +653,16 @@ fn format_filter_criteria_two() {
     );
 }
 
+#[test]
+fn format_filter_criteria_phase() {
+    let c1 = parse_filter("phase=review,build").unwrap();
+    let c2 = parse_filter("size=small").unwrap();
+    assert_eq!(
+        format_filter_criteria(&[c1, c2]),
+        "phase=review,build AND size=small"
+    );
+}
+
 #[test]
 fn format_filter_criteria_three() {
     let c1 = parse_filter("impact=high").unwrap();
@@ -717,6 +746,48 @@ fn parse_filter_multi_value_status() {
     );
 }
 
+#[test]
+fn parse_filter_status_accepts_hyphenated_in_progress() {
+    let f = parse_filter("status=in-progress").unwrap();
+    assert_eq!(f.values, vec![FilterValue::Status(ItemStatus::InProgress)]);
+    assert_eq!(f.to_string(), "status=in_progress");
+}
+
+#[test]
+fn multi_value_or_status_matching() {
+    let f = parse_filter("status=ready,scoping").unwrap();
+
+    let item1 = make_pg_item("WRK-001", ItemStatus::Ready);
+    let item2 = make_pg_item("WRK-002", ItemStatus::Blocked);
+    let item3 = make_pg_item("WRK-003", ItemStatus::Scoping);
+    let item4 = make_pg_item("WRK-004", ItemStatus::New);
+
+    let snapshot = vec![item1, item2, item3, item4];
+    let filtered = apply_filters(&[f], &snapshot);
+
+    assert_eq!(filtered.len(), 2);
+    assert_eq!(filtered[0].id(), "WRK-001");
+    assert_eq!(filtered[1].id(), "WRK-003");
+}
+
+#[test]
+fn status_or_combined_with_and_across_fields() {
+    let c1 = parse_filter("status=ready,scoping").unwrap();
+    let c2 = parse_filter("impact=high").unwrap();
+    assert!(validate_filter_criteria(&[c1.clone(), c2.clone()]).is_ok());
+
+    let item1 = make_item_with_impact("WRK-001", ItemStatus::Ready, DimensionLevel::High);
+    let item2 = make_item_with_impact("WRK-002", ItemStatus::Scoping, DimensionLevel::Low);
+    let item3 = make_item_with_impact("WRK-003", ItemStatus::Scoping, DimensionLevel::High);
+    let item4 = make_item_with_impact("WRK-004", ItemStatus::Blocked, DimensionLevel::High);
+
+    let snapshot = vec![item1, item2, item3, item4];
+    let filtered = apply_filters(&[c1, c2], &snapshot);
+
+    let ids: Vec<&str> = filtered.iter().map(|i| i.id()).collect();
+    assert_eq!(ids, vec!["WRK-001", "WRK-003"]);
+}
+
 #[test]
 fn parse_filter_multi_value_tag() {
     let f = parse_filter("tag=a,b").unwrap();
@@ -905,6 +976,50 @@ fn multi_value_or_pipeline_type_matching() {
     assert_eq!(filtered[1].id(), "WRK-003");
 }
 
+#[test]
+fn multi_value_or_phase_matching() {
+    let f = parse_filter("phase=review,build").unwrap();
+
+    let mut item1 = make_pg_item("WRK-001", ItemStatus::InProgress);
+    pg_item::set_phase(&mut item1.0, Some("build"));
+
+    let mut item2 = make_pg_item("WRK-002", ItemStatus::InProgress);
+    pg_item::set_phase(&mut item2.0, Some("design"));
+
+    let mut item3 = make_pg_item("WRK-003", ItemStatus::InProgress);
+    pg_item::set_phase(&mut item3.0, Some("review"));
+
+    let item4 = make_pg_item("WRK-004", ItemStatus::Ready);
+
+    let snapshot = vec![item1, item2, item3, item4];
+    let filtered = apply_filters(&[f], &snapshot);
+
+    assert_eq!(filtered.len(), 2);
+    assert_eq!(filtered[0].id(), "WRK-001");
+    assert_eq!(filtered[1].id(), "WRK-003");
+}
+
+#[test]
+fn validate_filter_criteria_accepts_phase() {
+    let c1 = parse_filter("phase=review").unwrap();
+    let c2 = parse_filter("impact=high").unwrap();
+    assert!(validate_filter_criteria(&[c1, c2]).is_ok());
+}
+
+#[test]
+fn validate_filter_criteria_rejects_repeated_phase() {
+    let c1 = parse_filter("phase=review").unwrap();
+    let c2 = parse_filter("phase=build").unwrap();
+    let err = validate_filter_criteria(&[c1, c2]).unwrap_err();
+    assert!(err.contains("Field 'phase' specified multiple times"));
+}
+
+#[test]
+fn phase_comparison_rejected() {
+    let c = parse_filter("phase>=review").unwrap();
+    assert!(validate_filter_criteria(&[c]).is_err());
+}
+
 // --- Multi-value display ---
 
 #[test]
@@ -1090,3 +1205,395 @@ fn validate_duplicate_scalar_error_mentions_separate_flags() {
     let err = validate_filter_criteria(&[c1, c2]).unwrap_err();
     assert!(err.contains("in separate --only flags"));
 }
+
+// --- Age windows ---
+
+#[test]
+fn parse_filter_age_named_windows() {
+    let f = parse_filter("age=today,this-week,older-than:7d").unwrap();
+    assert_eq!(f.field, FilterField::Age);
+    assert_eq!(
+        f.values,
+        vec![
+            FilterValue::Age(AgeWindow::Today),
+            FilterValue::Age(AgeWindow::ThisWeek),
+            FilterValue::Age(AgeWindow::OlderThan {
+                amount: 7,
+                unit: AgeUnit::Days
+            }),
+        ]
+    );
+}
+
+#[test]
+fn parse_filter_age_invalid_values_rejected() {
+    for raw in [
+        "age=yesterday",
+        "age=older-than:",
+        "age=older-than:7",
+        "age=older-than:7y",
+        "age=older-than:xd",
+    ] {
+        let err = parse_filter(raw).unwrap_err();
+        assert!(
+            err.contains("Invalid value") && err.contains("'age'"),
+            "unexpected error for {}: {}",
+            raw,
+            err
+        );
+    }
+}
+
+#[test]
+fn age_display_roundtrip() {
+    let f = parse_filter("age=older-than:12h,this-week").unwrap();
+    assert_eq!(f.to_string(), "age=older-than:12h,this-week");
+    assert_eq!(parse_filter(&f.to_string()).unwrap(), f);
+}
+
+#[test]
+fn age_today_selects_items_created_within_current_day() {
+    let now = Utc.with_ymd_and_hms(2026, 3, 11, 15, 0, 0).unwrap();
+    let criterion = parse_filter("age=today").unwrap();
+
+    let mut early_today = make_pg_item("WRK-001", ItemStatus::Ready);
+    early_today.0.created_at = Utc.with_ymd_and_hms(2026, 3, 11, 0, 5, 0).unwrap();
+    let mut yesterday = make_pg_item("WRK-002", ItemStatus::Ready);
+    yesterday.0.created_at = Utc.with_ymd_and_hms(2026, 3, 10, 23, 55, 0).unwrap();
+
+    assert!(matches_item_at(&criterion, &early_today, now));
+    assert!(!matches_item_at(&criterion, &yesterday, now));
+}
+
+#[test]
+fn age_this_week_starts_on_monday() {
+    // 2026-03-11 is a Wednesday; the week began Monday 2026-03-09.
+    let now = Utc.with_ymd_and_hms(2026, 3, 11, 15, 0, 0).unwrap();
+    let criterion = parse_filter("age=this-week").unwrap();
+
+    let mut monday = make_pg_item("WRK-001", ItemStatus::Ready);
+    monday.0.created_at = Utc.with_ymd_and_hms(2026, 3, 9, 0, 0, 0).unwrap();
+    let mut sunday = make_pg_item("WRK-002", ItemStatus::Ready);
+    sunday.0.created_at = Utc.with_ymd_and_hms(2026, 3, 8, 23, 59, 0).unwrap();
+
+    assert!(matches_item_at(&criterion, &monday, now));
+    assert!(!matches_item_at(&criterion, &sunday, now));
+}
+
+#[test]
+fn age_older_than_7d_selects_items_older_than_a_week() {
+    let criterion = parse_filter("age=older-than:7d").unwrap();
+
+    let mut old = make_pg_item("WRK-001", ItemStatus::Ready);
+    old.0.created_at = Utc::now() - Duration::days(8);
+    let mut recent = make_pg_item("WRK-002", ItemStatus::Ready);
+    recent.0.created_at = Utc::now() - Duration::days(6);
+    let fresh = make_pg_item("WRK-003", ItemStatus::Ready);
+
+    let result = apply_filters(&[criterion], &[old, recent, fresh]);
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].id(), "WRK-001");
+}
+
+#[test]
+fn parse_filter_age_comparison_takes_duration() {
+    let f = parse_filter("age>7d").unwrap();
+    assert_eq!(f.field, FilterField::Age);
+    assert_eq!(f.op, FilterOp::Gt);
+    assert_eq!(
+        f.values,
+        vec![FilterValue::AgeDuration(std::time::Duration::from_secs(
+            7 * 24 * 3600
+        ))]
+    );
+    assert!(validate_filter_criteria(&[f.clone()]).is_ok());
+    assert_eq!(parse_filter(&f.to_string()).unwrap(), f);
+}
+
+#[test]
+fn parse_filter_age_equality_with_duration_rejected() {
+    for raw in ["age=7d", "age!=24h"] {
+        let err = parse_filter(raw).unwrap_err();
+        assert!(err.contains("compares durations"), "{}: {}", raw, err);
+    }
+    let err = parse_filter("age>today").unwrap_err();
+    assert!(err.contains("Invalid duration 'today'"), "{}", err);
+}
+
+#[test]
+fn age_comparisons_use_injected_now() {
+    let now = Utc.with_ymd_and_hms(2026, 3, 11, 12, 0, 0).unwrap();
+    let mut week_old = make_pg_item("WRK-001", ItemStatus::Ready);
+    week_old.0.created_at = now - Duration::days(8);
+    let mut hours_old = make_pg_item("WRK-002", ItemStatus::Ready);
+    hours_old.0.created_at = now - Duration::hours(20);
+    let mut exactly_day = make_pg_item("WRK-003", ItemStatus::Ready);
+    exactly_day.0.created_at = now - Duration::hours(24);
+
+    let stale = parse_filter("age>7d").unwrap();
+    assert!(matches_item_at(&stale, &week_old, now));
+    assert!(!matches_item_at(&stale, &hours_old, now));
+
+    let fresh = parse_filter("age<=24h").unwrap();
+    assert!(!matches_item_at(&fresh, &week_old, now));
+    assert!(matches_item_at(&fresh, &hours_old, now));
+    assert!(matches_item_at(&fresh, &exactly_day, now));
+}
+
+#[test]
+fn age_range_on_same_field_allowed() {
+    let criteria = vec![
+        parse_filter("age>=1d").unwrap(),
+        parse_filter("age<2w").unwrap(),
+    ];
+    assert!(validate_filter_criteria(&criteria).is_ok());
+}
+
+// --- Filter files ---
+
+#[test]
+fn parse_filter_file_skips_comments_and_blank_lines() {
+    let contents = "\
+# Slice for this week's triage
+impact=high,medium
+
+  # indented comment
+size=small
+tag=backend
+";
+    let criteria = parse_filter_file(contents).unwrap();
+    assert_eq!(
+        format_filter_criteria(&criteria),
+        "impact=high,medium AND size=small AND tag=backend"
+    );
+}
+
+#[test]
+fn parse_filter_file_merges_with_inline_criteria() {
+    let mut criteria = parse_filter_file("impact=high\ntag=a\n").unwrap();
+    criteria.push(parse_filter("tag=b").unwrap());
+    assert!(validate_filter_criteria(&criteria).is_ok());
+
+    let mut tagged_both = pg_item::new_from_parts(
+        "WRK-001".to_string(),
+        "Test item WRK-001".to_string(),
+        ItemStatus::Ready,
+        vec![],
+        vec!["a".to_string(), "b".to_string()],
+    );
+    pg_item::set_impact(&mut tagged_both.0, Some(&DimensionLevel::High));
+    let mut tagged_one = pg_item::new_from_parts(
+        "WRK-002".to_string(),
+        "Test item WRK-002".to_string(),
+        ItemStatus::Ready,
+        vec![],
+        vec!["a".to_string()],
+    );
+    pg_item::set_impact(&mut tagged_one.0, Some(&DimensionLevel::High));
+
+    let result = apply_filters(&criteria, &[tagged_both, tagged_one]);
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].id(), "WRK-001");
+}
+
+#[test]
+fn parse_filter_file_duplicate_field_with_inline_rejected() {
+    let mut criteria = parse_filter_file("impact=high\n").unwrap();
+    criteria.push(parse_filter("impact=low").unwrap());
+    assert!(validate_filter_criteria(&criteria).is_err());
+}
+
+#[test]
+fn parse_filter_file_error_reports_line_number() {
+    let err = parse_filter_file("# header\nimpact=high\nbogus\n").unwrap_err();
+    assert!(err.starts_with("line 3:"), "unexpected error: {}", err);
+}
+
+#[test]
+fn parse_filter_file_empty_yields_no_criteria() {
+    assert!(parse_filter_file("# nothing here\n\n").unwrap().is_empty());
+}
+
+// --- Comparison operators ---
+
+#[test]
+fn parse_filter_comparison_operators() {
+    for (raw, op) in [
+        ("impact>=medium", FilterOp::Gte),
+        ("impact>medium", FilterOp::Gt),
+        ("impact<=medium", FilterOp::Lte),
+        ("impact<medium", FilterOp::Lt),
+        ("impact=medium", FilterOp::Eq),
+    ] {
+        let f = parse_filter(raw).unwrap();
+        assert_eq!(f.field, FilterField::Impact);
+        assert_eq!(f.op, op, "operator for {}", raw);
+        assert_eq!(
+            f.values,
+            vec![FilterValue::Dimension(DimensionLevel::Medium)]
+        );
+        assert_eq!(f.to_string(), raw);
+    }
+}
+
+#[test]
+fn parse_filter_comparison_rejects_multiple_values() {
+    let err = parse_filter("size>=small,medium").unwrap_err();
+    assert!(err.contains("takes a single value"), "got: {}", err);
+}
+
+#[test]
+fn comparison_gte_matches_at_least_medium_impact() {
+    let criterion = parse_filter("impact>=medium").unwrap();
+    let items = vec![
+        make_item_with_impact("WRK-001", ItemStatus::Ready, DimensionLevel::Low),
+        make_item_with_impact("WRK-002", ItemStatus::Ready, DimensionLevel::Medium),
+        make_item_with_impact("WRK-003", ItemStatus::Ready, DimensionLevel::High),
+    ];
+
+    let result = apply_filters(&[criterion], &items);
+    let ids: Vec<&str> = result.iter().map(|i| i.id()).collect();
+    assert_eq!(ids, vec!["WRK-002", "WRK-003"]);
+}
+
+#[test]
+fn comparison_lt_on_size_uses_size_ranking() {
+    let criterion = parse_filter("size<large").unwrap();
+    let mut small = make_pg_item("WRK-001", ItemStatus::Ready);
+    pg_item::set_size(&mut small.0, Some(&SizeLevel::Small));
+    let mut large = make_pg_item("WRK-002", ItemStatus::Ready);
+    pg_item::set_size(&mut large.0, Some(&SizeLevel::Large));
+
+    let result = apply_filters(&[criterion], &[small, large]);
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].id(), "WRK-001");
+}
+
+#[test]
+fn comparison_never_matches_missing_dimension() {
+    let item = make_pg_item("WRK-001", ItemStatus::Ready);
+    for raw in ["risk<=high", "risk>=low", "risk<high", "risk>low"] {
+        let criterion = parse_filter(raw).unwrap();
+        assert!(
+            apply_filters(&[criterion], &[item.clone()]).is_empty(),
+            "{}",
+            raw
+        );
+    }
+}
+
+#[test]
+fn validate_comparison_on_unordered_field_rejected() {
+    for raw in ["tag>=backend", "status<done", "pipeline_type>feature"] {
+        let criterion = parse_filter(raw).unwrap();
+        let err = validate_filter_criteria(&[criterion]).unwrap_err();
+        assert!(err.contains("not supported for field"), "{}: {}", raw, err);
+    }
+}
+
+#[test]
+fn validate_comparison_range_on_same_field_allowed() {
+    let criteria = vec![
+        parse_filter("complexity>=low").unwrap(),
+        parse_filter("complexity<high").unwrap(),
+    ];
+    assert!(validate_filter_criteria(&criteria).is_ok());
+    assert_eq!(
+        format_filter_criteria(&criteria),
+        "complexity>=low AND complexity<high"
+    );
+}
+
+// --- Negated equality ---
+
+fn make_item_with_tags(id: &str, tags: &[&str]) -> PgItem {
+    pg_item::new_from_parts(
+        id.to_string(),
+        format!("Test item {}", id),
+        ItemStatus::Ready,
+        vec![],
+        tags.iter().map(|t| t.to_string()).collect(),
+    )
+}
+
+#[test]
+fn parse_filter_not_equal() {
+    let f = parse_filter("tag!=spike").unwrap();
+    assert_eq!(f.field, FilterField::Tag);
+    assert_eq!(f.op, FilterOp::Ne);
+    assert_eq!(f.values, vec![FilterValue::Tag("spike".to_string())]);
+    assert_eq!(f.to_string(), "tag!=spike");
+}
+
+#[test]
+fn not_equal_excludes_matching_dimension() {
+    let criterion = parse_filter("impact!=low").unwrap();
+    let items = vec![
+        make_item_with_impact("WRK-001", ItemStatus::Ready, DimensionLevel::Low),
+        make_item_with_impact("WRK-002", ItemStatus::Ready, DimensionLevel::High),
+        make_pg_item("WRK-003", ItemStatus::Ready),
+    ];
+
+    let result = apply_filters(&[criterion], &items);
+    let ids: Vec<&str> = result.iter().map(|i| i.id()).collect();
+    assert_eq!(ids, vec!["WRK-002", "WRK-003"]);
+}
+
+#[test]
+fn not_equal_comma_values_exclude_any() {
+    let criterion = parse_filter("tag!=spike,chore").unwrap();
+    let items = vec![
+        make_item_with_tags("WRK-001", &["spike"]),
+        make_item_with_tags("WRK-002", &["chore", "backend"]),
+        make_item_with_tags("WRK-003", &["backend"]),
+    ];
+
+    let result = apply_filters(&[criterion], &items);
+    let ids: Vec<&str> = result.iter().map(|i| i.id()).collect();
+    assert_eq!(ids, vec!["WRK-003"]);
+}
+
+#[test]
+fn repeated_not_equal_flags_and_together() {
+    let criteria = vec![
+        parse_filter("tag!=spike").unwrap(),
+        parse_filter("tag!=chore").unwrap(),
+    ];
+    assert!(validate_filter_criteria(&criteria).is_ok());
+    assert_eq!(
+        format_filter_criteria(&criteria),
+        "tag!=spike AND tag!=chore"
+    );
+
+    let items = vec![
+        make_item_with_tags("WRK-001", &["spike"]),
+        make_item_with_tags("WRK-002", &["chore"]),
+        make_item_with_tags("WRK-003", &["backend"]),
+    ];
+    let result = apply_filters(&criteria, &items);
+    let ids: Vec<&str> = result.iter().map(|i| i.id()).collect();
+    assert_eq!(ids, vec!["WRK-003"]);
+}
+
+#[test]
+fn not_equal_combines_with_equality_on_same_field() {
+    let criteria = vec![
+        parse_filter("impact=high,medium").unwrap(),
+        parse_filter("impact!=medium").unwrap(),
+    ];
+    assert!(validate_filter_criteria(&criteria).is_ok());
+}
+
+#[test]
+fn parse_filter_rejects_ambiguous_operator() {
+    for raw in ["tag=!spike", "tag!==spike", "impact=!=low"] {
+        let err = parse_filter(raw).unwrap_err();
+        assert!(err.contains("Ambiguous operator"), "{}: {}", raw, err);
+    }
+}
+
+#[test]
+fn parse_filter_rejects_bare_bang() {
+    let err = parse_filter("tag!spike").unwrap_err();
+    assert!(err.contains("Filter must be in format KEY=VALUE"));
+}
@@ -0,0 +1,14 @@
+#!/bin/bash
+# Mock agent that chatters on stdout, then writes valid result JSON and exits 0
+RESULT_PATH="$1"
+echo "AGENT NOISE on stdout"
+cat > "$RESULT_PATH" << 'JSON'
+{
+  "item_id": "WRK-001",
+  "phase": "prd",
+  "result": "phase_complete",
+  "summary": "Created PRD",
+  "follow_ups": []
+}
+JSON
+exit 0
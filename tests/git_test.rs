@@ -47,7 +47,7 @@ fn setup_temp_repo() -> TempDir {
 #[test]
 fn check_preconditions_clean_repo() {
     let repo = setup_temp_repo();
-    let result = phase_golem::git::check_preconditions(Some(repo.path()));
+    let result = phase_golem::git::check_preconditions(Some(repo.path()), None);
     assert!(result.is_ok(), "Expected Ok, got: {:?}", result);
 }
 
@@ -58,7 +58,7 @@ fn check_preconditions_dirty_tree_fails() {
     // Create an untracked file to dirty the tree
     fs::write(repo.path().join("dirty.txt"), "dirty").expect("Failed to write file");
 
-    let result = phase_golem::git::check_preconditions(Some(repo.path()));
+    let result = phase_golem::git::check_preconditions(Some(repo.path()), None);
     assert!(result.is_err());
     let err = result.unwrap_err();
     assert!(
@@ -86,7 +86,7 @@ fn check_preconditions_detached_head_fails() {
         .output()
         .expect("Failed to detach HEAD");
 
-    let result = phase_golem::git::check_preconditions(Some(repo.path()));
+    let result = phase_golem::git::check_preconditions(Some(repo.path()), None);
     assert!(result.is_err());
     let err = result.unwrap_err();
     assert!(
@@ -100,7 +100,7 @@ fn check_preconditions_detached_head_fails() {
 fn check_preconditions_not_git_repo_fails() {
     let dir = TempDir::new().expect("Failed to create temp dir");
 
-    let result = phase_golem::git::check_preconditions(Some(dir.path()));
+    let result = phase_golem::git::check_preconditions(Some(dir.path()), None);
     assert!(result.is_err());
     let err = result.unwrap_err();
     assert!(
@@ -118,7 +118,7 @@ fn check_preconditions_rebase_in_progress_fails() {
     let rebase_dir = repo.path().join(".git/rebase-merge");
     fs::create_dir_all(&rebase_dir).expect("Failed to create rebase-merge dir");
 
-    let result = phase_golem::git::check_preconditions(Some(repo.path()));
+    let result = phase_golem::git::check_preconditions(Some(repo.path()), None);
     assert!(result.is_err());
     let err = result.unwrap_err();
     assert!(
@@ -136,7 +136,7 @@ fn check_preconditions_merge_in_progress_fails() {
     let merge_head = repo.path().join(".git/MERGE_HEAD");
     fs::write(&merge_head, "abc123").expect("Failed to create MERGE_HEAD");
 
-    let result = phase_golem::git::check_preconditions(Some(repo.path()));
+    let result = phase_golem::git::check_preconditions(Some(repo.path()), None);
     assert!(result.is_err());
     let err = result.unwrap_err();
     assert!(
@@ -146,6 +146,83 @@ fn check_preconditions_merge_in_progress_fails() {
     );
 }
 
+#[test]
+fn check_preconditions_reachable_remote_passes() {
+    let repo = setup_temp_repo();
+    let remote = TempDir::new().expect("Failed to create remote dir");
+    Command::new("git")
+        .args(["init", "--bare"])
+        .current_dir(remote.path())
+        .output()
+        .expect("Failed to init bare remote");
+    Command::new("git")
+        .args(["remote", "add", "origin", remote.path().to_str().unwrap()])
+        .current_dir(repo.path())
+        .output()
+        .expect("Failed to add remote");
+
+    let result = phase_golem::git::check_preconditions(Some(repo.path()), Some("origin"));
+    assert!(result.is_ok(), "Expected Ok, got: {:?}", result);
+}
+
+#[test]
+fn check_preconditions_unreachable_remote_fails() {
+    let repo = setup_temp_repo();
+    let missing = repo.path().join("no-such-remote.git");
+    Command::new("git")
+        .args(["remote", "add", "origin", missing.to_str().unwrap()])
+        .current_dir(repo.path())
+        .output()
+        .expect("Failed to add remote");
+
+    let result = phase_golem::git::check_preconditions(Some(repo.path()), Some("origin"));
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert!(
+        err.contains("'origin' is unreachable"),
+        "Expected unreachable remote error in: {}",
+        err
+    );
+}
+
+#[test]
+fn ls_remote_args_targets_remote_heads() {
+    assert_eq!(
+        phase_golem::git::ls_remote_args("origin"),
+        vec!["ls-remote", "--heads", "origin"]
+    );
+}
+
+#[test]
+fn push_args_defaults_to_head() {
+    assert_eq!(
+        phase_golem::git::push_args("origin", None),
+        vec!["push", "origin", "HEAD"]
+    );
+}
+
+#[test]
+fn push_args_uses_configured_remote_and_branch() {
+    assert_eq!(
+        phase_golem::git::push_args("upstream", Some("release")),
+        vec!["push", "upstream", "release"]
+    );
+}
+
+#[test]
+fn push_to_unreachable_remote_fails() {
+    let repo = setup_temp_repo();
+    let missing = repo.path().join("no-such-remote.git");
+    Command::new("git")
+        .args(["remote", "add", "origin", missing.to_str().unwrap()])
+        .current_dir(repo.path())
+        .output()
+        .expect("Failed to add remote");
+
+    let err = phase_golem::git::push("origin", None, Some(repo.path())).unwrap_err();
+    assert!(err.contains("git push to 'origin' failed"), "got: {}", err);
+}
+
 #[test]
 fn is_git_repo_valid() {
     let repo = setup_temp_repo();
@@ -392,3 +469,233 @@ fn is_ancestor_unknown_commit_returns_error() {
         result
     );
 }
+
+// --- isolated clone tests ---
+
+#[test]
+fn create_isolated_clone_checks_out_head() {
+    let repo = setup_temp_repo();
+    let dest = TempDir::new().unwrap();
+    let clone_dir = dest.path().join("clone");
+
+    phase_golem::git::create_isolated_clone(repo.path(), &clone_dir).unwrap();
+
+    assert!(clone_dir.join("README.md").exists());
+    assert_eq!(
+        phase_golem::git::get_head_sha(&clone_dir).unwrap(),
+        phase_golem::git::get_head_sha(repo.path()).unwrap()
+    );
+}
+
+#[test]
+fn isolated_clone_patch_applies_to_source_repo() {
+    let repo = setup_temp_repo();
+    let dest = TempDir::new().unwrap();
+    let clone_dir = dest.path().join("clone");
+    let base = phase_golem::git::create_isolated_clone(repo.path(), &clone_dir).unwrap();
+
+    fs::write(clone_dir.join("README.md"), "# Changed\n").unwrap();
+    fs::write(clone_dir.join("new.txt"), "new file\n").unwrap();
+    fs::create_dir_all(clone_dir.join(".task-golem")).unwrap();
+    fs::write(clone_dir.join(".task-golem/tasks.jsonl"), "{}\n").unwrap();
+
+    let patch = phase_golem::git::isolated_clone_patch(&clone_dir, &base).unwrap();
+    phase_golem::git::apply_patch(&patch, repo.path()).unwrap();
+
+    assert_eq!(
+        fs::read_to_string(repo.path().join("README.md")).unwrap(),
+        "# Changed\n"
+    );
+    assert_eq!(
+        fs::read_to_string(repo.path().join("new.txt")).unwrap(),
+        "new file\n"
+    );
+    assert!(
+        !repo.path().join(".task-golem").exists(),
+        "store changes in the clone should not be applied"
+    );
+}
+
+#[test]
+fn isolated_clone_patch_includes_commits_made_in_clone() {
+    let repo = setup_temp_repo();
+    let dest = TempDir::new().unwrap();
+    let clone_dir = dest.path().join("clone");
+    let base = phase_golem::git::create_isolated_clone(repo.path(), &clone_dir).unwrap();
+    assert_eq!(base, phase_golem::git::get_head_sha(repo.path()).unwrap());
+
+    fs::write(clone_dir.join("committed.txt"), "committed\n").unwrap();
+    Command::new("git")
+        .args(["add", "committed.txt"])
+        .current_dir(&clone_dir)
+        .status()
+        .unwrap();
+    let status = Command::new("git")
+        .args([
+            "-c",
+            "user.name=Agent",
+            "-c",
+            "user.email=agent@example.com",
+        ])
+        .args(["commit", "--quiet", "--no-gpg-sign", "-m", "agent commit"])
+        .current_dir(&clone_dir)
+        .status()
+        .unwrap();
+    assert!(status.success());
+    fs::write(clone_dir.join("loose.txt"), "loose\n").unwrap();
+
+    let patch = phase_golem::git::isolated_clone_patch(&clone_dir, &base).unwrap();
+    phase_golem::git::apply_patch(&patch, repo.path()).unwrap();
+
+    assert_eq!(
+        fs::read_to_string(repo.path().join("committed.txt")).unwrap(),
+        "committed\n"
+    );
+    assert_eq!(
+        fs::read_to_string(repo.path().join("loose.txt")).unwrap(),
+        "loose\n"
+    );
+}
+
+#[test]
+fn apply_empty_patch_is_noop() {
+    let repo = setup_temp_repo();
+    phase_golem::git::apply_patch("", repo.path()).unwrap();
+    let status = phase_golem::git::get_status(Some(repo.path())).unwrap();
+    assert!(status.is_empty());
+}
+
+// --- diff_stat_since tests ---
+
+#[test]
+fn diff_stat_since_reports_changed_lines_per_file() {
+    let repo = setup_temp_repo();
+    let start = phase_golem::git::get_head_sha(repo.path()).unwrap();
+
+    fs::write(repo.path().join("README.md"), "# Changed\nmore\n").unwrap();
+    fs::write(repo.path().join("new.txt"), "a\nb\nc\n").unwrap();
+    Command::new("git")
+        .args(["add", "-A"])
+        .current_dir(repo.path())
+        .output()
+        .unwrap();
+    Command::new("git")
+        .args(["commit", "-m", "Change files"])
+        .current_dir(repo.path())
+        .output()
+        .unwrap();
+
+    let stats = phase_golem::git::diff_stat_since(&start, repo.path()).unwrap();
+
+    assert_eq!(
+        stats,
+        vec![
+            phase_golem::git::FileDiffStat {
+                path: "README.md".to_string(),
+                added: Some(2),
+                removed: Some(1),
+            },
+            phase_golem::git::FileDiffStat {
+                path: "new.txt".to_string(),
+                added: Some(3),
+                removed: Some(0),
+            },
+        ]
+    );
+}
+
+#[test]
+fn diff_stat_since_unknown_commit_returns_error() {
+    let repo = setup_temp_repo();
+    let result =
+        phase_golem::git::diff_stat_since("0000000000000000000000000000000000000000", repo.path());
+    assert!(result.is_err());
+}
+
+#[test]
+fn parse_numstat_marks_binary_files() {
+    let stats = phase_golem::git::parse_numstat("-\t-\tlogo.png\n");
+    assert_eq!(stats[0].path, "logo.png");
+    assert_eq!(stats[0].added, None);
+    assert_eq!(stats[0].removed, None);
+}
+
+#[test]
+fn commits_since_lists_new_commits_oldest_first() {
+    let repo = setup_temp_repo();
+    let start = phase_golem::git::get_head_sha(repo.path()).unwrap();
+
+    for (file, message) in [
+        ("a.txt", "[WRK-001][build] Add a"),
+        ("b.txt", "[WRK-002][build] Add b"),
+    ] {
+        fs::write(repo.path().join(file), "x\n").unwrap();
+        Command::new("git")
+            .args(["add", "-A"])
+            .current_dir(repo.path())
+            .output()
+            .unwrap();
+        Command::new("git")
+            .args(["commit", "-m", message])
+            .current_dir(repo.path())
+            .output()
+            .unwrap();
+    }
+
+    let commits = phase_golem::git::commits_since(&start, repo.path()).unwrap();
+
+    let subjects: Vec<&str> = commits.iter().map(|(_, s)| s.as_str()).collect();
+    assert_eq!(
+        subjects,
+        vec!["[WRK-001][build] Add a", "[WRK-002][build] Add b"]
+    );
+    assert_eq!(
+        commits[1].0,
+        phase_golem::git::get_head_sha(repo.path()).unwrap()
+    );
+}
+
+// --- item branch tests ---
+
+#[test]
+fn checkout_item_branch_creates_then_merges_base() {
+    let repo = setup_temp_repo();
+    let base = phase_golem::git::current_branch(repo.path()).unwrap();
+    let branch = phase_golem::git::item_branch_name("WRK-001");
+
+    phase_golem::git::checkout_item_branch(&branch, &base, repo.path()).unwrap();
+    assert_eq!(
+        phase_golem::git::current_branch(repo.path()).unwrap(),
+        branch
+    );
+
+    // Work lands on base while the item branch is idle
+    phase_golem::git::checkout_branch(&base, repo.path()).unwrap();
+    fs::write(repo.path().join("later.txt"), "later\n").unwrap();
+    Command::new("git")
+        .args(["add", "later.txt"])
+        .current_dir(repo.path())
+        .output()
+        .unwrap();
+    Command::new("git")
+        .args(["commit", "-m", "Later work"])
+        .current_dir(repo.path())
+        .output()
+        .unwrap();
+
+    phase_golem::git::checkout_item_branch(&branch, &base, repo.path()).unwrap();
+    assert_eq!(
+        phase_golem::git::current_branch(repo.path()).unwrap(),
+        branch
+    );
+    assert!(repo.path().join("later.txt").exists());
+}
+
+#[test]
+fn branch_exists_reports_missing_branch() {
+    let repo = setup_temp_repo();
+    assert!(!phase_golem::git::branch_exists(
+        "phase-golem/WRK-404",
+        repo.path()
+    ));
+}
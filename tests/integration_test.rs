@@ -1,8 +1,12 @@
 mod common;
 
 use std::collections::HashMap;
+use std::fs;
+use std::process::Command;
 
-use phase_golem::config::{default_feature_pipeline, ExecutionConfig};
+use phase_golem::config::{
+    default_feature_pipeline, ExecutionConfig, Isolation, PrePhaseBlockPolicy, WorklogFormat,
+};
 use phase_golem::coordinator;
 use phase_golem::scheduler::{self, RunningTasks};
 use phase_golem::types::{ItemStatus, SchedulerAction};
@@ -51,7 +55,26 @@ async fn coordinator_snapshot_feeds_scheduler_select_actions() {
         max_concurrent: 2,
         phase_timeout_minutes: 30,
         max_retries: 2,
+        retry_backoff_seconds: 0,
+        retry_backoff_max_seconds: 300,
         default_phase_cap: 100,
+        worklog_include_full_result: false,
+        worklog_format: WorklogFormat::Markdown,
+        max_actions_per_iteration: None,
+        idle_poll_ms: 10,
+        dedup_follow_ups: true,
+        follow_up_similarity: 1.0,
+        isolation: Isolation::None,
+        triage_failure_threshold: 3,
+        branch_per_item: false,
+        on_prephase_block: PrePhaseBlockPolicy::Block,
+        guard_external_dirty: false,
+        circuit_breaker_threshold: 2,
+        event_log: false,
+        deadline_window_days: 3,
+        metrics_file: false,
+        archive_on_done: true,
+        post_phase_hook: None,
     };
 
     let running = RunningTasks::default();
@@ -130,7 +153,26 @@ async fn tg_add_item_defaults_to_new_and_is_triageable() {
         max_concurrent: 2,
         phase_timeout_minutes: 30,
         max_retries: 2,
+        retry_backoff_seconds: 0,
+        retry_backoff_max_seconds: 300,
         default_phase_cap: 100,
+        worklog_include_full_result: false,
+        worklog_format: WorklogFormat::Markdown,
+        max_actions_per_iteration: None,
+        idle_poll_ms: 10,
+        dedup_follow_ups: true,
+        follow_up_similarity: 1.0,
+        isolation: Isolation::None,
+        triage_failure_threshold: 3,
+        branch_per_item: false,
+        on_prephase_block: PrePhaseBlockPolicy::Block,
+        guard_external_dirty: false,
+        circuit_breaker_threshold: 2,
+        event_log: false,
+        deadline_window_days: 3,
+        metrics_file: false,
+        archive_on_done: true,
+        post_phase_hook: None,
     };
 
     let running = RunningTasks::default();
@@ -149,6 +191,121 @@ async fn tg_add_item_defaults_to_new_and_is_triageable() {
     drop(handle);
 }
 
+/// `run --dry-run` spawns nothing, so it works without the agent CLI installed
+/// and on a dirty tree.
+#[test]
+fn run_dry_run_needs_no_agent_cli_or_clean_tree() {
+    let dir = setup_test_env();
+    let store = setup_task_golem_store(dir.path());
+    store
+        .save_active(&[common::make_in_progress_pg_item("WRK-001", "prd").0])
+        .expect("save items");
+
+    fs::create_dir_all(dir.path().join("wf")).unwrap();
+    fs::write(dir.path().join("wf/prd.md"), "Write the PRD.\n").unwrap();
+    fs::write(
+        dir.path().join("phase-golem.toml"),
+        r#"
+[project]
+prefix = "WRK"
+
+[agent]
+cli = "custom"
+
+[agent.custom]
+command = "phase-golem-test-agent-that-is-not-installed"
+args = ["{result_file}"]
+
+[pipelines.feature]
+phases = [{ name = "prd", workflows = ["wf/prd.md"], is_destructive = false }]
+"#,
+    )
+    .unwrap();
+    // Nothing above is committed, so the tree is dirty
+
+    let output = Command::new(env!("CARGO_BIN_EXE_phase-golem"))
+        .arg("--root")
+        .arg(dir.path())
+        .args(["run", "--dry-run"])
+        .output()
+        .expect("run phase-golem");
+    let console = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert!(output.status.success(), "dry run failed: {}", console);
+    assert!(console.contains("WRK-001: prd"), "{}", console);
+}
+
+/// `run --summary-only` end to end: stdout holds exactly one JSON object even
+/// when the agent writes to stdout and its output log cannot be opened.
+#[test]
+fn run_summary_only_prints_one_json_object_on_stdout() {
+    let dir = setup_test_env();
+    let store = setup_task_golem_store(dir.path());
+    store
+        .save_active(&[common::make_in_progress_pg_item("WRK-001", "prd").0])
+        .expect("save items");
+
+    let agent = common::fixture_path("mock_agent_noisy_stdout.sh");
+    fs::create_dir_all(dir.path().join("wf")).unwrap();
+    fs::write(dir.path().join("wf/prd.md"), "Write the PRD.\n").unwrap();
+    fs::write(
+        dir.path().join("phase-golem.toml"),
+        format!(
+            r#"
+[project]
+prefix = "WRK"
+
+[agent]
+cli = "custom"
+
+[agent.custom]
+command = "bash"
+args = ["{}", "{{result_file}}"]
+
+[pipelines.feature]
+phases = [{{ name = "prd", workflows = ["wf/prd.md"], is_destructive = false }}]
+"#,
+            agent.display()
+        ),
+    )
+    .unwrap();
+    fs::write(dir.path().join(".gitignore"), ".phase-golem/\n").unwrap();
+    for args in [vec!["add", "-A"], vec!["commit", "-m", "Set up project"]] {
+        let status = Command::new("git")
+            .args(&args)
+            .current_dir(dir.path())
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {:?} failed", args);
+    }
+    // A file where the agent log directory belongs leaves agent output uncaptured
+    fs::write(dir.path().join(".phase-golem/logs"), "").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_phase-golem"))
+        .arg("--root")
+        .arg(dir.path())
+        .args(["run", "--summary-only", "--target", "WRK-001"])
+        .output()
+        .expect("run phase-golem");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        output.status.success(),
+        "run failed: {}\nstdout: {}",
+        String::from_utf8_lossy(&output.stderr),
+        stdout
+    );
+
+    assert!(!stdout.contains("AGENT NOISE"), "{}", stdout);
+    let mut values = serde_json::Deserializer::from_str(&stdout).into_iter::<serde_json::Value>();
+    let summary = values.next().expect("a JSON summary").unwrap();
+    assert!(values.next().is_none(), "expected exactly one JSON value");
+    assert_eq!(summary["items_completed"], serde_json::json!(["WRK-001"]));
+}
+
 /// handle_init does NOT create BACKLOG.yaml, checks for .task-golem/ existence.
 #[test]
 fn handle_init_does_not_create_backlog_yaml() {
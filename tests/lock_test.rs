@@ -10,7 +10,7 @@ fn lock_acquire_and_release() {
     // PID file should exist with our PID
     let pid_contents = std::fs::read_to_string(orch_dir.join("phase-golem.pid")).unwrap();
     assert_eq!(
-        pid_contents.trim().parse::<u32>().unwrap(),
+        pid_contents.lines().next().unwrap().parse::<u32>().unwrap(),
         std::process::id()
     );
 
@@ -44,7 +44,7 @@ fn lock_prevents_concurrent_acquisition() {
     // Second acquisition should fail because fslock is held
     let result = lock::try_acquire(&orch_dir);
     assert!(result.is_err());
-    let err = result.unwrap_err();
+    let err = result.unwrap_err().to_string();
     assert!(
         err.contains("Another phase-golem instance"),
         "Error message should mention another instance: {}",
@@ -52,6 +52,45 @@ fn lock_prevents_concurrent_acquisition() {
     );
 }
 
+#[tokio::test]
+async fn lock_with_timeout_waits_for_release() {
+    let dir = tempfile::tempdir().unwrap();
+    let orch_dir = dir.path().join(".phase-golem");
+
+    let holder = lock::try_acquire(&orch_dir).unwrap();
+    let release = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(200));
+        drop(holder);
+    });
+
+    let guard = lock::try_acquire_with_timeout(&orch_dir, std::time::Duration::from_secs(10)).await;
+    release.join().unwrap();
+
+    assert!(
+        guard.is_ok(),
+        "expected the lock after release: {:?}",
+        guard
+    );
+}
+
+#[tokio::test]
+async fn lock_with_timeout_fails_after_deadline() {
+    let dir = tempfile::tempdir().unwrap();
+    let orch_dir = dir.path().join(".phase-golem");
+
+    let _holder = lock::try_acquire(&orch_dir).unwrap();
+    let started = std::time::Instant::now();
+    let err = lock::try_acquire_with_timeout(&orch_dir, std::time::Duration::from_millis(300))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, lock::LockError::Held(_)), "{}", err);
+    let err = err.to_string();
+
+    assert!(started.elapsed() >= std::time::Duration::from_millis(300));
+    assert!(err.contains("Another phase-golem instance"), "{}", err);
+    assert!(err.contains("gave up after waiting 300ms"), "{}", err);
+}
+
 #[test]
 fn lock_acquires_when_stale_pid_file_exists() {
     let dir = tempfile::tempdir().unwrap();
@@ -72,7 +111,7 @@ fn lock_acquires_when_stale_pid_file_exists() {
     // Verify new PID was written
     let pid_contents = std::fs::read_to_string(&pid_path).unwrap();
     assert_eq!(
-        pid_contents.trim().parse::<u32>().unwrap(),
+        pid_contents.lines().next().unwrap().parse::<u32>().unwrap(),
         std::process::id()
     );
 
@@ -121,7 +160,7 @@ fn lock_contention_via_fslock_without_pid_file() {
     // try_acquire should fail because the fslock is held
     let result = lock::try_acquire(&orch_dir);
     assert!(result.is_err());
-    let err = result.unwrap_err();
+    let err = result.unwrap_err().to_string();
     assert!(
         err.contains("holds the lock"),
         "Error should mention held lock: {}",
@@ -130,3 +169,74 @@ fn lock_contention_via_fslock_without_pid_file() {
 
     external_lock.unlock().unwrap();
 }
+
+#[test]
+fn lock_concurrent_contenders_never_take_a_lock_held_past_its_dead_holder() {
+    let dir = tempfile::tempdir().unwrap();
+    let orch_dir = dir.path().join(".phase-golem");
+    std::fs::create_dir_all(&orch_dir).unwrap();
+
+    let lock_path = orch_dir.join("phase-golem.lock");
+    let mut orphaned_lock = fslock::LockFile::open(&lock_path).unwrap();
+    assert!(orphaned_lock.try_lock().unwrap());
+    std::fs::write(orch_dir.join("phase-golem.pid"), "99999999\n").unwrap();
+
+    let barrier = std::sync::Arc::new(std::sync::Barrier::new(2));
+    let contenders: Vec<_> = (0..2)
+        .map(|_| {
+            let orch_dir = orch_dir.clone();
+            let barrier = std::sync::Arc::clone(&barrier);
+            std::thread::spawn(move || {
+                barrier.wait();
+                lock::try_acquire(&orch_dir)
+            })
+        })
+        .collect();
+    let results: Vec<_> = contenders.into_iter().map(|t| t.join().unwrap()).collect();
+
+    for result in &results {
+        let err = result.as_ref().unwrap_err().to_string();
+        assert!(err.contains("leftover child process"), "{}", err);
+    }
+    // The held lock file was not replaced: a fresh handle still sees it locked
+    let mut probe = fslock::LockFile::open(&lock_path).unwrap();
+    assert!(!probe.try_lock().unwrap());
+
+    orphaned_lock.unlock().unwrap();
+}
+
+#[test]
+fn lock_refuses_lock_held_past_its_dead_holder() {
+    let dir = tempfile::tempdir().unwrap();
+    let orch_dir = dir.path().join(".phase-golem");
+    std::fs::create_dir_all(&orch_dir).unwrap();
+
+    // The flock outlives its recorded holder (e.g. inherited by an orphaned
+    // agent subprocess), and the PID file names a dead process
+    let lock_path = orch_dir.join("phase-golem.lock");
+    let mut orphaned_lock = fslock::LockFile::open(&lock_path).unwrap();
+    assert!(orphaned_lock.try_lock().unwrap());
+    let pid_path = orch_dir.join("phase-golem.pid");
+    std::fs::write(&pid_path, "99999999\n2026-01-01T00:00:00+00:00\n").unwrap();
+
+    let err = lock::try_acquire(&orch_dir).unwrap_err().to_string();
+    assert!(err.contains("PID 99999999"), "{}", err);
+    assert!(err.contains("leftover child process"), "{}", err);
+
+    // Nothing was unlinked or overwritten
+    assert!(lock_path.exists());
+    assert_eq!(
+        std::fs::read_to_string(&pid_path).unwrap(),
+        "99999999\n2026-01-01T00:00:00+00:00\n"
+    );
+
+    // Once the leftover process lets go, the stale PID file is overwritten
+    orphaned_lock.unlock().unwrap();
+    let guard = lock::try_acquire(&orch_dir).expect("released lock should be acquired");
+    let pid_contents = std::fs::read_to_string(&pid_path).unwrap();
+    assert_eq!(
+        pid_contents.lines().next().unwrap().parse::<u32>().unwrap(),
+        std::process::id()
+    );
+    drop(guard);
+}
@@ -7,8 +7,8 @@ use task_golem::store::Store;
 
 use phase_golem::pg_item::{self, PgItem};
 use phase_golem::types::{
-    BlockType, DimensionLevel, ItemStatus, ItemUpdate, PhasePool, SizeLevel, StructuredDescription,
-    UpdatedAssessments,
+    BlockType, DimensionLevel, ItemStatus, ItemUpdate, PhaseHistoryEntry, PhasePool, ResultCode,
+    SizeLevel, StructuredDescription, UpdatedAssessments,
 };
 
 // --- Helpers ---
@@ -772,6 +772,25 @@ fn apply_update_unblock() {
     assert!(pg.unblock_context().is_none());
 }
 
+#[test]
+fn apply_update_set_blocked_then_unblock_restores_prior_status() {
+    let mut item = make_test_item();
+    pg_item::set_pg_status(&mut item, ItemStatus::Scoping);
+
+    pg_item::apply_update(
+        &mut item,
+        ItemUpdate::SetBlocked("waiting on vendor".to_string()),
+    );
+    assert_eq!(PgItem(item.clone()).pg_status(), ItemStatus::Blocked);
+
+    pg_item::apply_update(&mut item, ItemUpdate::Unblock);
+
+    let pg = PgItem(item);
+    assert_eq!(pg.pg_status(), ItemStatus::Scoping);
+    assert!(pg.0.blocked_reason.is_none());
+    assert!(pg.pg_blocked_from_status().is_none());
+}
+
 #[test]
 fn apply_update_unblock_non_blocked_is_skipped() {
     let mut item = make_test_item();
@@ -835,6 +854,55 @@ fn apply_update_update_assessments_partial() {
     assert!(pg.impact().is_none()); // still absent
 }
 
+#[test]
+fn apply_update_single_dimension_overrides_leave_others_untouched() {
+    let mut item = make_test_item();
+    pg_item::apply_update(
+        &mut item,
+        ItemUpdate::UpdateAssessments(UpdatedAssessments {
+            size: Some(SizeLevel::Small),
+            complexity: Some(DimensionLevel::Low),
+            risk: Some(DimensionLevel::Low),
+            impact: Some(DimensionLevel::Low),
+        }),
+    );
+
+    pg_item::apply_update(&mut item, ItemUpdate::SetImpact(DimensionLevel::High));
+    let pg = PgItem(item.clone());
+    assert_eq!(pg.impact(), Some(DimensionLevel::High));
+    assert_eq!(pg.size(), Some(SizeLevel::Small));
+    assert_eq!(pg.risk(), Some(DimensionLevel::Low));
+    assert_eq!(pg.complexity(), Some(DimensionLevel::Low));
+
+    pg_item::apply_update(&mut item, ItemUpdate::SetSize(SizeLevel::Large));
+    let pg = PgItem(item.clone());
+    assert_eq!(pg.size(), Some(SizeLevel::Large));
+    assert_eq!(pg.impact(), Some(DimensionLevel::High));
+    assert_eq!(pg.risk(), Some(DimensionLevel::Low));
+
+    pg_item::apply_update(&mut item, ItemUpdate::SetRisk(DimensionLevel::Medium));
+    let pg = PgItem(item.clone());
+    assert_eq!(pg.risk(), Some(DimensionLevel::Medium));
+    assert_eq!(pg.complexity(), Some(DimensionLevel::Low));
+
+    pg_item::apply_update(&mut item, ItemUpdate::SetComplexity(DimensionLevel::High));
+    let pg = PgItem(item);
+    assert_eq!(pg.complexity(), Some(DimensionLevel::High));
+    assert_eq!(pg.size(), Some(SizeLevel::Large));
+    assert_eq!(pg.risk(), Some(DimensionLevel::Medium));
+    assert_eq!(pg.impact(), Some(DimensionLevel::High));
+}
+
+#[test]
+fn apply_update_set_dimension_does_not_change_status() {
+    let mut item = make_test_item();
+    let status_before = PgItem(item.clone()).pg_status();
+
+    pg_item::apply_update(&mut item, ItemUpdate::SetSize(SizeLevel::Large));
+
+    assert_eq!(PgItem(item).pg_status(), status_before);
+}
+
 #[test]
 fn apply_update_set_pipeline_type() {
     let mut item = make_test_item();
@@ -858,6 +926,95 @@ fn apply_update_set_last_phase_commit() {
     );
 }
 
+#[test]
+fn apply_update_record_phase_completed_deduplicates() {
+    let mut item = make_test_item();
+    pg_item::apply_update(
+        &mut item,
+        ItemUpdate::RecordPhaseCompleted("prd".to_string()),
+    );
+    pg_item::apply_update(
+        &mut item,
+        ItemUpdate::RecordPhaseCompleted("build".to_string()),
+    );
+    pg_item::apply_update(
+        &mut item,
+        ItemUpdate::RecordPhaseCompleted("prd".to_string()),
+    );
+
+    let pg = PgItem(item);
+    assert_eq!(pg.completed_phases(), vec!["prd", "build"]);
+}
+
+#[test]
+fn set_completed_phases_empty_removes_extension() {
+    let mut item = make_test_item();
+    pg_item::set_completed_phases(&mut item, &["prd".to_string()]);
+    pg_item::set_completed_phases(&mut item, &[]);
+
+    assert!(!item.extensions.contains_key(pg_item::X_PG_COMPLETED_PHASES));
+    assert!(PgItem(item).completed_phases().is_empty());
+}
+
+#[test]
+fn append_phase_history_preserves_order_and_round_trips() {
+    let mut item = make_test_item();
+    let entries = vec![
+        PhaseHistoryEntry {
+            phase: "prd".to_string(),
+            result: ResultCode::PhaseComplete,
+            completed_at: Utc::now(),
+            commit: Some("abc123".to_string()),
+        },
+        PhaseHistoryEntry {
+            phase: "build".to_string(),
+            result: ResultCode::Blocked,
+            completed_at: Utc::now(),
+            commit: None,
+        },
+    ];
+    for entry in &entries {
+        pg_item::append_phase_history(&mut item, entry);
+    }
+
+    assert_eq!(PgItem(item).phase_history(), entries);
+}
+
+#[test]
+fn phase_history_skips_malformed_entries() {
+    let mut item = make_test_item();
+    item.extensions.insert(
+        pg_item::X_PG_PHASE_HISTORY.to_string(),
+        serde_json::json!([
+            { "phase": "prd" },
+            {
+                "phase": "build",
+                "result": "phase_complete",
+                "completed_at": "2026-02-26T12:00:00Z",
+            },
+        ]),
+    );
+
+    let history = PgItem(item).phase_history();
+
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].phase, "build");
+    assert_eq!(history[0].commit, None);
+}
+
+#[test]
+fn set_priority_round_trips_and_clears() {
+    let mut item = make_test_item();
+    assert_eq!(PgItem(item.clone()).priority(), None);
+
+    pg_item::set_priority(&mut item, Some(-3));
+    assert_eq!(PgItem(item.clone()).priority(), Some(-3));
+
+    pg_item::set_priority(&mut item, None);
+    assert!(!item.extensions.contains_key(pg_item::X_PG_PRIORITY));
+    assert_eq!(PgItem(item).priority(), None);
+}
+
 #[test]
 fn apply_update_set_description() {
     let desc = StructuredDescription {
@@ -876,6 +1033,46 @@ fn apply_update_set_description() {
     assert_eq!(pg.0.description.as_deref(), Some("Context for this item"));
 }
 
+#[test]
+fn add_dependencies_skips_duplicates() {
+    let mut item = make_test_item();
+    let added = pg_item::add_dependencies(
+        &mut item,
+        &[
+            "WRK-dep1".to_string(),
+            "WRK-dep2".to_string(),
+            "WRK-dep2".to_string(),
+        ],
+    );
+
+    assert_eq!(added, vec!["WRK-dep2".to_string()]);
+    assert_eq!(item.dependencies, vec!["WRK-dep1", "WRK-dep2"]);
+    assert!(item.updated_at > make_test_item().updated_at);
+}
+
+#[test]
+fn remove_dependencies_ignores_missing() {
+    let mut item = make_test_item();
+    item.dependencies.push("WRK-dep2".to_string());
+
+    let removed =
+        pg_item::remove_dependencies(&mut item, &["WRK-dep1".to_string(), "WRK-nope".to_string()]);
+
+    assert_eq!(removed, vec!["WRK-dep1".to_string()]);
+    assert_eq!(item.dependencies, vec!["WRK-dep2"]);
+}
+
+#[test]
+fn remove_dependencies_noop_leaves_updated_at() {
+    let mut item = make_test_item();
+    let before = item.updated_at;
+
+    let removed = pg_item::remove_dependencies(&mut item, &["WRK-nope".to_string()]);
+
+    assert!(removed.is_empty());
+    assert_eq!(item.updated_at, before);
+}
+
 // =====================================================================
 // Native field delegate tests
 // =====================================================================
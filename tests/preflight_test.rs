@@ -4,15 +4,20 @@ use std::path::Path;
 
 use phase_golem::config::{PhaseConfig, PhaseGolemConfig, PipelineConfig, StalenessAction};
 use phase_golem::pg_item::{self, PgItem};
-use phase_golem::preflight::{run_preflight, PreflightError};
+use phase_golem::preflight::{
+    check_config, dependency_depths, find_deep_chains, find_dependency_cycles,
+    find_done_dependencies, find_interrupted_changes, find_unknown_dependencies,
+    lint_dependency_graph, run_preflight, PreflightError,
+};
 use phase_golem::types::{ItemStatus, PhasePool};
 
 // --- Test project root with .task-golem/ directory ---
 
 /// Returns a stable project root path with a `.task-golem/` directory.
 ///
-/// Uses `/tmp/pg-preflight-test` and creates `.task-golem/` on first call.
-/// All preflight tests share this root since they only read (never write) from it.
+/// Uses `/tmp/pg-preflight-test` and creates `.task-golem/` and the stub
+/// workflow file on first call. All preflight tests share this root since they
+/// only read (never write) from it.
 fn test_project_root() -> &'static Path {
     use std::sync::Once;
     static INIT: Once = Once::new();
@@ -20,10 +25,20 @@ fn test_project_root() -> &'static Path {
     INIT.call_once(|| {
         std::fs::create_dir_all(format!("{}/.task-golem", DIR))
             .expect("Failed to create .task-golem dir for preflight tests");
+        write_stub_workflow(Path::new(DIR));
     });
     Path::new(DIR)
 }
 
+/// Workflow file referenced by every phase of `feature_pipeline()`.
+const STUB_WORKFLOW: &str = "workflows/stub.md";
+
+fn write_stub_workflow(root: &Path) {
+    std::fs::create_dir_all(root.join("workflows")).expect("Failed to create workflows dir");
+    std::fs::write(root.join(STUB_WORKFLOW), "# Stub workflow\n")
+        .expect("Failed to write stub workflow");
+}
+
 // --- Test helpers ---
 
 fn make_feature_item(id: &str, status: ItemStatus) -> PgItem {
@@ -32,21 +47,27 @@ fn make_feature_item(id: &str, status: ItemStatus) -> PgItem {
     pg
 }
 
-/// Build a default feature pipeline with empty workflow lists.
+/// Build a default feature pipeline whose phases all use `STUB_WORKFLOW`.
 ///
-/// This mirrors the structure of `default_feature_pipeline()` but with no workflow
-/// file references, so the workflow probe phase does not require real files on disk.
-fn feature_pipeline_no_workflows() -> PipelineConfig {
+/// This mirrors the structure of `default_feature_pipeline()` but references a
+/// single stub file, so the workflow probe only needs `write_stub_workflow`.
+fn feature_pipeline() -> PipelineConfig {
+    let phase = |name: &str, is_destructive: bool| PhaseConfig {
+        workflows: vec![STUB_WORKFLOW.to_string()],
+        ..PhaseConfig::new(name, is_destructive)
+    };
     PipelineConfig {
-        pre_phases: vec![PhaseConfig::new("research", false)],
+        pre_phases: vec![phase("research", false)],
         phases: vec![
-            PhaseConfig::new("prd", false),
-            PhaseConfig::new("tech-research", false),
-            PhaseConfig::new("design", false),
-            PhaseConfig::new("spec", false),
-            PhaseConfig::new("build", true),
-            PhaseConfig::new("review", false),
+            phase("prd", false),
+            phase("tech-research", false),
+            phase("design", false),
+            phase("spec", false),
+            phase("build", true),
+            phase("review", false),
         ],
+        max_wip: None,
+        max_concurrent: None,
     }
 }
 
@@ -54,7 +75,7 @@ fn default_config() -> PhaseGolemConfig {
     let mut config = PhaseGolemConfig::default();
     config
         .pipelines
-        .insert("feature".to_string(), feature_pipeline_no_workflows());
+        .insert("feature".to_string(), feature_pipeline());
     config
 }
 
@@ -89,6 +110,7 @@ fn preflight_passes_when_task_golem_dir_exists() {
     let dir = tempfile::TempDir::new().expect("Failed to create temp dir");
     std::fs::create_dir_all(dir.path().join(".task-golem"))
         .expect("Failed to create .task-golem dir");
+    write_stub_workflow(dir.path());
 
     let config = default_config();
     let items: Vec<PgItem> = vec![];
@@ -118,6 +140,8 @@ fn preflight_no_main_phases_fails() {
         PipelineConfig {
             pre_phases: vec![],
             phases: vec![],
+            max_wip: None,
+            max_concurrent: None,
         },
     );
 
@@ -148,6 +172,8 @@ fn preflight_duplicate_phase_names_fails() {
                     ..PhaseConfig::new("build", false)
                 },
             ],
+            max_wip: None,
+            max_concurrent: None,
         },
     );
 
@@ -175,6 +201,8 @@ fn preflight_destructive_pre_phase_fails() {
                 workflows: vec!["workflow.md".to_string()],
                 ..PhaseConfig::new("build", false)
             }],
+            max_wip: None,
+            max_concurrent: None,
         },
     );
 
@@ -229,6 +257,8 @@ fn preflight_staleness_block_with_max_wip_gt_1_fails() {
                 staleness: StalenessAction::Block,
                 ..PhaseConfig::new("build", true)
             }],
+            max_wip: None,
+            max_concurrent: None,
         },
     );
 
@@ -295,6 +325,8 @@ fn preflight_workflow_files_exist_passes() {
                 workflows: vec![workflow_path.to_string()],
                 ..PhaseConfig::new("build", false)
             }],
+            max_wip: None,
+            max_concurrent: None,
         },
     );
 
@@ -319,6 +351,8 @@ fn preflight_missing_workflow_files_fails() {
                 workflows: vec!["workflows/nonexistent.md".to_string()],
                 ..PhaseConfig::new("build", false)
             }],
+            max_wip: None,
+            max_concurrent: None,
         },
     );
 
@@ -328,7 +362,82 @@ fn preflight_missing_workflow_files_fails() {
     let errors = result.unwrap_err();
     assert!(errors
         .iter()
-        .any(|e| e.condition.contains("Workflow file not found")));
+        .any(|e| e.condition.contains("workflow not found")));
+}
+
+#[test]
+fn preflight_missing_workflow_names_pipeline_and_phase() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    std::fs::create_dir_all(root.join(".task-golem")).unwrap();
+    std::fs::create_dir_all(root.join("workflows")).unwrap();
+    std::fs::write(root.join("workflows/prd.md"), "# PRD\n").unwrap();
+
+    let mut config = PhaseGolemConfig::default();
+    config.pipelines.insert(
+        "feature".to_string(),
+        PipelineConfig {
+            pre_phases: vec![],
+            phases: vec![
+                PhaseConfig {
+                    workflows: vec!["workflows/prd.md".to_string()],
+                    ..PhaseConfig::new("prd", false)
+                },
+                PhaseConfig {
+                    workflows: vec![
+                        "workflows/prd.md".to_string(),
+                        "workflows/buidl.md".to_string(),
+                    ],
+                    ..PhaseConfig::new("build", true)
+                },
+            ],
+            max_wip: None,
+            max_concurrent: None,
+        },
+    );
+
+    let result = run_preflight(&config, &[], root, root);
+
+    let errors = result.unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].condition,
+        "pipeline 'feature' phase 'build': workflow not found: workflows/buidl.md"
+    );
+    assert!(errors[0]
+        .config_location
+        .contains("pipelines.feature → build"));
+}
+
+#[test]
+fn preflight_phase_without_workflows_fails() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    std::fs::create_dir_all(root.join(".task-golem")).unwrap();
+    write_stub_workflow(root);
+
+    let mut config = PhaseGolemConfig::default();
+    config.pipelines.insert(
+        "bugfix".to_string(),
+        PipelineConfig {
+            pre_phases: vec![PhaseConfig::new("triage-notes", false)],
+            phases: vec![PhaseConfig {
+                workflows: vec![STUB_WORKFLOW.to_string()],
+                ..PhaseConfig::new("build", true)
+            }],
+            max_wip: None,
+            max_concurrent: None,
+        },
+    );
+
+    let result = run_preflight(&config, &[], root, root);
+
+    let errors = result.unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].condition,
+        "pipeline 'bugfix' phase 'triage-notes': no workflows configured"
+    );
 }
 
 // --- Item validation tests ---
@@ -946,6 +1055,8 @@ fn preflight_phase3_skipped_when_phase1_fails() {
         PipelineConfig {
             pre_phases: vec![],
             phases: vec![],
+            max_wip: None,
+            max_concurrent: None,
         },
     );
 
@@ -989,6 +1100,8 @@ fn preflight_phase3_runs_when_phase1_passes_but_phase2_fails() {
                 workflows: vec!["workflows/nonexistent.md".to_string()],
                 ..PhaseConfig::new("build", false)
             }],
+            max_wip: None,
+            max_concurrent: None,
         },
     );
 
@@ -1007,7 +1120,7 @@ fn preflight_phase3_runs_when_phase1_passes_but_phase2_fails() {
     // Phase 2 ran and found missing workflow file
     assert!(errors
         .iter()
-        .any(|e| e.condition.contains("Workflow file not found")));
+        .any(|e| e.condition.contains("workflow not found")));
     // Phase 3 ran (because Phase 1 passed) and caught the invalid phase reference
     assert!(errors.iter().any(|e| e.condition.contains("unknown phase")));
 }
@@ -1021,6 +1134,8 @@ fn preflight_phase4_and_phase5_run_when_phase1_fails() {
         PipelineConfig {
             pre_phases: vec![],
             phases: vec![],
+            max_wip: None,
+            max_concurrent: None,
         },
     );
 
@@ -1069,6 +1184,8 @@ fn preflight_config_base_differs_from_project_root() {
                 workflows: vec![workflow_path.to_string()],
                 ..PhaseConfig::new("build", false)
             }],
+            max_wip: None,
+            max_concurrent: None,
         },
     );
 
@@ -1085,5 +1202,302 @@ fn preflight_config_base_differs_from_project_root() {
     let errors = result.unwrap_err();
     assert!(errors
         .iter()
-        .any(|e| e.condition.contains("Workflow file not found")));
+        .any(|e| e.condition.contains("workflow not found")));
+}
+
+// --- Dependency graph lint ---
+
+fn item_with_deps(id: &str, status: ItemStatus, deps: &[&str]) -> PgItem {
+    pg_item::new_from_parts(
+        id.to_string(),
+        format!("Test item {}", id),
+        status,
+        deps.iter().map(|d| d.to_string()).collect(),
+        vec![],
+    )
+}
+
+/// A linear chain WRK-000 <- WRK-001 <- ... <- WRK-{len-1}.
+fn chain(len: usize) -> Vec<PgItem> {
+    (0..len)
+        .map(|i| {
+            let id = format!("WRK-{:03}", i);
+            if i == 0 {
+                item_with_deps(&id, ItemStatus::Ready, &[])
+            } else {
+                let dep = format!("WRK-{:03}", i - 1);
+                item_with_deps(&id, ItemStatus::Ready, &[dep.as_str()])
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn lint_finds_cycle() {
+    let items = vec![
+        item_with_deps("WRK-001", ItemStatus::Ready, &["WRK-002"]),
+        item_with_deps("WRK-002", ItemStatus::Ready, &["WRK-001"]),
+        item_with_deps("WRK-003", ItemStatus::Ready, &[]),
+    ];
+
+    let cycles = find_dependency_cycles(&items);
+    assert_eq!(cycles.len(), 1);
+    assert_eq!(cycles[0].first(), cycles[0].last());
+    assert!(cycles[0].contains(&"WRK-001".to_string()));
+    assert!(cycles[0].contains(&"WRK-002".to_string()));
+}
+
+#[test]
+fn lint_ignores_cycle_through_done_item() {
+    let items = vec![
+        item_with_deps("WRK-001", ItemStatus::Ready, &["WRK-002"]),
+        item_with_deps("WRK-002", ItemStatus::Done, &["WRK-001"]),
+    ];
+
+    assert!(find_dependency_cycles(&items).is_empty());
+}
+
+#[test]
+fn lint_finds_unknown_dependencies() {
+    let items = vec![
+        item_with_deps("WRK-001", ItemStatus::Ready, &["WRK-999", "WRK-002"]),
+        item_with_deps("WRK-002", ItemStatus::New, &[]),
+    ];
+
+    assert_eq!(
+        find_unknown_dependencies(&items),
+        vec![("WRK-001".to_string(), "WRK-999".to_string())]
+    );
+}
+
+#[test]
+fn lint_finds_done_dependencies() {
+    let items = vec![
+        item_with_deps("WRK-001", ItemStatus::Done, &[]),
+        item_with_deps("WRK-002", ItemStatus::Ready, &["WRK-001"]),
+        // Done items depending on Done items are not worth reporting
+        item_with_deps("WRK-003", ItemStatus::Done, &["WRK-001"]),
+    ];
+
+    assert_eq!(
+        find_done_dependencies(&items),
+        vec![("WRK-002".to_string(), "WRK-001".to_string())]
+    );
+}
+
+#[test]
+fn lint_dependency_depths_follow_longest_chain() {
+    let items = chain(4);
+    let depths = dependency_depths(&items);
+
+    assert_eq!(depths["WRK-000"], 0);
+    assert_eq!(depths["WRK-003"], 3);
+}
+
+#[test]
+fn lint_dependency_depths_terminate_on_cycle() {
+    let items = vec![
+        item_with_deps("WRK-001", ItemStatus::Ready, &["WRK-002"]),
+        item_with_deps("WRK-002", ItemStatus::Ready, &["WRK-001"]),
+    ];
+
+    let depths = dependency_depths(&items);
+    assert_eq!(depths.len(), 2);
+}
+
+#[test]
+fn lint_finds_deep_chains_over_threshold() {
+    let items = chain(5);
+
+    let deep = find_deep_chains(&items, 2);
+    assert_eq!(
+        deep,
+        vec![("WRK-004".to_string(), 4), ("WRK-003".to_string(), 3)]
+    );
+    assert!(find_deep_chains(&items, 4).is_empty());
+}
+
+#[test]
+fn lint_dependency_graph_classifies_hard_problems() {
+    let clean = chain(2);
+    let lint = lint_dependency_graph(&clean, 5);
+    assert!(lint.is_clean());
+
+    let informational = vec![
+        item_with_deps("WRK-001", ItemStatus::Done, &[]),
+        item_with_deps("WRK-002", ItemStatus::Ready, &["WRK-001"]),
+    ];
+    let lint = lint_dependency_graph(&informational, 5);
+    assert!(!lint.is_clean());
+    assert!(!lint.has_hard_problems());
+
+    let broken = vec![item_with_deps("WRK-001", ItemStatus::Ready, &["WRK-404"])];
+    assert!(lint_dependency_graph(&broken, 5).has_hard_problems());
+}
+
+// --- check_config ---
+
+#[test]
+fn check_config_passes_without_task_golem_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("workflows")).unwrap();
+    std::fs::write(dir.path().join("workflows/build.md"), "# Build\n").unwrap();
+
+    let mut config = default_config();
+    config.pipelines.insert(
+        "test".to_string(),
+        PipelineConfig {
+            pre_phases: vec![],
+            phases: vec![PhaseConfig {
+                workflows: vec!["workflows/build.md".to_string()],
+                ..PhaseConfig::new("build", false)
+            }],
+            max_wip: None,
+            max_concurrent: None,
+        },
+    );
+
+    assert!(check_config(&config, dir.path()).is_ok());
+}
+
+#[test]
+fn check_config_reports_structure_and_workflow_problems_together() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let mut config = default_config();
+    config.execution.max_wip = 0;
+    config.pipelines.insert(
+        "test".to_string(),
+        PipelineConfig {
+            pre_phases: vec![],
+            phases: vec![PhaseConfig {
+                workflows: vec!["workflows/nonexistent.md".to_string()],
+                ..PhaseConfig::new("build", false)
+            }],
+            max_wip: None,
+            max_concurrent: None,
+        },
+    );
+
+    let errors = check_config(&config, dir.path()).unwrap_err();
+    assert!(errors.iter().any(|e| e.condition.contains("max_wip")));
+    assert!(errors
+        .iter()
+        .any(|e| e.condition.contains("workflow not found")));
+}
+
+#[test]
+fn check_config_resolves_workflows_from_config_base() {
+    let project = tempfile::tempdir().unwrap();
+    let config_base = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(config_base.path().join("workflows")).unwrap();
+    std::fs::write(config_base.path().join("workflows/build.md"), "# Build\n").unwrap();
+
+    let mut config = default_config();
+    config.pipelines.insert(
+        "test".to_string(),
+        PipelineConfig {
+            pre_phases: vec![],
+            phases: vec![PhaseConfig {
+                workflows: vec!["workflows/build.md".to_string()],
+                ..PhaseConfig::new("build", false)
+            }],
+            max_wip: None,
+            max_concurrent: None,
+        },
+    );
+
+    assert!(check_config(&config, config_base.path()).is_ok());
+    assert!(check_config(&config, project.path()).is_err());
+}
+
+// --- Interrupted changes/ output ---
+
+/// A git repo whose `changes/` holds output from WRK-001 (in progress) and
+/// WRK-002 (done): an untracked change directory, a staged loose file, and an
+/// untracked file for the done item.
+fn dirty_changes_repo() -> tempfile::TempDir {
+    let dir = common::setup_test_env();
+    let changes = dir.path().join("changes");
+    std::fs::create_dir_all(changes.join("WRK-001_add-login")).unwrap();
+    std::fs::write(changes.join("WRK-001_add-login/spec.md"), "# Spec\n").unwrap();
+    std::fs::write(changes.join("WRK-001.md"), "# Notes\n").unwrap();
+    std::fs::write(changes.join("WRK-002_done.md"), "# Done\n").unwrap();
+    std::process::Command::new("git")
+        .args(["add", "changes/WRK-001.md"])
+        .current_dir(dir.path())
+        .output()
+        .expect("stage loose change file");
+    dir
+}
+
+#[test]
+fn find_interrupted_changes_matches_only_in_progress_items() {
+    let dir = dirty_changes_repo();
+    let items = vec![
+        common::make_in_progress_pg_item("WRK-001", "build"),
+        common::make_pg_item("WRK-002", ItemStatus::Done),
+        common::make_in_progress_pg_item("WRK-010", "build"),
+    ];
+
+    let status = phase_golem::git::get_status(Some(dir.path())).unwrap();
+    let mut leftovers = find_interrupted_changes(&status, &items, dir.path());
+    leftovers.sort();
+
+    assert_eq!(
+        leftovers,
+        vec![
+            ("WRK-001".to_string(), "changes/WRK-001.md".to_string()),
+            (
+                "WRK-001".to_string(),
+                "changes/WRK-001_add-login/".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn find_interrupted_changes_ignores_clean_changes_dir() {
+    let dir = common::setup_test_env();
+    let items = vec![common::make_in_progress_pg_item("WRK-001", "build")];
+
+    let status = phase_golem::git::get_status(Some(dir.path())).unwrap();
+
+    assert!(find_interrupted_changes(&status, &items, dir.path()).is_empty());
+}
+
+#[test]
+fn find_interrupted_changes_lists_wholly_untracked_changes_dir() {
+    let dir = common::setup_test_env();
+    let changes = dir.path().join("changes");
+    std::fs::create_dir_all(changes.join("WRK-001_add-login")).unwrap();
+    std::fs::write(changes.join("WRK-001_add-login/spec.md"), "# Spec\n").unwrap();
+    let items = vec![common::make_in_progress_pg_item("WRK-001", "build")];
+
+    let status = phase_golem::git::get_status(Some(dir.path())).unwrap();
+    assert!(status.iter().any(|entry| entry.path == "changes/"));
+
+    assert_eq!(
+        find_interrupted_changes(&status, &items, dir.path()),
+        vec![(
+            "WRK-001".to_string(),
+            "changes/WRK-001_add-login/".to_string()
+        )]
+    );
+}
+
+#[test]
+fn stash_paths_clears_interrupted_changes_and_keeps_other_files() {
+    let dir = dirty_changes_repo();
+    let items = vec![common::make_in_progress_pg_item("WRK-001", "build")];
+    let status = phase_golem::git::get_status(Some(dir.path())).unwrap();
+    let leftovers = find_interrupted_changes(&status, &items, dir.path());
+    let paths: Vec<&str> = leftovers.iter().map(|(_, path)| path.as_str()).collect();
+
+    phase_golem::git::stash_paths(&paths, "interrupted run", Some(dir.path())).unwrap();
+
+    let status = phase_golem::git::get_status(Some(dir.path())).unwrap();
+    assert!(find_interrupted_changes(&status, &items, dir.path()).is_empty());
+    assert!(!dir.path().join("changes/WRK-001_add-login").exists());
+    assert!(dir.path().join("changes/WRK-002_done.md").exists());
 }
@@ -972,6 +972,8 @@ fn triage_prompt_with_multiple_pipelines_lists_all() {
                 workflows: vec!["writing/draft".to_string()],
                 ..PhaseConfig::new("draft", false)
             }],
+            max_wip: None,
+            max_concurrent: None,
         },
     );
 
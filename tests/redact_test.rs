@@ -0,0 +1,85 @@
+use phase_golem::config::SecurityConfig;
+use phase_golem::redact::{redact, redact_text, REDACTED};
+
+fn default_keys() -> Vec<String> {
+    SecurityConfig::default().redact_keys
+}
+
+#[test]
+fn redact_masks_configured_keys_in_nested_report() {
+    let mut report = serde_json::json!({
+        "halt_reason": "AllDone",
+        "config": {
+            "notifications": {
+                "webhook_url": "https://hooks.example.com/T000/B000/XXXX",
+                "events": ["run_finished"],
+            },
+            "agents": [{ "name": "claude", "API_KEY": "sk-123" }],
+        },
+    });
+
+    redact(&mut report, &default_keys());
+    let serialized = report.to_string();
+
+    assert!(!serialized.contains("hooks.example.com"), "{}", serialized);
+    assert!(!serialized.contains("sk-123"), "{}", serialized);
+    assert_eq!(report["config"]["notifications"]["webhook_url"], REDACTED);
+    assert_eq!(report["config"]["agents"][0]["API_KEY"], REDACTED);
+}
+
+#[test]
+fn redact_leaves_non_sensitive_fields_untouched() {
+    let original = serde_json::json!({
+        "halt_reason": "AllDone",
+        "items_completed": ["WRK-001"],
+        "phases_executed": 3,
+        "config": { "remote": "origin", "token_budget": 100 },
+    });
+    let mut report = original.clone();
+
+    redact(&mut report, &default_keys());
+
+    assert_eq!(report, original);
+}
+
+#[test]
+fn redact_text_masks_key_value_pairs_in_free_text() {
+    let text =
+        "Push rejected (GITHUB_TOKEN=ghp_abc123); retry with api_key: \"sk-456\", token_budget=100";
+
+    let masked = redact_text(text, &default_keys());
+
+    assert_eq!(
+        masked,
+        format!(
+            "Push rejected (GITHUB_TOKEN={}); retry with api_key: \"{}\", token_budget=100",
+            REDACTED, REDACTED
+        )
+    );
+}
+
+#[test]
+fn redact_masks_pairs_inside_string_values() {
+    let mut event = serde_json::json!({
+        "event": "item_blocked",
+        "data": { "item_id": "WRK-001", "reason": "Auth failed: password=hunter2" },
+    });
+
+    redact(&mut event, &default_keys());
+
+    assert_eq!(
+        event["data"]["reason"],
+        format!("Auth failed: password={}", REDACTED)
+    );
+    assert_eq!(event["data"]["item_id"], "WRK-001");
+}
+
+#[test]
+fn redact_with_no_keys_is_a_no_op() {
+    let original = serde_json::json!({ "token": "abc" });
+    let mut report = original.clone();
+
+    redact(&mut report, &[]);
+
+    assert_eq!(report, original);
+}
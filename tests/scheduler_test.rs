@@ -1,26 +1,28 @@
 mod common;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::process::Command;
 use std::sync::Arc;
 
 use task_golem::model::item::Item;
 
-use phase_golem::agent::MockAgentRunner;
+use phase_golem::agent::{AgentRunner, MockAgentRunner};
 use phase_golem::config::{
-    default_feature_pipeline, ExecutionConfig, PhaseConfig, PhaseGolemConfig, PipelineConfig,
+    default_feature_pipeline, ExecutionConfig, GuardrailViolationPolicy, Isolation, PhaseConfig,
+    PhaseGolemConfig, PipelineConfig, PrePhaseBlockPolicy, WorklogFormat,
 };
 use phase_golem::coordinator;
 use phase_golem::filter;
 use phase_golem::pg_item::{self, PgItem};
 use phase_golem::scheduler::{
-    self, advance_to_next_active_target, select_actions, select_targeted_actions,
-    unmet_dep_summary, HaltReason, RunParams, RunningTasks,
+    self, advance_to_next_active_target, classify_idle_halt, effective_target_id, limit_actions,
+    next_runnable_dependency, select_actions, select_targeted_actions, unmet_dep_summary,
+    HaltReason, RunParams, RunningTasks, SchedulerEvent,
 };
 use phase_golem::types::{
-    DimensionLevel, FollowUp, ItemStatus, PhasePool, PhaseResult, ResultCode, SchedulerAction,
-    SizeLevel, StructuredDescription, UpdatedAssessments,
+    DimensionLevel, FollowUp, ItemStatus, ItemUpdate, PhasePool, PhaseResult, ResultCode,
+    SchedulerAction, SizeLevel, StructuredDescription, UpdatedAssessments,
 };
 
 // --- Test helpers ---
@@ -65,9 +67,28 @@ fn default_execution_config() -> ExecutionConfig {
     ExecutionConfig {
         phase_timeout_minutes: 30,
         max_retries: 1,
+        retry_backoff_seconds: 0,
+        retry_backoff_max_seconds: 300,
         default_phase_cap: 100,
         max_wip: 2,
         max_concurrent: 3,
+        worklog_include_full_result: false,
+        worklog_format: WorklogFormat::Markdown,
+        max_actions_per_iteration: None,
+        idle_poll_ms: 10,
+        dedup_follow_ups: true,
+        follow_up_similarity: 1.0,
+        isolation: Isolation::None,
+        triage_failure_threshold: 3,
+        branch_per_item: false,
+        on_prephase_block: PrePhaseBlockPolicy::Block,
+        guard_external_dirty: false,
+        circuit_breaker_threshold: 2,
+        event_log: false,
+        deadline_window_days: 3,
+        metrics_file: false,
+        archive_on_done: true,
+        post_phase_hook: None,
     }
 }
 
@@ -87,6 +108,8 @@ fn simple_pipeline() -> HashMap<String, PipelineConfig> {
                 PhaseConfig::new("build", true),
                 PhaseConfig::new("review", false),
             ],
+            max_wip: None,
+            max_concurrent: None,
         },
     );
     map
@@ -106,6 +129,7 @@ fn phase_complete_result(item_id: &str, phase: &str) -> PhaseResult {
         commit_summary: None,
         duplicates: Vec::new(),
         description: None,
+        confidence: None,
     }
 }
 
@@ -123,6 +147,7 @@ fn failed_result(item_id: &str, phase: &str) -> PhaseResult {
         commit_summary: None,
         duplicates: Vec::new(),
         description: None,
+        confidence: None,
     }
 }
 
@@ -140,6 +165,7 @@ fn blocked_result(item_id: &str, phase: &str) -> PhaseResult {
         commit_summary: None,
         duplicates: Vec::new(),
         description: None,
+        confidence: None,
     }
 }
 
@@ -157,6 +183,7 @@ fn subphase_complete_result(item_id: &str, phase: &str) -> PhaseResult {
         commit_summary: None,
         duplicates: Vec::new(),
         description: None,
+        confidence: None,
     }
 }
 
@@ -179,6 +206,7 @@ fn triage_result_with_assessments(item_id: &str) -> PhaseResult {
         commit_summary: None,
         duplicates: Vec::new(),
         description: None,
+        confidence: None,
     }
 }
 
@@ -190,6 +218,16 @@ fn run_params(root: &Path, target: Option<&str>, cap: u32) -> RunParams {
         root: root.to_path_buf(),
         config_base: root.to_path_buf(),
         auto_advance: false,
+        dry_run: false,
+        wait_deps: false,
+        max_dep_depth: None,
+        resume: false,
+        events: None,
+        max_duration: None,
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
     }
 }
 
@@ -493,6 +531,116 @@ fn select_actions_respects_max_concurrent() {
     assert_eq!(executor_actions.len(), 1);
 }
 
+/// `feature` (default phases) and `docs` pipelines, with per-pipeline limits on `feature`.
+fn feature_and_docs_pipelines(
+    feature_max_wip: Option<u32>,
+    feature_max_concurrent: Option<u32>,
+) -> HashMap<String, PipelineConfig> {
+    let mut map = default_pipelines();
+    let feature = map.get_mut("feature").unwrap();
+    feature.max_wip = feature_max_wip;
+    feature.max_concurrent = feature_max_concurrent;
+    map.insert(
+        "docs".to_string(),
+        PipelineConfig {
+            pre_phases: vec![],
+            phases: vec![
+                PhaseConfig::new("draft", false),
+                PhaseConfig::new("edit", false),
+            ],
+            max_wip: None,
+            max_concurrent: None,
+        },
+    );
+    map
+}
+
+fn with_pipeline_type(mut item: PgItem, pipeline_type: &str) -> PgItem {
+    pg_item::set_pipeline_type(&mut item.0, Some(pipeline_type));
+    item
+}
+
+fn scheduled_ids(actions: &[SchedulerAction]) -> Vec<&str> {
+    actions.iter().map(action_item_id).collect()
+}
+
+#[test]
+fn select_actions_pipeline_max_wip_saturated_promotes_other_pipeline() {
+    let snapshot = vec![
+        with_pipeline_type(
+            make_item("WRK-001", "Feature A", ItemStatus::InProgress),
+            "feature",
+        ),
+        with_pipeline_type(make_ready_item("WRK-002", "Feature B", None), "feature"),
+        with_pipeline_type(make_ready_item("WRK-003", "Docs A", None), "docs"),
+    ];
+    let running = RunningTasks::new();
+    let config = ExecutionConfig {
+        max_wip: 5,
+        ..default_execution_config()
+    };
+    let pipelines = feature_and_docs_pipelines(Some(1), None);
+
+    let actions = select_actions(&snapshot, &running, &config, &pipelines);
+
+    let promoted: Vec<&str> = actions
+        .iter()
+        .filter_map(|a| match a {
+            SchedulerAction::Promote(id) => Some(id.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(promoted, vec!["WRK-003"]);
+}
+
+#[test]
+fn select_actions_pipeline_max_concurrent_saturated_schedules_other_pipeline() {
+    let snapshot = vec![
+        with_pipeline_type(
+            make_in_progress_item("WRK-001", "Feature A", "prd"),
+            "feature",
+        ),
+        with_pipeline_type(
+            make_in_progress_item("WRK-002", "Feature B", "spec"),
+            "feature",
+        ),
+        with_pipeline_type(make_in_progress_item("WRK-003", "Docs A", "draft"), "docs"),
+    ];
+
+    let mut running = RunningTasks::new();
+    running.insert_non_destructive("WRK-001", "prd");
+    let config = ExecutionConfig {
+        max_wip: 5,
+        max_concurrent: 5,
+        ..default_execution_config()
+    };
+    let pipelines = feature_and_docs_pipelines(None, Some(1));
+
+    let actions = select_actions(&snapshot, &running, &config, &pipelines);
+
+    assert_eq!(scheduled_ids(&actions), vec!["WRK-003"]);
+}
+
+#[test]
+fn select_actions_pipeline_limits_capped_by_global() {
+    let mut pipelines = feature_and_docs_pipelines(None, None);
+    pipelines.get_mut("docs").unwrap().max_concurrent = Some(10);
+    let snapshot: Vec<PgItem> = ["WRK-001", "WRK-002", "WRK-003"]
+        .iter()
+        .map(|id| with_pipeline_type(make_in_progress_item(id, "Docs", "draft"), "docs"))
+        .collect();
+    let running = RunningTasks::new();
+    let config = ExecutionConfig {
+        max_wip: 5,
+        max_concurrent: 2,
+        ..default_execution_config()
+    };
+
+    let actions = select_actions(&snapshot, &running, &config, &pipelines);
+
+    assert_eq!(actions.len(), 2);
+}
+
 #[test]
 fn select_actions_skips_already_running_items() {
     let snapshot = vec![
@@ -570,6 +718,154 @@ fn select_actions_promotion_tiebreaks_by_impact() {
     assert_eq!(promotions[2], "WRK-001"); // Low
 }
 
+#[test]
+fn select_actions_promotes_diamond_dependency_before_higher_impact_dependents() {
+    // WRK-001 depends on WRK-002 and WRK-003, which both depend on WRK-004.
+    let mut top = make_ready_item("WRK-001", "Top", Some(DimensionLevel::High));
+    top.0.dependencies = vec!["WRK-002".to_string(), "WRK-003".to_string()];
+    let mut left = make_ready_item("WRK-002", "Left", Some(DimensionLevel::High));
+    left.0.dependencies = vec!["WRK-004".to_string()];
+    let mut right = make_ready_item("WRK-003", "Right", Some(DimensionLevel::High));
+    right.0.dependencies = vec!["WRK-004".to_string()];
+    let bottom = make_ready_item("WRK-004", "Bottom", Some(DimensionLevel::Low));
+    let snapshot = vec![top, left, right, bottom];
+    let running = RunningTasks::new();
+    let config = ExecutionConfig {
+        max_wip: 1,
+        ..default_execution_config()
+    };
+    let pipelines = default_pipelines();
+
+    let actions = select_actions(&snapshot, &running, &config, &pipelines);
+
+    let promotions: Vec<String> = actions
+        .iter()
+        .filter_map(|a| match a {
+            SchedulerAction::Promote(id) => Some(id.clone()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(promotions, vec!["WRK-004"]);
+}
+
+#[test]
+fn promotable_item_ids_matches_select_actions_promotions() {
+    let mut waiting = make_ready_item("WRK-003", "Waiting", Some(DimensionLevel::High));
+    waiting.0.dependencies = vec!["WRK-005".to_string()];
+    let snapshot = vec![
+        make_in_progress_item("WRK-001", "Running", "build"),
+        make_ready_item("WRK-002", "Low impact", Some(DimensionLevel::Low)),
+        waiting,
+        make_ready_item("WRK-004", "High impact", Some(DimensionLevel::High)),
+        make_ready_item("WRK-005", "Medium impact", Some(DimensionLevel::Medium)),
+    ];
+    let config = ExecutionConfig {
+        max_wip: 3,
+        ..default_execution_config()
+    };
+    let pipelines = default_pipelines();
+
+    let expected: Vec<String> =
+        select_actions(&snapshot, &RunningTasks::new(), &config, &pipelines)
+            .into_iter()
+            .filter_map(|a| match a {
+                SchedulerAction::Promote(id) => Some(id),
+                _ => None,
+            })
+            .collect();
+
+    let promotable = scheduler::promotable_item_ids(&snapshot, &config, &pipelines);
+
+    assert_eq!(promotable, expected);
+    assert_eq!(promotable, vec!["WRK-004", "WRK-005"]);
+}
+
+// --- limit_actions ---
+
+fn many_ready_and_new_items(count: usize) -> Vec<PgItem> {
+    let mut items = Vec::new();
+    for i in 0..count {
+        items.push(make_ready_item(
+            &format!("WRK-{:03}", i),
+            "Ready task",
+            Some(DimensionLevel::Medium),
+        ));
+        items.push(make_item(
+            &format!("WRK-{:03}", count + i),
+            "New task",
+            ItemStatus::New,
+        ));
+    }
+    items
+}
+
+fn action_item_id(action: &SchedulerAction) -> &str {
+    match action {
+        SchedulerAction::Promote(id) | SchedulerAction::Triage(id) => id,
+        SchedulerAction::RunPhase { item_id, .. } => item_id,
+    }
+}
+
+#[test]
+fn limit_actions_caps_iteration_with_many_actionable_items() {
+    let snapshot = many_ready_and_new_items(10);
+    let running = RunningTasks::new();
+    let config = ExecutionConfig {
+        max_wip: 10,
+        max_concurrent: 10,
+        max_actions_per_iteration: Some(3),
+        ..default_execution_config()
+    };
+    let pipelines = default_pipelines();
+
+    let actions = select_actions(&snapshot, &running, &config, &pipelines);
+    assert!(actions.len() > 3);
+
+    let limited = limit_actions(actions, config.max_actions_per_iteration, 0);
+    assert_eq!(limited.len(), 3);
+}
+
+#[test]
+fn limit_actions_unlimited_by_default() {
+    let snapshot = many_ready_and_new_items(5);
+    let running = RunningTasks::new();
+    let config = ExecutionConfig {
+        max_wip: 5,
+        max_concurrent: 5,
+        ..default_execution_config()
+    };
+    let pipelines = default_pipelines();
+
+    let actions = select_actions(&snapshot, &running, &config, &pipelines);
+    let expected = actions.clone();
+
+    assert_eq!(limit_actions(actions, None, 7), expected);
+}
+
+#[test]
+fn limit_actions_rotation_varies_kept_items() {
+    let actions: Vec<SchedulerAction> = (0..5)
+        .map(|i| SchedulerAction::Triage(format!("WRK-{:03}", i)))
+        .collect();
+
+    let first: Vec<String> = limit_actions(actions.clone(), Some(2), 0)
+        .iter()
+        .map(|a| action_item_id(a).to_string())
+        .collect();
+    let second: Vec<String> = limit_actions(actions.clone(), Some(2), 2)
+        .iter()
+        .map(|a| action_item_id(a).to_string())
+        .collect();
+    let wrapped: Vec<String> = limit_actions(actions, Some(2), 9)
+        .iter()
+        .map(|a| action_item_id(a).to_string())
+        .collect();
+
+    assert_eq!(first, vec!["WRK-000", "WRK-001"]);
+    assert_eq!(second, vec!["WRK-002", "WRK-003"]);
+    assert_eq!(wrapped, vec!["WRK-004", "WRK-000"]);
+}
+
 #[test]
 fn select_actions_no_destructive_when_non_destructive_running() {
     // build (destructive) should NOT be scheduled if non-destructive tasks are already running
@@ -646,18 +942,22 @@ async fn scheduler_happy_path_single_item_all_phases() {
 
     assert_eq!(summary.items_completed, vec!["WRK-001"]);
     assert!(summary.items_blocked.is_empty());
-    assert_eq!(summary.halt_reason, HaltReason::AllDoneOrBlocked);
+    assert_eq!(summary.halt_reason, HaltReason::AllDone);
 }
 
 #[tokio::test]
-async fn scheduler_blocked_result_blocks_item() {
-    let item = make_in_progress_item("WRK-001", "Feature", "build");
+async fn scheduler_keeps_done_items_active_when_archive_on_done_is_false() {
+    let item = make_in_progress_item("WRK-001", "Test feature", "build");
     let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
 
-    let runner = MockAgentRunner::new(vec![Ok(blocked_result("WRK-001", "build"))]);
+    let runner = MockAgentRunner::new(vec![
+        Ok(phase_complete_result("WRK-001", "build")),
+        Ok(phase_complete_result("WRK-001", "review")),
+    ]);
 
     let mut config = default_config();
     config.pipelines = simple_pipeline();
+    config.execution.archive_on_done = false;
 
     let cancel = tokio_util::sync::CancellationToken::new();
     let params = run_params(dir.path(), None, 100);
@@ -667,115 +967,251 @@ async fn scheduler_blocked_result_blocks_item() {
             .await
             .expect("Scheduler should succeed");
 
-    assert!(summary.items_completed.is_empty());
-    assert_eq!(summary.items_blocked, vec!["WRK-001"]);
-    assert_eq!(summary.halt_reason, HaltReason::AllDoneOrBlocked);
+    assert_eq!(summary.items_completed, vec!["WRK-001"]);
+    assert_eq!(summary.halt_reason, HaltReason::AllDone);
+
+    let active = task_golem::store::Store::new(dir.path().join(".task-golem"))
+        .load_active()
+        .expect("load active store");
+    let item = active
+        .into_iter()
+        .map(PgItem)
+        .find(|i| i.id() == "WRK-001")
+        .expect("completed item should stay in the active store");
+    assert_eq!(item.pg_status(), ItemStatus::Done);
 }
 
 #[tokio::test]
-async fn scheduler_retry_then_success() {
-    let item = make_in_progress_item("WRK-001", "Feature", "build");
+async fn scheduler_emits_events_for_happy_path() {
+    let item = make_in_progress_item("WRK-001", "Test feature", "build");
     let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
 
-    // First attempt fails, second succeeds (within executor retry)
-    // max_retries=1 means 2 attempts total
     let runner = MockAgentRunner::new(vec![
-        Ok(failed_result("WRK-001", "build")),
         Ok(phase_complete_result("WRK-001", "build")),
         Ok(phase_complete_result("WRK-001", "review")),
     ]);
 
     let mut config = default_config();
     config.pipelines = simple_pipeline();
-    config.execution.max_retries = 1;
 
+    let (tx, mut rx) = tokio::sync::mpsc::channel(64);
     let cancel = tokio_util::sync::CancellationToken::new();
-    let params = run_params(dir.path(), None, 100);
+    let params = RunParams {
+        events: Some(tx),
+        ..run_params(dir.path(), None, 100)
+    };
 
-    let summary =
-        scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
-            .await
-            .expect("Scheduler should succeed");
+    scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
+        .await
+        .expect("Scheduler should succeed");
 
-    assert_eq!(summary.items_completed, vec!["WRK-001"]);
+    let mut events = Vec::new();
+    while let Ok(event) = rx.try_recv() {
+        events.push(event);
+    }
+
+    let started = |phase: &str| SchedulerEvent::PhaseStarted {
+        item_id: "WRK-001".to_string(),
+        phase: phase.to_string(),
+    };
+    let completed = |phase: &str| SchedulerEvent::PhaseCompleted {
+        item_id: "WRK-001".to_string(),
+        phase: phase.to_string(),
+    };
+    assert_eq!(
+        events,
+        vec![
+            started("build"),
+            completed("build"),
+            started("review"),
+            completed("review"),
+            SchedulerEvent::ItemCompleted {
+                item_id: "WRK-001".to_string()
+            },
+            SchedulerEvent::Halted(HaltReason::AllDone),
+        ]
+    );
 }
 
 #[tokio::test]
-async fn scheduler_retry_exhaustion_blocks_item() {
-    let item = make_in_progress_item("WRK-001", "Feature", "build");
+async fn scheduler_halted_event_waits_for_a_full_channel() {
+    let item = make_in_progress_item("WRK-001", "Test feature", "build");
     let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
 
-    // Two consecutive failures exhausts retries (max_retries=1)
     let runner = MockAgentRunner::new(vec![
-        Ok(failed_result("WRK-001", "build")),
-        Ok(failed_result("WRK-001", "build")),
+        Ok(phase_complete_result("WRK-001", "build")),
+        Ok(phase_complete_result("WRK-001", "review")),
     ]);
 
     let mut config = default_config();
     config.pipelines = simple_pipeline();
-    config.execution.max_retries = 1;
 
+    // Room for one event, and nobody receiving until the run is underway
+    let (tx, mut rx) = tokio::sync::mpsc::channel(1);
     let cancel = tokio_util::sync::CancellationToken::new();
-    let params = run_params(dir.path(), None, 100);
+    let params = RunParams {
+        events: Some(tx),
+        ..run_params(dir.path(), None, 100)
+    };
 
-    let summary =
-        scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
-            .await
-            .expect("Scheduler should succeed");
+    let listener = async {
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        let mut events = Vec::new();
+        while let Some(event) = rx.recv().await {
+            events.push(event);
+        }
+        events
+    };
+    let (summary, events) = tokio::join!(
+        scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel),
+        listener
+    );
 
-    assert!(summary.items_completed.is_empty());
-    assert_eq!(summary.items_blocked, vec!["WRK-001"]);
+    assert_eq!(
+        summary.expect("Scheduler should succeed").halt_reason,
+        HaltReason::AllDone
+    );
+    assert_eq!(
+        events.last(),
+        Some(&SchedulerEvent::Halted(HaltReason::AllDone)),
+        "{:?}",
+        events
+    );
 }
 
 #[tokio::test]
-async fn scheduler_cap_limits_phase_execution() {
-    let item = make_in_progress_item("WRK-001", "Feature", "build");
+async fn scheduler_event_log_records_one_json_object_per_line() {
+    let item = make_in_progress_item("WRK-001", "Test feature", "build");
     let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
 
     let runner = MockAgentRunner::new(vec![
         Ok(phase_complete_result("WRK-001", "build")),
-        // review would need another result, but cap=1 stops after build
+        Ok(phase_complete_result("WRK-001", "review")),
     ]);
 
     let mut config = default_config();
     config.pipelines = simple_pipeline();
 
+    let log_path = dir.path().join("events.jsonl");
     let cancel = tokio_util::sync::CancellationToken::new();
-    let params = run_params(dir.path(), None, 1); // cap=1
+    let params = RunParams {
+        event_log: Some(log_path.clone()),
+        ..run_params(dir.path(), None, 100)
+    };
 
-    let summary =
-        scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
-            .await
-            .expect("Scheduler should succeed");
+    scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
+        .await
+        .expect("Scheduler should succeed");
 
-    assert_eq!(summary.halt_reason, HaltReason::CapReached);
-    assert_eq!(summary.phases_executed, 1);
+    let records: Vec<serde_json::Value> = std::fs::read_to_string(&log_path)
+        .expect("event log written")
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line is a JSON object"))
+        .collect();
+    assert!(records.iter().all(|r| r["timestamp"].is_string()));
+
+    let events: Vec<&str> = records
+        .iter()
+        .map(|r| r["event"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        events,
+        vec![
+            "actions_scheduled",
+            "phase_started",
+            "phase_completed",
+            "actions_scheduled",
+            "phase_started",
+            "phase_completed",
+            "item_completed",
+            "halted",
+        ]
+    );
+    assert_eq!(records[0]["data"]["actions"][0], "WRK-001 → build");
+    assert_eq!(records[1]["data"]["phase"], "build");
+    assert_eq!(records[7]["data"], "AllDone");
 }
 
 #[tokio::test]
-async fn scheduler_no_actionable_items_exits() {
-    let item = make_item("WRK-001", "Done item", ItemStatus::Done);
+async fn scheduler_event_log_and_events_mask_redact_keys() {
+    let item = make_in_progress_item("WRK-001", "Test feature", "build");
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
+
+    let mut blocked = blocked_result("WRK-001", "build");
+    blocked.context = Some("Registry login failed: api_key=sk-live-123".to_string());
+    let runner = MockAgentRunner::new(vec![Ok(blocked)]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+
+    let log_path = dir.path().join("events.jsonl");
+    let trace_path = dir.path().join("trace.jsonl");
+    let (tx, mut rx) = tokio::sync::mpsc::channel(64);
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = RunParams {
+        event_log: Some(log_path.clone()),
+        trace: Some(trace_path.clone()),
+        events: Some(tx),
+        ..run_params(dir.path(), None, 100)
+    };
+
+    scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
+        .await
+        .expect("Scheduler should succeed");
+
+    let log = std::fs::read_to_string(&log_path).unwrap();
+    assert!(log.contains("item_blocked"), "{}", log);
+    assert!(!log.contains("sk-live-123"), "{}", log);
+    assert!(!std::fs::read_to_string(&trace_path)
+        .unwrap()
+        .contains("sk-live-123"));
+
+    let mut reasons = Vec::new();
+    while let Ok(event) = rx.try_recv() {
+        if let SchedulerEvent::ItemBlocked { reason, .. } = event {
+            reasons.push(reason);
+        }
+    }
+    assert!(!reasons.is_empty());
+    assert!(
+        reasons.iter().all(|r| !r.contains("sk-live-123")),
+        "{:?}",
+        reasons
+    );
+}
+
+#[tokio::test]
+async fn scheduler_max_duration_elapsed_halts_without_starting_phases() {
+    let item = make_in_progress_item("WRK-001", "Test feature", "build");
     let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
 
     let runner = MockAgentRunner::new(vec![]);
 
-    let config = default_config();
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
 
     let cancel = tokio_util::sync::CancellationToken::new();
-    let params = run_params(dir.path(), None, 100);
+    let params = RunParams {
+        max_duration: Some(std::time::Duration::ZERO),
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
+        ..run_params(dir.path(), None, 100)
+    };
 
     let summary =
         scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
             .await
             .expect("Scheduler should succeed");
 
-    assert_eq!(summary.halt_reason, HaltReason::AllDoneOrBlocked);
+    assert_eq!(summary.halt_reason, HaltReason::DurationReached);
     assert_eq!(summary.phases_executed, 0);
 }
 
 #[tokio::test]
-async fn scheduler_target_mode_completes_specific_item() {
-    let item = make_in_progress_item("WRK-001", "Feature", "build");
+async fn scheduler_max_duration_beyond_instant_range_means_no_deadline() {
+    let item = make_in_progress_item("WRK-001", "Test feature", "build");
     let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
 
     let runner = MockAgentRunner::new(vec![
@@ -787,15 +1223,815 @@ async fn scheduler_target_mode_completes_specific_item() {
     config.pipelines = simple_pipeline();
 
     let cancel = tokio_util::sync::CancellationToken::new();
-    let params = run_params(dir.path(), Some("WRK-001"), 100);
+    let params = RunParams {
+        max_duration: Some(std::time::Duration::MAX),
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
+        ..run_params(dir.path(), None, 100)
+    };
 
     let summary =
         scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
             .await
             .expect("Scheduler should succeed");
 
-    assert_eq!(summary.items_completed, vec!["WRK-001"]);
-    assert_eq!(summary.halt_reason, HaltReason::TargetCompleted);
+    assert_eq!(summary.halt_reason, HaltReason::AllDone);
+    assert_eq!(summary.phases_executed, 2);
+}
+
+#[tokio::test]
+async fn scheduler_idle_wait_respects_idle_poll_ms() {
+    // A Ready item is promoted on the first pass with nothing else to run,
+    // so the scheduler takes the idle wait before starting its first phase.
+    let item = make_ready_item("WRK-001", "Feature", None);
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
+
+    let runner = MockAgentRunner::new(vec![
+        Ok(phase_complete_result("WRK-001", "build")),
+        Ok(phase_complete_result("WRK-001", "review")),
+    ]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+    config.execution.idle_poll_ms = 300;
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = run_params(dir.path(), None, 100);
+
+    let started = std::time::Instant::now();
+    let summary =
+        scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
+            .await
+            .expect("Scheduler should succeed");
+
+    assert_eq!(summary.items_completed, vec!["WRK-001"]);
+    assert!(
+        started.elapsed() >= std::time::Duration::from_millis(300),
+        "idle wait should last idle_poll_ms, took {:?}",
+        started.elapsed()
+    );
+}
+
+#[tokio::test]
+async fn scheduler_blocked_result_blocks_item() {
+    let item = make_in_progress_item("WRK-001", "Feature", "build");
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
+
+    let runner = MockAgentRunner::new(vec![Ok(blocked_result("WRK-001", "build"))]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = run_params(dir.path(), None, 100);
+
+    let summary =
+        scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
+            .await
+            .expect("Scheduler should succeed");
+
+    assert!(summary.items_completed.is_empty());
+    assert_eq!(summary.items_blocked, vec!["WRK-001"]);
+    assert_eq!(summary.halt_reason, HaltReason::AllBlocked);
+}
+
+/// Agent that completes WRK-001's review based on the HEAD it started from, optionally
+/// rewriting that commit before returning (as a concurrent run might).
+struct HeadMovingRunner {
+    root: std::path::PathBuf,
+    move_head: bool,
+}
+
+impl AgentRunner for HeadMovingRunner {
+    async fn run_agent(
+        &self,
+        _prompt: &str,
+        _result_path: &Path,
+        _timeout: std::time::Duration,
+    ) -> Result<PhaseResult, String> {
+        let git = |args: &[&str]| {
+            let output = Command::new("git")
+                .args(args)
+                .current_dir(&self.root)
+                .output()
+                .map_err(|e| e.to_string())?;
+            Ok::<_, String>(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        };
+        let start_sha = git(&["rev-parse", "HEAD"])?;
+        if self.move_head {
+            git(&["commit", "--amend", "--allow-empty", "-m", "Rewritten"])?;
+        }
+
+        let mut result = phase_complete_result("WRK-001", "review");
+        result.based_on_commit = Some(start_sha);
+        Ok(result)
+    }
+}
+
+async fn run_with_head_moving_runner(move_head: bool) -> (scheduler::RunSummary, Option<PgItem>) {
+    let item = make_in_progress_item("WRK-001", "Feature", "review");
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
+
+    let runner = HeadMovingRunner {
+        root: dir.path().to_path_buf(),
+        move_head,
+    };
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = run_params(dir.path(), None, 100);
+
+    let summary = scheduler::run_scheduler(
+        coordinator_handle.clone(),
+        Arc::new(runner),
+        config,
+        params,
+        cancel,
+    )
+    .await
+    .expect("Scheduler should succeed");
+
+    let snapshot = coordinator_handle.get_snapshot().await.unwrap();
+    let item = snapshot.into_iter().find(|i| i.id() == "WRK-001");
+    (summary, item)
+}
+
+#[tokio::test]
+async fn scheduler_blocks_phase_output_based_on_rewritten_head() {
+    let (summary, item) = run_with_head_moving_runner(true).await;
+
+    assert!(summary.items_completed.is_empty());
+    assert_eq!(summary.items_blocked, vec!["WRK-001"]);
+    let item = item.expect("blocked item stays active");
+    assert_eq!(item.pg_status(), ItemStatus::Blocked);
+    assert!(item
+        .blocked_reason()
+        .is_some_and(|r| r.contains("Stale — rebase/rerun")));
+    assert!(item.completed_phases().is_empty());
+}
+
+#[tokio::test]
+async fn scheduler_accepts_phase_output_based_on_current_head() {
+    let (summary, _) = run_with_head_moving_runner(false).await;
+
+    assert_eq!(summary.items_completed, vec!["WRK-001"]);
+    assert!(summary.items_blocked.is_empty());
+}
+
+/// `build` then a `validate` validation phase.
+fn validated_pipeline() -> HashMap<String, PipelineConfig> {
+    let mut map = HashMap::new();
+    map.insert(
+        "feature".to_string(),
+        PipelineConfig {
+            pre_phases: vec![],
+            phases: vec![
+                PhaseConfig::new("build", true),
+                PhaseConfig {
+                    is_validation: true,
+                    ..PhaseConfig::new("validate", false)
+                },
+            ],
+            max_wip: None,
+            max_concurrent: None,
+        },
+    );
+    map
+}
+
+async fn run_validation_phase(result: PhaseResult) -> (scheduler::RunSummary, Option<PgItem>) {
+    let item = make_in_progress_item("WRK-001", "Feature", "validate");
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
+
+    // A single queued result: a retry would surface as an agent error instead
+    let runner = MockAgentRunner::new(vec![Ok(result)]);
+
+    let mut config = default_config();
+    config.pipelines = validated_pipeline();
+    config.execution.max_retries = 2;
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = run_params(dir.path(), None, 100);
+
+    let summary = scheduler::run_scheduler(
+        coordinator_handle.clone(),
+        Arc::new(runner),
+        config,
+        params,
+        cancel,
+    )
+    .await
+    .expect("Scheduler should succeed");
+
+    let snapshot = coordinator_handle.get_snapshot().await.unwrap();
+    let item = snapshot.into_iter().find(|i| i.id() == "WRK-001");
+    (summary, item)
+}
+
+#[tokio::test]
+async fn scheduler_validation_phase_pass_completes_item() {
+    let (summary, _) = run_validation_phase(phase_complete_result("WRK-001", "validate")).await;
+
+    assert_eq!(summary.items_completed, vec!["WRK-001"]);
+    assert!(summary.items_blocked.is_empty());
+    assert!(summary.items_failed_validation.is_empty());
+}
+
+#[tokio::test]
+async fn scheduler_validation_phase_failure_fails_item_without_retry() {
+    let (summary, item) = run_validation_phase(failed_result("WRK-001", "validate")).await;
+
+    assert!(summary.items_completed.is_empty());
+    assert_eq!(summary.items_blocked, vec!["WRK-001"]);
+    assert_eq!(summary.items_failed_validation, vec!["WRK-001"]);
+    assert_eq!(summary.phases_executed, 1);
+
+    let item = item.expect("failed item stays active");
+    assert_eq!(item.pg_status(), ItemStatus::Blocked);
+    assert!(item.tags().iter().any(|t| t == "validation-failed"));
+    assert!(item
+        .blocked_reason()
+        .is_some_and(|r| r.starts_with("Validation failed in phase validate")));
+    assert!(item.completed_phases().is_empty());
+}
+
+fn research_pipeline() -> HashMap<String, PipelineConfig> {
+    let mut map = simple_pipeline();
+    map.get_mut("feature")
+        .unwrap()
+        .pre_phases
+        .push(PhaseConfig::new("research", false));
+    map
+}
+
+#[tokio::test]
+async fn scheduler_prephase_block_blocks_item_by_default() {
+    let item = make_scoping_item("WRK-001", "Feature", "research");
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
+
+    let runner = MockAgentRunner::new(vec![Ok(blocked_result("WRK-001", "research"))]);
+
+    let mut config = default_config();
+    config.pipelines = research_pipeline();
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = run_params(dir.path(), None, 1);
+
+    let summary = scheduler::run_scheduler(
+        coordinator_handle.clone(),
+        Arc::new(runner),
+        config,
+        params,
+        cancel,
+    )
+    .await
+    .expect("Scheduler should succeed");
+
+    assert_eq!(summary.items_blocked, vec!["WRK-001"]);
+    let snapshot = coordinator_handle.get_snapshot().await.unwrap();
+    let item = snapshot.iter().find(|i| i.id() == "WRK-001").unwrap();
+    assert_eq!(item.pg_status(), ItemStatus::Blocked);
+}
+
+#[tokio::test]
+async fn scheduler_prephase_block_returns_item_to_new_under_policy() {
+    let item = make_scoping_item("WRK-001", "Feature", "research");
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
+
+    let runner = MockAgentRunner::new(vec![Ok(blocked_result("WRK-001", "research"))]);
+
+    let mut config = default_config();
+    config.pipelines = research_pipeline();
+    config.execution.on_prephase_block = PrePhaseBlockPolicy::ReturnToNew;
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = run_params(dir.path(), None, 1);
+
+    let summary = scheduler::run_scheduler(
+        coordinator_handle.clone(),
+        Arc::new(runner),
+        config,
+        params,
+        cancel,
+    )
+    .await
+    .expect("Scheduler should succeed");
+
+    assert!(summary.items_blocked.is_empty());
+    let snapshot = coordinator_handle.get_snapshot().await.unwrap();
+    let item = snapshot.iter().find(|i| i.id() == "WRK-001").unwrap();
+    assert_eq!(item.pg_status(), ItemStatus::New);
+    assert_eq!(item.phase(), None);
+    assert_eq!(item.phase_pool(), None);
+}
+
+/// Runs two independent in-progress targets at their final, non-destructive
+/// phase and returns the emitted events.
+async fn run_two_review_targets(parallel_targets: bool) -> (Vec<SchedulerEvent>, HaltReason) {
+    let first = make_in_progress_item("WRK-001", "First", "review");
+    let second = make_in_progress_item("WRK-002", "Second", "review");
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![first, second]);
+
+    let runner = MockAgentRunner::new(vec![
+        Ok(phase_complete_result("WRK-001", "review")),
+        Ok(phase_complete_result("WRK-002", "review")),
+    ]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+    config.execution = default_execution_config();
+
+    let (tx, mut rx) = tokio::sync::mpsc::channel(64);
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = RunParams {
+        targets: vec!["WRK-001".to_string(), "WRK-002".to_string()],
+        events: Some(tx),
+        parallel_targets,
+        ..run_params(dir.path(), None, 100)
+    };
+
+    let summary =
+        scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
+            .await
+            .expect("Scheduler should succeed");
+
+    let mut events = Vec::new();
+    while let Ok(event) = rx.try_recv() {
+        events.push(event);
+    }
+    (events, summary.halt_reason)
+}
+
+fn started_item(event: &SchedulerEvent) -> Option<&str> {
+    match event {
+        SchedulerEvent::PhaseStarted { item_id, .. } => Some(item_id),
+        _ => None,
+    }
+}
+
+#[tokio::test]
+async fn scheduler_parallel_targets_run_concurrently() {
+    let (events, halt_reason) = run_two_review_targets(true).await;
+
+    // Both phases start before either completes
+    let mut first_two: Vec<&str> = events[..2].iter().filter_map(started_item).collect();
+    first_two.sort();
+    assert_eq!(first_two, vec!["WRK-001", "WRK-002"]);
+    assert_eq!(halt_reason, HaltReason::TargetCompleted);
+}
+
+#[tokio::test]
+async fn scheduler_multiple_targets_run_sequentially_by_default() {
+    let (events, halt_reason) = run_two_review_targets(false).await;
+
+    assert_eq!(started_item(&events[0]), Some("WRK-001"));
+    assert!(matches!(
+        &events[1],
+        SchedulerEvent::PhaseCompleted { item_id, .. } if item_id == "WRK-001"
+    ));
+    assert_eq!(halt_reason, HaltReason::TargetCompleted);
+}
+
+#[test]
+fn select_parallel_target_actions_respects_dependencies_outside_the_set() {
+    let mut dependent = make_in_progress_item("WRK-001", "Dependent", "review");
+    dependent.0.dependencies = vec!["WRK-003".to_string()];
+    let independent = make_in_progress_item("WRK-002", "Independent", "review");
+    let outside = make_in_progress_item("WRK-003", "Outside the set", "build");
+    let items = vec![dependent, independent, outside];
+
+    let actions = scheduler::select_parallel_target_actions(
+        &items,
+        &RunningTasks::new(),
+        &default_execution_config(),
+        &simple_pipeline(),
+        &["WRK-001".to_string(), "WRK-002".to_string()],
+    );
+
+    assert_eq!(scheduled_ids(&actions), vec!["WRK-002"]);
+}
+
+async fn wait_for_item_completed(
+    rx: &mut tokio::sync::mpsc::Receiver<SchedulerEvent>,
+    expected: &str,
+) {
+    while let Some(event) = rx.recv().await {
+        if matches!(&event, SchedulerEvent::ItemCompleted { item_id } if item_id == expected) {
+            return;
+        }
+    }
+    panic!("event channel closed before {} completed", expected);
+}
+
+#[tokio::test]
+async fn scheduler_watch_picks_up_items_added_while_idle() {
+    let first = make_in_progress_item("WRK-001", "First", "review");
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![first]);
+
+    let runner = MockAgentRunner::new(vec![
+        Ok(phase_complete_result("WRK-001", "review")),
+        Ok(phase_complete_result("WRK-002", "review")),
+    ]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+    config.execution = default_execution_config();
+
+    let (tx, mut rx) = tokio::sync::mpsc::channel(64);
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = RunParams {
+        events: Some(tx),
+        watch: Some(std::time::Duration::from_millis(20)),
+        ..run_params(dir.path(), None, 100)
+    };
+
+    let scheduler = tokio::spawn(scheduler::run_scheduler(
+        coordinator_handle,
+        Arc::new(runner),
+        config,
+        params,
+        cancel.clone(),
+    ));
+
+    wait_for_item_completed(&mut rx, "WRK-001").await;
+
+    // Add a second item while the scheduler is idle
+    let store = task_golem::store::Store::new(dir.path().join(".task-golem"));
+    store
+        .with_lock(|s| {
+            let mut items = s.load_active()?;
+            items.push(make_in_progress_item("WRK-002", "Second", "review").0);
+            s.save_active(&items)
+        })
+        .expect("add second item");
+
+    wait_for_item_completed(&mut rx, "WRK-002").await;
+    cancel.cancel();
+
+    let summary = scheduler
+        .await
+        .expect("scheduler task should not panic")
+        .expect("Scheduler should succeed");
+    assert_eq!(summary.halt_reason, HaltReason::ShutdownRequested);
+    assert_eq!(summary.items_completed, vec!["WRK-001", "WRK-002"]);
+}
+
+#[tokio::test]
+async fn scheduler_trace_records_iteration_actions() {
+    let item = make_in_progress_item("WRK-001", "Traced", "review");
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
+
+    let runner = MockAgentRunner::new(vec![Ok(phase_complete_result("WRK-001", "review"))]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+    config.execution = default_execution_config();
+
+    let trace_path = dir.path().join("trace.jsonl");
+    let params = RunParams {
+        trace: Some(trace_path.clone()),
+        ..run_params(dir.path(), None, 100)
+    };
+
+    scheduler::run_scheduler(
+        coordinator_handle,
+        Arc::new(runner),
+        config,
+        params,
+        tokio_util::sync::CancellationToken::new(),
+    )
+    .await
+    .expect("Scheduler should succeed");
+
+    let contents = std::fs::read_to_string(&trace_path).expect("trace file written");
+    let records: Vec<serde_json::Value> = contents
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line is JSON"))
+        .collect();
+
+    let first = &records[0];
+    assert_eq!(first["iteration"], 1);
+    assert_eq!(first["snapshot"]["inprogress"], 1);
+    assert_eq!(first["selected"], serde_json::json!(["WRK-001 → review"]));
+    assert_eq!(first["spawned"], serde_json::json!(["WRK-001 → review"]));
+    assert_eq!(first["skipped"], serde_json::json!([]));
+
+    // The run ends on an idle iteration with nothing selected
+    let last = records.last().unwrap();
+    assert_eq!(last["selected"], serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn scheduler_trace_records_cap_skips() {
+    let first = make_in_progress_item("WRK-001", "First", "review");
+    let second = make_in_progress_item("WRK-002", "Second", "review");
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![first, second]);
+
+    let runner = MockAgentRunner::new(vec![Ok(phase_complete_result("WRK-001", "review"))]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+    config.execution = default_execution_config(); // max_wip 2, max_concurrent 3
+
+    let trace_path = dir.path().join("trace.jsonl");
+    let params = RunParams {
+        trace: Some(trace_path.clone()),
+        ..run_params(dir.path(), None, 1)
+    };
+
+    scheduler::run_scheduler(
+        coordinator_handle,
+        Arc::new(runner),
+        config,
+        params,
+        tokio_util::sync::CancellationToken::new(),
+    )
+    .await
+    .expect("Scheduler should succeed");
+
+    let contents = std::fs::read_to_string(&trace_path).expect("trace file written");
+    let first: serde_json::Value = serde_json::from_str(contents.lines().next().unwrap()).unwrap();
+    assert_eq!(first["spawned"].as_array().unwrap().len(), 1);
+    assert_eq!(first["skipped"][0]["reason"], "phase cap reached");
+}
+
+fn design_then_build_pipeline(skip_if: &str) -> HashMap<String, PipelineConfig> {
+    let mut map = HashMap::new();
+    map.insert(
+        "feature".to_string(),
+        PipelineConfig {
+            pre_phases: vec![],
+            phases: vec![
+                PhaseConfig {
+                    skip_if: Some(skip_if.to_string()),
+                    ..PhaseConfig::new("design", false)
+                },
+                PhaseConfig::new("build", true),
+            ],
+            max_wip: None,
+            max_concurrent: None,
+        },
+    );
+    map
+}
+
+fn read_worklogs(root: &Path) -> String {
+    std::fs::read_dir(root.join("_worklog"))
+        .map(|entries| {
+            entries
+                .filter_map(|entry| std::fs::read_to_string(entry.ok()?.path()).ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[test]
+fn skipped_phase_result_only_for_matching_items() {
+    let phase = PhaseConfig {
+        skip_if: Some("size=small".to_string()),
+        ..PhaseConfig::new("design", false)
+    };
+    let mut small = make_in_progress_item("WRK-001", "Small", "design");
+    pg_item::set_size(&mut small.0, Some(&SizeLevel::Small));
+    let mut large = make_in_progress_item("WRK-002", "Large", "design");
+    pg_item::set_size(&mut large.0, Some(&SizeLevel::Large));
+
+    let skipped = scheduler::skipped_phase_result(&small, &phase).expect("small item skips");
+    assert_eq!(skipped.result, ResultCode::PhaseComplete);
+    assert_eq!(skipped.phase, "design");
+    assert!(scheduler::skipped_phase_result(&large, &phase).is_none());
+    assert!(scheduler::skipped_phase_result(&small, &PhaseConfig::new("design", false)).is_none());
+}
+
+#[tokio::test]
+async fn scheduler_skips_phase_when_skip_if_matches() {
+    let mut item = make_in_progress_item("WRK-001", "Small feature", "design");
+    pg_item::set_size(&mut item.0, Some(&SizeLevel::Small));
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
+
+    // Only build runs an agent; a design run would consume the build result and fail
+    let runner = MockAgentRunner::new(vec![Ok(phase_complete_result("WRK-001", "build"))]);
+
+    let mut config = default_config();
+    config.pipelines = design_then_build_pipeline("size=small");
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = run_params(dir.path(), None, 100);
+
+    let summary =
+        scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
+            .await
+            .expect("Scheduler should succeed");
+
+    assert_eq!(summary.items_completed, vec!["WRK-001"]);
+    assert!(read_worklogs(dir.path()).contains("Skipped: item matches skip_if \"size=small\""));
+}
+
+#[tokio::test]
+async fn scheduler_dry_run_marks_phase_skipped_when_skip_if_matches() {
+    let mut item = make_in_progress_item("WRK-001", "Small feature", "design");
+    pg_item::set_size(&mut item.0, Some(&SizeLevel::Small));
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
+
+    let runner = MockAgentRunner::new(vec![]);
+
+    let mut config = default_config();
+    config.pipelines = design_then_build_pipeline("size=small");
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = dry_run_params(dir.path(), vec![], 100);
+
+    let summary =
+        scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
+            .await
+            .expect("Dry run should succeed");
+
+    assert_eq!(
+        summary.planned_phases,
+        vec![(
+            "WRK-001".to_string(),
+            vec!["design (skipped)".to_string(), "build".to_string()]
+        )]
+    );
+}
+
+#[tokio::test]
+async fn scheduler_runs_phase_when_skip_if_does_not_match() {
+    let mut item = make_in_progress_item("WRK-001", "Large feature", "design");
+    pg_item::set_size(&mut item.0, Some(&SizeLevel::Large));
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
+
+    let runner = MockAgentRunner::new(vec![
+        Ok(phase_complete_result("WRK-001", "design")),
+        Ok(phase_complete_result("WRK-001", "build")),
+    ]);
+
+    let mut config = default_config();
+    config.pipelines = design_then_build_pipeline("size=small");
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = run_params(dir.path(), None, 100);
+
+    let summary =
+        scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
+            .await
+            .expect("Scheduler should succeed");
+
+    assert_eq!(summary.items_completed, vec!["WRK-001"]);
+    assert!(!read_worklogs(dir.path()).contains("Skipped"));
+}
+
+#[tokio::test]
+async fn scheduler_halts_mixed_when_done_and_blocked_items_remain() {
+    let done = make_item("WRK-001", "Already done", ItemStatus::Done);
+    let item = make_in_progress_item("WRK-002", "Feature", "build");
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![done, item]);
+
+    let runner = MockAgentRunner::new(vec![Ok(blocked_result("WRK-002", "build"))]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = run_params(dir.path(), None, 100);
+
+    let summary =
+        scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
+            .await
+            .expect("Scheduler should succeed");
+
+    assert_eq!(summary.items_blocked, vec!["WRK-002"]);
+    assert_eq!(summary.halt_reason, HaltReason::Mixed);
+}
+
+#[tokio::test]
+async fn scheduler_retry_then_success() {
+    let item = make_in_progress_item("WRK-001", "Feature", "build");
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
+
+    // First attempt fails, second succeeds (within executor retry)
+    // max_retries=1 means 2 attempts total
+    let runner = MockAgentRunner::new(vec![
+        Ok(failed_result("WRK-001", "build")),
+        Ok(phase_complete_result("WRK-001", "build")),
+        Ok(phase_complete_result("WRK-001", "review")),
+    ]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+    config.execution.max_retries = 1;
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = run_params(dir.path(), None, 100);
+
+    let summary =
+        scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
+            .await
+            .expect("Scheduler should succeed");
+
+    assert_eq!(summary.items_completed, vec!["WRK-001"]);
+}
+
+#[tokio::test]
+async fn scheduler_retry_exhaustion_blocks_item() {
+    let item = make_in_progress_item("WRK-001", "Feature", "build");
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
+
+    // Two consecutive failures exhausts retries (max_retries=1)
+    let runner = MockAgentRunner::new(vec![
+        Ok(failed_result("WRK-001", "build")),
+        Ok(failed_result("WRK-001", "build")),
+    ]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+    config.execution.max_retries = 1;
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = run_params(dir.path(), None, 100);
+
+    let summary =
+        scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
+            .await
+            .expect("Scheduler should succeed");
+
+    assert!(summary.items_completed.is_empty());
+    assert_eq!(summary.items_blocked, vec!["WRK-001"]);
+}
+
+#[tokio::test]
+async fn scheduler_cap_limits_phase_execution() {
+    let item = make_in_progress_item("WRK-001", "Feature", "build");
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
+
+    let runner = MockAgentRunner::new(vec![
+        Ok(phase_complete_result("WRK-001", "build")),
+        // review would need another result, but cap=1 stops after build
+    ]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = run_params(dir.path(), None, 1); // cap=1
+
+    let summary =
+        scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
+            .await
+            .expect("Scheduler should succeed");
+
+    assert_eq!(summary.halt_reason, HaltReason::CapReached);
+    assert_eq!(summary.phases_executed, 1);
+}
+
+#[tokio::test]
+async fn scheduler_no_actionable_items_exits() {
+    let item = make_item("WRK-001", "Done item", ItemStatus::Done);
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
+
+    let runner = MockAgentRunner::new(vec![]);
+
+    let config = default_config();
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = run_params(dir.path(), None, 100);
+
+    let summary =
+        scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
+            .await
+            .expect("Scheduler should succeed");
+
+    assert_eq!(summary.halt_reason, HaltReason::AllDone);
+    assert_eq!(summary.phases_executed, 0);
+}
+
+#[tokio::test]
+async fn scheduler_target_mode_completes_specific_item() {
+    let item = make_in_progress_item("WRK-001", "Feature", "build");
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
+
+    let runner = MockAgentRunner::new(vec![
+        Ok(phase_complete_result("WRK-001", "build")),
+        Ok(phase_complete_result("WRK-001", "review")),
+    ]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = run_params(dir.path(), Some("WRK-001"), 100);
+
+    let summary =
+        scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
+            .await
+            .expect("Scheduler should succeed");
+
+    assert_eq!(summary.items_completed, vec!["WRK-001"]);
+    assert_eq!(summary.halt_reason, HaltReason::TargetCompleted);
 }
 
 #[tokio::test]
@@ -803,18 +2039,102 @@ async fn scheduler_subphase_complete_re_executes_phase() {
     let item = make_in_progress_item("WRK-001", "Feature", "build");
     let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
 
-    // First invocation returns SubphaseComplete, second returns PhaseComplete
-    let runner = MockAgentRunner::new(vec![
-        Ok(subphase_complete_result("WRK-001", "build")),
-        Ok(phase_complete_result("WRK-001", "build")),
-        Ok(phase_complete_result("WRK-001", "review")),
-    ]);
+    // First invocation returns SubphaseComplete, second returns PhaseComplete
+    let runner = MockAgentRunner::new(vec![
+        Ok(subphase_complete_result("WRK-001", "build")),
+        Ok(phase_complete_result("WRK-001", "build")),
+        Ok(phase_complete_result("WRK-001", "review")),
+    ]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = run_params(dir.path(), None, 100);
+
+    let summary =
+        scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
+            .await
+            .expect("Scheduler should succeed");
+
+    assert_eq!(summary.items_completed, vec!["WRK-001"]);
+}
+
+#[tokio::test]
+async fn scheduler_subphase_complete_does_not_record_completed_phase() {
+    let item = make_in_progress_item("WRK-001", "Feature", "build");
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
+
+    let runner = MockAgentRunner::new(vec![Ok(subphase_complete_result("WRK-001", "build"))]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = run_params(dir.path(), None, 1);
+
+    let summary = scheduler::run_scheduler(
+        coordinator_handle.clone(),
+        Arc::new(runner),
+        config,
+        params,
+        cancel,
+    )
+    .await
+    .expect("Scheduler should succeed");
+    assert_eq!(summary.halt_reason, HaltReason::CapReached);
+
+    let snapshot = coordinator_handle.get_snapshot().await.unwrap();
+    let item = snapshot.iter().find(|i| i.id() == "WRK-001").unwrap();
+    assert!(item.completed_phases().is_empty());
+    assert!(scheduler::resumed_phase_result(item, "build").is_none());
+}
+
+#[tokio::test]
+async fn scheduler_phase_complete_records_completed_phase() {
+    let item = make_in_progress_item("WRK-001", "Feature", "build");
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
+
+    let runner = MockAgentRunner::new(vec![Ok(phase_complete_result("WRK-001", "build"))]);
 
     let mut config = default_config();
     config.pipelines = simple_pipeline();
 
     let cancel = tokio_util::sync::CancellationToken::new();
-    let params = run_params(dir.path(), None, 100);
+    let params = run_params(dir.path(), None, 1);
+
+    scheduler::run_scheduler(
+        coordinator_handle.clone(),
+        Arc::new(runner),
+        config,
+        params,
+        cancel,
+    )
+    .await
+    .expect("Scheduler should succeed");
+
+    let snapshot = coordinator_handle.get_snapshot().await.unwrap();
+    let item = snapshot.iter().find(|i| i.id() == "WRK-001").unwrap();
+    assert_eq!(item.completed_phases(), vec!["build"]);
+}
+
+#[tokio::test]
+async fn scheduler_resume_skips_agent_for_completed_phase() {
+    let mut item = make_in_progress_item("WRK-001", "Feature", "build");
+    pg_item::set_completed_phases(&mut item.0, &["build".to_string()]);
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
+
+    // Only the review phase reaches the agent; build is reused from the earlier run
+    let runner = MockAgentRunner::new(vec![Ok(phase_complete_result("WRK-001", "review"))]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = RunParams {
+        resume: true,
+        ..run_params(dir.path(), None, 100)
+    };
 
     let summary =
         scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
@@ -824,6 +2144,63 @@ async fn scheduler_subphase_complete_re_executes_phase() {
     assert_eq!(summary.items_completed, vec!["WRK-001"]);
 }
 
+#[tokio::test]
+async fn scheduler_resume_reruns_phase_whose_output_blocked_the_item() {
+    let item = make_in_progress_item("WRK-001", "Feature", "review");
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
+
+    let mut pipelines = simple_pipeline();
+    pipelines.get_mut("feature").unwrap().phases[1] = PhaseConfig {
+        followups_block: true,
+        ..PhaseConfig::new("review", false)
+    };
+    let blocking_review = || {
+        let mut result = phase_complete_result("WRK-001", "review");
+        result.follow_ups = vec![follow_up("Fix the flaky test")];
+        result
+    };
+
+    let mut config = default_config();
+    config.pipelines = pipelines;
+
+    let summary = scheduler::run_scheduler(
+        coordinator_handle.clone(),
+        Arc::new(MockAgentRunner::new(vec![Ok(blocking_review())])),
+        config.clone(),
+        run_params(dir.path(), Some("WRK-001"), 100),
+        tokio_util::sync::CancellationToken::new(),
+    )
+    .await
+    .expect("Scheduler should succeed");
+    assert_eq!(summary.items_blocked, vec!["WRK-001"]);
+
+    let snapshot = coordinator_handle.get_snapshot().await.unwrap();
+    let item = snapshot.iter().find(|i| i.id() == "WRK-001").unwrap();
+    assert!(item.completed_phases().is_empty());
+
+    // A plain unblock (no --retry), then resume: review must run again and
+    // block again on its follow-up instead of being skipped
+    coordinator_handle
+        .unblock_item("WRK-001", Some("Looked into it".to_string()))
+        .await
+        .unwrap();
+    let summary = scheduler::run_scheduler(
+        coordinator_handle.clone(),
+        Arc::new(MockAgentRunner::new(vec![Ok(blocking_review())])),
+        config,
+        RunParams {
+            resume: true,
+            ..run_params(dir.path(), Some("WRK-001"), 100)
+        },
+        tokio_util::sync::CancellationToken::new(),
+    )
+    .await
+    .expect("Scheduler should succeed");
+
+    assert!(summary.items_completed.is_empty());
+    assert_eq!(summary.items_blocked, vec!["WRK-001"]);
+}
+
 #[tokio::test]
 async fn scheduler_follow_ups_are_ingested() {
     let item = make_in_progress_item("WRK-001", "Feature", "build");
@@ -856,6 +2233,160 @@ async fn scheduler_follow_ups_are_ingested() {
     assert!(summary.follow_ups_created >= 1);
 }
 
+fn follow_up(title: &str) -> FollowUp {
+    FollowUp {
+        title: title.to_string(),
+        context: None,
+        suggested_size: None,
+        suggested_risk: None,
+    }
+}
+
+#[tokio::test]
+async fn scheduler_skips_follow_up_duplicating_same_origin_item() {
+    let item = make_in_progress_item("WRK-001", "Feature", "build");
+    let mut existing = make_item("WRK-002", "Add tests", ItemStatus::Blocked);
+    pg_item::set_origin(&mut existing.0, Some("WRK-001/design"));
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item, existing]);
+
+    let mut result = phase_complete_result("WRK-001", "build");
+    result.follow_ups = vec![follow_up("  add   TESTS."), follow_up("Write docs")];
+
+    let runner = MockAgentRunner::new(vec![
+        Ok(result),
+        Ok(phase_complete_result("WRK-001", "review")),
+    ]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = run_params(dir.path(), None, 100);
+
+    let summary = scheduler::run_scheduler(
+        coordinator_handle.clone(),
+        Arc::new(runner),
+        config,
+        params,
+        cancel,
+    )
+    .await
+    .expect("Scheduler should succeed");
+
+    assert_eq!(summary.follow_ups_created, 1);
+    let snapshot = coordinator_handle.get_snapshot().await.unwrap();
+    let titles: Vec<&str> = snapshot
+        .iter()
+        .filter(|i| i.origin().as_deref() == Some("WRK-001/build"))
+        .map(|i| i.title())
+        .collect();
+    assert_eq!(titles, vec!["Write docs"]);
+}
+
+#[test]
+fn dedup_follow_ups_matches_same_origin_or_new_items() {
+    let mut same_origin = make_item("WRK-002", "Add tests", ItemStatus::Ready);
+    pg_item::set_origin(&mut same_origin.0, Some("WRK-001/build"));
+    let mut other_origin = make_item("WRK-003", "Update docs", ItemStatus::Ready);
+    pg_item::set_origin(&mut other_origin.0, Some("WRK-009/build"));
+    let new_item = make_item("WRK-004", "Add Retries", ItemStatus::New);
+    let snapshot = vec![same_origin, other_origin, new_item];
+
+    let kept = scheduler::dedup_follow_ups(
+        "WRK-001",
+        &[
+            follow_up("Add tests"),
+            follow_up("Update docs"),
+            follow_up("add retries"),
+            follow_up("Refactor parser"),
+            follow_up("refactor parser"),
+        ],
+        &snapshot,
+        1.0,
+    );
+
+    let titles: Vec<&str> = kept.iter().map(|fu| fu.title.as_str()).collect();
+    assert_eq!(titles, vec!["Update docs", "Refactor parser"]);
+}
+
+#[test]
+fn dedup_follow_ups_applies_similarity_threshold() {
+    let snapshot = vec![make_item(
+        "WRK-002",
+        "Add retry backoff to the agent runner",
+        ItemStatus::New,
+    )];
+    let follow_ups = [
+        follow_up("Add retry backoff to agent runner"),
+        follow_up("Document the config file"),
+    ];
+
+    let exact = scheduler::dedup_follow_ups("WRK-001", &follow_ups, &snapshot, 1.0);
+    assert_eq!(exact.len(), 2);
+
+    let similar = scheduler::dedup_follow_ups("WRK-001", &follow_ups, &snapshot, 0.8);
+    let titles: Vec<&str> = similar.iter().map(|fu| fu.title.as_str()).collect();
+    assert_eq!(titles, vec!["Document the config file"]);
+}
+
+#[test]
+fn follow_up_title_similarity_is_word_overlap() {
+    assert_eq!(
+        scheduler::follow_up_title_similarity("add retries", "add retries"),
+        1.0
+    );
+    assert_eq!(
+        scheduler::follow_up_title_similarity("retries add", "add retries"),
+        1.0
+    );
+    assert_eq!(
+        scheduler::follow_up_title_similarity("add retries", "add docs"),
+        1.0 / 3.0
+    );
+    assert_eq!(scheduler::follow_up_title_similarity("add", "docs"), 0.0);
+}
+
+#[tokio::test]
+async fn scheduler_creates_one_item_when_two_phases_emit_same_follow_up() {
+    let item = make_in_progress_item("WRK-001", "Feature", "build");
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
+
+    let mut build = phase_complete_result("WRK-001", "build");
+    build.follow_ups = vec![follow_up("Add retries")];
+    let mut review = phase_complete_result("WRK-001", "review");
+    review.follow_ups = vec![follow_up("Add Retries.")];
+    let runner = MockAgentRunner::new(vec![Ok(build), Ok(review)]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = run_params(dir.path(), None, 100);
+
+    let summary = scheduler::run_scheduler(
+        coordinator_handle.clone(),
+        Arc::new(runner),
+        config,
+        params,
+        cancel,
+    )
+    .await
+    .expect("Scheduler should succeed");
+
+    assert_eq!(summary.follow_ups_created, 1);
+    assert_eq!(summary.follow_ups_suppressed, 1);
+    let snapshot = coordinator_handle.get_snapshot().await.unwrap();
+    let spawned: Vec<&str> = snapshot
+        .iter()
+        .filter(|i| {
+            i.origin()
+                .is_some_and(|origin| origin.starts_with("WRK-001/"))
+        })
+        .map(|i| i.title())
+        .collect();
+    assert_eq!(spawned, vec!["Add retries"]);
+}
+
 // ============================================================
 // Triage integration tests
 // ============================================================
@@ -881,6 +2412,388 @@ async fn triage_small_low_risk_promotes_to_ready() {
     assert_eq!(item.risk(), Some(DimensionLevel::Low));
 }
 
+#[tokio::test]
+async fn scheduler_records_merged_pairs_in_summary() {
+    let original = make_item("WRK-001", "Add login", ItemStatus::Blocked);
+    let duplicate = make_item("WRK-002", "Add login page", ItemStatus::New);
+    let (coordinator_handle, _coord_task, dir) =
+        setup_coordinator_with_items(vec![original, duplicate]);
+
+    let mut triage_result = triage_result_with_assessments("WRK-002");
+    triage_result.duplicates = vec!["WRK-001".to_string()];
+    let runner = MockAgentRunner::new(vec![Ok(triage_result)]);
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let summary = scheduler::run_scheduler(
+        coordinator_handle.clone(),
+        Arc::new(runner),
+        default_config(),
+        run_params(dir.path(), None, 100),
+        cancel,
+    )
+    .await
+    .expect("Scheduler should succeed");
+
+    assert_eq!(summary.items_merged, 1);
+    assert_eq!(
+        summary.merges,
+        vec![("WRK-002".to_string(), "WRK-001".to_string())]
+    );
+    let snapshot = coordinator_handle.get_snapshot().await.unwrap();
+    assert!(snapshot.iter().all(|i| i.id() != "WRK-002"));
+}
+
+/// Agent that fails every call, counting how many times it was invoked.
+struct FailingAgentRunner {
+    calls: std::sync::atomic::AtomicU32,
+}
+
+impl AgentRunner for FailingAgentRunner {
+    async fn run_agent(
+        &self,
+        _prompt: &str,
+        _result_path: &Path,
+        _timeout: std::time::Duration,
+    ) -> Result<PhaseResult, String> {
+        self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        Err("agent unavailable".to_string())
+    }
+}
+
+#[tokio::test]
+async fn triage_new_items_halts_after_consecutive_failures() {
+    let items = (1..=5)
+        .map(|i| make_item(&format!("WRK-{:03}", i), "New item", ItemStatus::New))
+        .collect();
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(items);
+    let mut config = default_config();
+    config.execution.triage_failure_threshold = 2;
+    let runner = Arc::new(FailingAgentRunner {
+        calls: std::sync::atomic::AtomicU32::new(0),
+    });
+
+    let result = scheduler::triage_new_items(
+        &coordinator_handle,
+        runner.clone(),
+        &config,
+        dir.path(),
+        &tokio_util::sync::CancellationToken::new(),
+    )
+    .await;
+
+    let err = result.expect_err("triage should halt");
+    assert!(err.contains("2 consecutive failures"), "got: {}", err);
+    assert!(err.contains("agent unavailable"), "got: {}", err);
+    assert_eq!(runner.calls.load(std::sync::atomic::Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn triage_new_items_success_resets_failure_count() {
+    let items = vec![
+        make_item("WRK-001", "First", ItemStatus::New),
+        make_item("WRK-002", "Second", ItemStatus::New),
+        make_item("WRK-003", "Third", ItemStatus::New),
+    ];
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(items);
+    let mut config = default_config();
+    config.execution.triage_failure_threshold = 2;
+    let runner = MockAgentRunner::new(vec![
+        Err("agent unavailable".to_string()),
+        Ok(triage_result_with_assessments("WRK-002")),
+        Err("agent unavailable".to_string()),
+    ]);
+
+    let triaged = scheduler::triage_new_items(
+        &coordinator_handle,
+        Arc::new(runner),
+        &config,
+        dir.path(),
+        &tokio_util::sync::CancellationToken::new(),
+    )
+    .await
+    .expect("failures were not consecutive");
+
+    assert_eq!(triaged, 1);
+}
+
+/// Agent that triages every item successfully after a short delay, recording
+/// the peak number of calls in flight at once. Fails for `fail_id`, if set.
+struct ConcurrencyTrackingRunner {
+    in_flight: std::sync::atomic::AtomicU32,
+    peak: std::sync::atomic::AtomicU32,
+    fail_id: Option<&'static str>,
+}
+
+impl ConcurrencyTrackingRunner {
+    fn new(fail_id: Option<&'static str>) -> Self {
+        Self {
+            in_flight: std::sync::atomic::AtomicU32::new(0),
+            peak: std::sync::atomic::AtomicU32::new(0),
+            fail_id,
+        }
+    }
+}
+
+impl AgentRunner for ConcurrencyTrackingRunner {
+    async fn run_agent(
+        &self,
+        _prompt: &str,
+        result_path: &Path,
+        _timeout: std::time::Duration,
+    ) -> Result<PhaseResult, String> {
+        use std::sync::atomic::Ordering;
+
+        let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak.fetch_max(now, Ordering::SeqCst);
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+        // Result files are named `phase_result_{item_id}_triage.json`
+        let file_name = result_path.file_name().unwrap().to_string_lossy();
+        let item_id = file_name
+            .trim_start_matches("phase_result_")
+            .trim_end_matches("_triage.json")
+            .to_string();
+        if self.fail_id == Some(item_id.as_str()) {
+            return Err("agent unavailable".to_string());
+        }
+        Ok(triage_result_with_assessments(&item_id))
+    }
+}
+
+fn four_new_items() -> Vec<PgItem> {
+    (1..=4)
+        .map(|i| make_item(&format!("WRK-{:03}", i), "New item", ItemStatus::New))
+        .collect()
+}
+
+#[tokio::test]
+async fn triage_new_items_runs_up_to_max_concurrent() {
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(four_new_items());
+    let mut config = default_config();
+    config.execution.max_concurrent = 2;
+    let runner = Arc::new(ConcurrencyTrackingRunner::new(None));
+
+    let triaged = scheduler::triage_new_items(
+        &coordinator_handle,
+        runner.clone(),
+        &config,
+        dir.path(),
+        &tokio_util::sync::CancellationToken::new(),
+    )
+    .await
+    .expect("triage should succeed");
+
+    assert_eq!(triaged, 4);
+    assert_eq!(runner.peak.load(std::sync::atomic::Ordering::SeqCst), 2);
+    let snapshot = coordinator_handle.get_snapshot().await.unwrap();
+    assert!(snapshot.iter().all(|i| i.pg_status() != ItemStatus::New));
+}
+
+#[tokio::test]
+async fn triage_new_items_failure_does_not_abort_concurrent_triages() {
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(four_new_items());
+    let mut config = default_config();
+    config.execution.max_concurrent = 4;
+    let runner = Arc::new(ConcurrencyTrackingRunner::new(Some("WRK-002")));
+
+    let triaged = scheduler::triage_new_items(
+        &coordinator_handle,
+        runner,
+        &config,
+        dir.path(),
+        &tokio_util::sync::CancellationToken::new(),
+    )
+    .await
+    .expect("a single failure is below the threshold");
+
+    assert_eq!(triaged, 3);
+    let snapshot = coordinator_handle.get_snapshot().await.unwrap();
+    let still_new: Vec<&str> = snapshot
+        .iter()
+        .filter(|i| i.pg_status() == ItemStatus::New)
+        .map(|i| i.id())
+        .collect();
+    assert_eq!(still_new, vec!["WRK-002"]);
+}
+
+#[tokio::test]
+async fn triage_items_retriages_an_in_progress_item_from_scratch() {
+    let mut in_progress = make_in_progress_item("WRK-001", "Edited", "build");
+    pg_item::set_completed_phases(&mut in_progress.0, &["design".to_string()]);
+    let items = vec![
+        in_progress,
+        make_item("WRK-002", "Untouched", ItemStatus::New),
+    ];
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(items);
+    let runner = MockAgentRunner::new(vec![Ok(triage_result_with_assessments("WRK-001"))]);
+
+    let triaged = scheduler::triage_items(
+        &coordinator_handle,
+        Arc::new(runner),
+        &default_config(),
+        dir.path(),
+        &tokio_util::sync::CancellationToken::new(),
+        vec!["WRK-001".to_string()],
+    )
+    .await
+    .expect("re-triage should succeed");
+
+    assert_eq!(triaged, 1);
+    let snapshot = coordinator_handle.get_snapshot().await.unwrap();
+    let item = snapshot.iter().find(|i| i.id() == "WRK-001").unwrap();
+    // Small, low-risk: routed straight to Ready, with the old routing cleared
+    assert_eq!(item.pg_status(), ItemStatus::Ready);
+    assert_eq!(item.phase(), None);
+    assert!(item.completed_phases().is_empty());
+    let other = snapshot.iter().find(|i| i.id() == "WRK-002").unwrap();
+    assert_eq!(other.pg_status(), ItemStatus::New);
+}
+
+/// `triage_updates` for a small, low-risk item whose complexity is
+/// `complexity` (guardrail max: medium), under `policy`.
+fn triage_updates_with_complexity(
+    complexity: DimensionLevel,
+    policy: GuardrailViolationPolicy,
+) -> Vec<ItemUpdate> {
+    let item = make_item("WRK-001", "Triaged", ItemStatus::New);
+    let mut config = default_config();
+    config.guardrails.on_violation = policy;
+    let result = PhaseResult {
+        updated_assessments: Some(UpdatedAssessments {
+            size: Some(SizeLevel::Small),
+            complexity: Some(complexity),
+            risk: Some(DimensionLevel::Low),
+            impact: Some(DimensionLevel::Medium),
+        }),
+        ..triage_result_with_assessments("WRK-001")
+    };
+    scheduler::triage_updates(&item, &result, &config)
+}
+
+fn routing_updates(updates: &[ItemUpdate]) -> Vec<ItemUpdate> {
+    updates
+        .iter()
+        .filter(|u| !matches!(u, ItemUpdate::UpdateAssessments(_)))
+        .cloned()
+        .collect()
+}
+
+#[test]
+fn triage_guardrail_at_max_routes_to_ready_under_every_policy() {
+    for policy in [
+        GuardrailViolationPolicy::Block,
+        GuardrailViolationPolicy::Scope,
+        GuardrailViolationPolicy::Warn,
+    ] {
+        let updates = triage_updates_with_complexity(DimensionLevel::Medium, policy);
+        assert_eq!(
+            routing_updates(&updates),
+            vec![
+                ItemUpdate::TransitionStatus(ItemStatus::Scoping),
+                ItemUpdate::TransitionStatus(ItemStatus::Ready),
+            ],
+            "policy {:?}",
+            policy
+        );
+    }
+}
+
+#[test]
+fn triage_guardrail_violation_block_policy_blocks() {
+    let updates =
+        triage_updates_with_complexity(DimensionLevel::High, GuardrailViolationPolicy::Block);
+
+    assert_eq!(
+        routing_updates(&updates),
+        vec![ItemUpdate::SetBlocked(
+            "Exceeds autonomous guardrail thresholds (complexity high > medium)".to_string()
+        )]
+    );
+}
+
+#[test]
+fn triage_guardrail_violation_scope_policy_enters_pre_phases() {
+    let updates =
+        triage_updates_with_complexity(DimensionLevel::High, GuardrailViolationPolicy::Scope);
+
+    assert_eq!(
+        routing_updates(&updates),
+        vec![
+            ItemUpdate::TransitionStatus(ItemStatus::Scoping),
+            ItemUpdate::SetPhase("research".to_string()),
+            ItemUpdate::SetPhasePool(PhasePool::Pre),
+        ]
+    );
+}
+
+#[test]
+fn triage_guardrail_violation_warn_policy_routes_to_ready() {
+    let updates =
+        triage_updates_with_complexity(DimensionLevel::High, GuardrailViolationPolicy::Warn);
+
+    assert_eq!(
+        routing_updates(&updates),
+        vec![
+            ItemUpdate::TransitionStatus(ItemStatus::Scoping),
+            ItemUpdate::TransitionStatus(ItemStatus::Ready),
+        ]
+    );
+}
+
+/// Run `apply_triage_result` for `assessments` and assert the pure preview
+/// predicted the same status, phase, and pool.
+async fn assert_triage_preview_matches(assessments: UpdatedAssessments) -> PgItem {
+    let item = make_item("WRK-001", "Previewed", ItemStatus::New);
+    let (coordinator_handle, _coord_task, _dir) = setup_coordinator_with_items(vec![item.clone()]);
+    let config = default_config();
+
+    let previewed = scheduler::preview_triage(&item, assessments.clone(), None, &config);
+
+    let triage_result = PhaseResult {
+        updated_assessments: Some(assessments),
+        ..triage_result_with_assessments("WRK-001")
+    };
+    scheduler::apply_triage_result(&coordinator_handle, "WRK-001", &triage_result, &config)
+        .await
+        .expect("apply_triage_result should succeed");
+
+    let snapshot = coordinator_handle.get_snapshot().await.unwrap();
+    let actual = snapshot.iter().find(|i| i.id() == "WRK-001").unwrap();
+    assert_eq!(previewed.pg_status(), actual.pg_status());
+    assert_eq!(previewed.phase(), actual.phase());
+    assert_eq!(previewed.phase_pool(), actual.phase_pool());
+    previewed
+}
+
+#[tokio::test]
+async fn triage_preview_matches_small_low_risk_routing() {
+    let previewed = assert_triage_preview_matches(UpdatedAssessments {
+        size: Some(SizeLevel::Small),
+        complexity: None,
+        risk: Some(DimensionLevel::Low),
+        impact: None,
+    })
+    .await;
+
+    assert_eq!(previewed.pg_status(), ItemStatus::Ready);
+}
+
+#[tokio::test]
+async fn triage_preview_matches_large_high_risk_routing() {
+    let previewed = assert_triage_preview_matches(UpdatedAssessments {
+        size: Some(SizeLevel::Large),
+        complexity: None,
+        risk: Some(DimensionLevel::High),
+        impact: None,
+    })
+    .await;
+
+    assert_eq!(previewed.pg_status(), ItemStatus::Scoping);
+    assert_eq!(previewed.phase().as_deref(), Some("research"));
+    assert_eq!(previewed.phase_pool(), Some(PhasePool::Pre));
+}
+
 #[tokio::test]
 async fn triage_large_item_goes_to_scoping_with_pre_phase() {
     let item = make_item("WRK-001", "Big feature", ItemStatus::New);
@@ -927,15 +2840,45 @@ async fn triage_blocked_result_blocks_item() {
     assert_eq!(item.pg_status(), ItemStatus::Blocked);
 }
 
-#[tokio::test]
-async fn triage_with_invalid_pipeline_type_blocks() {
-    let item = make_item("WRK-001", "Item", ItemStatus::New);
+#[tokio::test]
+async fn triage_with_invalid_pipeline_type_blocks() {
+    let item = make_item("WRK-001", "Item", ItemStatus::New);
+    let (coordinator_handle, _coord_task, _dir) = setup_coordinator_with_items(vec![item]);
+
+    let config = default_config();
+
+    let mut triage_result = triage_result_with_assessments("WRK-001");
+    triage_result.pipeline_type = Some("nonexistent_pipeline".to_string());
+
+    scheduler::apply_triage_result(&coordinator_handle, "WRK-001", &triage_result, &config)
+        .await
+        .expect("apply_triage_result should succeed");
+
+    let snapshot = coordinator_handle.get_snapshot().await.unwrap();
+    let item = snapshot.iter().find(|i| i.id() == "WRK-001").unwrap();
+
+    assert_eq!(item.pg_status(), ItemStatus::Blocked);
+    assert!(item
+        .blocked_reason()
+        .unwrap()
+        .contains("nonexistent_pipeline"));
+}
+
+/// Apply a triage result suggesting `feature` to an item tagged `tags`, with
+/// `docs`-tagged items routed to the `docs` pipeline.
+async fn pipeline_after_routed_triage(tags: &[&str]) -> Option<String> {
+    let mut item = make_item("WRK-001", "Item", ItemStatus::New);
+    item.0.tags = tags.iter().map(|t| t.to_string()).collect();
     let (coordinator_handle, _coord_task, _dir) = setup_coordinator_with_items(vec![item]);
 
-    let config = default_config();
+    let mut config = default_config();
+    config.pipelines = feature_and_docs_pipelines(None, None);
+    config
+        .routing
+        .insert("docs".to_string(), "docs".to_string());
 
     let mut triage_result = triage_result_with_assessments("WRK-001");
-    triage_result.pipeline_type = Some("nonexistent_pipeline".to_string());
+    triage_result.pipeline_type = Some("feature".to_string());
 
     scheduler::apply_triage_result(&coordinator_handle, "WRK-001", &triage_result, &config)
         .await
@@ -943,12 +2886,26 @@ async fn triage_with_invalid_pipeline_type_blocks() {
 
     let snapshot = coordinator_handle.get_snapshot().await.unwrap();
     let item = snapshot.iter().find(|i| i.id() == "WRK-001").unwrap();
+    assert_ne!(item.pg_status(), ItemStatus::Blocked);
+    item.pipeline_type()
+}
 
-    assert_eq!(item.pg_status(), ItemStatus::Blocked);
-    assert!(item
-        .blocked_reason()
-        .unwrap()
-        .contains("nonexistent_pipeline"));
+#[tokio::test]
+async fn triage_routing_tag_overrides_suggested_pipeline() {
+    assert_eq!(
+        pipeline_after_routed_triage(&["ui", "docs"])
+            .await
+            .as_deref(),
+        Some("docs")
+    );
+}
+
+#[tokio::test]
+async fn triage_untagged_item_uses_suggested_pipeline() {
+    assert_eq!(
+        pipeline_after_routed_triage(&[]).await.as_deref(),
+        Some("feature")
+    );
 }
 
 // --- Triage description application tests ---
@@ -1500,6 +3457,44 @@ fn test_unmet_dep_does_not_consume_wip_slot() {
     );
 }
 
+// ============================================================
+// Idle halt classification
+// ============================================================
+
+#[test]
+fn test_classify_idle_halt_all_done() {
+    let snapshot = vec![
+        make_item("WRK-001", "Done", ItemStatus::Done),
+        make_item("WRK-002", "Also done", ItemStatus::Done),
+    ];
+    assert_eq!(classify_idle_halt(&snapshot, &[]), HaltReason::AllDone);
+    // Completed items may already be archived out of the snapshot
+    assert_eq!(
+        classify_idle_halt(&[], &["WRK-003".to_string()]),
+        HaltReason::AllDone
+    );
+}
+
+#[test]
+fn test_classify_idle_halt_all_blocked() {
+    let mut waiting = make_item("WRK-002", "Waiting", ItemStatus::Ready);
+    waiting.0.dependencies = vec!["WRK-001".to_string()];
+    let snapshot = vec![
+        make_item("WRK-001", "Blocked", ItemStatus::Blocked),
+        waiting,
+    ];
+    assert_eq!(classify_idle_halt(&snapshot, &[]), HaltReason::AllBlocked);
+}
+
+#[test]
+fn test_classify_idle_halt_mixed() {
+    let snapshot = vec![make_item("WRK-001", "Blocked", ItemStatus::Blocked)];
+    assert_eq!(
+        classify_idle_halt(&snapshot, &["WRK-002".to_string()]),
+        HaltReason::Mixed
+    );
+}
+
 // ============================================================
 // Dependency filtering tests — select_targeted_actions()
 // ============================================================
@@ -1560,6 +3555,102 @@ fn test_targeted_with_absent_dep_returns_action() {
     );
 }
 
+#[test]
+fn test_next_runnable_dependency_walks_chain() {
+    // WRK-001 -> WRK-002 -> WRK-003; only WRK-003 is runnable
+    let mut item_a = make_in_progress_item("WRK-001", "Target", "build");
+    item_a.0.dependencies = vec!["WRK-002".to_string()];
+    let mut item_b = make_item("WRK-002", "Middle", ItemStatus::Ready);
+    item_b.0.dependencies = vec!["WRK-003".to_string()];
+    let item_c = make_item("WRK-003", "Leaf", ItemStatus::Ready);
+
+    let snapshot = vec![item_a, item_b, item_c];
+
+    assert_eq!(
+        next_runnable_dependency(&snapshot, "WRK-001", None),
+        Some("WRK-003".to_string())
+    );
+}
+
+#[test]
+fn test_next_runnable_dependency_skips_blocked_and_cycles() {
+    let mut item_a = make_in_progress_item("WRK-001", "Target", "build");
+    item_a.0.dependencies = vec!["WRK-002".to_string(), "WRK-003".to_string()];
+    let item_b = make_item("WRK-002", "Blocked dep", ItemStatus::Blocked);
+    let mut item_c = make_item("WRK-003", "Cyclic dep", ItemStatus::Ready);
+    item_c.0.dependencies = vec!["WRK-001".to_string()];
+
+    let snapshot = vec![item_a, item_b, item_c];
+
+    assert_eq!(next_runnable_dependency(&snapshot, "WRK-001", None), None);
+}
+
+#[test]
+fn test_next_runnable_dependency_stops_at_max_depth() {
+    // WRK-001 -> WRK-002 -> WRK-003 -> WRK-004; only WRK-004 is runnable (depth 3)
+    let mut item_a = make_in_progress_item("WRK-001", "Target", "build");
+    item_a.0.dependencies = vec!["WRK-002".to_string()];
+    let mut item_b = make_item("WRK-002", "Depth 1", ItemStatus::Ready);
+    item_b.0.dependencies = vec!["WRK-003".to_string()];
+    let mut item_c = make_item("WRK-003", "Depth 2", ItemStatus::Ready);
+    item_c.0.dependencies = vec!["WRK-004".to_string()];
+    let item_d = make_item("WRK-004", "Depth 3", ItemStatus::Ready);
+
+    let snapshot = vec![item_a, item_b, item_c, item_d];
+
+    assert_eq!(
+        next_runnable_dependency(&snapshot, "WRK-001", Some(1)),
+        None
+    );
+    assert_eq!(
+        next_runnable_dependency(&snapshot, "WRK-001", Some(2)),
+        None
+    );
+    assert_eq!(
+        next_runnable_dependency(&snapshot, "WRK-001", Some(3)),
+        Some("WRK-004".to_string())
+    );
+    let mut warned = HashSet::new();
+    assert_eq!(
+        effective_target_id(&snapshot, "WRK-001", true, Some(2), &mut warned),
+        "WRK-001",
+        "Target should stay put when its runnable dependency is beyond the limit"
+    );
+    assert!(warned.contains("WRK-001"));
+
+    // Later iterations keep the target in place without warning again
+    assert_eq!(
+        effective_target_id(&snapshot, "WRK-001", true, Some(2), &mut warned),
+        "WRK-001"
+    );
+    assert_eq!(warned.len(), 1);
+
+    let mut warned = HashSet::new();
+    effective_target_id(&snapshot, "WRK-001", true, Some(3), &mut warned);
+    assert!(
+        warned.is_empty(),
+        "Nothing to warn about when a dependency is runnable within the limit"
+    );
+}
+
+#[test]
+fn test_effective_target_id_only_swaps_with_wait_deps() {
+    let mut item_a = make_in_progress_item("WRK-001", "Target", "build");
+    item_a.0.dependencies = vec!["WRK-002".to_string()];
+    let item_b = make_item("WRK-002", "Dep", ItemStatus::Ready);
+
+    let snapshot = vec![item_a, item_b];
+
+    assert_eq!(
+        effective_target_id(&snapshot, "WRK-001", false, None, &mut HashSet::new()),
+        "WRK-001"
+    );
+    assert_eq!(
+        effective_target_id(&snapshot, "WRK-001", true, None, &mut HashSet::new()),
+        "WRK-002"
+    );
+}
+
 // ============================================================
 // Mixed ID format dependency resolution
 // ============================================================
@@ -1862,6 +3953,16 @@ async fn test_multi_target_processes_in_order() {
         root: dir.path().to_path_buf(),
         config_base: dir.path().to_path_buf(),
         auto_advance: false,
+        dry_run: false,
+        wait_deps: false,
+        max_dep_depth: None,
+        resume: false,
+        events: None,
+        max_duration: None,
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
     };
 
     let summary =
@@ -1874,6 +3975,63 @@ async fn test_multi_target_processes_in_order() {
     assert_eq!(summary.halt_reason, HaltReason::TargetCompleted);
 }
 
+#[tokio::test]
+async fn test_target_wait_deps_runs_dependency_first() {
+    let mut target = make_in_progress_item("WRK-001", "Target", "build");
+    target.0.dependencies = vec!["WRK-002".to_string()];
+    let dep = make_in_progress_item("WRK-002", "Dependency", "build");
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![target, dep]);
+
+    let runner = MockAgentRunner::new(vec![
+        Ok(phase_complete_result("WRK-002", "build")),
+        Ok(phase_complete_result("WRK-002", "review")),
+        Ok(phase_complete_result("WRK-001", "build")),
+        Ok(phase_complete_result("WRK-001", "review")),
+    ]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = RunParams {
+        wait_deps: true,
+        ..run_params(dir.path(), Some("WRK-001"), 100)
+    };
+
+    let summary =
+        scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
+            .await
+            .expect("Scheduler should succeed");
+
+    assert_eq!(summary.items_completed, vec!["WRK-002", "WRK-001"]);
+    assert_eq!(summary.halt_reason, HaltReason::TargetCompleted);
+}
+
+#[tokio::test]
+async fn test_target_without_wait_deps_halts_on_unmet_deps() {
+    let mut target = make_in_progress_item("WRK-001", "Target", "build");
+    target.0.dependencies = vec!["WRK-002".to_string()];
+    let dep = make_in_progress_item("WRK-002", "Dependency", "build");
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![target, dep]);
+
+    let runner = MockAgentRunner::new(vec![]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = run_params(dir.path(), Some("WRK-001"), 100);
+
+    let summary =
+        scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
+            .await
+            .expect("Scheduler should succeed");
+
+    assert!(summary.items_completed.is_empty());
+    assert_eq!(summary.phases_executed, 0);
+    assert_eq!(summary.halt_reason, HaltReason::AllBlocked);
+}
+
 #[tokio::test]
 async fn test_multi_target_halts_on_block() {
     let item1 = make_in_progress_item("WRK-001", "First", "build");
@@ -1897,6 +4055,16 @@ async fn test_multi_target_halts_on_block() {
         root: dir.path().to_path_buf(),
         config_base: dir.path().to_path_buf(),
         auto_advance: false,
+        dry_run: false,
+        wait_deps: false,
+        max_dep_depth: None,
+        resume: false,
+        events: None,
+        max_duration: None,
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
     };
 
     let summary =
@@ -1928,6 +4096,16 @@ async fn test_multi_target_all_done_at_startup() {
         root: dir.path().to_path_buf(),
         config_base: dir.path().to_path_buf(),
         auto_advance: false,
+        dry_run: false,
+        wait_deps: false,
+        max_dep_depth: None,
+        resume: false,
+        events: None,
+        max_duration: None,
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
     };
 
     let summary =
@@ -1961,6 +4139,16 @@ async fn test_multi_target_skips_done_targets() {
         root: dir.path().to_path_buf(),
         config_base: dir.path().to_path_buf(),
         auto_advance: false,
+        dry_run: false,
+        wait_deps: false,
+        max_dep_depth: None,
+        resume: false,
+        events: None,
+        max_duration: None,
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
     };
 
     let summary =
@@ -1994,6 +4182,16 @@ async fn test_multi_target_single_element_backward_compat() {
         root: dir.path().to_path_buf(),
         config_base: dir.path().to_path_buf(),
         auto_advance: false,
+        dry_run: false,
+        wait_deps: false,
+        max_dep_depth: None,
+        resume: false,
+        events: None,
+        max_duration: None,
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
     };
 
     let summary =
@@ -2028,6 +4226,16 @@ async fn test_multi_target_target_archived_during_run() {
         root: dir.path().to_path_buf(),
         config_base: dir.path().to_path_buf(),
         auto_advance: false,
+        dry_run: false,
+        wait_deps: false,
+        max_dep_depth: None,
+        resume: false,
+        events: None,
+        max_duration: None,
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
     };
 
     let summary =
@@ -2062,6 +4270,16 @@ async fn test_multi_target_skips_pre_blocked_targets() {
         root: dir.path().to_path_buf(),
         config_base: dir.path().to_path_buf(),
         auto_advance: false,
+        dry_run: false,
+        wait_deps: false,
+        max_dep_depth: None,
+        resume: false,
+        events: None,
+        max_duration: None,
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
     };
 
     let summary =
@@ -2100,6 +4318,16 @@ async fn test_auto_advance_skips_blocked_target() {
         root: dir.path().to_path_buf(),
         config_base: dir.path().to_path_buf(),
         auto_advance: true,
+        dry_run: false,
+        wait_deps: false,
+        max_dep_depth: None,
+        resume: false,
+        events: None,
+        max_duration: None,
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
     };
 
     let summary =
@@ -2136,6 +4364,16 @@ async fn test_auto_advance_all_targets_blocked() {
         root: dir.path().to_path_buf(),
         config_base: dir.path().to_path_buf(),
         auto_advance: true,
+        dry_run: false,
+        wait_deps: false,
+        max_dep_depth: None,
+        resume: false,
+        events: None,
+        max_duration: None,
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
     };
 
     let summary =
@@ -2168,6 +4406,16 @@ async fn test_auto_advance_single_target_blocked() {
         root: dir.path().to_path_buf(),
         config_base: dir.path().to_path_buf(),
         auto_advance: true,
+        dry_run: false,
+        wait_deps: false,
+        max_dep_depth: None,
+        resume: false,
+        events: None,
+        max_duration: None,
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
     };
 
     let summary =
@@ -2207,6 +4455,16 @@ async fn test_auto_advance_circuit_breaker_not_tripped() {
         root: dir.path().to_path_buf(),
         config_base: dir.path().to_path_buf(),
         auto_advance: true,
+        dry_run: false,
+        wait_deps: false,
+        max_dep_depth: None,
+        resume: false,
+        events: None,
+        max_duration: None,
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
     };
 
     let summary =
@@ -2245,6 +4503,16 @@ async fn test_auto_advance_backward_compat() {
         root: dir.path().to_path_buf(),
         config_base: dir.path().to_path_buf(),
         auto_advance: false,
+        dry_run: false,
+        wait_deps: false,
+        max_dep_depth: None,
+        resume: false,
+        events: None,
+        max_duration: None,
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
     };
 
     let summary =
@@ -2289,6 +4557,16 @@ async fn test_filter_restricts_scheduler_to_matching_items() {
         root: dir.path().to_path_buf(),
         config_base: dir.path().to_path_buf(),
         auto_advance: false,
+        dry_run: false,
+        wait_deps: false,
+        max_dep_depth: None,
+        resume: false,
+        events: None,
+        max_duration: None,
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
     };
 
     let summary =
@@ -2321,6 +4599,76 @@ async fn test_filter_no_matching_items_halts() {
         root: dir.path().to_path_buf(),
         config_base: dir.path().to_path_buf(),
         auto_advance: false,
+        dry_run: false,
+        wait_deps: false,
+        max_dep_depth: None,
+        resume: false,
+        events: None,
+        max_duration: None,
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
+    };
+
+    let summary =
+        scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
+            .await
+            .expect("Scheduler should succeed");
+
+    assert_eq!(summary.halt_reason, HaltReason::NoMatchingItems);
+    assert_eq!(summary.phases_executed, 0);
+}
+
+#[tokio::test]
+async fn scheduler_targets_with_filter_runs_only_matching_targets() {
+    let mut high = make_in_progress_item("WRK-001", "High impact", "build");
+    pg_item::set_impact(&mut high.0, Some(&DimensionLevel::High));
+    let mut low = make_in_progress_item("WRK-002", "Low impact", "build");
+    pg_item::set_impact(&mut low.0, Some(&DimensionLevel::Low));
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![high, low]);
+
+    // Only WRK-001's phases are scripted; running WRK-002 would fail the run
+    let runner = MockAgentRunner::new(vec![
+        Ok(phase_complete_result("WRK-001", "build")),
+        Ok(phase_complete_result("WRK-001", "review")),
+    ]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = RunParams {
+        targets: vec!["WRK-002".to_string(), "WRK-001".to_string()],
+        filter: vec![filter::parse_filter("impact=high").unwrap()],
+        ..run_params(dir.path(), None, 100)
+    };
+
+    let summary =
+        scheduler::run_scheduler(coordinator_handle, Arc::new(runner), config, params, cancel)
+            .await
+            .expect("Scheduler should succeed");
+
+    assert_eq!(summary.items_completed, vec!["WRK-001"]);
+    assert_eq!(summary.phases_executed, 2);
+    assert_eq!(summary.halt_reason, HaltReason::TargetCompleted);
+}
+
+#[tokio::test]
+async fn scheduler_targets_with_no_filter_matches_halts_no_matching_items() {
+    let mut low = make_in_progress_item("WRK-001", "Low impact", "build");
+    pg_item::set_impact(&mut low.0, Some(&DimensionLevel::Low));
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![low]);
+
+    let runner = MockAgentRunner::new(vec![]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = RunParams {
+        filter: vec![filter::parse_filter("impact=high").unwrap()],
+        ..run_params(dir.path(), Some("WRK-001"), 100)
     };
 
     let summary =
@@ -2332,6 +4680,33 @@ async fn test_filter_no_matching_items_halts() {
     assert_eq!(summary.phases_executed, 0);
 }
 
+#[test]
+fn scope_targets_to_filter_reports_skipped_targets_with_reasons() {
+    let mut high = make_item("WRK-001", "High", ItemStatus::Ready);
+    pg_item::set_impact(&mut high.0, Some(&DimensionLevel::High));
+    let low = make_item("WRK-002", "Unassessed", ItemStatus::Ready);
+    let criteria = vec![filter::parse_filter("impact=high").unwrap()];
+    let targets: Vec<String> = ["WRK-002", "WRK-001", "WRK-009"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+
+    let (kept, skipped) = scheduler::scope_targets_to_filter(&targets, &criteria, &[high, low]);
+
+    assert_eq!(kept, vec!["WRK-001"]);
+    assert_eq!(skipped.len(), 2);
+    assert_eq!(skipped[0].0, "WRK-002");
+    assert!(
+        skipped[0].1.contains("does not match filter"),
+        "got: {}",
+        skipped[0].1
+    );
+    assert_eq!(
+        skipped[1],
+        ("WRK-009".to_string(), "not in the backlog".to_string())
+    );
+}
+
 #[tokio::test]
 async fn test_filter_all_exhausted_halts() {
     let mut done_item = make_item("WRK-001", "Done high impact", ItemStatus::Done);
@@ -2351,6 +4726,16 @@ async fn test_filter_all_exhausted_halts() {
         root: dir.path().to_path_buf(),
         config_base: dir.path().to_path_buf(),
         auto_advance: false,
+        dry_run: false,
+        wait_deps: false,
+        max_dep_depth: None,
+        resume: false,
+        events: None,
+        max_duration: None,
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
     };
 
     let summary =
@@ -2387,6 +4772,16 @@ async fn test_integration_single_target_backward_compat() {
         root: dir.path().to_path_buf(),
         config_base: dir.path().to_path_buf(),
         auto_advance: false,
+        dry_run: false,
+        wait_deps: false,
+        max_dep_depth: None,
+        resume: false,
+        events: None,
+        max_duration: None,
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
     };
 
     let summary =
@@ -2435,6 +4830,16 @@ async fn test_integration_multi_target_sequential() {
         root: dir.path().to_path_buf(),
         config_base: dir.path().to_path_buf(),
         auto_advance: false,
+        dry_run: false,
+        wait_deps: false,
+        max_dep_depth: None,
+        resume: false,
+        events: None,
+        max_duration: None,
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
     };
 
     let summary =
@@ -2475,6 +4880,16 @@ async fn test_integration_multi_target_with_block() {
         root: dir.path().to_path_buf(),
         config_base: dir.path().to_path_buf(),
         auto_advance: false,
+        dry_run: false,
+        wait_deps: false,
+        max_dep_depth: None,
+        resume: false,
+        events: None,
+        max_duration: None,
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
     };
 
     let summary =
@@ -2518,6 +4933,16 @@ async fn test_integration_filter_impact_high() {
         root: dir.path().to_path_buf(),
         config_base: dir.path().to_path_buf(),
         auto_advance: false,
+        dry_run: false,
+        wait_deps: false,
+        max_dep_depth: None,
+        resume: false,
+        events: None,
+        max_duration: None,
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
     };
 
     let summary =
@@ -2553,6 +4978,16 @@ async fn test_integration_filter_no_matches() {
         root: dir.path().to_path_buf(),
         config_base: dir.path().to_path_buf(),
         auto_advance: false,
+        dry_run: false,
+        wait_deps: false,
+        max_dep_depth: None,
+        resume: false,
+        events: None,
+        max_duration: None,
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
     };
 
     let summary =
@@ -2609,7 +5044,7 @@ async fn cleanup_done_via_handle_phase_success() {
 
     assert_eq!(summary.items_completed, vec!["WRK-001"]);
     assert!(summary.items_blocked.is_empty());
-    assert_eq!(summary.halt_reason, HaltReason::AllDoneOrBlocked);
+    assert_eq!(summary.halt_reason, HaltReason::AllDone);
 }
 
 #[tokio::test]
@@ -2657,7 +5092,7 @@ async fn cleanup_blocked_via_handle_phase_blocked() {
 
     assert!(summary.items_completed.is_empty());
     assert_eq!(summary.items_blocked, vec!["WRK-001"]);
-    assert_eq!(summary.halt_reason, HaltReason::AllDoneOrBlocked);
+    assert_eq!(summary.halt_reason, HaltReason::AllBlocked);
 }
 
 #[tokio::test]
@@ -2723,7 +5158,7 @@ async fn many_items_complete_with_bounded_summaries() {
 
     assert_eq!(summary.items_completed.len(), 4);
     assert!(summary.items_blocked.is_empty());
-    assert_eq!(summary.halt_reason, HaltReason::AllDoneOrBlocked);
+    assert_eq!(summary.halt_reason, HaltReason::AllDone);
 }
 
 #[tokio::test]
@@ -2778,6 +5213,16 @@ async fn test_multi_filter_no_matching_items_halts() {
         root: dir.path().to_path_buf(),
         config_base: dir.path().to_path_buf(),
         auto_advance: false,
+        dry_run: false,
+        wait_deps: false,
+        max_dep_depth: None,
+        resume: false,
+        events: None,
+        max_duration: None,
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
     };
 
     let summary =
@@ -2816,6 +5261,16 @@ async fn test_multi_filter_exhausted_halts() {
         root: dir.path().to_path_buf(),
         config_base: dir.path().to_path_buf(),
         auto_advance: false,
+        dry_run: false,
+        wait_deps: false,
+        max_dep_depth: None,
+        resume: false,
+        events: None,
+        max_duration: None,
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
     };
 
     let summary =
@@ -2826,3 +5281,163 @@ async fn test_multi_filter_exhausted_halts() {
     assert!(summary.items_completed.contains(&"WRK-001".to_string()));
     assert_eq!(summary.halt_reason, HaltReason::FilterExhausted);
 }
+
+// ============================================================
+// Dry run
+// ============================================================
+
+fn dry_run_params(root: &Path, targets: Vec<String>, cap: u32) -> RunParams {
+    RunParams {
+        targets,
+        filter: vec![],
+        cap,
+        root: root.to_path_buf(),
+        config_base: root.to_path_buf(),
+        auto_advance: false,
+        dry_run: true,
+        wait_deps: false,
+        max_dep_depth: None,
+        resume: false,
+        events: None,
+        max_duration: None,
+        parallel_targets: false,
+        watch: None,
+        trace: None,
+        event_log: None,
+    }
+}
+
+#[tokio::test]
+async fn dry_run_plans_phases_without_spawning_or_mutating() {
+    let item1 = make_in_progress_item("WRK-001", "First", "build");
+    let item2 = make_ready_item("WRK-002", "Second", None);
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item1, item2]);
+
+    // Any agent call would fail the run: the mock has no results queued.
+    let runner = MockAgentRunner::new(vec![]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = dry_run_params(dir.path(), vec![], 100);
+
+    let summary = scheduler::run_scheduler(
+        coordinator_handle.clone(),
+        Arc::new(runner),
+        config,
+        params,
+        cancel,
+    )
+    .await
+    .expect("Dry run should succeed");
+
+    assert_eq!(summary.halt_reason, HaltReason::AllDone);
+    assert_eq!(summary.phases_executed, 4);
+    assert_eq!(
+        summary.planned_phases,
+        vec![
+            (
+                "WRK-001".to_string(),
+                vec!["build".to_string(), "review".to_string()]
+            ),
+            (
+                "WRK-002".to_string(),
+                vec!["build".to_string(), "review".to_string()]
+            ),
+        ]
+    );
+
+    // Backlog is untouched
+    let snapshot = coordinator_handle.get_snapshot().await.unwrap();
+    let wrk1 = snapshot.iter().find(|i| i.id() == "WRK-001").unwrap();
+    let wrk2 = snapshot.iter().find(|i| i.id() == "WRK-002").unwrap();
+    assert_eq!(wrk1.pg_status(), ItemStatus::InProgress);
+    assert_eq!(wrk1.phase().as_deref(), Some("build"));
+    assert_eq!(wrk2.pg_status(), ItemStatus::Ready);
+}
+
+#[tokio::test]
+async fn dry_run_cap_bounds_simulated_phases() {
+    let item1 = make_in_progress_item("WRK-001", "First", "build");
+    let item2 = make_ready_item("WRK-002", "Second", None);
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item1, item2]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = dry_run_params(dir.path(), vec![], 3);
+
+    let summary = scheduler::run_scheduler(
+        coordinator_handle,
+        Arc::new(MockAgentRunner::new(vec![])),
+        config,
+        params,
+        cancel,
+    )
+    .await
+    .expect("Dry run should succeed");
+
+    assert_eq!(summary.halt_reason, HaltReason::CapReached);
+    assert_eq!(summary.phases_executed, 3);
+    assert_eq!(summary.planned_phases[1].1, vec!["build".to_string()]);
+}
+
+#[tokio::test]
+async fn dry_run_multi_target_reports_target_completed() {
+    let item1 = make_in_progress_item("WRK-001", "First", "build");
+    let item2 = make_in_progress_item("WRK-002", "Second", "review");
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item1, item2]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let params = dry_run_params(
+        dir.path(),
+        vec!["WRK-002".to_string(), "WRK-001".to_string()],
+        100,
+    );
+
+    let summary = scheduler::run_scheduler(
+        coordinator_handle,
+        Arc::new(MockAgentRunner::new(vec![])),
+        config,
+        params,
+        cancel,
+    )
+    .await
+    .expect("Dry run should succeed");
+
+    assert_eq!(summary.halt_reason, HaltReason::TargetCompleted);
+    assert_eq!(summary.items_completed, vec!["WRK-002", "WRK-001"]);
+    assert_eq!(summary.planned_phases[0].0, "WRK-002");
+}
+
+#[tokio::test]
+async fn dry_run_filter_reports_no_matching_items() {
+    let mut item = make_in_progress_item("WRK-001", "Low impact", "build");
+    pg_item::set_impact(&mut item.0, Some(&DimensionLevel::Low));
+    let (coordinator_handle, _coord_task, dir) = setup_coordinator_with_items(vec![item]);
+
+    let mut config = default_config();
+    config.pipelines = simple_pipeline();
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let mut params = dry_run_params(dir.path(), vec![], 100);
+    params.filter = vec![filter::parse_filter("impact=high").unwrap()];
+
+    let summary = scheduler::run_scheduler(
+        coordinator_handle,
+        Arc::new(MockAgentRunner::new(vec![])),
+        config,
+        params,
+        cancel,
+    )
+    .await
+    .expect("Dry run should succeed");
+
+    assert_eq!(summary.halt_reason, HaltReason::NoMatchingItems);
+    assert!(summary.planned_phases.is_empty());
+}
@@ -110,6 +110,7 @@ fn json_round_trip_phase_result_full() {
         commit_summary: None,
         duplicates: Vec::new(),
         description: None,
+        confidence: None,
     };
 
     let json = serde_json::to_string_pretty(&result).unwrap();
@@ -132,6 +133,7 @@ fn json_round_trip_phase_result_minimal() {
         commit_summary: None,
         duplicates: Vec::new(),
         description: None,
+        confidence: None,
     };
 
     let json = serde_json::to_string(&result).unwrap();
@@ -154,6 +156,7 @@ fn json_round_trip_phase_result_blocked() {
         commit_summary: None,
         duplicates: Vec::new(),
         description: None,
+        confidence: None,
     };
 
     let json = serde_json::to_string(&result).unwrap();
@@ -176,6 +179,7 @@ fn json_round_trip_phase_result_subphase_complete() {
         commit_summary: None,
         duplicates: Vec::new(),
         description: None,
+        confidence: None,
     };
 
     let json = serde_json::to_string(&result).unwrap();
@@ -280,6 +284,7 @@ fn yaml_round_trip_item_update_variants() {
         }),
         ItemUpdate::SetPipelineType("feature".to_string()),
         ItemUpdate::SetLastPhaseCommit("abc123def456".to_string()),
+        ItemUpdate::RecordPhaseCompleted("build".to_string()),
         ItemUpdate::SetDescription(StructuredDescription {
             context: "Add dark mode toggle".to_string(),
             problem: String::new(),
@@ -330,6 +335,7 @@ fn yaml_round_trip_phase_execution_result_variants() {
             commit_summary: None,
             duplicates: Vec::new(),
             description: None,
+            confidence: None,
         }),
         PhaseExecutionResult::SubphaseComplete(PhaseResult {
             item_id: "WRK-001".to_string(),
@@ -344,6 +350,7 @@ fn yaml_round_trip_phase_execution_result_variants() {
             commit_summary: None,
             duplicates: Vec::new(),
             description: None,
+            confidence: None,
         }),
         PhaseExecutionResult::Failed("Something went wrong".to_string()),
         PhaseExecutionResult::Blocked("Needs human review".to_string()),
@@ -371,6 +378,7 @@ fn json_round_trip_phase_result_with_new_fields() {
         commit_summary: None,
         duplicates: Vec::new(),
         description: None,
+        confidence: None,
     };
 
     let json = serde_json::to_string_pretty(&result).unwrap();
@@ -393,6 +401,7 @@ fn json_round_trip_phase_result_without_new_fields() {
         commit_summary: None,
         duplicates: Vec::new(),
         description: None,
+        confidence: None,
     };
 
     let json = serde_json::to_string(&result).unwrap();
@@ -527,6 +536,28 @@ fn phase_result_json_with_empty_description_object() {
     assert!(result.description.as_ref().unwrap().is_empty());
 }
 
+#[test]
+fn phase_result_json_confidence_is_optional() {
+    let json = r#"{
+        "item_id": "WRK-001",
+        "phase": "build",
+        "result": "phase_complete",
+        "summary": "Built",
+        "confidence": 0.25
+    }"#;
+    let result: PhaseResult = serde_json::from_str(json).unwrap();
+    assert_eq!(result.confidence, Some(0.25));
+
+    let json = r#"{
+        "item_id": "WRK-001",
+        "phase": "build",
+        "result": "phase_complete",
+        "summary": "Built"
+    }"#;
+    let result: PhaseResult = serde_json::from_str(json).unwrap();
+    assert_eq!(result.confidence, None);
+}
+
 // --- FollowUp flexible deserialization ---
 
 #[test]
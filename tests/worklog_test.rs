@@ -1,5 +1,6 @@
 use std::fs;
 
+use phase_golem::config::WorklogNaming;
 use tempfile::TempDir;
 
 #[test]
@@ -9,6 +10,7 @@ fn write_entry_creates_file() {
 
     phase_golem::worklog::write_entry(
         &worklog_dir,
+        WorklogNaming::PerMonth,
         "WRK-001",
         "Test item",
         "Review",
@@ -52,6 +54,7 @@ fn write_entry_contains_expected_fields() {
 
     phase_golem::worklog::write_entry(
         &worklog_dir,
+        WorklogNaming::PerMonth,
         "WRK-001",
         "Test item",
         "Build",
@@ -88,6 +91,7 @@ fn write_entry_appends_chronologically() {
     // Write first entry
     phase_golem::worklog::write_entry(
         &worklog_dir,
+        WorklogNaming::PerMonth,
         "WRK-001",
         "Test item",
         "Build",
@@ -99,6 +103,7 @@ fn write_entry_appends_chronologically() {
     // Write second entry
     phase_golem::worklog::write_entry(
         &worklog_dir,
+        WorklogNaming::PerMonth,
         "WRK-002",
         "Second item",
         "Review",
@@ -134,6 +139,7 @@ fn write_entry_creates_parent_dirs() {
 
     phase_golem::worklog::write_entry(
         &worklog_dir,
+        WorklogNaming::PerMonth,
         "WRK-001",
         "Test item",
         "Design",
@@ -147,3 +153,34 @@ fn write_entry_creates_parent_dirs() {
         "Deeply nested worklog directory should exist"
     );
 }
+
+#[test]
+fn write_jsonl_entry_appends_parseable_line() {
+    let dir = TempDir::new().expect("Failed to create temp dir");
+    let worklog_dir = dir.path().join("_worklog");
+
+    phase_golem::worklog::write_jsonl_entry(
+        &worklog_dir,
+        WorklogNaming::Single,
+        "WRK-001",
+        "Test item",
+        "build",
+        "Complete",
+        "Implemented \"login\"",
+        None,
+    )
+    .expect("Failed to write entry");
+
+    let content = fs::read_to_string(worklog_dir.join("worklog.jsonl")).unwrap();
+    assert!(content.ends_with('\n'));
+    assert_eq!(content.lines().count(), 1);
+
+    let entry: serde_json::Value = serde_json::from_str(content.trim_end()).unwrap();
+    assert!(entry["timestamp"].is_string());
+    assert_eq!(entry["item_id"], "WRK-001");
+    assert_eq!(entry["title"], "Test item");
+    assert_eq!(entry["phase"], "build");
+    assert_eq!(entry["outcome"], "Complete");
+    assert_eq!(entry["summary"], "Implemented \"login\"");
+    assert!(entry.get("result").is_none());
+}